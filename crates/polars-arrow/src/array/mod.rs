@@ -646,6 +646,136 @@ pub fn clone(array: &dyn Array) -> Box<dyn Array> {
     }
 }
 
+/// Returns the dynamic [`Array`] with its [`ArrowDataType`] replaced by `dtype`.
+///
+/// This is mostly useful to add or strip an [`ArrowDataType::Extension`].
+/// # Panics
+/// Panics iff `dtype`'s physical type differs from the array's.
+pub fn with_dtype(array: &dyn Array, dtype: ArrowDataType) -> Box<dyn Array> {
+    use crate::datatypes::PhysicalType::*;
+    assert_eq!(
+        array.dtype().to_physical_type(),
+        dtype.to_physical_type(),
+        "cannot change the physical type of an array"
+    );
+
+    macro_rules! downcast {
+        ($ty:ty) => {
+            array.as_any().downcast_ref::<$ty>().unwrap()
+        };
+    }
+    macro_rules! with_offsets {
+        ($ty:ty) => {{
+            let arr = downcast!($ty);
+            // SAFETY: the offsets and values come from a valid array.
+            Box::new(unsafe {
+                <$ty>::new_unchecked(
+                    dtype,
+                    arr.offsets().clone(),
+                    arr.values().clone(),
+                    arr.validity().cloned(),
+                )
+            })
+        }};
+    }
+    macro_rules! with_views {
+        ($ty:ty) => {{
+            let arr = downcast!($ty);
+            // SAFETY: the views and buffers come from a valid array.
+            Box::new(unsafe {
+                <$ty>::new_unchecked(
+                    dtype,
+                    arr.views().clone(),
+                    arr.data_buffers().clone(),
+                    arr.validity().cloned(),
+                    arr.total_bytes_len(),
+                    arr.total_buffer_len(),
+                )
+            })
+        }};
+    }
+    macro_rules! with_list {
+        ($ty:ty) => {{
+            let arr = downcast!($ty);
+            Box::new(<$ty>::new(
+                dtype,
+                arr.offsets().clone(),
+                arr.values().clone(),
+                arr.validity().cloned(),
+            ))
+        }};
+    }
+
+    match dtype.to_physical_type() {
+        Null => Box::new(NullArray::new(dtype, array.len())),
+        Boolean => {
+            let arr = downcast!(BooleanArray);
+            Box::new(BooleanArray::new(
+                dtype,
+                arr.values().clone(),
+                arr.validity().cloned(),
+            ))
+        },
+        Primitive(primitive) => with_match_primitive_type_full!(primitive, |$T| {
+            Box::new(downcast!(PrimitiveArray<$T>).clone().to(dtype))
+        }),
+        Binary => with_offsets!(BinaryArray<i32>),
+        LargeBinary => with_offsets!(BinaryArray<i64>),
+        FixedSizeBinary => Box::new(downcast!(FixedSizeBinaryArray).clone().to(dtype)),
+        Utf8 => with_offsets!(Utf8Array<i32>),
+        LargeUtf8 => with_offsets!(Utf8Array<i64>),
+        List => with_list!(ListArray<i32>),
+        LargeList => with_list!(ListArray<i64>),
+        FixedSizeList => {
+            let arr = downcast!(FixedSizeListArray);
+            Box::new(FixedSizeListArray::new(
+                dtype,
+                arr.len(),
+                arr.values().clone(),
+                arr.validity().cloned(),
+            ))
+        },
+        Struct => {
+            let arr = downcast!(StructArray);
+            Box::new(StructArray::new(
+                dtype,
+                arr.len(),
+                arr.values().to_vec(),
+                arr.validity().cloned(),
+            ))
+        },
+        Union => {
+            let arr = downcast!(UnionArray);
+            Box::new(UnionArray::new(
+                dtype,
+                arr.types().clone(),
+                arr.fields().clone(),
+                arr.offsets().cloned(),
+            ))
+        },
+        Map => {
+            let arr = downcast!(MapArray);
+            Box::new(MapArray::new(
+                dtype,
+                arr.offsets().clone(),
+                arr.field().clone(),
+                arr.validity().cloned(),
+            ))
+        },
+        BinaryView => with_views!(BinaryViewArray),
+        Utf8View => with_views!(Utf8ViewArray),
+        Dictionary(key_type) => {
+            match_integer_type!(key_type, |$T| {
+                let arr = downcast!(DictionaryArray<$T>);
+                // SAFETY: the keys and values come from a valid array.
+                Box::new(unsafe {
+                    DictionaryArray::<$T>::try_new_unchecked(dtype, arr.keys().clone(), arr.values().clone())
+                }.unwrap())
+            })
+        },
+    }
+}
+
 // see https://users.rust-lang.org/t/generic-for-dyn-a-or-box-dyn-a-or-arc-dyn-a/69430/3
 // for details
 impl<'a> AsRef<dyn Array + 'a> for dyn Array {
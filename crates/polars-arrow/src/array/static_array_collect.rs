@@ -351,6 +351,7 @@ impl<T: TrivialIntoBytes> IntoBytes for T {
 impl TrivialIntoBytes for Vec<u8> {}
 impl TrivialIntoBytes for Cow<'_, [u8]> {}
 impl TrivialIntoBytes for &[u8] {}
+impl<const N: usize> TrivialIntoBytes for [u8; N] {}
 impl TrivialIntoBytes for String {}
 impl TrivialIntoBytes for &str {}
 impl<'a> IntoBytes for Cow<'a, str> {
@@ -9,7 +9,7 @@ use super::{Dictionaries, IpcBuffer, Node};
 use crate::array::*;
 use crate::datatypes::{ArrowDataType, Field, PhysicalType};
 use crate::io::ipc::IpcField;
-use crate::types::{PrimitiveType, months_days_ns};
+use crate::types::{PrimitiveType, i256, months_days_ns};
 use crate::{match_integer_type, with_match_primitive_type_full};
 
 #[allow(clippy::too_many_arguments)]
@@ -57,6 +57,18 @@ pub fn read<R: Read + Seek>(
             scratch,
         )
         .map(|x| x.boxed()),
+        Primitive(PrimitiveType::Int256) => read_primitive::<i256, _>(
+            field_nodes,
+            dtype,
+            buffers,
+            reader,
+            block_offset,
+            is_little_endian,
+            compression,
+            limit,
+            scratch,
+        )
+        .map(|x| x.boxed()),
         Primitive(primitive) => with_match_primitive_type_full!(primitive, |$T| {
             read_primitive::<$T, _>(
                 field_nodes,
@@ -259,6 +259,12 @@ fn set_variadic_buffer_counts(counts: &mut Vec<i64>, array: &dyn Array) {
                 set_variadic_buffer_counts(counts, array.as_ref())
             }
         },
+        ArrowDataType::Union(_) => {
+            let array = array.as_any().downcast_ref::<UnionArray>().unwrap();
+            for array in array.fields() {
+                set_variadic_buffer_counts(counts, array.as_ref())
+            }
+        },
         ArrowDataType::LargeList(_) => {
             // Subslicing can change the variadic buffer count, so we have to
             // slice here as well to stay synchronized.
@@ -271,6 +277,16 @@ fn set_variadic_buffer_counts(counts: &mut Vec<i64>, array: &dyn Array) {
                 .sliced(first.to_usize(), last.to_usize() - first.to_usize());
             set_variadic_buffer_counts(counts, &*subslice)
         },
+        ArrowDataType::Map(_, _) => {
+            let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+            let offsets = array.offsets().buffer();
+            let first = *offsets.first().unwrap();
+            let last = *offsets.last().unwrap();
+            let subslice = array
+                .field()
+                .sliced(first.to_usize(), last.to_usize() - first.to_usize());
+            set_variadic_buffer_counts(counts, &*subslice)
+        },
         ArrowDataType::FixedSizeList(_, _) => {
             let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
             set_variadic_buffer_counts(counts, array.values().as_ref())
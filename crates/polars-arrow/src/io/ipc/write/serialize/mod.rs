@@ -6,10 +6,10 @@ use super::super::endianness::is_native_little_endian;
 use super::common::{Compression, pad_to_64};
 use crate::array::*;
 use crate::bitmap::Bitmap;
-use crate::datatypes::PhysicalType;
+use crate::datatypes::{PhysicalType, PrimitiveType};
 use crate::offset::{Offset, OffsetsBuffer};
 use crate::trusted_len::TrustedLen;
-use crate::types::NativeType;
+use crate::types::{NativeType, i256, months_days_ns};
 use crate::{match_integer_type, with_match_primitive_type_full};
 mod binary;
 mod binview;
@@ -60,6 +60,22 @@ pub fn write(
             is_little_endian,
             compression,
         ),
+        Primitive(PrimitiveType::MonthDayNano) => write_primitive::<months_days_ns>(
+            array.as_any().downcast_ref().unwrap(),
+            buffers,
+            arrow_data,
+            offset,
+            is_little_endian,
+            compression,
+        ),
+        Primitive(PrimitiveType::Int256) => write_primitive::<i256>(
+            array.as_any().downcast_ref().unwrap(),
+            buffers,
+            arrow_data,
+            offset,
+            is_little_endian,
+            compression,
+        ),
         Primitive(primitive) => with_match_primitive_type_full!(primitive, |$T| {
             let array = array.as_any().downcast_ref().unwrap();
             write_primitive::<$T>(array, buffers, arrow_data, offset, is_little_endian, compression)
@@ -454,9 +454,9 @@ impl PartialEq for f16 {
 #[inline]
 pub fn canonical_f16(x: f16) -> f16 {
     // zero out the sign bit if the f16 is zero.
-    let convert_zero = f16(x.0 & (0x7FFF | (u16::from(x.0 & 0x7FFF == 0) << 15)));
+    let convert_zero = f16(x.0 & (0x7FFF | (u16::from(x.0 & 0x7FFF != 0) << 15)));
     if convert_zero.is_nan() {
-        f16::from_bits(0x7c00) // Canonical quiet NaN.
+        f16::from_bits(0x7e00) // Canonical quiet NaN.
     } else {
         convert_zero
     }
@@ -507,7 +507,7 @@ impl f16 {
 
     #[inline]
     #[must_use]
-    pub(crate) const fn is_nan(self) -> bool {
+    pub const fn is_nan(self) -> bool {
         self.0 & 0x7FFFu16 > 0x7C00u16
     }
 
@@ -655,18 +655,34 @@ impl TotalEq for f16 {
 
 impl TotalOrd for f16 {
     #[inline]
-    fn tot_cmp(&self, _other: &Self) -> std::cmp::Ordering {
-        unimplemented!()
+    fn tot_cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.to_f32().tot_cmp(&other.to_f32())
     }
 }
 
 impl MinMax for f16 {
-    fn nan_min_lt(&self, _other: &Self) -> bool {
-        unimplemented!()
+    #[inline]
+    fn nan_min_lt(&self, other: &Self) -> bool {
+        self.to_f32().nan_min_lt(&other.to_f32())
+    }
+
+    #[inline]
+    fn nan_max_lt(&self, other: &Self) -> bool {
+        self.to_f32().nan_max_lt(&other.to_f32())
+    }
+}
+
+impl num_traits::AsPrimitive<f32> for f16 {
+    #[inline]
+    fn as_(self) -> f32 {
+        self.to_f32()
     }
+}
 
-    fn nan_max_lt(&self, _other: &Self) -> bool {
-        unimplemented!()
+impl num_traits::AsPrimitive<f64> for f16 {
+    #[inline]
+    fn as_(self) -> f64 {
+        self.to_f32() as f64
     }
 }
 
@@ -864,4 +880,17 @@ mod test {
         assert_eq!(format!("{}", f16::from_f32(7.0)), "7".to_string());
         assert_eq!(format!("{:?}", f16::from_f32(7.0)), "7.0".to_string());
     }
+
+    #[test]
+    fn test_canonical_f16() {
+        let neg = f16::from_f32(-1.5);
+        assert_eq!(canonical_f16(neg).to_bits(), neg.to_bits());
+        assert_eq!(canonical_f16(f16::from_f32(-0.0)).to_bits(), 0);
+        let nan = canonical_f16(f16::from_bits(0xFE01));
+        assert!(nan.is_nan());
+        assert_eq!(
+            nan.to_bits(),
+            canonical_f16(f16::from_f32(f32::NAN)).to_bits()
+        );
+    }
 }
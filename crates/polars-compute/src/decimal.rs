@@ -767,49 +767,532 @@ impl DecimalFmtBuffer {
         trim_zeros: bool,
         decimal_comma: bool,
     ) -> &str {
-        let decimal_sep = if decimal_comma { b',' } else { b'.' };
-
         let mut itoa_buf = itoa::Buffer::new();
         let xs = itoa_buf.format(x.unsigned_abs()).as_bytes();
+        self.len = write_decimal(&mut self.data, xs, x < 0, scale, trim_zeros, decimal_comma);
+        unsafe { std::str::from_utf8_unchecked(&self.data[..self.len]) }
+    }
+}
+
+/// Writes the decimal with the given (absolute) digits and scale to `out`, returning the number
+/// of bytes written.
+fn write_decimal(
+    out: &mut [u8],
+    xs: &[u8],
+    negative: bool,
+    scale: usize,
+    trim_zeros: bool,
+    decimal_comma: bool,
+) -> usize {
+    let decimal_sep = if decimal_comma { b',' } else { b'.' };
 
-        if x >= 0 {
-            self.len = 0;
+    let mut len = if negative {
+        out[0] = b'-';
+        1
+    } else {
+        0
+    };
+
+    if scale == 0 {
+        out[len..len + xs.len()].copy_from_slice(xs);
+        len += xs.len();
+    } else {
+        let whole_len = xs.len().saturating_sub(scale);
+        let frac_len = xs.len() - whole_len;
+        if whole_len == 0 {
+            out[len] = b'0';
+            out[len + 1] = decimal_sep;
+            out[len + 2..len + 2 + scale - frac_len].fill(b'0');
+            len += 2 + scale - frac_len;
         } else {
-            self.data[0] = b'-';
-            self.len = 1;
+            out[len..len + whole_len].copy_from_slice(&xs[..whole_len]);
+            out[len + whole_len] = decimal_sep;
+            len += whole_len + 1;
+        }
+
+        out[len..len + frac_len].copy_from_slice(&xs[whole_len..]);
+        len += frac_len;
+
+        if trim_zeros {
+            while out.get(len - 1) == Some(&b'0') {
+                len -= 1;
+            }
+            if out.get(len - 1) == Some(&decimal_sep) {
+                len -= 1;
+            }
+        }
+    }
+
+    len
+}
+
+#[cfg(feature = "dtype-decimal256")]
+pub use decimal256::*;
+
+/// Decimal256 implementation.
+///
+/// Values are `i256`s with up to 76 significant digits. Intermediate results of multiplication
+/// and division can take up to 512 bits, these are handled as little-endian 64-bit limbs.
+#[cfg(feature = "dtype-decimal256")]
+mod decimal256 {
+    use std::sync::LazyLock;
+
+    use arrow::types::i256;
+    use ethnum::{AsI256, I256, U256 as EU256};
+
+    use super::*;
+
+    /// The maximum precision of a Decimal256.
+    pub const DEC256_MAX_PREC: usize = 76;
+
+    pub fn dec256_verify_prec_scale(p: usize, s: usize) -> PolarsResult<()> {
+        polars_ensure!((1..=DEC256_MAX_PREC).contains(&p), InvalidOperation: "precision must be between 1 and 76");
+        polars_ensure!(s <= p, InvalidOperation: "scale must be less than or equal to precision");
+        Ok(())
+    }
+
+    static POW10_U256: LazyLock<[EU256; DEC256_MAX_PREC + 1]> = LazyLock::new(|| {
+        let mut out = [EU256::ONE; DEC256_MAX_PREC + 1];
+        for i in 1..out.len() {
+            out[i] = out[i - 1] * 10;
+        }
+        out
+    });
+
+    /// A 512-bit unsigned integer, as little-endian 64-bit limbs.
+    type U512 = [u64; 8];
+
+    #[inline]
+    fn to_limbs(x: EU256) -> U256 {
+        let (hi, lo) = x.into_words();
+        U256::from_lo_hi(lo, hi)
+    }
+
+    #[inline]
+    fn widening_mul_u256(a: EU256, b: EU256) -> U512 {
+        let (lo, hi) = widening_mul_256(to_limbs(a), to_limbs(b));
+        let mut out = [0; 8];
+        out[..4].copy_from_slice(&lo.0);
+        out[4..].copy_from_slice(&hi.0);
+        out
+    }
+
+    /// Returns x as a 256-bit integer, or None if it doesn't fit.
+    #[inline]
+    fn u512_to_u256(x: &U512) -> Option<EU256> {
+        if x[4..].iter().any(|l| *l != 0) {
+            return None;
+        }
+        Some(EU256::from_words(
+            u128_from_lo_hi(x[2], x[3]),
+            u128_from_lo_hi(x[0], x[1]),
+        ))
+    }
+
+    #[inline]
+    fn u512_add_one(x: &mut U512) {
+        for limb in x.iter_mut() {
+            let carry;
+            (*limb, carry) = limb.overflowing_add(1);
+            if !carry {
+                return;
+            }
+        }
+    }
+
+    /// Divides x in-place by d, returning the remainder.
+    fn u512_divrem_u64(x: &mut U512, d: u64) -> u64 {
+        let mut rem = 0;
+        for limb in x.iter_mut().rev() {
+            // SAFETY: rem < d, so the quotient fits in a u64, and d != 0.
+            let (q, r) = unsafe { divrem_128_64(u128_from_lo_hi(*limb, rem), d) };
+            *limb = q;
+            rem = r;
         }
+        rem
+    }
+
+    /// Returns round(x / 10^e), rounding to nearest even.
+    fn u512_div_pow10(mut x: U512, mut e: usize) -> U512 {
+        // Divide by (at most) 10^19 at a time, the last remainder holds the most significant
+        // digits that are divided out and decides the rounding.
+        let mut rest_is_zero = true;
+        while e > 0 {
+            let k = e.min(19);
+            let d = 10u64.pow(k as u32);
+            let rem = u512_divrem_u64(&mut x, d);
+            e -= k;
+            if e == 0 {
+                let half = d / 2;
+                if rem > half || rem == half && (!rest_is_zero || x[0] % 2 == 1) {
+                    u512_add_one(&mut x);
+                }
+            } else {
+                rest_is_zero &= rem == 0;
+            }
+        }
+        x
+    }
+
+    /// Returns the quotient and remainder of n / d, with d != 0 and d < 2^255.
+    fn u512_divrem_u256(n: &U512, d: EU256) -> (U512, EU256) {
+        let mut q = [0; 8];
+        let mut rem = EU256::ZERO;
+        for i in (0..512).rev() {
+            rem = (rem << 1) | EU256::from((n[i / 64] >> (i % 64)) & 1);
+            if rem >= d {
+                rem -= d;
+                q[i / 64] |= 1 << (i % 64);
+            }
+        }
+        (q, rem)
+    }
+
+    #[inline]
+    fn with_sign(x: EU256, negative: bool) -> i256 {
+        let x = x.as_i256();
+        i256(if negative { -x } else { x })
+    }
 
-        if scale == 0 {
-            self.data[self.len..self.len + xs.len()].copy_from_slice(xs);
-            self.len += xs.len();
+    /// Returns whether the given Decimal256 fits in the given precision.
+    #[inline]
+    pub fn dec256_fits(x: i256, p: usize) -> bool {
+        x.0.unsigned_abs() < POW10_U256[p]
+    }
+
+    /// Returns round(x / 10^e), rounding to nearest even.
+    #[inline]
+    fn div_256_pow10(x: I256, e: usize) -> I256 {
+        if e == 0 {
+            return x;
+        }
+
+        let n = x.unsigned_abs();
+        let d = POW10_U256[e];
+        let (mut q, r) = (n / d, n % d);
+        let half = d / 2;
+        if r > half || r == half && q % 2 == 1 {
+            q += 1;
+        }
+        if x < 0 { -q.as_i256() } else { q.as_i256() }
+    }
+
+    /// Returns x * 10^e, or None if the multiplication overflows.
+    #[inline]
+    fn mul_256_pow10(x: I256, e: usize) -> Option<I256> {
+        x.checked_mul(POW10_U256[e].as_i256())
+    }
+
+    /// Converts a Decimal256 with the given scale to an i256, rounding to nearest even.
+    #[inline]
+    pub fn dec256_to_i256(x: i256, s: usize) -> i256 {
+        i256(div_256_pow10(x.0, s))
+    }
+
+    /// Converts an i256 to a Decimal256 with the given precision and scale,
+    /// returning None if the value doesn't fit.
+    #[inline]
+    pub fn i256_to_dec256(x: i256, p: usize, s: usize) -> Option<i256> {
+        let r = i256(mul_256_pow10(x.0, s)?);
+        dec256_fits(r, p).then_some(r)
+    }
+
+    /// Converts a Decimal256 with the given scale to a f64.
+    #[inline]
+    pub fn dec256_to_f64(x: i256, s: usize) -> f64 {
+        // TODO: correctly rounded result. Splitting off the integer part keeps values
+        // without a fractional part exact.
+        if s == 0 {
+            return x.0.as_f64();
+        }
+        let d = POW10_U256[s].as_i256();
+        (x.0 / d).as_f64() + (x.0 % d).as_f64() / 10f64.powi(s as i32)
+    }
+
+    /// Converts a f64 to a Decimal256 with the given precision and scale, returning
+    /// None if the value doesn't fit.
+    #[inline]
+    pub fn f64_to_dec256(x: f64, p: usize, s: usize) -> Option<i256> {
+        // TODO: correctly rounded result. This rounds multiple times.
+        #[allow(clippy::neg_cmp_op_on_partial_ord)]
+        if !(x.abs() < 10f64.powi(p as i32)) {
+            // Comparison will fail for NaN, making us return None.
+            return None;
+        }
+        let r = i256((x * 10f64.powi(s as i32)).round_ties_even().as_i256());
+        dec256_fits(r, p).then_some(r)
+    }
+
+    /// Converts between two Decimal256s, with a new precision and scale, returning
+    /// None if the value doesn't fit.
+    #[inline]
+    pub fn dec256_rescale(x: i256, old_s: usize, new_p: usize, new_s: usize) -> Option<i256> {
+        let r = if new_s < old_s {
+            i256(div_256_pow10(x.0, old_s - new_s))
+        } else if new_s > old_s {
+            i256(mul_256_pow10(x.0, new_s - old_s)?)
         } else {
-            let whole_len = xs.len().saturating_sub(scale);
-            let frac_len = xs.len() - whole_len;
-            if whole_len == 0 {
-                self.data[self.len] = b'0';
-                self.data[self.len + 1] = decimal_sep;
-                self.data[self.len + 2..self.len + 2 + scale - frac_len].fill(b'0');
-                self.len += 2 + scale - frac_len;
+            x
+        };
+
+        dec256_fits(r, new_p).then_some(r)
+    }
+
+    /// Adds two Decimal256s, assuming they have the same scale.
+    #[inline]
+    pub fn dec256_add(l: i256, r: i256, p: usize) -> Option<i256> {
+        l.0.checked_add(r.0)
+            .map(i256)
+            .filter(|x| dec256_fits(*x, p))
+    }
+
+    /// Subs two Decimal256s, assuming they have the same scale.
+    #[inline]
+    pub fn dec256_sub(l: i256, r: i256, p: usize) -> Option<i256> {
+        l.0.checked_sub(r.0)
+            .map(i256)
+            .filter(|x| dec256_fits(*x, p))
+    }
+
+    /// Multiplies two Decimal256s, assuming they have the same scale s.
+    pub fn dec256_mul(l: i256, r: i256, p: usize, s: usize) -> Option<i256> {
+        // Computes round(l * r / 10^s), rounding to nearest even.
+        let negative = (l.0 < 0) ^ (r.0 < 0);
+        let prod = widening_mul_u256(l.0.unsigned_abs(), r.0.unsigned_abs());
+        let retu = u512_to_u256(&u512_div_pow10(prod, s))?;
+        if retu >= POW10_U256[p] {
+            return None;
+        }
+        Some(with_sign(retu, negative))
+    }
+
+    /// Divides two Decimal256s, assuming they have the same scale s.
+    pub fn dec256_div(l: i256, r: i256, p: usize, s: usize) -> Option<i256> {
+        if r.0 == 0 {
+            return None;
+        }
+
+        // Computes round((l / r) * 10^s), rounding to nearest even.
+        let negative = (l.0 < 0) ^ (r.0 < 0);
+        let ru = r.0.unsigned_abs();
+        let n = widening_mul_u256(l.0.unsigned_abs(), POW10_U256[s]);
+        let (mut retu, rem) = match u512_to_u256(&n) {
+            // Fast path, intermediate product representable as u256.
+            Some(n) => (n / ru, n % ru),
+            None => {
+                let (q, rem) = u512_divrem_u256(&n, ru);
+                (u512_to_u256(&q)?, rem)
+            },
+        };
+
+        // Round to nearest even, the remainder is < 10^76 so doubling can't overflow.
+        let twice_rem = rem << 1;
+        if twice_rem > ru || twice_rem == ru && retu % 2 == 1 {
+            retu += 1;
+        }
+
+        if retu >= POW10_U256[p] {
+            return None;
+        }
+        Some(with_sign(retu, negative))
+    }
+
+    /// Checks if two Decimal256s are equal in value.
+    #[inline]
+    pub fn dec256_eq(lv: i256, ls: usize, rv: i256, rs: usize) -> bool {
+        // Rescale to largest scale. If this overflows the numbers can't be equal anyway.
+        if ls < rs {
+            mul_256_pow10(lv.0, rs - ls).is_some_and(|lv| lv == rv.0)
+        } else if ls > rs {
+            mul_256_pow10(rv.0, ls - rs).is_some_and(|rv| lv.0 == rv)
+        } else {
+            lv == rv
+        }
+    }
+
+    /// Checks how two Decimal256s compare.
+    #[inline]
+    pub fn dec256_cmp(lv: i256, ls: usize, rv: i256, rs: usize) -> Ordering {
+        // Rescale to largest scale. If this overflows we know the magnitude of the
+        // (attempted) rescaled number is larger and we can resolve the answer just
+        // using its sign.
+        if ls < rs {
+            match mul_256_pow10(lv.0, rs - ls) {
+                Some(lv) => lv.cmp(&rv.0),
+                None if lv.0 < 0 => Ordering::Less,
+                None => Ordering::Greater,
+            }
+        } else if ls > rs {
+            match mul_256_pow10(rv.0, ls - rs) {
+                Some(rv) => lv.0.cmp(&rv),
+                None if rv.0 > 0 => Ordering::Less,
+                None => Ordering::Greater,
+            }
+        } else {
+            lv.cmp(&rv)
+        }
+    }
+
+    /// Parses unsigned digits, returning None on any other character or on overflow.
+    fn parse_u256(digits: &[u8]) -> Option<EU256> {
+        digits.iter().try_fold(EU256::ZERO, |acc, b| {
+            let d = b.checked_sub(b'0').filter(|d| *d < 10)?;
+            acc.checked_mul(EU256::from(10u8))?
+                .checked_add(EU256::from(d))
+        })
+    }
+
+    /// Deserialize bytes to a single i256 representing a decimal, at a specified
+    /// precision and scale. See [`str_to_dec128`] for the accepted formats.
+    pub fn str_to_dec256(bytes: &[u8], p: usize, s: usize, decimal_comma: bool) -> Option<i256> {
+        assert!(dec256_verify_prec_scale(p, s).is_ok());
+
+        let decimal_sep = if decimal_comma { b',' } else { b'.' };
+
+        let separator = bytes
+            .iter()
+            .position(|b| *b == decimal_sep)
+            .unwrap_or(bytes.len());
+        let (mut int, mut frac) = bytes.split_at(separator);
+
+        // Skip period.
+        if !frac.is_empty() {
+            frac = &frac[1..];
+        }
+
+        // Trim trailing zeroes.
+        while let Some((b'0', rest)) = frac.split_last() {
+            frac = rest;
+        }
+
+        // Skip sign.
+        let negative = match int.first() {
+            Some(s @ (b'+' | b'-')) => {
+                int = &int[1..];
+                *s == b'-'
+            },
+            _ => false,
+        };
+
+        if int.is_empty() && separator == bytes.len() {
+            return None;
+        }
+
+        // Round if digits extend beyond the scale.
+        let (next_digit, all_zero_after);
+        let frac_scale = if frac.len() > s {
+            if !frac[s..].iter().all(|b| b.is_ascii_digit()) {
+                return None;
+            }
+            next_digit = frac[s];
+            all_zero_after = frac[s + 1..].iter().all(|b| *b == b'0');
+            frac = &frac[..s];
+            0
+        } else {
+            next_digit = b'0';
+            all_zero_after = true;
+            s - frac.len()
+        };
+
+        // Parse and combine parts.
+        let mut pint = parse_u256(int)?;
+        let mut pfrac = parse_u256(frac)?;
+
+        // Round-to-even.
+        if next_digit > b'5' || next_digit == b'5' && !all_zero_after {
+            pfrac += 1;
+        } else if next_digit == b'5' {
+            if s == 0 {
+                pint += pint % 2;
             } else {
-                self.data[self.len..self.len + whole_len].copy_from_slice(&xs[..whole_len]);
-                self.data[self.len + whole_len] = decimal_sep;
-                self.len += whole_len + 1;
+                pfrac += pfrac % 2;
             }
+        }
 
-            self.data[self.len..self.len + frac_len].copy_from_slice(&xs[whole_len..]);
-            self.len += frac_len;
+        let ret = pint
+            .checked_mul(POW10_U256[s])?
+            .checked_add(pfrac.checked_mul(POW10_U256[frac_scale])?)?;
+        if ret >= POW10_U256[p] {
+            return None;
+        }
+        Some(with_sign(ret, negative))
+    }
 
-            if trim_zeros {
-                while self.data.get(self.len - 1) == Some(&b'0') {
-                    self.len -= 1;
-                }
-                if self.data.get(self.len - 1) == Some(&decimal_sep) {
-                    self.len -= 1;
-                }
+    /// Encodes a Decimal256 as 32 bytes which order (lexicographically) as the values do.
+    #[inline]
+    pub fn dec256_to_ordered_bytes(x: i256) -> [u8; 32] {
+        let mut bytes = x.0.to_be_bytes();
+        bytes[0] ^= 0x80;
+        bytes
+    }
+
+    /// Decodes a Decimal256 encoded by [`dec256_to_ordered_bytes`].
+    #[inline]
+    pub fn dec256_from_ordered_bytes(bytes: &[u8]) -> i256 {
+        let mut bytes: [u8; 32] = bytes.try_into().unwrap();
+        bytes[0] ^= 0x80;
+        i256(I256::from_be_bytes(bytes))
+    }
+
+    const DEC256_MAX_LEN: usize = 77 + 2;
+
+    #[derive(Clone, Copy)]
+    pub struct Decimal256FmtBuffer {
+        data: [u8; DEC256_MAX_LEN],
+        len: usize,
+    }
+
+    impl Default for Decimal256FmtBuffer {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl Decimal256FmtBuffer {
+        #[inline]
+        pub const fn new() -> Self {
+            Self {
+                data: [0; DEC256_MAX_LEN],
+                len: 0,
             }
         }
 
-        unsafe { std::str::from_utf8_unchecked(&self.data[..self.len]) }
+        pub fn format_dec256(
+            &mut self,
+            x: i256,
+            scale: usize,
+            trim_zeros: bool,
+            decimal_comma: bool,
+        ) -> &str {
+            // Write the digits to the back of a scratch buffer, 19 at a time.
+            const CHUNK: u64 = 10u64.pow(19);
+            let mut digits = [b'0'; DEC256_MAX_LEN];
+            let mut start = digits.len();
+            let mut v = x.0.unsigned_abs();
+            let mut itoa_buf = itoa::Buffer::new();
+            loop {
+                let (q, r) = (v / CHUNK as u128, (v % CHUNK as u128).as_u64());
+                let rs = itoa_buf.format(r).as_bytes();
+                if q == 0 {
+                    start -= rs.len();
+                    digits[start..start + rs.len()].copy_from_slice(rs);
+                    break;
+                }
+                start -= 19;
+                digits[start + 19 - rs.len()..start + 19].copy_from_slice(rs);
+                v = q;
+            }
+
+            self.len = write_decimal(
+                &mut self.data,
+                &digits[start..],
+                x.0 < 0,
+                scale,
+                trim_zeros,
+                decimal_comma,
+            );
+            unsafe { std::str::from_utf8_unchecked(&self.data[..self.len]) }
+        }
     }
 }
 
@@ -1002,4 +1485,182 @@ mod test {
             }
         }
     }
+
+    #[cfg(feature = "dtype-decimal256")]
+    mod dec256 {
+        use arrow::types::i256;
+
+        use super::*;
+
+        fn bigdecimal_to_dec256(x: &BigDecimal, p: usize, s: usize) -> Option<i256> {
+            let n = x
+                .with_scale_round(s as i64, RoundingMode::HalfEven)
+                .into_bigint_and_scale()
+                .0;
+            let r = i256(ethnum::I256::from_str_radix(&n.to_string(), 10).ok()?);
+            dec256_fits(r, p).then_some(r)
+        }
+
+        fn dec256_to_bigdecimal(x: i256, s: usize) -> BigDecimal {
+            BigDecimal::from_bigint(x.to_string().parse::<BigInt>().unwrap(), s as i64)
+        }
+
+        static INTERESTING_SCALE_PREC: [usize; 13] =
+            [0, 1, 2, 5, 11, 18, 27, 38, 45, 57, 64, 75, 76];
+
+        static INTERESTING_VALUES_256: LazyLock<Vec<BigDecimal>> = LazyLock::new(|| {
+            let mut r = SmallRng::seed_from_u64(42);
+            let mut base = Vec::new();
+            base.extend((0..255).map(|e| BigDecimal::from(BigInt::from(2u8).pow(e))));
+            base.extend((0..77).map(|e| BigDecimal::from(BigInt::from(10u8).pow(e))));
+            base.extend((0..32).map(BigDecimal::from));
+            base.extend((0..32).map(|_| BigDecimal::from(r.random::<u64>())));
+            base.extend((0..32).map(|_| BigDecimal::from(r.random::<u128>())));
+            base.extend(base.clone().into_iter().map(|x| -x));
+
+            let mut out = PlHashSet::default();
+            out.extend(base.iter().cloned());
+
+            // The full cross product is too large for the wider range, so sample it.
+            let zero = BigDecimal::from(0u8);
+            for _ in 0..2_000 {
+                let l = base.choose(&mut r).unwrap();
+                let rhs = base.choose(&mut r).unwrap();
+                out.insert(l + rhs);
+                out.insert(l * rhs);
+                if *rhs != zero {
+                    out.insert((l.clone().with_prec(100) / rhs).with_prec(100));
+                }
+            }
+
+            let mut out: Vec<_> = out.into_iter().collect();
+            out.sort_by_key(|d| d.abs());
+            out
+        });
+
+        fn check_binary_op(
+            op: impl Fn(i256, i256, usize, usize) -> Option<i256>,
+            opb: impl Fn(&BigDecimal, &BigDecimal) -> Option<BigDecimal>,
+        ) {
+            for &p in &INTERESTING_SCALE_PREC {
+                for &s in &INTERESTING_SCALE_PREC {
+                    if s > p || p == 0 {
+                        continue;
+                    }
+                    let values: Vec<_> = INTERESTING_VALUES_256
+                        .iter()
+                        .map_while(|x| bigdecimal_to_dec256(x, p, s))
+                        .map(|d| (d, dec256_to_bigdecimal(d, s)))
+                        .collect();
+                    let mut r = SmallRng::seed_from_u64(42);
+                    for _ in 0..1_000 {
+                        let (x, xb) = values.choose(&mut r).unwrap();
+                        let (y, yb) = values.choose(&mut r).unwrap();
+                        let out = op(*x, *y, p, s);
+                        let outb = opb(xb, yb).and_then(|v| bigdecimal_to_dec256(&v, p, s));
+                        assert_eq!(out, outb, "{x} {y} p={p} s={s}");
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn test_mul() {
+            check_binary_op(dec256_mul, |x, y| Some(x * y));
+        }
+
+        #[test]
+        fn test_div() {
+            let zero = BigDecimal::from(0u8);
+            check_binary_op(dec256_div, |x, y| {
+                // Divide with enough digits to round correctly.
+                (*y != zero).then(|| x.clone().with_prec(200) / y)
+            });
+        }
+
+        fn d(v: i128) -> i256 {
+            i256(ethnum::I256::from(v))
+        }
+
+        #[test]
+        fn test_rescale() {
+            let big = i256(ethnum::I256::from(123456) * ethnum::I256::from(10).pow(38));
+            assert_eq!(dec256_rescale(d(123456), 2, 76, 40), Some(big));
+            assert_eq!(dec256_rescale(big, 40, 76, 2), Some(d(123456)));
+            assert_eq!(dec256_rescale(big, 40, 43, 40), None);
+            assert_eq!(dec256_rescale(d(125), 2, 76, 0), Some(d(1)));
+            assert_eq!(dec256_rescale(d(-150), 2, 76, 0), Some(d(-2)));
+        }
+
+        #[test]
+        fn str_dec_roundtrip() {
+            let mut buf = Decimal256FmtBuffer::new();
+            for &p in &INTERESTING_SCALE_PREC {
+                for &s in &INTERESTING_SCALE_PREC {
+                    if s > p || p == 0 {
+                        continue;
+                    }
+                    let values = INTERESTING_VALUES_256
+                        .iter()
+                        .map_while(|x| bigdecimal_to_dec256(x, p, s));
+                    for d in values {
+                        for d_comma in [true, false] {
+                            let fmt = buf.format_dec256(d, s, false, d_comma);
+                            assert_eq!(
+                                fmt.replace(',', "."),
+                                dec256_to_bigdecimal(d, s).to_plain_string()
+                            );
+                            let d2 = str_to_dec256(fmt.as_bytes(), p, s, d_comma);
+                            assert_eq!(d, d2.unwrap());
+                        }
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn test_str_to_dec() {
+            assert_eq!(str_to_dec256(b"-1.25", 76, 1, false), Some(d(-12)));
+            assert_eq!(str_to_dec256(b"+000000.5", 8, 2, false), Some(d(50)));
+            assert_eq!(str_to_dec256(b".5", 8, 5, false), Some(d(50000)));
+            assert_eq!(str_to_dec256(b"5.", 8, 5, false), Some(d(500000)));
+            assert_eq!(str_to_dec256(b"2.25", 5, 1, false), Some(d(22)));
+            assert_eq!(str_to_dec256(b"2.26", 5, 1, false), Some(d(23)));
+            assert_eq!(str_to_dec256(b"1200,90", 8, 2, true), Some(d(120090)));
+
+            assert_eq!(str_to_dec256(b"", 8, 5, false), None);
+            assert_eq!(str_to_dec256(b"-", 8, 5, false), None);
+            assert_eq!(str_to_dec256(b"12.-3", 8, 5, false), None);
+            assert_eq!(str_to_dec256(b"1e3", 5, 1, false), None);
+            assert_eq!(str_to_dec256(b"1200", 3, 0, false), None);
+
+            let digits = "9".repeat(76);
+            assert!(str_to_dec256(digits.as_bytes(), 76, 0, false).is_some());
+            assert!(str_to_dec256(format!("1{digits}").as_bytes(), 76, 0, false).is_none());
+        }
+
+        #[test]
+        fn test_eq_cmp() {
+            let big = i256(d(1).0 << 250);
+            assert!(dec256_eq(d(15), 1, d(150), 2));
+            assert!(!dec256_eq(big, 0, d(1), 10));
+            assert_eq!(dec256_cmp(d(15), 1, d(151), 2), Ordering::Less);
+            assert_eq!(dec256_cmp(big, 0, d(1), 10), Ordering::Greater);
+            assert_eq!(dec256_cmp(d(1), 10, -big, 0), Ordering::Greater);
+            assert_eq!(dec256_cmp(-big, 0, d(1), 10), Ordering::Less);
+        }
+
+        #[test]
+        fn test_ordered_bytes() {
+            let values = [-(1i128 << 100), -5, 0, 3, 1i128 << 120];
+            let bytes: Vec<_> = values
+                .iter()
+                .map(|v| dec256_to_ordered_bytes(d(*v)))
+                .collect();
+            assert!(bytes.is_sorted());
+            for (b, v) in bytes.iter().zip(values) {
+                assert_eq!(dec256_from_ordered_bytes(b), d(v));
+            }
+        }
+    }
 }
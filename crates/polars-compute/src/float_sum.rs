@@ -6,6 +6,8 @@ use arrow::array::{Array, PrimitiveArray};
 use arrow::bitmap::Bitmap;
 use arrow::bitmap::bitmask::BitMask;
 use arrow::types::NativeType;
+#[cfg(feature = "simd")]
+use arrow::types::f16;
 use num_traits::{AsPrimitive, Float};
 
 const STRIPE: usize = 16;
@@ -120,6 +122,24 @@ where
     }
 }
 
+#[cfg(feature = "simd")]
+impl<F> SumBlock<F> for [f16; PAIRWISE_RECURSION_LIMIT]
+where
+    f16: AsPrimitive<F>,
+    F: Float + std::iter::Sum + 'static,
+{
+    fn sum_block_vectorized(&self) -> F {
+        self.iter().map(|x| x.as_()).sum()
+    }
+
+    fn sum_block_vectorized_with_mask(&self, mask: BitMask<'_>) -> F {
+        self.iter()
+            .enumerate()
+            .map(|(idx, x)| if mask.get(idx) { x.as_() } else { F::zero() })
+            .sum()
+    }
+}
+
 #[cfg(feature = "simd")]
 impl<F> SumBlock<F> for [u128; PAIRWISE_RECURSION_LIMIT]
 where
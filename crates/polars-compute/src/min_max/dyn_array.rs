@@ -39,6 +39,7 @@ macro_rules! call {
         let arr = $arr;
 
         use arrow::datatypes::{PhysicalType as PH, PrimitiveType as PR};
+        use arrow::types::f16;
         use PrimitiveArray as PArr;
         use PrimitiveScalar as PScalar;
         match arr.dtype().to_physical_type() {
@@ -53,6 +54,7 @@ macro_rules! call {
             PH::Primitive(PR::UInt32) => call_op!(dt: PArr<u32>, PScalar<u32>, arr, $op$(, $variant)?),
             PH::Primitive(PR::UInt64) => call_op!(dt: PArr<u64>, PScalar<u64>, arr, $op$(, $variant)?),
             PH::Primitive(PR::UInt128) => call_op!(dt: PArr<u128>, PScalar<u128>, arr, $op$(, $variant)?),
+            PH::Primitive(PR::Float16) => call_op!(dt: PArr<f16>, PScalar<f16>, arr, $op$(, $variant)?),
             PH::Primitive(PR::Float32) => call_op!(dt: PArr<f32>, PScalar<f32>, arr, $op$(, $variant)?),
             PH::Primitive(PR::Float64) => call_op!(dt: PArr<f64>, PScalar<f64>, arr, $op$(, $variant)?),
 
@@ -112,6 +112,30 @@ pub fn get_list_builder(
             list_capacity,
             Some(inner_type_logical.clone()),
         )),
+        #[cfg(feature = "dtype-interval")]
+        DataType::Interval => Box::new(
+            ListPrimitiveChunkedBuilder::<Int128Type>::new_with_values_type(
+                name,
+                list_capacity,
+                value_capacity,
+                physical_type,
+                inner_type_logical.clone(),
+            ),
+        ),
+        #[cfg(feature = "dtype-f16")]
+        DataType::Float16 => Box::new(
+            ListPrimitiveChunkedBuilder::<Int16Type>::new_with_values_type(
+                name,
+                list_capacity,
+                value_capacity,
+                physical_type,
+                inner_type_logical.clone(),
+            ),
+        ),
+        #[cfg(feature = "dtype-decimal256")]
+        DataType::Binary if inner_type_logical.is_decimal256() => Box::new(
+            AnonymousOwnedListBuilder::new(name, list_capacity, Some(inner_type_logical.clone())),
+        ),
         #[cfg(feature = "dtype-decimal")]
         DataType::Decimal(_, _) => Box::new(
             ListPrimitiveChunkedBuilder::<Int128Type>::new_with_values_type(
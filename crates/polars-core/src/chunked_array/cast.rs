@@ -78,6 +78,35 @@ fn cast_impl_inner(
     options: CastOptions,
 ) -> PolarsResult<Series> {
     let chunks = match dtype {
+        #[cfg(feature = "dtype-interval")]
+        DataType::Interval => {
+            polars_bail!(
+                InvalidOperation: "casting from {:?} to Interval not supported",
+                chunks.first().map(|arr| arr.dtype())
+            )
+        },
+        #[cfg(feature = "dtype-f16")]
+        DataType::Float16 => {
+            // Round through single precision.
+            let chunks = cast_chunks(chunks, &DataType::Float32, options)?;
+            let out = Series::try_from((name, chunks))?;
+            return Ok(out.f32()?.to_float16().into_series());
+        },
+        #[cfg(feature = "dtype-decimal256")]
+        DataType::Decimal256(precision, scale) => {
+            let s = Series::try_from((name, chunks.to_vec()))?;
+            return crate::chunked_array::logical::cast_to_decimal256(&s, *precision, *scale);
+        },
+        #[cfg(feature = "dtype-uuid")]
+        DataType::Uuid => {
+            let s = Series::try_from((name, chunks.to_vec()))?;
+            return crate::chunked_array::logical::cast_to_uuid(&s);
+        },
+        #[cfg(feature = "dtype-json")]
+        DataType::Json => {
+            let s = Series::try_from((name, chunks.to_vec()))?;
+            return crate::chunked_array::logical::cast_to_json(&s);
+        },
         #[cfg(feature = "dtype-decimal")]
         DataType::Decimal(_, _) => {
             let mut chunks = cast_chunks(chunks, dtype, options)?;
@@ -71,6 +71,11 @@ impl LogicalType for DecimalChunked {
                     .into_series());
             },
 
+            #[cfg(feature = "dtype-decimal256")]
+            DataType::Decimal256(to_prec, to_scale) => {
+                cast_to_decimal256(&self.clone().into_series(), *to_prec, *to_scale)
+            },
+
             dt if dt.is_primitive_numeric()
                 | matches!(dt, DataType::String | DataType::Boolean) =>
             {
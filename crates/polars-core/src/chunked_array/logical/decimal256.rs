@@ -0,0 +1,444 @@
+use std::borrow::Cow;
+
+use arrow::array::BinaryViewArray;
+use arrow::types::i256;
+use polars_compute::decimal::{
+    Decimal256FmtBuffer, dec256_fits, dec256_from_ordered_bytes, dec256_rescale, dec256_to_f64,
+    dec256_to_i256, dec256_to_ordered_bytes, dec256_verify_prec_scale, f64_to_dec256,
+    i256_to_dec256, str_to_dec256,
+};
+
+use super::*;
+use crate::prelude::arity::unary_elementwise;
+use crate::prelude::*;
+
+/// A 256-bit decimal. The values are stored as 32 big-endian bytes with the sign bit flipped
+/// (see [`dec256_to_ordered_bytes`]), so that comparing, sorting and hashing the physical
+/// binary values behaves as it would on the numbers.
+///
+/// The physical type is binary rather than a `PrimitiveArray<i256>`, as there is no `i256`
+/// numeric type to build on: with the ordered encoding, sorting, hashing, comparisons,
+/// row encoding and group-by all reuse the binary implementations. Arithmetic and casts
+/// decode the values, and conversion to and from Arrow goes through `PrimitiveArray<i256>`.
+pub type Decimal256Chunked = Logical<Decimal256Type, BinaryType>;
+
+/// Convert a physical Decimal256 array to an arrow `Decimal256` array.
+pub(crate) fn decimal256_array_to_arrow(
+    arr: &BinaryViewArray,
+    precision: usize,
+    scale: usize,
+) -> PrimitiveArray<i256> {
+    // Null slots may hold empty views, so don't decode those.
+    let values = arr
+        .iter()
+        .map(|v| v.map_or(i256::default(), dec256_from_ordered_bytes))
+        .collect::<Vec<_>>();
+    PrimitiveArray::from_vec(values)
+        .with_validity(arr.validity().cloned())
+        .to(ArrowDataType::Decimal256(precision, scale))
+}
+
+/// Convert an arrow `Decimal256` array to its physical representation.
+pub(crate) fn decimal256_array_from_arrow(arr: &PrimitiveArray<i256>) -> BinaryViewArray {
+    let out: BinaryViewArray = arr
+        .values_iter()
+        .map(|v| dec256_to_ordered_bytes(*v))
+        .collect_arr();
+    out.with_validity(arr.validity().cloned())
+}
+
+/// Cast a Series of another type to a Decimal256, values that don't fit become null.
+pub(crate) fn cast_to_decimal256(
+    s: &Series,
+    precision: usize,
+    scale: usize,
+) -> PolarsResult<Series> {
+    dec256_verify_prec_scale(precision, scale)?;
+    let out = match s.dtype() {
+        DataType::Decimal256(_, _) => {
+            return Ok(s
+                .decimal256()?
+                .with_prec_scale(precision, scale, false)?
+                .into_owned()
+                .into_series());
+        },
+        DataType::Decimal(_, old_s) => {
+            let old_s = *old_s;
+            unary_elementwise(s.decimal()?.physical(), |opt_v| {
+                let v = i256(opt_v?.into());
+                dec256_rescale(v, old_s, precision, scale).map(dec256_to_ordered_bytes)
+            })
+        },
+        DataType::String => unary_elementwise(s.str()?.as_binary().as_ref(), |opt_v| {
+            str_to_dec256(opt_v?, precision, scale, false).map(dec256_to_ordered_bytes)
+        }),
+        dt if dt.is_float() => {
+            let s = s.cast(&DataType::Float64)?;
+            unary_elementwise(s.f64()?, |opt_v| {
+                f64_to_dec256(opt_v?, precision, scale).map(dec256_to_ordered_bytes)
+            })
+        },
+        dt if dt.is_integer() || dt.is_bool() => {
+            let s = s.cast_with_options(&DataType::Int128, CastOptions::NonStrict)?;
+            unary_elementwise(s.i128()?, |opt_v| {
+                i256_to_dec256(i256(opt_v?.into()), precision, scale).map(dec256_to_ordered_bytes)
+            })
+        },
+        dt => polars_bail!(
+            InvalidOperation: "casting from {:?} to {:?} not supported",
+            dt, DataType::Decimal256(precision, scale)
+        ),
+    };
+    Ok(out
+        .with_name(s.name().clone())
+        .into_decimal256_unchecked(precision, scale)
+        .into_series())
+}
+
+impl BinaryChunked {
+    /// Interpret order-preserving 32-byte values (see [`dec256_to_ordered_bytes`]) as 256-bit
+    /// decimals with the given precision and scale.
+    #[inline]
+    pub fn into_decimal256_unchecked(self, precision: usize, scale: usize) -> Decimal256Chunked {
+        // SAFETY: no invalid states (from a safety perspective).
+        unsafe { Decimal256Chunked::new_logical(self, DataType::Decimal256(precision, scale)) }
+    }
+}
+
+impl Decimal256Chunked {
+    /// Create a [`Decimal256Chunked`] from an iterator of (optional) unscaled values, failing if
+    /// a value doesn't fit in the precision.
+    pub fn from_i256_iter<I: IntoIterator<Item = Option<i256>>>(
+        name: PlSmallStr,
+        values: I,
+        precision: usize,
+        scale: usize,
+    ) -> PolarsResult<Self> {
+        dec256_verify_prec_scale(precision, scale)?;
+        let arr: BinaryViewArray = values
+            .into_iter()
+            .map(|opt_v| {
+                opt_v
+                    .map(|v| {
+                        polars_ensure!(
+                            dec256_fits(v, precision),
+                            ComputeError: "decimal precision {} can't fit values with {} digits",
+                            precision,
+                            v.to_string().trim_start_matches('-').len()
+                        );
+                        Ok(dec256_to_ordered_bytes(v))
+                    })
+                    .transpose()
+            })
+            .try_collect_arr()?;
+        Ok(BinaryChunked::with_chunk(name, arr).into_decimal256_unchecked(precision, scale))
+    }
+
+    /// Iterate over the (optional) unscaled values.
+    pub fn iter_i256(&self) -> impl Iterator<Item = Option<i256>> + '_ {
+        self.phys
+            .iter()
+            .map(|opt_v| opt_v.map(dec256_from_ordered_bytes))
+    }
+
+    /// Iterate over the chunks as arrow `Decimal256` arrays.
+    pub fn i256_chunks(&self) -> impl Iterator<Item = PrimitiveArray<i256>> + '_ {
+        let (precision, scale) = (self.precision(), self.scale());
+        self.phys
+            .downcast_iter()
+            .map(move |arr| decimal256_array_to_arrow(arr, precision, scale))
+    }
+
+    pub fn precision(&self) -> usize {
+        match &self.dtype {
+            DataType::Decimal256(precision, _) => *precision,
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn scale(&self) -> usize {
+        match &self.dtype {
+            DataType::Decimal256(_, scale) => *scale,
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn to_float64(&self) -> Float64Chunked {
+        let scale = self.scale();
+        unary_elementwise(&self.phys, |opt_v| {
+            opt_v.map(|v| dec256_to_f64(dec256_from_ordered_bytes(v), scale))
+        })
+    }
+
+    pub fn with_prec_scale(
+        &self,
+        prec: usize,
+        scale: usize,
+        strict: bool,
+    ) -> PolarsResult<Cow<'_, Self>> {
+        if self.precision() == prec && self.scale() == scale {
+            return Ok(Cow::Borrowed(self));
+        }
+
+        dec256_verify_prec_scale(prec, scale)?;
+        if self.scale() == scale && prec >= self.precision() {
+            // Increasing precision is always allowed.
+            return Ok(Cow::Owned(
+                self.phys.clone().into_decimal256_unchecked(prec, scale),
+            ));
+        }
+
+        let old_s = self.scale();
+        let phys: BinaryChunked = unary_elementwise(&self.phys, |opt_v| {
+            let v = dec256_from_ordered_bytes(opt_v?);
+            dec256_rescale(v, old_s, prec, scale).map(dec256_to_ordered_bytes)
+        });
+        polars_ensure!(
+            !strict || phys.null_count() == self.null_count(),
+            ComputeError: "decimal precision {} can't fit all values of {}", prec, self.dtype()
+        );
+        Ok(Cow::Owned(phys.into_decimal256_unchecked(prec, scale)))
+    }
+}
+
+impl LogicalType for Decimal256Chunked {
+    fn dtype(&self) -> &DataType {
+        &self.dtype
+    }
+
+    #[inline]
+    fn get_any_value(&self, i: usize) -> PolarsResult<AnyValue<'_>> {
+        polars_ensure!(i < self.len(), oob = i, self.len());
+        Ok(unsafe { self.get_any_value_unchecked(i) })
+    }
+
+    #[inline]
+    unsafe fn get_any_value_unchecked(&self, i: usize) -> AnyValue<'_> {
+        match self.phys.get_unchecked(i) {
+            Some(v) => {
+                AnyValue::Decimal256(dec256_from_ordered_bytes(v), self.precision(), self.scale())
+            },
+            None => AnyValue::Null,
+        }
+    }
+
+    fn cast_with_options(
+        &self,
+        dtype: &DataType,
+        cast_options: CastOptions,
+    ) -> PolarsResult<Series> {
+        let scale = self.scale();
+        match dtype {
+            DataType::Decimal256(to_prec, to_scale) => Ok(self
+                .with_prec_scale(*to_prec, *to_scale, cast_options.is_strict())?
+                .into_owned()
+                .into_series()),
+            DataType::Decimal(to_prec, to_scale) => {
+                let phys: Int128Chunked = unary_elementwise(&self.phys, |opt_v| {
+                    let v = dec256_from_ordered_bytes(opt_v?);
+                    dec256_rescale(v, scale, *to_prec, *to_scale)?
+                        .try_into()
+                        .ok()
+                });
+                Ok(phys
+                    .into_decimal_unchecked(*to_prec, *to_scale)
+                    .into_series())
+            },
+            DataType::String => {
+                let mut fmt_buf = Decimal256FmtBuffer::new();
+                let out: StringChunked = unary_elementwise(&self.phys, |opt_v| {
+                    let v = dec256_from_ordered_bytes(opt_v?);
+                    Some(fmt_buf.format_dec256(v, scale, false, false).to_string())
+                });
+                Ok(out.into_series())
+            },
+            dt if dt.is_float() => self
+                .to_float64()
+                .into_series()
+                .cast_with_options(dt, cast_options),
+            dt if dt.is_integer() || dt.is_bool() => {
+                let phys: Int128Chunked = unary_elementwise(&self.phys, |opt_v| {
+                    let v = dec256_from_ordered_bytes(opt_v?);
+                    dec256_to_i256(v, scale).try_into().ok()
+                });
+                phys.into_series().cast_with_options(dt, cast_options)
+            },
+            dt => polars_bail!(
+                InvalidOperation:
+                "casting from {:?} to {:?} not supported",
+                self.dtype(), dt
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn d(v: i128) -> i256 {
+        i256(v.into())
+    }
+
+    #[test]
+    fn test_decimal256_arrow_round_trip() {
+        let big = i256(d(-123456789).0 * d(10).0.pow(60));
+        let ca = Decimal256Chunked::from_i256_iter(
+            PlSmallStr::from_static("a"),
+            [Some(big), None, Some(d(125))],
+            76,
+            18,
+        )
+        .unwrap();
+        let s = ca.into_series();
+        let arr = s.to_arrow(0, CompatLevel::newest());
+        assert_eq!(arr.dtype(), &ArrowDataType::Decimal256(76, 18));
+        let out = Series::from_arrow(PlSmallStr::from_static("a"), arr).unwrap();
+        assert!(out.equals_missing(&s));
+
+        let sorted = s.sort(Default::default()).unwrap();
+        let values = sorted.decimal256().unwrap().iter_i256().collect::<Vec<_>>();
+        assert_eq!(values, &[None, Some(big), Some(d(125))]);
+    }
+
+    #[test]
+    fn test_decimal256_cast() {
+        let s = Series::new(PlSmallStr::from_static("a"), ["1.25", "x"]);
+        assert!(s.strict_cast(&DataType::Decimal256(50, 2)).is_err());
+        let out = s.cast(&DataType::Decimal256(50, 2)).unwrap();
+        assert_eq!(out.null_count(), 1);
+
+        let s = Series::new(
+            PlSmallStr::from_static("a"),
+            [Some("1.25"), None, Some("-3")],
+        )
+        .strict_cast(&DataType::Decimal256(50, 2))
+        .unwrap();
+        let ca = s.decimal256().unwrap();
+        assert_eq!(
+            ca.iter_i256().collect::<Vec<_>>(),
+            &[Some(d(125)), None, Some(d(-300))]
+        );
+
+        let out = s.cast(&DataType::String).unwrap();
+        assert_eq!(
+            Vec::from(out.str().unwrap()),
+            &[Some("1.25"), None, Some("-3.00")]
+        );
+        let out = s.cast(&DataType::Int64).unwrap();
+        assert_eq!(Vec::from(out.i64().unwrap()), &[Some(1), None, Some(-3)]);
+        let out = s.cast(&DataType::Decimal(10, 1)).unwrap();
+        assert_eq!(
+            Vec::from(out.decimal().unwrap().physical()),
+            &[Some(12), None, Some(-30)]
+        );
+        let back = out.cast(&DataType::Decimal256(50, 2)).unwrap();
+        assert_eq!(
+            back.decimal256().unwrap().iter_i256().collect::<Vec<_>>(),
+            &[Some(d(120)), None, Some(d(-300))]
+        );
+
+        // Values beyond the 128-bit range.
+        let wide = Series::new(
+            PlSmallStr::from_static("a"),
+            [format!("1{}", "0".repeat(60))],
+        )
+        .strict_cast(&DataType::Decimal256(76, 10))
+        .unwrap();
+        assert!(
+            wide.strict_cast(&DataType::Decimal(38, 0)).is_err(),
+            "narrowing should fail"
+        );
+        assert_eq!(
+            wide.cast(&DataType::Float64).unwrap().f64().unwrap().get(0),
+            Some(1e60)
+        );
+    }
+
+    #[test]
+    fn test_decimal256_arithmetic_and_aggregations() {
+        let big = i256(d(10).0.pow(60));
+        let a = Decimal256Chunked::from_i256_iter(
+            PlSmallStr::from_static("a"),
+            [Some(big), Some(d(150)), None, Some(d(-25))],
+            76,
+            2,
+        )
+        .unwrap()
+        .into_series();
+        let b = Decimal256Chunked::from_i256_iter(
+            PlSmallStr::from_static("b"),
+            [Some(d(1)), Some(d(5)), Some(d(7)), Some(d(10))],
+            76,
+            1,
+        )
+        .unwrap()
+        .into_series();
+
+        let values = |s: &Series| s.decimal256().unwrap().iter_i256().collect::<Vec<_>>();
+
+        let sum = (&a + &b).unwrap();
+        assert_eq!(sum.dtype(), &DataType::Decimal256(76, 2));
+        assert_eq!(
+            values(&sum),
+            &[Some(i256(big.0 + 10)), Some(d(200)), None, Some(d(75))]
+        );
+        let prod = (&a * &b).unwrap();
+        assert_eq!(
+            values(&prod),
+            &[Some(i256(big.0 / 10)), Some(d(75)), None, Some(d(-25))]
+        );
+        let quot = (&b / &a).unwrap();
+        assert_eq!(
+            values(&quot),
+            &[Some(d(0)), Some(d(33)), None, Some(d(-400))]
+        );
+
+        assert_eq!(
+            a.sum_reduce().unwrap().value(),
+            &AnyValue::Decimal256(i256(big.0 + 125), 76, 2)
+        );
+        assert_eq!(
+            a.min_reduce().unwrap().value(),
+            &AnyValue::Decimal256(d(-25), 76, 2)
+        );
+        assert_eq!(
+            a.max_reduce().unwrap().value(),
+            &AnyValue::Decimal256(big, 76, 2)
+        );
+        assert_eq!(format!("{}", a.get(1).unwrap()), "1.50",);
+    }
+
+    #[test]
+    fn test_decimal256_add_mixed_scales_and_overflow() {
+        let dec = |values: &[i256], scale: usize| {
+            Decimal256Chunked::from_i256_iter(
+                PlSmallStr::from_static("a"),
+                values.iter().map(|v| Some(*v)),
+                76,
+                scale,
+            )
+            .unwrap()
+            .into_series()
+        };
+        let values = |s: &Series| s.decimal256().unwrap().iter_i256().collect::<Vec<_>>();
+
+        // 1.5 + 0.25 and -0.5 + 0.01 are added at the larger scale
+        let sum = (&dec(&[d(15), d(-5)], 1) + &dec(&[d(25), d(1)], 2)).unwrap();
+        assert_eq!(sum.dtype(), &DataType::Decimal256(76, 2));
+        assert_eq!(values(&sum), &[Some(d(175)), Some(d(-49))]);
+
+        // the largest values with 76 digits, well within the range of an i256
+        let max = i256(d(10).0.pow(76) - d(1).0);
+        let min = i256(-max.0);
+        let one = dec(&[d(1)], 0);
+        assert_eq!(
+            values(&(&dec(&[max], 0) - &one).unwrap()),
+            &[Some(i256(max.0 - d(1).0))]
+        );
+        assert!((&dec(&[max], 0) + &one).is_err());
+        assert!((&dec(&[min], 0) - &one).is_err());
+        // rescaling to the scale of the other operand doesn't fit either
+        assert!((&dec(&[max], 0) + &dec(&[d(0)], 1)).is_err());
+    }
+}
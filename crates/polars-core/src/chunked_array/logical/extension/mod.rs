@@ -0,0 +1,262 @@
+mod registry;
+
+use std::borrow::Cow;
+
+pub(crate) use registry::get_extension_type;
+pub use registry::{ExtensionTypeImpl, register_extension_type, unregister_extension_type};
+
+use crate::chunked_array::cast::CastOptions;
+use crate::prelude::*;
+
+/// A series of an Arrow extension type ([`DataType::Extension`]).
+///
+/// The values are held by a [`Series`] of the storage type. Operations that keep the storage
+/// (filter, gather, concat, sort, ...) keep the extension type, anything else acts on the
+/// storage.
+#[derive(Clone)]
+pub struct ExtensionChunked {
+    storage: Series,
+    dtype: DataType,
+}
+
+impl Series {
+    /// Interpret the values as the storage of the extension type `name`.
+    pub fn into_extension(self, name: PlSmallStr, metadata: Option<PlSmallStr>) -> Series {
+        // Extension types can't be nested, replace the existing one instead.
+        let storage = match self.try_extension() {
+            Some(ca) => ca.storage().clone(),
+            None => self,
+        };
+        let dtype = DataType::Extension {
+            name,
+            metadata: metadata.map(Box::new),
+            storage: Box::new(storage.dtype().clone()),
+        };
+        ExtensionChunked { storage, dtype }.into_series()
+    }
+}
+
+impl ExtensionChunked {
+    /// The values, as a series of the storage type.
+    pub fn storage(&self) -> &Series {
+        &self.storage
+    }
+
+    pub fn into_storage(self) -> Series {
+        self.storage
+    }
+
+    pub fn extension_name(&self) -> &PlSmallStr {
+        let DataType::Extension { name, .. } = &self.dtype else {
+            unreachable!()
+        };
+        name
+    }
+
+    pub fn extension_metadata(&self) -> Option<&PlSmallStr> {
+        let DataType::Extension { metadata, .. } = &self.dtype else {
+            unreachable!()
+        };
+        metadata.as_deref()
+    }
+
+    pub fn dtype(&self) -> &DataType {
+        &self.dtype
+    }
+
+    pub fn name(&self) -> &PlSmallStr {
+        self.storage.name()
+    }
+
+    pub fn rename(&mut self, name: PlSmallStr) {
+        self.storage.rename(name);
+    }
+
+    pub fn len(&self) -> usize {
+        self.storage.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.storage.is_empty()
+    }
+
+    pub(crate) fn storage_mut(&mut self) -> &mut Series {
+        &mut self.storage
+    }
+
+    pub(crate) fn with_storage(&self, storage: Series) -> Self {
+        let DataType::Extension {
+            storage: storage_dtype,
+            ..
+        } = &self.dtype
+        else {
+            unreachable!()
+        };
+        debug_assert_eq!(storage.dtype(), storage_dtype.as_ref());
+        Self {
+            storage,
+            dtype: self.dtype.clone(),
+        }
+    }
+
+    /// Format the value at `index`, using the registered [`ExtensionTypeImpl`] if any.
+    pub fn str_value(&self, index: usize) -> PolarsResult<Cow<'_, str>> {
+        let av = self.storage.get(index)?;
+        if av.is_null() {
+            return Ok(av.str_value());
+        }
+        Ok(match get_extension_type(self.extension_name()) {
+            Some(ext) => {
+                Cow::Owned(ext.fmt_value(&av, self.extension_metadata().map(|md| md.as_str())))
+            },
+            None => av.str_value(),
+        })
+    }
+
+    pub fn cast_with_options(
+        &self,
+        dtype: &DataType,
+        options: CastOptions,
+    ) -> PolarsResult<Series> {
+        if dtype == self.dtype() {
+            return Ok(self.clone().into_series());
+        }
+        if let Some(ext) = get_extension_type(self.extension_name()) {
+            let metadata = self.extension_metadata().map(|md| md.as_str());
+            if let Some(out) = ext.cast_to(&self.storage, metadata, dtype, options) {
+                return out;
+            }
+        }
+        self.storage.cast_with_options(dtype, options)
+    }
+}
+
+/// Cast a series that isn't of an extension type to the extension type `dtype`.
+pub(crate) fn cast_into_extension(
+    s: &Series,
+    dtype: &DataType,
+    options: CastOptions,
+) -> PolarsResult<Series> {
+    let DataType::Extension {
+        name,
+        metadata,
+        storage,
+    } = dtype
+    else {
+        unreachable!()
+    };
+    let out = match get_extension_type(name).and_then(|ext| ext.cast_from(s, dtype, options)) {
+        Some(out) => out?,
+        None => s.cast_with_options(storage, options)?,
+    };
+    polars_ensure!(
+        out.dtype() == storage.as_ref(),
+        SchemaMismatch: "casting to extension type '{}' produced {}, expected its storage type {}",
+        name, out.dtype(), storage
+    );
+    Ok(out.into_extension(name.clone(), metadata.as_deref().cloned()))
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use super::*;
+
+    struct Hex;
+
+    impl ExtensionTypeImpl for Hex {
+        fn fmt_value(&self, value: &AnyValue<'_>, _metadata: Option<&str>) -> String {
+            format!("{:#x}", value.extract::<i64>().unwrap())
+        }
+
+        fn cast_from(
+            &self,
+            s: &Series,
+            _dtype: &DataType,
+            _options: CastOptions,
+        ) -> Option<PolarsResult<Series>> {
+            let ca = s.str().ok()?;
+            let parse = |v: &str| i64::from_str_radix(v.trim_start_matches("0x"), 16).ok();
+            let out: Int64Chunked = ca.into_iter().map(|opt_v| opt_v.and_then(parse)).collect();
+            Some(Ok(out.into_series()))
+        }
+    }
+
+    fn extension(s: Series, name: &'static str) -> Series {
+        s.into_extension(
+            PlSmallStr::from_static(name),
+            Some(PlSmallStr::from_static("{}")),
+        )
+    }
+
+    #[test]
+    fn test_extension_keeps_dtype() {
+        let s = extension(
+            Series::new(
+                PlSmallStr::from_static("a"),
+                [Some(3i64), None, Some(1), Some(2)],
+            ),
+            "test.keep",
+        );
+        let dtype = s.dtype().clone();
+        assert_eq!(
+            dtype,
+            DataType::Extension {
+                name: PlSmallStr::from_static("test.keep"),
+                metadata: Some(Box::new(PlSmallStr::from_static("{}"))),
+                storage: Box::new(DataType::Int64),
+            }
+        );
+        assert_eq!(s.to_physical_repr().dtype(), &DataType::Int64);
+
+        let mask = BooleanChunked::new(PlSmallStr::EMPTY, [true, false, true, true]);
+        let filtered = s.filter(&mask).unwrap();
+        assert_eq!(filtered.dtype(), &dtype);
+        let sorted = filtered.sort(SortOptions::default()).unwrap();
+        assert_eq!(sorted.dtype(), &dtype);
+        assert_eq!(
+            Vec::from(sorted.extension().unwrap().storage().i64().unwrap()),
+            &[Some(1), Some(2), Some(3)]
+        );
+        let mut appended = s.take_slice(&[1, 0]).unwrap();
+        appended.append(&sorted).unwrap();
+        assert_eq!(appended.dtype(), &dtype);
+        assert_eq!(appended.len(), 5);
+        assert_eq!(appended.null_count(), 1);
+
+        let arr = s.to_arrow(0, CompatLevel::newest());
+        assert!(matches!(arr.dtype(), ArrowDataType::Extension(_)));
+        let out = Series::from_arrow(PlSmallStr::from_static("a"), arr).unwrap();
+        assert_eq!(out.dtype(), &dtype);
+        assert!(out.equals_missing(&s));
+
+        // Casting to another type acts on the storage.
+        let out = s.cast(&DataType::Float64).unwrap();
+        assert_eq!(out.dtype(), &DataType::Float64);
+        let out = out.cast(&dtype).unwrap();
+        assert!(out.equals_missing(&s));
+    }
+
+    #[test]
+    fn test_extension_registry() {
+        register_extension_type(PlSmallStr::from_static("test.hex"), Arc::new(Hex));
+        let s = extension(
+            Series::new(PlSmallStr::from_static("a"), [Some(255i64), None]),
+            "test.hex",
+        );
+        assert_eq!(s.str_value(0).unwrap(), "0xff");
+        assert_eq!(s.str_value(1).unwrap(), "null");
+
+        let strings = Series::new(PlSmallStr::from_static("a"), ["0x10", "ff"]);
+        let out = strings.cast(s.dtype()).unwrap();
+        assert_eq!(out.dtype(), s.dtype());
+        assert_eq!(out.str_value(0).unwrap(), "0x10");
+        assert_eq!(
+            Vec::from(out.extension().unwrap().storage().i64().unwrap()),
+            &[Some(16), Some(255)]
+        );
+        assert!(unregister_extension_type("test.hex").is_some());
+        assert_eq!(s.str_value(0).unwrap(), "255");
+    }
+}
@@ -0,0 +1,64 @@
+//! Registry of custom behavior for Arrow extension types.
+//!
+//! Extension types are identified by their name (e.g. `arrow.uuid`). Types that aren't
+//! registered behave exactly like their storage type.
+use std::sync::{Arc, LazyLock, RwLock};
+
+use polars_utils::aliases::PlHashMap;
+use polars_utils::pl_str::PlSmallStr;
+
+use crate::chunked_array::cast::CastOptions;
+use crate::prelude::*;
+
+/// Custom behavior of an extension type, registered with [`register_extension_type`].
+///
+/// All methods have a default that defers to the storage type.
+pub trait ExtensionTypeImpl: Send + Sync {
+    /// Format a single non-null value, given as its storage value.
+    fn fmt_value(&self, value: &AnyValue<'_>, _metadata: Option<&str>) -> String {
+        value.str_value().into_owned()
+    }
+
+    /// Cast the `storage` of a series of this extension type to `dtype`.
+    ///
+    /// Return `None` to cast the storage instead.
+    fn cast_to(
+        &self,
+        _storage: &Series,
+        _metadata: Option<&str>,
+        _dtype: &DataType,
+        _options: CastOptions,
+    ) -> Option<PolarsResult<Series>> {
+        None
+    }
+
+    /// Cast `s` to the storage type of this extension type, `dtype` being the extension type
+    /// itself.
+    ///
+    /// Return `None` to cast `s` to the storage type instead.
+    fn cast_from(
+        &self,
+        _s: &Series,
+        _dtype: &DataType,
+        _options: CastOptions,
+    ) -> Option<PolarsResult<Series>> {
+        None
+    }
+}
+
+static EXTENSION_REGISTRY: LazyLock<RwLock<PlHashMap<PlSmallStr, Arc<dyn ExtensionTypeImpl>>>> =
+    LazyLock::new(Default::default);
+
+/// Register the behavior of the extension type `name`, replacing any earlier registration.
+pub fn register_extension_type(name: PlSmallStr, ext: Arc<dyn ExtensionTypeImpl>) {
+    EXTENSION_REGISTRY.write().unwrap().insert(name, ext);
+}
+
+/// Remove the registration of the extension type `name`, returning it if it existed.
+pub fn unregister_extension_type(name: &str) -> Option<Arc<dyn ExtensionTypeImpl>> {
+    EXTENSION_REGISTRY.write().unwrap().remove(name)
+}
+
+pub(crate) fn get_extension_type(name: &str) -> Option<Arc<dyn ExtensionTypeImpl>> {
+    EXTENSION_REGISTRY.read().unwrap().get(name).cloned()
+}
@@ -0,0 +1,233 @@
+use arrow::types::canonical_f16;
+
+use super::*;
+use crate::prelude::*;
+
+pub type Float16Chunked = Logical<Float16Type, Int16Type>;
+
+/// Convert a half-precision float into its physical (16-bit integer) representation.
+///
+/// Negative numbers have their magnitude bits flipped, so that the physical values order as the
+/// floats do. Zeros and NaNs are canonicalized first, so `-0.0 == 0.0` and all NaNs are equal and
+/// sort last, just like the other float types.
+#[inline]
+pub fn float16_to_physical(v: f16) -> i16 {
+    let bits = canonical_f16(v).to_bits();
+    if bits & 0x8000 != 0 {
+        (bits ^ 0x7FFF) as i16
+    } else {
+        bits as i16
+    }
+}
+
+/// Convert a half-precision float from its physical (16-bit integer) representation.
+#[inline]
+pub fn float16_from_physical(v: i16) -> f16 {
+    let bits = v as u16;
+    if bits & 0x8000 != 0 {
+        f16::from_bits(bits ^ 0x7FFF)
+    } else {
+        f16::from_bits(bits)
+    }
+}
+
+/// Convert a physical Float16 array to an arrow `Float16` array.
+pub(crate) fn float16_array_to_arrow(arr: &PrimitiveArray<i16>) -> PrimitiveArray<f16> {
+    let values = arr
+        .values_iter()
+        .map(|v| float16_from_physical(*v))
+        .collect();
+    PrimitiveArray::from_vec(values).with_validity(arr.validity().cloned())
+}
+
+/// Convert an arrow `Float16` array to its physical representation.
+pub(crate) fn float16_array_from_arrow(arr: &PrimitiveArray<f16>) -> PrimitiveArray<i16> {
+    let values = arr.values_iter().map(|v| float16_to_physical(*v)).collect();
+    PrimitiveArray::from_vec(values).with_validity(arr.validity().cloned())
+}
+
+impl Int16Chunked {
+    /// Interpret the (order-preserving) values as half-precision floats.
+    pub fn into_float16(self) -> Float16Chunked {
+        // SAFETY: every 16-bit value is a valid half-precision float.
+        unsafe { Float16Chunked::new_logical(self, DataType::Float16) }
+    }
+}
+
+impl Float32Chunked {
+    /// Round to the nearest half-precision float.
+    pub fn to_float16(&self) -> Float16Chunked {
+        let chunks = self
+            .downcast_iter()
+            .map(|arr| float16_array_from_arrow(&polars_compute::cast::f32_to_f16(arr)))
+            .collect::<Vec<_>>();
+        Int16Chunked::from_chunk_iter(self.name().clone(), chunks).into_float16()
+    }
+}
+
+impl Float16Chunked {
+    /// Create a [`Float16Chunked`] from an iterator of (optional) half-precision floats.
+    pub fn from_f16_iter<I: IntoIterator<Item = Option<f16>>>(name: PlSmallStr, values: I) -> Self {
+        Int16Chunked::from_iter_options(
+            name,
+            values.into_iter().map(|v| v.map(float16_to_physical)),
+        )
+        .into_float16()
+    }
+
+    /// Iterate over the (optional) half-precision floats.
+    pub fn iter_f16(&self) -> impl Iterator<Item = Option<f16>> + '_ {
+        self.phys
+            .iter()
+            .map(|opt_v| opt_v.map(float16_from_physical))
+    }
+
+    /// Iterate over the chunks as arrow `Float16` arrays.
+    pub fn f16_chunks(&self) -> impl Iterator<Item = PrimitiveArray<f16>> + '_ {
+        self.phys.downcast_iter().map(float16_array_to_arrow)
+    }
+
+    /// Widen to single precision; this is exact.
+    pub fn to_float32(&self) -> Float32Chunked {
+        let chunks = self
+            .f16_chunks()
+            .map(|arr| polars_compute::cast::f16_to_f32(&arr))
+            .collect::<Vec<_>>();
+        Float32Chunked::from_chunk_iter(self.name().clone(), chunks)
+    }
+}
+
+impl LogicalType for Float16Chunked {
+    fn dtype(&self) -> &DataType {
+        &self.dtype
+    }
+
+    #[inline]
+    fn get_any_value(&self, i: usize) -> PolarsResult<AnyValue<'_>> {
+        polars_ensure!(i < self.len(), oob = i, self.len());
+        Ok(unsafe { self.get_any_value_unchecked(i) })
+    }
+
+    #[inline]
+    unsafe fn get_any_value_unchecked(&self, i: usize) -> AnyValue<'_> {
+        match self.phys.get_unchecked(i) {
+            Some(v) => AnyValue::Float16(float16_from_physical(v)),
+            None => AnyValue::Null,
+        }
+    }
+
+    fn cast_with_options(
+        &self,
+        dtype: &DataType,
+        cast_options: CastOptions,
+    ) -> PolarsResult<Series> {
+        match dtype {
+            DataType::Float16 => Ok(self.clone().into_series()),
+            DataType::Float32 => Ok(self.to_float32().into_series()),
+            dt => self
+                .to_float32()
+                .into_series()
+                .cast_with_options(dt, cast_options),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_float16_physical_order() {
+        let values = [
+            f16::from_f32(f32::NEG_INFINITY),
+            f16::from_f32(-65504.0),
+            f16::from_f32(-1.5),
+            f16::from_f32(-0.0),
+            f16::from_f32(6e-8),
+            f16::from_f32(1.0),
+            f16::from_f32(f32::INFINITY),
+            f16::from_f32(f32::NAN),
+        ];
+        let physical = values.map(float16_to_physical);
+        assert!(physical.is_sorted());
+        // -0.0 is canonicalized to 0.0.
+        assert_eq!(physical[3], 0);
+        for (v, p) in values.iter().zip(physical).skip(4) {
+            assert_eq!(float16_from_physical(p).to_bits(), v.to_bits());
+        }
+    }
+
+    #[test]
+    fn test_float16_arrow_round_trip() {
+        let ca = Float16Chunked::from_f16_iter(
+            PlSmallStr::from_static("a"),
+            [Some(f16::from_f32(-2.5)), None, Some(f16::from_f32(0.125))],
+        );
+        let s = ca.into_series();
+        let arr = s.to_arrow(0, CompatLevel::newest());
+        assert_eq!(arr.dtype(), &ArrowDataType::Float16);
+        let out = Series::from_arrow(PlSmallStr::from_static("a"), arr).unwrap();
+        assert!(out.equals_missing(&s));
+
+        let out = s.cast(&DataType::Float64).unwrap();
+        assert_eq!(
+            Vec::from(out.f64().unwrap()),
+            &[Some(-2.5), None, Some(0.125)]
+        );
+        let back = out.cast(&DataType::Float16).unwrap();
+        assert!(back.equals_missing(&s));
+    }
+
+    #[test]
+    fn test_float16_arithmetic_and_aggregations() {
+        let s = Float16Chunked::from_f16_iter(
+            PlSmallStr::from_static("a"),
+            [1.5, -3.0, f32::NAN, 0.25].map(|v| Some(f16::from_f32(v))),
+        )
+        .into_series();
+
+        let out = (&s + &s).unwrap();
+        assert_eq!(out.dtype(), &DataType::Float16);
+        let out = (&out * 2).cast(&DataType::Float32).unwrap();
+        let out = Vec::from(out.f32().unwrap());
+        assert_eq!(out[0], Some(6.0));
+        assert_eq!(out[1], Some(-12.0));
+        assert!(out[2].unwrap().is_nan());
+
+        // -0.0 and 0.0 compare equal.
+        let zeros = Series::new(PlSmallStr::from_static("z"), [0.0f32, -0.0f32])
+            .cast(&DataType::Float16)
+            .unwrap();
+        assert!(zeros.equal(&zeros.reverse()).unwrap().all());
+
+        assert_eq!(
+            s.min_reduce().unwrap().value(),
+            &AnyValue::Float16(f16::from_f32(-3.0))
+        );
+        assert_eq!(
+            s.max_reduce().unwrap().value(),
+            &AnyValue::Float16(f16::from_f32(1.5))
+        );
+        let finite = s.drop_nulls().filter(&s.is_not_nan().unwrap()).unwrap();
+        let sum = finite.sum_reduce().unwrap();
+        assert_eq!(sum.dtype(), &DataType::Float32);
+        assert_eq!(sum.value(), &AnyValue::Float32(-1.25));
+
+        #[cfg(feature = "algorithm_group_by")]
+        {
+            let groups = GroupsType::Slice {
+                groups: vec![[0, 2], [2, 2]],
+                rolling: false,
+            };
+            let out = unsafe { s.agg_list(&groups) };
+            assert_eq!(out.dtype(), &DataType::List(Box::new(DataType::Float16)));
+            assert!(
+                out.list()
+                    .unwrap()
+                    .get_as_series(1)
+                    .unwrap()
+                    .equals_missing(&s.slice(2, 2).to_physical_repr())
+            );
+        }
+    }
+}
@@ -0,0 +1,265 @@
+use arrow::types::months_days_ns;
+
+use super::*;
+use crate::fmt::{fmt_interval_string, iso_interval_string};
+use crate::prelude::*;
+
+pub type IntervalChunked = Logical<IntervalType, Int128Type>;
+
+const DAYS_SIGN: i128 = 1 << 31;
+const NS_SIGN: i128 = 1 << 63;
+
+/// Pack an interval into its physical (128-bit integer) representation.
+///
+/// The months occupy the upper 32 bits, followed by the days and the nanoseconds. The sign bits
+/// of the lower components are flipped, so that the physical values order as the intervals do:
+/// by months, then days, then nanoseconds.
+#[inline]
+pub fn interval_to_physical(v: months_days_ns) -> i128 {
+    ((v.months() as i128) << 96)
+        | ((((v.days() as u32) as i128) ^ DAYS_SIGN) << 64)
+        | (((v.ns() as u64) as i128) ^ NS_SIGN)
+}
+
+/// Unpack an interval from its physical (128-bit integer) representation.
+#[inline]
+pub fn interval_from_physical(v: i128) -> months_days_ns {
+    let months = (v >> 96) as i32;
+    let days = ((v >> 64) ^ DAYS_SIGN) as u32 as i32;
+    let ns = (v ^ NS_SIGN) as u64 as i64;
+    months_days_ns::new(months, days, ns)
+}
+
+/// Convert a physical interval array to an arrow `Interval(MonthDayNano)` array.
+pub(crate) fn interval_array_to_arrow(
+    arr: &PrimitiveArray<i128>,
+) -> PrimitiveArray<months_days_ns> {
+    let values = arr
+        .values_iter()
+        .map(|v| interval_from_physical(*v))
+        .collect();
+    PrimitiveArray::from_vec(values).with_validity(arr.validity().cloned())
+}
+
+/// Convert an arrow `Interval(MonthDayNano)` array to its physical representation.
+pub(crate) fn interval_array_from_arrow(
+    arr: &PrimitiveArray<months_days_ns>,
+) -> PrimitiveArray<i128> {
+    let values = arr
+        .values_iter()
+        .map(|v| interval_to_physical(*v))
+        .collect();
+    PrimitiveArray::from_vec(values).with_validity(arr.validity().cloned())
+}
+
+impl Int128Chunked {
+    /// Interpret the (packed) values as intervals.
+    pub fn into_interval(self) -> IntervalChunked {
+        // SAFETY: every 128-bit value is a valid interval.
+        unsafe { IntervalChunked::new_logical(self, DataType::Interval) }
+    }
+}
+
+impl IntervalChunked {
+    /// Create an [`IntervalChunked`] from an iterator of (optional) intervals.
+    pub fn from_intervals<I: IntoIterator<Item = Option<months_days_ns>>>(
+        name: PlSmallStr,
+        values: I,
+    ) -> Self {
+        Int128Chunked::from_iter_options(
+            name,
+            values.into_iter().map(|v| v.map(interval_to_physical)),
+        )
+        .into_interval()
+    }
+
+    /// Iterate over the (optional) intervals.
+    pub fn iter_intervals(&self) -> impl Iterator<Item = Option<months_days_ns>> + '_ {
+        self.phys
+            .iter()
+            .map(|opt_v| opt_v.map(interval_from_physical))
+    }
+
+    /// Convert to String; only the specifiers 'iso' (ISO 8601, e.g. `P1Y2M10DT2H30M`) and
+    /// 'polars' (e.g. `14mo 10d 2h 30m`) are supported.
+    pub fn to_string(&self, format: &str) -> PolarsResult<StringChunked> {
+        let fmt: fn(&mut String, months_days_ns) = match format {
+            "iso" | "iso:strict" => iso_interval_string,
+            "polars" => |s, v| fmt_interval_string(s, v).unwrap(),
+            _ => polars_bail!(
+                InvalidOperation: "format {:?} not supported for Interval type (expected one of 'iso' or 'polars')",
+                format
+            ),
+        };
+        let mut s = String::with_capacity(32);
+        let out: StringChunked = self
+            .iter_intervals()
+            .map(|opt_v| {
+                opt_v.map(|v| {
+                    s.clear();
+                    fmt(&mut s, v);
+                    s.clone()
+                })
+            })
+            .collect();
+        Ok(out.with_name(self.name().clone()))
+    }
+
+    /// Convert to a [`DurationChunked`], counting a day as 24 hours.
+    ///
+    /// Months don't have a fixed length, so intervals with a month component can't be converted;
+    /// these raise if `strict`, and become null otherwise.
+    #[cfg(feature = "dtype-duration")]
+    pub fn to_duration(&self, tu: TimeUnit, strict: bool) -> PolarsResult<DurationChunked> {
+        if strict {
+            if let Some(v) = self.iter_intervals().flatten().find(|v| v.months() != 0) {
+                polars_bail!(
+                    InvalidOperation: "cannot convert interval '{}' with a month component to Duration",
+                    AnyValue::Interval(v)
+                );
+            }
+        }
+        let factor = tu_to_ns_factor(tu);
+        let out: Int64Chunked = self
+            .iter_intervals()
+            .map(|opt_v| {
+                opt_v
+                    .filter(|v| v.months() == 0)
+                    .map(|v| (v.days() as i64 * NS_IN_DAY + v.ns()) / factor)
+            })
+            .collect();
+        Ok(out.with_name(self.name().clone()).into_duration(tu))
+    }
+}
+
+#[cfg(feature = "dtype-duration")]
+impl DurationChunked {
+    /// Convert to an [`IntervalChunked`] (of nanoseconds only).
+    pub fn to_interval(&self) -> IntervalChunked {
+        let factor = tu_to_ns_factor(self.time_unit());
+        let out: Int128Chunked = self
+            .phys
+            .apply_nonnull_values_generic(DataType::Int128, |v| {
+                interval_to_physical(months_days_ns::new(0, 0, v * factor))
+            });
+        out.into_interval()
+    }
+}
+
+#[cfg(feature = "dtype-duration")]
+fn tu_to_ns_factor(tu: TimeUnit) -> i64 {
+    match tu {
+        TimeUnit::Nanoseconds => 1,
+        TimeUnit::Microseconds => 1_000,
+        TimeUnit::Milliseconds => 1_000_000,
+    }
+}
+
+impl LogicalType for IntervalChunked {
+    fn dtype(&self) -> &DataType {
+        &self.dtype
+    }
+
+    #[inline]
+    fn get_any_value(&self, i: usize) -> PolarsResult<AnyValue<'_>> {
+        polars_ensure!(i < self.len(), oob = i, self.len());
+        Ok(unsafe { self.get_any_value_unchecked(i) })
+    }
+
+    #[inline]
+    unsafe fn get_any_value_unchecked(&self, i: usize) -> AnyValue<'_> {
+        match self.phys.get_unchecked(i) {
+            Some(v) => AnyValue::Interval(interval_from_physical(v)),
+            None => AnyValue::Null,
+        }
+    }
+
+    fn cast_with_options(
+        &self,
+        dtype: &DataType,
+        cast_options: CastOptions,
+    ) -> PolarsResult<Series> {
+        match dtype {
+            DataType::Interval => Ok(self.clone().into_series()),
+            #[cfg(feature = "dtype-duration")]
+            DataType::Duration(tu) => Ok(self
+                .to_duration(*tu, cast_options.is_strict())?
+                .into_series()),
+            DataType::String => Ok(self.to_string("polars")?.into_series()),
+            dt => {
+                polars_bail!(
+                    InvalidOperation:
+                    "casting from {:?} to {:?} not supported",
+                    self.dtype(), dt
+                )
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_interval_physical_order() {
+        let intervals = [
+            months_days_ns::new(-1, 40, 0),
+            months_days_ns::new(0, -3, i64::MAX),
+            months_days_ns::new(0, 0, -1),
+            months_days_ns::new(0, 0, 0),
+            months_days_ns::new(0, 0, 1),
+            months_days_ns::new(0, 1, i64::MIN),
+            months_days_ns::new(2, i32::MIN, 5),
+        ];
+        let physical = intervals.map(interval_to_physical);
+        assert!(physical.is_sorted());
+        assert_eq!(physical.map(interval_from_physical), intervals);
+    }
+
+    #[test]
+    fn test_interval_arrow_round_trip() {
+        let ca = IntervalChunked::from_intervals(
+            PlSmallStr::from_static("a"),
+            [Some(months_days_ns::new(1, -2, 3)), None],
+        );
+        let s = ca.into_series();
+        let arr = s.to_arrow(0, CompatLevel::newest());
+        assert_eq!(
+            arr.dtype(),
+            &ArrowDataType::Interval(arrow::datatypes::IntervalUnit::MonthDayNano)
+        );
+        let out = Series::from_arrow(PlSmallStr::from_static("a"), arr).unwrap();
+        assert!(out.equals_missing(&s));
+        assert_eq!(
+            out.cast(&DataType::String).unwrap().str().unwrap().get(0),
+            Some("1mo -2d 3ns")
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "algorithm_group_by")]
+    fn test_interval_agg_list() {
+        let s = IntervalChunked::from_intervals(
+            PlSmallStr::from_static("a"),
+            [
+                Some(months_days_ns::new(1, -2, 3)),
+                None,
+                Some(months_days_ns::new(0, 4, 0)),
+            ],
+        )
+        .into_series();
+        let groups = GroupsType::Slice {
+            groups: vec![[0, 1], [1, 2]],
+            rolling: false,
+        };
+        let out = unsafe { s.agg_list(&groups) };
+        assert_eq!(out.dtype(), &DataType::List(Box::new(DataType::Interval)));
+        let out = out.list().unwrap();
+        assert!(
+            out.get_as_series(1)
+                .unwrap()
+                .equals_missing(&s.slice(1, 2).to_physical_repr())
+        );
+    }
+}
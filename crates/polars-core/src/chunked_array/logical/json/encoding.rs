@@ -0,0 +1,466 @@
+//! A compact binary encoding of JSON documents.
+//!
+//! Every value starts with a tag byte. Arrays and objects store their length and the byte size
+//! of their contents up front, so values can be skipped without looking at them and paths can be
+//! followed without parsing the document again.
+//!
+//! | tag      | payload                                                                  |
+//! |----------|--------------------------------------------------------------------------|
+//! | `NULL`   |                                                                          |
+//! | `FALSE`  |                                                                          |
+//! | `TRUE`   |                                                                          |
+//! | `INT`    | `i64`                                                                    |
+//! | `UINT`   | `u64`, only for integers that don't fit in an `i64`                      |
+//! | `FLOAT`  | `f64`                                                                    |
+//! | `STRING` | `u32` byte length, the UTF-8 bytes                                       |
+//! | `ARRAY`  | `u32` length, `u32` byte size, the elements                              |
+//! | `OBJECT` | `u32` length, `u32` byte size, per entry the key as a `STRING` payload and the value |
+//!
+//! All numbers are little-endian. Object entries keep the order of the document.
+use std::fmt;
+
+use serde::de::{DeserializeSeed, Deserializer, Error, MapAccess, SeqAccess, Visitor};
+
+const NULL: u8 = 0;
+const FALSE: u8 = 1;
+const TRUE: u8 = 2;
+const INT: u8 = 3;
+const UINT: u8 = 4;
+const FLOAT: u8 = 5;
+const STRING: u8 = 6;
+const ARRAY: u8 = 7;
+const OBJECT: u8 = 8;
+
+/// Size of the tag, length and byte size of an array or object.
+const CONTAINER_HEADER: usize = 9;
+
+/// Parse a JSON document and append its binary encoding to `out`.
+///
+/// Nothing is appended if the document is invalid.
+pub fn encode_json(json: &[u8], out: &mut Vec<u8>) -> Result<(), serde_json::Error> {
+    let start = out.len();
+    let mut de = serde_json::Deserializer::from_slice(json);
+    let result = Encoder(out).deserialize(&mut de).and_then(|_| de.end());
+    if result.is_err() {
+        out.truncate(start);
+    }
+    result
+}
+
+fn push_str(out: &mut Vec<u8>, v: &str) -> Result<(), String> {
+    let len = u32::try_from(v.len()).map_err(|_| "JSON string too large".to_string())?;
+    out.extend_from_slice(&len.to_le_bytes());
+    out.extend_from_slice(v.as_bytes());
+    Ok(())
+}
+
+/// Fill in the header of a container that was started at `start`.
+fn finish_container(out: &mut [u8], start: usize, len: usize) -> Result<(), String> {
+    let size = out.len() - start - CONTAINER_HEADER;
+    let (Ok(len), Ok(size)) = (u32::try_from(len), u32::try_from(size)) else {
+        return Err("JSON document too large".to_string());
+    };
+    out[start + 1..start + 5].copy_from_slice(&len.to_le_bytes());
+    out[start + 5..start + 9].copy_from_slice(&size.to_le_bytes());
+    Ok(())
+}
+
+struct Encoder<'o>(&'o mut Vec<u8>);
+
+impl<'de> DeserializeSeed<'de> for Encoder<'_> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de> Visitor<'de> for Encoder<'_> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a JSON value")
+    }
+
+    fn visit_unit<E: Error>(self) -> Result<(), E> {
+        self.0.push(NULL);
+        Ok(())
+    }
+
+    fn visit_bool<E: Error>(self, v: bool) -> Result<(), E> {
+        self.0.push(if v { TRUE } else { FALSE });
+        Ok(())
+    }
+
+    fn visit_i64<E: Error>(self, v: i64) -> Result<(), E> {
+        self.0.push(INT);
+        self.0.extend_from_slice(&v.to_le_bytes());
+        Ok(())
+    }
+
+    fn visit_u64<E: Error>(self, v: u64) -> Result<(), E> {
+        match i64::try_from(v) {
+            Ok(v) => self.visit_i64(v),
+            Err(_) => {
+                self.0.push(UINT);
+                self.0.extend_from_slice(&v.to_le_bytes());
+                Ok(())
+            },
+        }
+    }
+
+    fn visit_f64<E: Error>(self, v: f64) -> Result<(), E> {
+        self.0.push(FLOAT);
+        self.0.extend_from_slice(&v.to_le_bytes());
+        Ok(())
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<(), E> {
+        self.0.push(STRING);
+        push_str(self.0, v).map_err(E::custom)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        let out = self.0;
+        let start = out.len();
+        out.push(ARRAY);
+        out.extend_from_slice(&[0; CONTAINER_HEADER - 1]);
+        let mut len = 0;
+        while seq.next_element_seed(Encoder(out))?.is_some() {
+            len += 1;
+        }
+        finish_container(out, start, len).map_err(A::Error::custom)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        let out = self.0;
+        let start = out.len();
+        out.push(OBJECT);
+        out.extend_from_slice(&[0; CONTAINER_HEADER - 1]);
+        let mut len = 0;
+        while map.next_key_seed(KeyEncoder(out))?.is_some() {
+            map.next_value_seed(Encoder(out))?;
+            len += 1;
+        }
+        finish_container(out, start, len).map_err(A::Error::custom)
+    }
+}
+
+/// Writes object keys, which are untagged strings.
+struct KeyEncoder<'o>(&'o mut Vec<u8>);
+
+impl<'de> DeserializeSeed<'de> for KeyEncoder<'_> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_str(self)
+    }
+}
+
+impl<'de> Visitor<'de> for KeyEncoder<'_> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a JSON object key")
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<(), E> {
+        push_str(self.0, v).map_err(E::custom)
+    }
+}
+
+#[inline]
+fn read_u32(bytes: &[u8], offset: usize) -> usize {
+    u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap()) as usize
+}
+
+#[inline]
+fn read_8(bytes: &[u8], offset: usize) -> [u8; 8] {
+    bytes[offset..offset + 8].try_into().unwrap()
+}
+
+/// The number of bytes of the encoded value at the start of `bytes`.
+fn encoded_len(bytes: &[u8]) -> usize {
+    match bytes[0] {
+        NULL | FALSE | TRUE => 1,
+        INT | UINT | FLOAT => 9,
+        STRING => 5 + read_u32(bytes, 1),
+        ARRAY | OBJECT => CONTAINER_HEADER + read_u32(bytes, 5),
+        tag => panic!("invalid tag {tag} in binary JSON"),
+    }
+}
+
+/// Read the untagged string at the start of `bytes`, returning it and the remaining bytes.
+fn split_str(bytes: &[u8]) -> (&str, &[u8]) {
+    let len = read_u32(bytes, 0);
+    let s = std::str::from_utf8(&bytes[4..4 + len]).expect("invalid UTF-8 in binary JSON");
+    (s, &bytes[4 + len..])
+}
+
+/// Whether `bytes` holds exactly one well-formed value in the binary encoding, so that
+/// reading it with [`JsonRef`] doesn't panic.
+pub fn is_valid_json_encoding(bytes: &[u8]) -> bool {
+    valid_value_len(bytes) == Some(bytes.len())
+}
+
+/// The byte length of the well-formed encoded value at the start of `bytes`, if it is one.
+fn valid_value_len(bytes: &[u8]) -> Option<usize> {
+    let tag = *bytes.first()?;
+    let len = match tag {
+        NULL | FALSE | TRUE => 1,
+        INT | UINT | FLOAT => 9,
+        STRING => 1 + valid_str_len(&bytes[1..])?,
+        ARRAY | OBJECT => {
+            let header = bytes.get(..CONTAINER_HEADER)?;
+            let (n, size) = (read_u32(header, 1), read_u32(header, 5));
+            let mut rest = bytes.get(CONTAINER_HEADER..CONTAINER_HEADER.checked_add(size)?)?;
+            for _ in 0..n {
+                if tag == OBJECT {
+                    rest = &rest[valid_str_len(rest)?..];
+                }
+                rest = &rest[valid_value_len(rest)?..];
+            }
+            if !rest.is_empty() {
+                return None;
+            }
+            CONTAINER_HEADER + size
+        },
+        _ => return None,
+    };
+    (bytes.len() >= len).then_some(len)
+}
+
+/// The byte length of the well-formed untagged string at the start of `bytes`, if it is one.
+fn valid_str_len(bytes: &[u8]) -> Option<usize> {
+    let len = read_u32(bytes.get(..4)?, 0);
+    std::str::from_utf8(bytes.get(4..4usize.checked_add(len)?)?).ok()?;
+    Some(4 + len)
+}
+
+/// A borrowed JSON value in the binary encoding.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct JsonRef<'a>(&'a [u8]);
+
+impl<'a> JsonRef<'a> {
+    /// View the encoded value at the start of `bytes`.
+    ///
+    /// # Panics
+    /// Accessing the value panics if the bytes don't hold a value in the binary JSON encoding.
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self(&bytes[..encoded_len(bytes)])
+    }
+
+    /// The encoded bytes of this value.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.0
+    }
+
+    pub fn is_null(&self) -> bool {
+        self.0[0] == NULL
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self.0[0] {
+            FALSE => Some(false),
+            TRUE => Some(true),
+            _ => None,
+        }
+    }
+
+    pub fn as_i64(&self) -> Option<i64> {
+        (self.0[0] == INT).then(|| i64::from_le_bytes(read_8(self.0, 1)))
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self.0[0] {
+            INT => Some(i64::from_le_bytes(read_8(self.0, 1)) as f64),
+            UINT => Some(u64::from_le_bytes(read_8(self.0, 1)) as f64),
+            FLOAT => Some(f64::from_le_bytes(read_8(self.0, 1))),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&'a str> {
+        (self.0[0] == STRING).then(|| split_str(&self.0[1..]).0)
+    }
+
+    /// The number of elements or entries of an array or object.
+    pub fn len(&self) -> Option<usize> {
+        matches!(self.0[0], ARRAY | OBJECT).then(|| read_u32(self.0, 1))
+    }
+
+    /// Whether an array or object has no elements or entries.
+    pub fn is_empty(&self) -> Option<bool> {
+        self.len().map(|len| len == 0)
+    }
+
+    /// Iterate over the elements of an array.
+    pub fn elements(&self) -> Option<impl Iterator<Item = JsonRef<'a>> + use<'a>> {
+        let mut rest = self.contents(ARRAY)?;
+        Some(std::iter::from_fn(move || {
+            if rest.is_empty() {
+                return None;
+            }
+            let value = JsonRef::new(rest);
+            rest = &rest[value.0.len()..];
+            Some(value)
+        }))
+    }
+
+    /// Iterate over the entries of an object, in the order of the document.
+    pub fn entries(&self) -> Option<impl Iterator<Item = (&'a str, JsonRef<'a>)> + use<'a>> {
+        let mut rest = self.contents(OBJECT)?;
+        Some(std::iter::from_fn(move || {
+            if rest.is_empty() {
+                return None;
+            }
+            let (key, value_bytes) = split_str(rest);
+            let value = JsonRef::new(value_bytes);
+            rest = &value_bytes[value.0.len()..];
+            Some((key, value))
+        }))
+    }
+
+    /// Get the value of the first entry with this key, if this is an object.
+    pub fn get(&self, key: &str) -> Option<JsonRef<'a>> {
+        self.entries()?.find(|(k, _)| *k == key).map(|(_, v)| v)
+    }
+
+    /// Get the element at this index, if this is an array.
+    pub fn index(&self, idx: usize) -> Option<JsonRef<'a>> {
+        self.elements()?.nth(idx)
+    }
+
+    /// Follow a path of keys and indices.
+    pub fn get_path(&self, path: &[JsonPathElement]) -> Option<JsonRef<'a>> {
+        path.iter().try_fold(*self, |value, elem| match elem {
+            JsonPathElement::Key(key) => value.get(key),
+            JsonPathElement::Index(idx) => value.index(*idx),
+        })
+    }
+
+    fn contents(&self, tag: u8) -> Option<&'a [u8]> {
+        (self.0[0] == tag).then(|| &self.0[CONTAINER_HEADER..])
+    }
+
+    /// Write this value as (compact) JSON text.
+    pub fn write_json(&self, out: &mut Vec<u8>) {
+        let write_str = |out: &mut Vec<u8>, s: &str| serde_json::to_writer(out, s).unwrap();
+        match self.0[0] {
+            NULL => out.extend_from_slice(b"null"),
+            FALSE => out.extend_from_slice(b"false"),
+            TRUE => out.extend_from_slice(b"true"),
+            INT => out.extend_from_slice(
+                itoa::Buffer::new()
+                    .format(i64::from_le_bytes(read_8(self.0, 1)))
+                    .as_bytes(),
+            ),
+            UINT => out.extend_from_slice(
+                itoa::Buffer::new()
+                    .format(u64::from_le_bytes(read_8(self.0, 1)))
+                    .as_bytes(),
+            ),
+            FLOAT => serde_json::to_writer(out, &f64::from_le_bytes(read_8(self.0, 1))).unwrap(),
+            STRING => write_str(out, self.as_str().unwrap()),
+            ARRAY => {
+                out.push(b'[');
+                for (i, value) in self.elements().unwrap().enumerate() {
+                    if i > 0 {
+                        out.push(b',');
+                    }
+                    value.write_json(out);
+                }
+                out.push(b']');
+            },
+            OBJECT => {
+                out.push(b'{');
+                for (i, (key, value)) in self.entries().unwrap().enumerate() {
+                    if i > 0 {
+                        out.push(b',');
+                    }
+                    write_str(out, key);
+                    out.push(b':');
+                    value.write_json(out);
+                }
+                out.push(b'}');
+            },
+            tag => panic!("invalid tag {tag} in binary JSON"),
+        }
+    }
+
+    /// This value as (compact) JSON text.
+    pub fn to_json_string(&self) -> String {
+        let mut out = Vec::with_capacity(self.0.len());
+        self.write_json(&mut out);
+        // SAFETY: we only write valid UTF-8.
+        unsafe { String::from_utf8_unchecked(out) }
+    }
+
+    /// Convert to a [`serde_json::Value`].
+    pub fn to_value(&self) -> serde_json::Value {
+        use serde_json::Value;
+        match self.0[0] {
+            NULL => Value::Null,
+            FALSE | TRUE => Value::Bool(self.0[0] == TRUE),
+            INT => Value::from(i64::from_le_bytes(read_8(self.0, 1))),
+            UINT => Value::from(u64::from_le_bytes(read_8(self.0, 1))),
+            FLOAT => Value::from(f64::from_le_bytes(read_8(self.0, 1))),
+            STRING => Value::from(self.as_str().unwrap()),
+            ARRAY => Value::Array(self.elements().unwrap().map(|v| v.to_value()).collect()),
+            OBJECT => Value::Object(
+                self.entries()
+                    .unwrap()
+                    .map(|(k, v)| (k.to_string(), v.to_value()))
+                    .collect(),
+            ),
+            tag => panic!("invalid tag {tag} in binary JSON"),
+        }
+    }
+}
+
+/// A step in a [`JsonRef::get_path`] path.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum JsonPathElement {
+    Key(String),
+    Index(usize),
+}
+
+/// Parse a JSONPath that only consists of keys and indices, e.g. `$.a.b[0]` or `$['a'][1]`.
+///
+/// Returns `None` for anything else, such as wildcards, slices, filters and recursive descent.
+pub fn parse_simple_json_path(path: &str) -> Option<Vec<JsonPathElement>> {
+    let mut rest = path.trim().strip_prefix('$')?;
+    let mut out = Vec::new();
+    while !rest.is_empty() {
+        if let Some(r) = rest.strip_prefix('.') {
+            let end = r.find(['.', '[']).unwrap_or(r.len());
+            let key = &r[..end];
+            if key.is_empty() || key == "*" {
+                return None;
+            }
+            out.push(JsonPathElement::Key(key.to_string()));
+            rest = &r[end..];
+        } else if let Some(r) = rest.strip_prefix('[') {
+            let end = r.find(']')?;
+            let inner = r[..end].trim();
+            let elem = match inner.as_bytes().first()? {
+                q @ (b'\'' | b'"') => {
+                    let key = inner[1..].strip_suffix(*q as char)?;
+                    if key.contains(['\'', '"', '\\']) {
+                        return None;
+                    }
+                    JsonPathElement::Key(key.to_string())
+                },
+                _ if inner.bytes().all(|b| b.is_ascii_digit()) => {
+                    JsonPathElement::Index(inner.parse().ok()?)
+                },
+                _ => return None,
+            };
+            out.push(elem);
+            rest = &r[end + 1..];
+        } else {
+            return None;
+        }
+    }
+    Some(out)
+}
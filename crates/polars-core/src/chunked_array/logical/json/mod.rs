@@ -0,0 +1,337 @@
+mod encoding;
+
+use arrow::array::MutableBinaryViewArray;
+use arrow::datatypes::ExtensionType;
+pub use encoding::*;
+
+use super::*;
+use crate::prelude::*;
+
+pub type JsonChunked = Logical<JsonType, BinaryType>;
+
+/// Name of the canonical Arrow extension type for JSON.
+pub const JSON_EXTENSION_NAME: &str = "arrow.json";
+
+/// Whether an arrow extension type is JSON text that we load as [`DataType::Json`].
+pub fn is_json_extension(ext: &ExtensionType) -> bool {
+    ext.name == JSON_EXTENSION_NAME
+}
+
+/// The arrow data type of JSON: the `arrow.json` extension over strings.
+pub fn json_arrow_dtype(compat_level: CompatLevel) -> ArrowDataType {
+    ArrowDataType::Extension(Box::new(ExtensionType {
+        name: PlSmallStr::from_static(JSON_EXTENSION_NAME),
+        inner: DataType::String.to_arrow(compat_level),
+        metadata: None,
+    }))
+}
+
+/// Convert a physical JSON array to an arrow `arrow.json` array of JSON text.
+pub(crate) fn json_array_to_arrow(arr: &BinaryViewArray, compat_level: CompatLevel) -> ArrayRef {
+    let mut buf = Vec::new();
+    let mut out = MutableBinaryViewArray::<str>::with_capacity(arr.len());
+    for opt_v in arr.iter() {
+        match opt_v {
+            Some(v) => {
+                buf.clear();
+                JsonRef::new(v).write_json(&mut buf);
+                // SAFETY: we only write valid UTF-8.
+                out.push_value(unsafe { std::str::from_utf8_unchecked(&buf) });
+            },
+            None => out.push_null(),
+        }
+    }
+    let arr: Utf8ViewArray = out.into();
+    let arr = if compat_level.0 >= 1 {
+        arr.boxed()
+    } else {
+        polars_compute::cast::cast_unchecked(&arr, &ArrowDataType::LargeUtf8).unwrap()
+    };
+    arrow::array::with_dtype(arr.as_ref(), json_arrow_dtype(compat_level))
+}
+
+/// Parse JSON text, values that aren't valid JSON become null.
+fn encode_json_array<'a>(values: impl Iterator<Item = Option<&'a [u8]>>) -> BinaryViewArray {
+    let mut buf = Vec::new();
+    let mut out = MutableBinaryViewArray::<[u8]>::with_capacity(values.size_hint().0);
+    for opt_v in values {
+        buf.clear();
+        match opt_v.map(|v| encode_json(v, &mut buf)) {
+            Some(Ok(())) => out.push_value(&buf),
+            _ => out.push_null(),
+        }
+    }
+    out.into()
+}
+
+/// Cast a Series of another type to JSON, values that aren't valid JSON become null.
+pub(crate) fn cast_to_json(s: &Series) -> PolarsResult<Series> {
+    let chunks = match s.dtype() {
+        DataType::Json => return Ok(s.clone()),
+        DataType::String => s
+            .str()?
+            .downcast_iter()
+            .map(|arr| encode_json_array(arr.iter().map(|opt_v| opt_v.map(str::as_bytes))))
+            .collect::<Vec<_>>(),
+        DataType::Binary => s
+            .binary()?
+            .downcast_iter()
+            .map(|arr| encode_json_array(arr.iter()))
+            .collect(),
+        DataType::Null => {
+            return Ok(BinaryChunked::full_null(s.name().clone(), s.len())
+                .into_json_unchecked()
+                .into_series());
+        },
+        dt => polars_bail!(InvalidOperation: "casting from {:?} to Json not supported", dt),
+    };
+    Ok(BinaryChunked::from_chunk_iter(s.name().clone(), chunks)
+        .into_json_unchecked()
+        .into_series())
+}
+
+impl BinaryChunked {
+    /// Interpret the values as JSON in the binary encoding of [`JsonRef`], checking that
+    /// they are.
+    ///
+    /// Use a cast to parse JSON text instead.
+    pub fn into_json(self) -> PolarsResult<JsonChunked> {
+        polars_ensure!(
+            self.iter().flatten().all(is_valid_json_encoding),
+            ComputeError: "invalid binary JSON in column '{}'", self.name()
+        );
+        Ok(self.into_json_unchecked())
+    }
+
+    /// Interpret the values as JSON in the binary encoding of [`JsonRef`], without checking
+    /// that they are; reading invalid values panics.
+    pub(crate) fn into_json_unchecked(self) -> JsonChunked {
+        // SAFETY: reading invalid binary JSON panics, but isn't unsafe.
+        unsafe { JsonChunked::new_logical(self, DataType::Json) }
+    }
+}
+
+impl JsonChunked {
+    /// Iterate over the JSON values.
+    pub fn iter_json(&self) -> impl Iterator<Item = Option<JsonRef<'_>>> {
+        self.phys.iter().map(|opt_v| opt_v.map(JsonRef::new))
+    }
+
+    /// The JSON values as (compact) JSON text.
+    pub fn to_string(&self) -> StringChunked {
+        self.phys.apply_into_string_amortized(|v, out| {
+            // SAFETY: we only write valid UTF-8.
+            JsonRef::new(v).write_json(unsafe { out.as_mut_vec() });
+        })
+    }
+
+    /// Get the value at a path of keys and indices, e.g. `$.a.b[0]` or `$['a'][1]`.
+    ///
+    /// Rows where the path doesn't exist are null. This works on the binary encoding, so no
+    /// JSON is parsed.
+    pub fn get_path(&self, path: &str) -> PolarsResult<JsonChunked> {
+        let Some(path) = parse_simple_json_path(path) else {
+            polars_bail!(
+                InvalidOperation:
+                "JSON path '{}' is not supported, only keys and indices are", path
+            )
+        };
+        let chunks = self.phys.downcast_iter().map(|arr| {
+            let mut out = MutableBinaryViewArray::<[u8]>::with_capacity(arr.len());
+            for opt_v in arr.iter() {
+                out.push(opt_v.and_then(|v| JsonRef::new(v).get_path(&path).map(|v| v.as_bytes())));
+            }
+            BinaryViewArray::from(out)
+        });
+        Ok(BinaryChunked::from_chunk_iter(self.name().clone(), chunks).into_json_unchecked())
+    }
+
+    /// Get the value of a key of JSON objects, null for rows that don't have it.
+    pub fn field_by_name(&self, name: &str) -> JsonChunked {
+        let path = [JsonPathElement::Key(name.to_string())];
+        let chunks = self.phys.downcast_iter().map(|arr| {
+            let mut out = MutableBinaryViewArray::<[u8]>::with_capacity(arr.len());
+            for opt_v in arr.iter() {
+                out.push(opt_v.and_then(|v| JsonRef::new(v).get_path(&path).map(|v| v.as_bytes())));
+            }
+            BinaryViewArray::from(out)
+        });
+        BinaryChunked::from_chunk_iter(self.name().clone(), chunks).into_json_unchecked()
+    }
+}
+
+impl LogicalType for JsonChunked {
+    fn dtype(&self) -> &DataType {
+        &DataType::Json
+    }
+
+    #[inline]
+    fn get_any_value(&self, i: usize) -> PolarsResult<AnyValue<'_>> {
+        polars_ensure!(i < self.len(), oob = i, self.len());
+        Ok(unsafe { self.get_any_value_unchecked(i) })
+    }
+
+    #[inline]
+    unsafe fn get_any_value_unchecked(&self, i: usize) -> AnyValue<'_> {
+        match self.phys.get_unchecked(i) {
+            Some(v) => AnyValue::StringOwned(JsonRef::new(v).to_json_string().into()),
+            None => AnyValue::Null,
+        }
+    }
+
+    #[cfg_attr(not(feature = "dtype-categorical"), allow(unused_variables))]
+    fn cast_with_options(
+        &self,
+        dtype: &DataType,
+        cast_options: CastOptions,
+    ) -> PolarsResult<Series> {
+        match dtype {
+            DataType::Json => Ok(self.clone().into_series()),
+            DataType::String => Ok(self.to_string().into_series()),
+            DataType::Binary => Ok(self.to_string().as_binary().into_series()),
+            #[cfg(feature = "dtype-categorical")]
+            DataType::Categorical(_, _) | DataType::Enum(_, _) => self
+                .to_string()
+                .into_series()
+                .cast_with_options(dtype, cast_options),
+            dt => polars_bail!(
+                InvalidOperation:
+                "casting from {:?} to {:?} not supported",
+                self.dtype(), dt
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_json_encoding_round_trip() {
+        let text = r#"{"b":[1,-2.5,18446744073709551615,"x\"y",null,true],"a":{},"c":[]}"#;
+        let mut buf = Vec::new();
+        encode_json(text.as_bytes(), &mut buf).unwrap();
+        let v = JsonRef::new(&buf);
+        assert_eq!(v.to_json_string(), text);
+        assert_eq!(v.len(), Some(3));
+        assert_eq!(
+            v.entries().unwrap().map(|(k, _)| k).collect::<Vec<_>>(),
+            ["b", "a", "c"]
+        );
+        let b = v.get("b").unwrap();
+        assert_eq!(b.index(0).unwrap().as_i64(), Some(1));
+        assert_eq!(b.index(1).unwrap().as_f64(), Some(-2.5));
+        assert_eq!(b.index(3).unwrap().as_str(), Some("x\"y"));
+        assert!(b.index(4).unwrap().is_null());
+        assert_eq!(b.index(5).unwrap().as_bool(), Some(true));
+        assert_eq!(b.index(6), None);
+        assert_eq!(v.get("d"), None);
+        assert_eq!(
+            v.to_value(),
+            serde_json::from_str::<serde_json::Value>(text).unwrap()
+        );
+
+        // Whitespace doesn't matter, and invalid documents leave nothing behind.
+        let mut other = Vec::new();
+        encode_json(b" { \"b\" : [1, -2.5, 18446744073709551615, \"x\\\"y\", null, true], \"a\": {}, \"c\": [] } ", &mut other).unwrap();
+        assert_eq!(buf, other);
+        assert!(encode_json(b"{\"a\": 1", &mut other).is_err());
+        assert!(encode_json(b"1 2", &mut other).is_err());
+        assert_eq!(buf, other);
+    }
+
+    #[test]
+    fn test_into_json_validates() {
+        let mut buf = Vec::new();
+        encode_json(br#"{"a":[1,"b"]}"#, &mut buf).unwrap();
+        assert!(is_valid_json_encoding(&buf));
+
+        let mut invalid = vec![&buf[..buf.len() - 1], &[42], &[], b"\x06\xff\xff\xff\xff"];
+        let mut bad_utf8 = buf.clone();
+        *bad_utf8.last_mut().unwrap() = 0xff;
+        invalid.push(&bad_utf8);
+        for bytes in &invalid {
+            assert!(!is_valid_json_encoding(bytes), "{bytes:?}");
+        }
+
+        let name = PlSmallStr::from_static("a");
+        let ca = BinaryChunked::from_slice(name.clone(), &[buf.as_slice()]);
+        assert!(ca.into_json().is_ok());
+        for bytes in invalid {
+            let ca = BinaryChunked::from_slice(name.clone(), &[bytes]);
+            assert!(ca.into_json().is_err());
+        }
+    }
+
+    #[test]
+    fn test_simple_json_path() {
+        use JsonPathElement::*;
+        assert_eq!(parse_simple_json_path("$"), Some(vec![]));
+        assert_eq!(
+            parse_simple_json_path("$.a['b c'][2].d"),
+            Some(vec![
+                Key("a".into()),
+                Key("b c".into()),
+                Index(2),
+                Key("d".into())
+            ])
+        );
+        for path in [
+            "$..a",
+            "$.a[*]",
+            "$.a[1:2]",
+            "$[?(@.a)]",
+            "$.*",
+            "a",
+            "$[-1]",
+        ] {
+            assert_eq!(parse_simple_json_path(path), None, "{path}");
+        }
+    }
+
+    #[test]
+    fn test_json_cast_and_get_path() {
+        let s = Series::new(
+            PlSmallStr::from_static("a"),
+            [
+                Some(r#"{"a": {"b": [1, 2]}, "c": "x"}"#),
+                None,
+                Some(r#"{"a": 3}"#),
+                Some("not json"),
+            ],
+        );
+        assert!(s.strict_cast(&DataType::Json).is_err());
+        let json = s.cast(&DataType::Json).unwrap();
+        assert_eq!(json.dtype(), &DataType::Json);
+        assert_eq!(json.null_count(), 2);
+        let ca = json.json().unwrap();
+
+        let b = ca.get_path("$.a.b[1]").unwrap();
+        assert_eq!(Vec::from(&b.to_string()), &[Some("2"), None, None, None]);
+        assert_eq!(
+            Vec::from(&ca.field_by_name("c").to_string()),
+            &[Some("\"x\""), None, None, None]
+        );
+        assert!(ca.get_path("$..b").is_err());
+
+        let strings = json.cast(&DataType::String).unwrap();
+        assert_eq!(
+            Vec::from(strings.str().unwrap()),
+            &[
+                Some(r#"{"a":{"b":[1,2]},"c":"x"}"#),
+                None,
+                Some(r#"{"a":3}"#),
+                None
+            ]
+        );
+
+        for compat_level in [CompatLevel::oldest(), CompatLevel::newest()] {
+            let field = json.field().to_arrow(compat_level);
+            assert_eq!(field.dtype(), &json_arrow_dtype(compat_level));
+            let arr = json.to_arrow(0, compat_level);
+            let out = Series::try_from((&field, arr)).unwrap();
+            assert!(out.equals_missing(&json));
+        }
+    }
+}
@@ -0,0 +1,227 @@
+use arrow::array::{ListArray, MapArray, StructArray};
+use arrow::offset::OffsetsBuffer;
+
+use super::*;
+use crate::prelude::*;
+
+pub type MapChunked = Logical<MapType, ListType>;
+
+/// Rename the fields of the `{key, value}` structs inside a list array.
+fn rename_entries_array(arr: &ListArray<i64>) -> ListArray<i64> {
+    let values = arr.values().as_any().downcast_ref::<StructArray>().unwrap();
+    let ArrowDataType::Struct(fields) = values.dtype() else {
+        unreachable!()
+    };
+    let fields = fields
+        .iter()
+        .zip(["key", "value"])
+        .map(|(fld, name)| {
+            let mut fld = fld.clone();
+            fld.name = PlSmallStr::from_static(name);
+            fld
+        })
+        .collect();
+    let values = StructArray::new(
+        ArrowDataType::Struct(fields),
+        values.len(),
+        values.values().to_vec(),
+        values.validity().cloned(),
+    );
+    ListArray::<i64>::new(
+        ListArray::<i64>::default_datatype(values.dtype().clone()),
+        arr.offsets().clone(),
+        values.boxed(),
+        arr.validity().cloned(),
+    )
+}
+
+/// Convert a physical list of `{key, value}` structs to an arrow [`MapArray`] of the given type.
+pub(crate) fn entries_array_to_map_array(arr: &ListArray<i64>, dtype: ArrowDataType) -> MapArray {
+    let ArrowDataType::Map(entries, _) = &dtype else {
+        unreachable!()
+    };
+    let values = arr.values().as_any().downcast_ref::<StructArray>().unwrap();
+    let values = StructArray::new(
+        entries.dtype().clone(),
+        values.len(),
+        values.values().to_vec(),
+        values.validity().cloned(),
+    );
+    let offsets = OffsetsBuffer::<i32>::try_from(arr.offsets()).expect("map entries overflow i32");
+    MapArray::new(dtype, offsets, values.boxed(), arr.validity().cloned())
+}
+
+impl ListChunked {
+    /// Interpret a list of two-field structs as a map, the first field holding the keys and the
+    /// second the values. Fails if the inner type isn't such a struct, or if any key is null.
+    pub fn into_map(self) -> PolarsResult<MapChunked> {
+        let (key, value) = match self.inner_dtype() {
+            DataType::Struct(fields) if fields.len() == 2 => {
+                (fields[0].dtype().clone(), fields[1].dtype().clone())
+            },
+            dt => polars_bail!(
+                InvalidOperation: "cannot interpret a list of {} as a map, expected a list of {{key, value}} structs", dt
+            ),
+        };
+        let ca = self.propagate_nulls().unwrap_or(self);
+        for arr in ca.downcast_iter() {
+            let values = arr.values().as_any().downcast_ref::<StructArray>().unwrap();
+            let start = *arr.offsets().first() as usize;
+            let keys = values.values()[0].sliced(start, arr.offsets().range() as usize);
+            polars_ensure!(
+                keys.null_count() == 0,
+                InvalidOperation: "map keys cannot be null"
+            );
+        }
+        let chunks = ca
+            .downcast_iter()
+            .map(|arr| rename_entries_array(arr).boxed())
+            .collect();
+        let dtype = DataType::Map(Box::new(key), Box::new(value));
+        // SAFETY: the inner type is the `{key, value}` struct of the map's key and value types.
+        unsafe {
+            let ca = ListChunked::from_chunks_and_dtype_unchecked(
+                ca.name().clone(),
+                chunks,
+                dtype.to_entries_list_type(),
+            );
+            Ok(MapChunked::new_logical(ca, dtype))
+        }
+    }
+}
+
+impl DataType {
+    /// The list of `{key, value}` structs that backs a [`DataType::Map`], keeping the logical
+    /// key and value types.
+    pub(crate) fn to_entries_list_type(&self) -> DataType {
+        let DataType::Map(key, value) = self else {
+            unreachable!()
+        };
+        DataType::List(Box::new(DataType::_map_entries_type(
+            (**key).clone(),
+            (**value).clone(),
+        )))
+    }
+}
+
+impl MapChunked {
+    /// # Safety
+    /// The chunks must be lists of `{key, value}` structs of the physical key and value types.
+    pub unsafe fn from_chunks_and_dtype_unchecked(
+        name: PlSmallStr,
+        chunks: Vec<ArrayRef>,
+        dtype: DataType,
+    ) -> Self {
+        let ca = ListChunked::from_chunks_and_dtype_unchecked(
+            name,
+            chunks,
+            dtype.to_entries_list_type(),
+        );
+        MapChunked::new_logical(ca, dtype)
+    }
+
+    pub fn key_dtype(&self) -> &DataType {
+        let DataType::Map(key, _) = self.dtype() else {
+            unreachable!()
+        };
+        key
+    }
+
+    pub fn value_dtype(&self) -> &DataType {
+        let DataType::Map(_, value) = self.dtype() else {
+            unreachable!()
+        };
+        value
+    }
+
+    /// The entries of the map as a list of `{key, value}` structs.
+    pub fn entries(&self) -> &ListChunked {
+        self.physical()
+    }
+
+    pub(crate) fn with_entries(&self, entries: ListChunked) -> Self {
+        // SAFETY: the entries were derived from our own.
+        unsafe { MapChunked::new_logical(entries, self.dtype().clone()) }
+    }
+}
+
+impl LogicalType for MapChunked {
+    fn dtype(&self) -> &DataType {
+        &self.dtype
+    }
+
+    /// Maps are represented as lists of their `{key, value}` entries.
+    fn get_any_value(&self, i: usize) -> PolarsResult<AnyValue<'_>> {
+        self.phys.get_any_value(i)
+    }
+
+    unsafe fn get_any_value_unchecked(&self, i: usize) -> AnyValue<'_> {
+        self.phys.get_any_value_unchecked(i)
+    }
+
+    fn cast_with_options(&self, dtype: &DataType, options: CastOptions) -> PolarsResult<Series> {
+        match dtype {
+            dt if dt == self.dtype() => Ok(self.clone().into_series()),
+            DataType::Map(_, _) => {
+                let entries = self
+                    .phys
+                    .cast_with_options(&dtype.to_entries_list_type(), options)?;
+                Ok(entries.list()?.clone().into_map()?.into_series())
+            },
+            DataType::List(_) => self.phys.cast_with_options(dtype, options),
+            dt => polars_bail!(
+                InvalidOperation: "casting from {:?} to {:?} not supported", self.dtype(), dt
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::chunked_array::builder::get_list_builder;
+
+    fn entries() -> ListChunked {
+        let keys = Series::new(PlSmallStr::from_static("k"), ["a", "b", "c"]);
+        let values = Series::new(PlSmallStr::from_static("v"), [Some(1i32), None, Some(3)]);
+        let structs = StructChunked::from_series(
+            PlSmallStr::from_static("entries"),
+            3,
+            [keys, values].iter(),
+        )
+        .unwrap()
+        .into_series();
+        let mut builder = get_list_builder(structs.dtype(), 3, 2, PlSmallStr::from_static("m"));
+        builder.append_series(&structs.slice(0, 2)).unwrap();
+        builder.append_null();
+        builder.append_series(&structs.slice(2, 1)).unwrap();
+        builder.finish()
+    }
+
+    #[test]
+    fn test_map_arrow_round_trip() {
+        let s = entries().into_map().unwrap().into_series();
+        assert_eq!(
+            s.dtype(),
+            &DataType::Map(Box::new(DataType::String), Box::new(DataType::Int32))
+        );
+        let arr = s.to_arrow(0, CompatLevel::newest());
+        assert!(matches!(arr.dtype(), ArrowDataType::Map(_, _)));
+        let out = Series::from_arrow(PlSmallStr::from_static("m"), arr).unwrap();
+        assert_eq!(out.dtype(), s.dtype());
+        assert!(out.equals_missing(&s));
+        assert_eq!(out.null_count(), 1);
+    }
+
+    #[test]
+    fn test_map_null_keys() {
+        let keys = Series::new(PlSmallStr::from_static("k"), [Some("a"), None]);
+        let values = Series::new(PlSmallStr::from_static("v"), [1i32, 2]);
+        let structs =
+            StructChunked::from_series(PlSmallStr::from_static("s"), 2, [keys, values].iter())
+                .unwrap()
+                .into_series();
+        let ca = structs.implode().unwrap();
+        assert!(ca.into_map().is_err());
+    }
+}
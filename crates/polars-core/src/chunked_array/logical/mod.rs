@@ -10,10 +10,42 @@ pub use datetime::*;
 mod decimal;
 #[cfg(feature = "dtype-decimal")]
 pub use decimal::*;
+#[cfg(feature = "dtype-decimal256")]
+mod decimal256;
+#[cfg(feature = "dtype-decimal256")]
+pub use decimal256::*;
 #[cfg(feature = "dtype-duration")]
 mod duration;
 #[cfg(feature = "dtype-duration")]
 pub use duration::*;
+#[cfg(feature = "dtype-extension")]
+mod extension;
+#[cfg(feature = "dtype-extension")]
+pub use extension::*;
+#[cfg(feature = "dtype-f16")]
+mod float16;
+#[cfg(feature = "dtype-f16")]
+pub use float16::*;
+#[cfg(feature = "dtype-interval")]
+mod interval;
+#[cfg(feature = "dtype-interval")]
+pub use interval::*;
+#[cfg(feature = "dtype-json")]
+mod json;
+#[cfg(feature = "dtype-json")]
+pub use json::*;
+#[cfg(feature = "dtype-map")]
+mod map;
+#[cfg(feature = "dtype-map")]
+pub use map::*;
+#[cfg(feature = "dtype-union")]
+mod union;
+#[cfg(feature = "dtype-union")]
+pub use union::*;
+#[cfg(feature = "dtype-uuid")]
+mod uuid;
+#[cfg(feature = "dtype-uuid")]
+pub use uuid::*;
 #[cfg(feature = "dtype-categorical")]
 pub mod categorical;
 #[cfg(feature = "dtype-time")]
@@ -0,0 +1,401 @@
+use arrow::array::UnionArray;
+use arrow::buffer::Buffer;
+
+use super::*;
+use crate::chunked_array::ops::any_value::arr_to_any_value;
+use crate::prelude::*;
+use crate::utils::try_get_supertype;
+
+pub type UnionChunked = Logical<UnionType, StructType>;
+
+impl StructChunked {
+    /// Interpret the fields of the struct as the variants of a union. Fails if more than one
+    /// field is valid in any row.
+    pub fn into_union(self, mode: UnionMode) -> PolarsResult<UnionChunked> {
+        let ca = self
+            .propagate_nulls()
+            .unwrap_or(self)
+            .rechunk()
+            .into_owned();
+        let mut any_valid = BooleanChunked::full(PlSmallStr::EMPTY, false, ca.len());
+        for s in ca.fields_as_series() {
+            let is_valid = s.is_not_null();
+            polars_ensure!(
+                !(&any_valid & &is_valid).any(),
+                InvalidOperation: "cannot interpret {} as a union, multiple fields are valid in a row", ca.dtype()
+            );
+            any_valid = &any_valid | &is_valid;
+        }
+        let validity = any_valid.rechunk().downcast_as_array().values().clone();
+        let ca = ca.with_outer_validity(Some(validity));
+        let dtype = DataType::Union(ca.struct_fields().to_vec(), mode);
+        // SAFETY: at most one field is valid in every row, and the struct is null where none is.
+        Ok(unsafe { UnionChunked::new_logical(ca, dtype) })
+    }
+}
+
+impl UnionChunked {
+    pub fn variants(&self) -> &[Field] {
+        let DataType::Union(fields, _) = self.dtype() else {
+            unreachable!()
+        };
+        fields
+    }
+
+    pub fn mode(&self) -> UnionMode {
+        let DataType::Union(_, mode) = self.dtype() else {
+            unreachable!()
+        };
+        *mode
+    }
+
+    /// The variants as a struct with a field per variant, of which at most one is valid in
+    /// every row.
+    pub fn to_struct(&self) -> &StructChunked {
+        self.physical()
+    }
+
+    /// The values of the variant `name`, null in the rows in which another variant is active.
+    pub fn field_by_name(&self, name: &str) -> PolarsResult<Series> {
+        self.physical().field_by_name(name)
+    }
+
+    /// The index of the active variant in every row, null for null values.
+    pub fn variant_indices(&self) -> IdxCa {
+        let mut indices = vec![None; self.len()];
+        for (i, s) in self.physical().fields_as_series().iter().enumerate() {
+            let is_valid = s.is_not_null();
+            for (idx, valid) in indices.iter_mut().zip(is_valid.into_no_null_iter()) {
+                if valid {
+                    *idx = Some(i as IdxSize);
+                }
+            }
+        }
+        IdxCa::from_iter_options(self.name().clone(), indices.into_iter())
+    }
+
+    /// Cast the values of all variants to their supertype.
+    pub fn to_supertype(&self) -> PolarsResult<Series> {
+        let dtype = self
+            .variants()
+            .iter()
+            .map(|fld| fld.dtype().clone())
+            .try_fold(DataType::Null, |acc, dt| try_get_supertype(&acc, &dt))?;
+        self.cast(&dtype)
+    }
+
+    /// Cast the values of every variant to `dtype`, and take the values of the active ones.
+    fn merge_variants(&self, dtype: &DataType, options: CastOptions) -> PolarsResult<Series> {
+        let len = self.len();
+        let mut values = Series::new_empty(self.name().clone(), dtype);
+        for s in self.physical().fields_as_series() {
+            values.append_owned(s.cast_with_options(dtype, options)?)?;
+        }
+        let indices = self.variant_indices();
+        let indices: IdxCa = indices
+            .into_iter()
+            .enumerate()
+            .map(|(row, opt_i)| opt_i.map(|i| i * len as IdxSize + row as IdxSize))
+            .collect();
+        values.take(&indices)
+    }
+
+    pub(crate) fn with_struct(&self, ca: StructChunked) -> Self {
+        // SAFETY: the struct was derived from our own.
+        unsafe { UnionChunked::new_logical(ca, self.dtype().clone()) }
+    }
+
+    /// Convert a chunk to an arrow [`UnionArray`].
+    pub(crate) fn to_union_array(&self, chunk_idx: usize, compat_level: CompatLevel) -> UnionArray {
+        let ca = self.physical();
+        let arr = ca.downcast_chunks().get(chunk_idx).unwrap();
+        // SAFETY: the chunk is one of our own.
+        let ca = unsafe {
+            StructChunked::from_chunks_and_dtype_unchecked(
+                PlSmallStr::EMPTY,
+                vec![arr.clone().boxed()],
+                ca.dtype().clone(),
+            )
+        };
+        let fields = ca.fields_as_series();
+        // Null values are a null of the first variant.
+        let mut types = vec![0i8; ca.len()];
+        for (i, s) in fields.iter().enumerate() {
+            for (tp, valid) in types.iter_mut().zip(s.is_not_null().into_no_null_iter()) {
+                if valid {
+                    *tp = i as i8;
+                }
+            }
+        }
+
+        let (values, offsets) = match self.mode() {
+            UnionMode::Sparse => {
+                let values = fields
+                    .iter()
+                    .map(|s| s.rechunk().to_arrow(0, compat_level))
+                    .collect();
+                (values, None)
+            },
+            UnionMode::Dense => {
+                let mut lengths = vec![0i32; fields.len()];
+                let offsets: Vec<i32> = types
+                    .iter()
+                    .map(|tp| {
+                        let offset = lengths[*tp as usize];
+                        lengths[*tp as usize] += 1;
+                        offset
+                    })
+                    .collect();
+                let values = fields
+                    .iter()
+                    .enumerate()
+                    .map(|(i, s)| {
+                        let mask: BooleanChunked = types
+                            .iter()
+                            .map(|tp| *tp as usize == i)
+                            .collect_ca(PlSmallStr::EMPTY);
+                        s.filter(&mask).unwrap().rechunk().to_arrow(0, compat_level)
+                    })
+                    .collect();
+                (values, Some(Buffer::from(offsets)))
+            },
+        };
+        UnionArray::new(
+            self.dtype().to_arrow(compat_level),
+            Buffer::from(types),
+            values,
+            offsets,
+        )
+    }
+}
+
+/// Convert arrow union arrays to a [`UnionChunked`], as a [`Series`].
+pub(crate) fn union_arrays_to_series(
+    name: PlSmallStr,
+    chunks: &[ArrayRef],
+    union: &arrow::datatypes::UnionType,
+) -> PolarsResult<Series> {
+    let mut variants = union
+        .fields
+        .iter()
+        .map(|fld| Series::new_empty(fld.name.clone(), &DataType::from_arrow_field(fld)))
+        .collect::<Vec<_>>();
+    for arr in chunks {
+        let arr = arr.as_any().downcast_ref::<UnionArray>().unwrap();
+        // The position of every row in the child arrays, for the variant that is active in it.
+        let slots = (0..arr.len()).map(|i| arr.index(i)).collect::<Vec<_>>();
+        for (i, (variant, fld)) in variants.iter_mut().zip(&union.fields).enumerate() {
+            let values = unsafe {
+                Series::_try_from_arrow_unchecked_with_md(
+                    fld.name.clone(),
+                    vec![arr.fields()[i].clone()],
+                    fld.dtype(),
+                    fld.metadata.as_deref(),
+                )
+            }?;
+            let indices = slots
+                .iter()
+                .map(|(tp, slot)| (*tp == i).then_some(*slot as IdxSize));
+            let indices = IdxCa::from_iter_options(PlSmallStr::EMPTY, indices);
+            variant.append_owned(values.take(&indices)?)?;
+        }
+    }
+    let len = chunks.iter().map(|arr| arr.len()).sum();
+    let ca = StructChunked::from_series(name, len, variants.iter())?;
+    Ok(ca.into_union(union.mode.into())?.into_series())
+}
+
+/// Cast a series that isn't a union to the union type `dtype`.
+///
+/// Structs are cast field by field, other values go into the first variant of their type.
+pub(crate) fn cast_into_union(
+    s: &Series,
+    dtype: &DataType,
+    options: CastOptions,
+) -> PolarsResult<Series> {
+    let DataType::Union(fields, mode) = dtype else {
+        unreachable!()
+    };
+    let ca = match s.dtype() {
+        DataType::Struct(_) => s
+            .cast_with_options(&DataType::Struct(fields.clone()), options)?
+            .struct_()?
+            .clone(),
+        DataType::Null => {
+            Series::full_null(s.name().clone(), s.len(), &DataType::Struct(fields.clone()))
+                .struct_()?
+                .clone()
+        },
+        dt => {
+            let Some(idx) = fields.iter().position(|fld| fld.dtype() == dt) else {
+                polars_bail!(InvalidOperation: "casting from {} to {:?} not supported, no variant has this type", dt, dtype)
+            };
+            let variants = fields
+                .iter()
+                .enumerate()
+                .map(|(i, fld)| {
+                    if i == idx {
+                        s.clone().with_name(fld.name().clone())
+                    } else {
+                        Series::full_null(fld.name().clone(), s.len(), fld.dtype())
+                    }
+                })
+                .collect::<Vec<_>>();
+            StructChunked::from_series(s.name().clone(), s.len(), variants.iter())?
+        },
+    };
+    Ok(ca.into_union(*mode)?.into_series())
+}
+
+impl LogicalType for UnionChunked {
+    fn dtype(&self) -> &DataType {
+        &self.dtype
+    }
+
+    /// Values are represented by the value of their active variant.
+    fn get_any_value(&self, i: usize) -> PolarsResult<AnyValue<'_>> {
+        polars_ensure!(i < self.len(), oob = i, self.len());
+        Ok(unsafe { self.get_any_value_unchecked(i) })
+    }
+
+    unsafe fn get_any_value_unchecked(&self, i: usize) -> AnyValue<'_> {
+        let (chunk_idx, idx) = self.phys.index_to_chunked_index(i);
+        let arr = self.phys.downcast_get_unchecked(chunk_idx);
+        if arr.is_null_unchecked(idx) {
+            return AnyValue::Null;
+        }
+        for (values, fld) in arr.values().iter().zip(self.variants()) {
+            if values.is_valid(idx) {
+                return arr_to_any_value(values.as_ref(), idx, fld.dtype());
+            }
+        }
+        AnyValue::Null
+    }
+
+    fn cast_with_options(&self, dtype: &DataType, options: CastOptions) -> PolarsResult<Series> {
+        match dtype {
+            dt if dt == self.dtype() => Ok(self.clone().into_series()),
+            DataType::Union(fields, mode) => {
+                let ca = self
+                    .phys
+                    .cast_with_options(&DataType::Struct(fields.clone()), options)?;
+                Ok(ca.struct_()?.clone().into_union(*mode)?.into_series())
+            },
+            DataType::Struct(_) => self.phys.cast_with_options(dtype, options),
+            dt => self.merge_variants(dt, options),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use arrow::datatypes::{ArrowDataType, Field as ArrowField, UnionType as ArrowUnionType};
+
+    use super::*;
+
+    fn union_array(mode: arrow::datatypes::UnionMode) -> ArrayRef {
+        let fields = vec![
+            ArrowField::new("int".into(), ArrowDataType::Int64, true),
+            ArrowField::new("str".into(), ArrowDataType::Utf8View, true),
+        ];
+        let dtype = ArrowDataType::Union(Box::new(ArrowUnionType {
+            fields,
+            ids: Some(vec![5, 7]),
+            mode,
+        }));
+        let types = Buffer::from(vec![5i8, 7, 5, 7]);
+        let (ints, strs, offsets) = if mode.is_sparse() {
+            (
+                Int64Chunked::new("".into(), [Some(1), Some(-1), None, Some(-1)]),
+                StringChunked::new("".into(), ["x", "a", "x", "b"]),
+                None,
+            )
+        } else {
+            (
+                Int64Chunked::new("".into(), [Some(1), None]),
+                StringChunked::new("".into(), ["a", "b"]),
+                Some(Buffer::from(vec![0, 0, 1, 1])),
+            )
+        };
+        UnionArray::new(
+            dtype,
+            types,
+            vec![ints.chunks()[0].clone(), strs.chunks()[0].clone()],
+            offsets,
+        )
+        .boxed()
+    }
+
+    #[test]
+    fn test_union_from_arrow() {
+        for mode in [
+            arrow::datatypes::UnionMode::Sparse,
+            arrow::datatypes::UnionMode::Dense,
+        ] {
+            let s = Series::from_arrow("u".into(), union_array(mode)).unwrap();
+            assert_eq!(
+                s.dtype(),
+                &DataType::Union(
+                    vec![
+                        Field::new("int".into(), DataType::Int64),
+                        Field::new("str".into(), DataType::String),
+                    ],
+                    mode.into()
+                )
+            );
+            assert_eq!(s.null_count(), 1);
+            let ca = s.union().unwrap();
+            assert_eq!(
+                Vec::from(&ca.variant_indices()),
+                &[Some(0), Some(1), None, Some(1)]
+            );
+            assert_eq!(
+                Vec::from(ca.field_by_name("str").unwrap().str().unwrap()),
+                &[None, Some("a"), None, Some("b")]
+            );
+            assert_eq!(s.get(1).unwrap(), AnyValue::String("a"));
+
+            let strings = s.cast(&DataType::String).unwrap();
+            assert_eq!(
+                Vec::from(strings.str().unwrap()),
+                &[Some("1"), Some("a"), None, Some("b")]
+            );
+            assert_eq!(ca.to_supertype().unwrap().dtype(), &DataType::String);
+
+            // Round trip through arrow, keeping the mode.
+            let arr = s.to_arrow(0, CompatLevel::newest());
+            assert_eq!(UnionArray::is_sparse(arr.dtype()), mode.is_sparse());
+            let out = Series::from_arrow("u".into(), arr).unwrap();
+            assert_eq!(out.dtype(), s.dtype());
+            assert!(out.equals_missing(&s));
+
+            let taken = s.take_slice(&[3, 2, 0]).unwrap();
+            assert_eq!(taken.dtype(), s.dtype());
+            assert_eq!(taken.null_count(), 1);
+        }
+    }
+
+    #[test]
+    fn test_struct_into_union() {
+        let a = Series::new("a".into(), [Some(1i32), None, None]);
+        let b = Series::new("b".into(), [None, Some(2.5f64), None]);
+        let ca = StructChunked::from_series("s".into(), 3, [a.clone(), b].iter()).unwrap();
+        let u = ca.into_union(UnionMode::Dense).unwrap().into_series();
+        assert_eq!(u.null_count(), 1);
+        assert_eq!(
+            Vec::from(u.cast(&DataType::Float64).unwrap().f64().unwrap()),
+            &[Some(1.0), Some(2.5), None]
+        );
+
+        let b = Series::new("b".into(), [Some(1.5f64), None, None]);
+        let ca = StructChunked::from_series("s".into(), 3, [a.clone(), b].iter()).unwrap();
+        assert!(ca.into_union(UnionMode::Sparse).is_err());
+
+        let out = a.cast(u.dtype()).unwrap();
+        assert_eq!(out.dtype(), u.dtype());
+        assert_eq!(
+            Vec::from(&out.union().unwrap().variant_indices()),
+            &[Some(0), None, None]
+        );
+    }
+}
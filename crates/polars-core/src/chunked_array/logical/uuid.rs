@@ -0,0 +1,218 @@
+use arrow::array::FixedSizeBinaryArray;
+use arrow::buffer::Buffer;
+use arrow::datatypes::ExtensionType;
+
+use super::*;
+use crate::prelude::*;
+
+pub type UuidChunked = Logical<UuidType, UInt128Type>;
+
+/// Name of the canonical Arrow extension type for UUIDs.
+pub const UUID_EXTENSION_NAME: &str = "arrow.uuid";
+
+/// Parse a UUID in any of the common textual forms (hyphenated, simple, braced or URN).
+#[inline]
+pub fn parse_uuid(s: &str) -> Option<u128> {
+    ::uuid::Uuid::try_parse(s).ok().map(|u| u.as_u128())
+}
+
+/// Format a UUID in its canonical, lowercase hyphenated form.
+#[inline]
+pub fn format_uuid(v: u128) -> ::uuid::fmt::Hyphenated {
+    ::uuid::Uuid::from_u128(v).hyphenated()
+}
+
+/// Whether an arrow extension type is a UUID that we load as [`DataType::Uuid`].
+pub fn is_uuid_extension(ext: &ExtensionType) -> bool {
+    ext.name == UUID_EXTENSION_NAME && ext.inner == ArrowDataType::FixedSizeBinary(16)
+}
+
+/// The arrow data type of a UUID: the `arrow.uuid` extension over a 16-byte fixed-size binary.
+pub fn uuid_arrow_dtype() -> ArrowDataType {
+    ArrowDataType::Extension(Box::new(ExtensionType {
+        name: PlSmallStr::from_static(UUID_EXTENSION_NAME),
+        inner: ArrowDataType::FixedSizeBinary(16),
+        metadata: None,
+    }))
+}
+
+/// Convert a physical UUID array to an arrow `arrow.uuid` array.
+///
+/// The integers are stored big-endian, so the bytes are in the usual UUID byte order.
+pub(crate) fn uuid_array_to_arrow(arr: &PrimitiveArray<u128>) -> FixedSizeBinaryArray {
+    let values: Vec<u8> = arr.values_iter().flat_map(|v| v.to_be_bytes()).collect();
+    FixedSizeBinaryArray::new(
+        uuid_arrow_dtype(),
+        Buffer::from(values),
+        arr.validity().cloned(),
+    )
+}
+
+/// Convert a 16-byte fixed-size binary array to its physical UUID representation.
+pub(crate) fn uuid_array_from_arrow(
+    arr: &FixedSizeBinaryArray,
+) -> PolarsResult<PrimitiveArray<u128>> {
+    polars_ensure!(
+        arr.size() == 16,
+        ComputeError: "expected 16 bytes per UUID, got {}", arr.size()
+    );
+    let values = arr
+        .values()
+        .chunks_exact(16)
+        .map(|b| u128::from_be_bytes(b.try_into().unwrap()))
+        .collect();
+    Ok(PrimitiveArray::from_vec(values).with_validity(arr.validity().cloned()))
+}
+
+/// Cast a Series of another type to a UUID, values that can't be converted become null.
+pub(crate) fn cast_to_uuid(s: &Series) -> PolarsResult<Series> {
+    let ca = match s.dtype() {
+        DataType::Uuid => return Ok(s.clone()),
+        DataType::UInt128 => s.u128()?.clone(),
+        DataType::String => UInt128Chunked::from_iter_options(
+            s.name().clone(),
+            s.str()?.iter().map(|opt_v| opt_v.and_then(parse_uuid)),
+        ),
+        DataType::Binary => UInt128Chunked::from_iter_options(
+            s.name().clone(),
+            s.binary()?.iter().map(|opt_v| {
+                opt_v
+                    .and_then(|v| <[u8; 16]>::try_from(v).ok())
+                    .map(u128::from_be_bytes)
+            }),
+        ),
+        DataType::Null => UInt128Chunked::full_null(s.name().clone(), s.len()),
+        dt => polars_bail!(InvalidOperation: "casting from {:?} to Uuid not supported", dt),
+    };
+    Ok(ca.into_uuid().into_series())
+}
+
+impl UInt128Chunked {
+    /// Interpret the (big-endian) integers as UUIDs.
+    pub fn into_uuid(self) -> UuidChunked {
+        // SAFETY: every 128-bit value is a valid UUID.
+        unsafe { UuidChunked::new_logical(self, DataType::Uuid) }
+    }
+}
+
+impl UuidChunked {
+    /// Format the UUIDs in their canonical, lowercase hyphenated form.
+    pub fn to_string(&self) -> StringChunked {
+        let mut buf = ::uuid::Uuid::encode_buffer();
+        self.phys.apply_into_string_amortized(|v, out| {
+            out.push_str(format_uuid(v).encode_lower(&mut buf));
+        })
+    }
+
+    /// The 16 bytes of the UUIDs, in network byte order.
+    pub fn to_binary(&self) -> BinaryChunked {
+        BinaryChunked::from_iter_options(
+            self.name().clone(),
+            self.phys.iter().map(|opt_v| opt_v.map(|v| v.to_be_bytes())),
+        )
+    }
+}
+
+impl LogicalType for UuidChunked {
+    fn dtype(&self) -> &DataType {
+        &DataType::Uuid
+    }
+
+    #[inline]
+    fn get_any_value(&self, i: usize) -> PolarsResult<AnyValue<'_>> {
+        polars_ensure!(i < self.len(), oob = i, self.len());
+        Ok(unsafe { self.get_any_value_unchecked(i) })
+    }
+
+    #[inline]
+    unsafe fn get_any_value_unchecked(&self, i: usize) -> AnyValue<'_> {
+        match self.phys.get_unchecked(i) {
+            Some(v) => AnyValue::Uuid(v),
+            None => AnyValue::Null,
+        }
+    }
+
+    #[cfg_attr(not(feature = "dtype-categorical"), allow(unused_variables))]
+    fn cast_with_options(
+        &self,
+        dtype: &DataType,
+        cast_options: CastOptions,
+    ) -> PolarsResult<Series> {
+        match dtype {
+            DataType::Uuid => Ok(self.clone().into_series()),
+            DataType::UInt128 => Ok(self.phys.clone().into_series()),
+            DataType::String => Ok(self.to_string().into_series()),
+            DataType::Binary => Ok(self.to_binary().into_series()),
+            #[cfg(feature = "dtype-categorical")]
+            DataType::Categorical(_, _) | DataType::Enum(_, _) => self
+                .to_string()
+                .into_series()
+                .cast_with_options(dtype, cast_options),
+            dt => polars_bail!(
+                InvalidOperation:
+                "casting from {:?} to {:?} not supported",
+                self.dtype(), dt
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_uuid_parse_format() {
+        let v = parse_uuid("67E55044-10B1-426F-9247-BB680E5FE0C8").unwrap();
+        assert_eq!(v, 0x67e55044_10b1_426f_9247_bb680e5fe0c8);
+        assert_eq!(parse_uuid("67e5504410b1426f9247bb680e5fe0c8"), Some(v));
+        assert_eq!(
+            parse_uuid("urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8"),
+            Some(v)
+        );
+        assert_eq!(parse_uuid("67e55044-10b1-426f-9247"), None);
+        assert_eq!(
+            format_uuid(v).to_string(),
+            "67e55044-10b1-426f-9247-bb680e5fe0c8"
+        );
+    }
+
+    #[test]
+    fn test_uuid_cast_and_arrow_round_trip() {
+        let s = Series::new(
+            PlSmallStr::from_static("a"),
+            [
+                Some("00000000-0000-0000-0000-000000000001"),
+                None,
+                Some("ffffffff-ffff-ffff-ffff-ffffffffffff"),
+                Some("not a uuid"),
+            ],
+        );
+        assert!(s.strict_cast(&DataType::Uuid).is_err());
+        let uuids = s.cast(&DataType::Uuid).unwrap();
+        assert_eq!(uuids.dtype(), &DataType::Uuid);
+        assert_eq!(
+            Vec::from(uuids.cast(&DataType::UInt128).unwrap().u128().unwrap()),
+            &[Some(1), None, Some(u128::MAX), None]
+        );
+        assert!(
+            uuids
+                .cast(&DataType::String)
+                .unwrap()
+                .equals_missing(&s.slice(0, 3).extend_constant(AnyValue::Null, 1).unwrap())
+        );
+
+        let arr = uuids.to_arrow(0, CompatLevel::newest());
+        assert_eq!(arr.dtype(), &uuid_arrow_dtype());
+        let out = Series::from_arrow(PlSmallStr::from_static("a"), arr).unwrap();
+        assert!(out.equals_missing(&uuids));
+
+        // Binary is in network byte order.
+        let bin = uuids.cast(&DataType::Binary).unwrap();
+        assert_eq!(
+            bin.binary().unwrap().get(0).unwrap(),
+            &1u128.to_be_bytes()[..]
+        );
+        assert!(bin.cast(&DataType::Uuid).unwrap().equals_missing(&uuids));
+    }
+}
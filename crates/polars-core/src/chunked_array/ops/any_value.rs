@@ -63,6 +63,20 @@ pub(crate) unsafe fn arr_to_any_value<'a>(
                 AnyValue::List(s)
             }
         },
+        #[cfg(feature = "dtype-map")]
+        DataType::Map(_, _) => {
+            let v: ArrayRef = downcast!(LargeListArray);
+            let entries = dtype.to_entries_list_type();
+            let inner = entries.inner_dtype().unwrap();
+            let s = Series::from_chunks_and_dtype_unchecked(
+                PlSmallStr::EMPTY,
+                vec![v],
+                &inner.to_physical(),
+            )
+            .from_physical_unchecked(inner)
+            .unwrap();
+            AnyValue::List(s)
+        },
         #[cfg(feature = "dtype-array")]
         DataType::Array(dt, width) => {
             let v: ArrayRef = downcast!(FixedSizeListArray);
@@ -127,12 +141,62 @@ pub(crate) unsafe fn arr_to_any_value<'a>(
             let v = arr.value_unchecked(idx);
             AnyValue::Time(v)
         },
+        #[cfg(feature = "dtype-interval")]
+        DataType::Interval => {
+            let arr = &*(arr as *const dyn Array as *const Int128Array);
+            let v = arr.value_unchecked(idx);
+            AnyValue::Interval(interval_from_physical(v))
+        },
+        #[cfg(feature = "dtype-f16")]
+        DataType::Float16 => {
+            let arr = &*(arr as *const dyn Array as *const Int16Array);
+            let v = arr.value_unchecked(idx);
+            AnyValue::Float16(float16_from_physical(v))
+        },
+        #[cfg(feature = "dtype-uuid")]
+        DataType::Uuid => {
+            let arr = &*(arr as *const dyn Array as *const UInt128Array);
+            AnyValue::Uuid(arr.value_unchecked(idx))
+        },
+        #[cfg(feature = "dtype-json")]
+        DataType::Json => {
+            let arr = &*(arr as *const dyn Array as *const BinaryViewArray);
+            AnyValue::StringOwned(
+                JsonRef::new(arr.value_unchecked(idx))
+                    .to_json_string()
+                    .into(),
+            )
+        },
         #[cfg(feature = "dtype-decimal")]
         DataType::Decimal(precision, scale) => {
             let arr = &*(arr as *const dyn Array as *const Int128Array);
             let v = arr.value_unchecked(idx);
             AnyValue::Decimal(v, *precision, *scale)
         },
+        #[cfg(feature = "dtype-decimal256")]
+        DataType::Decimal256(precision, scale) => {
+            let arr = &*(arr as *const dyn Array as *const BinaryViewArray);
+            let v = polars_compute::decimal::dec256_from_ordered_bytes(arr.value_unchecked(idx));
+            AnyValue::Decimal256(v, *precision, *scale)
+        },
+        // Values are represented by the value of their active variant.
+        #[cfg(feature = "dtype-union")]
+        DataType::Union(fields, _) => {
+            let arr = &*(arr as *const dyn Array as *const StructArray);
+            if arr.is_null_unchecked(idx) {
+                return AnyValue::Null;
+            }
+            arr.values()
+                .iter()
+                .zip(fields)
+                .find(|(values, _)| values.is_valid(idx))
+                .map_or(AnyValue::Null, |(values, fld)| {
+                    arr_to_any_value(values.as_ref(), idx, fld.dtype())
+                })
+        },
+        // Values are represented by their storage values.
+        #[cfg(feature = "dtype-extension")]
+        DataType::Extension { storage, .. } => arr_to_any_value(arr, idx, storage),
         #[cfg(feature = "object")]
         DataType::Object(_) => {
             // We should almost never hit this. The only known exception is when we put objects in
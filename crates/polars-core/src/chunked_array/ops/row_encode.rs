@@ -95,6 +95,16 @@ pub fn get_row_encoding_context(dtype: &DataType) -> Option<RowEncodingContext>
         | DataType::Date
         | DataType::Datetime(_, _)
         | DataType::Duration(_) => None,
+        #[cfg(feature = "dtype-interval")]
+        DataType::Interval => None,
+        #[cfg(feature = "dtype-f16")]
+        DataType::Float16 => None,
+        #[cfg(feature = "dtype-uuid")]
+        DataType::Uuid => None,
+        #[cfg(feature = "dtype-json")]
+        DataType::Json => None,
+        #[cfg(feature = "dtype-decimal256")]
+        DataType::Decimal256(_, _) => None,
 
         #[cfg(feature = "dtype-categorical")]
         DataType::Categorical(_, mapping) | DataType::Enum(_, mapping) => {
@@ -119,6 +129,12 @@ pub fn get_row_encoding_context(dtype: &DataType) -> Option<RowEncodingContext>
         #[cfg(feature = "dtype-array")]
         DataType::Array(dtype, _) => get_row_encoding_context(dtype),
         DataType::List(dtype) => get_row_encoding_context(dtype),
+        #[cfg(feature = "dtype-map")]
+        DataType::Map(_, _) => get_row_encoding_context(&dtype.to_entries_list_type()),
+        #[cfg(feature = "dtype-extension")]
+        DataType::Extension { storage, .. } => get_row_encoding_context(storage),
+        #[cfg(feature = "dtype-union")]
+        DataType::Union(fields, _) => get_row_encoding_context(&DataType::Struct(fields.clone())),
         #[cfg(feature = "dtype-struct")]
         DataType::Struct(fs) => {
             let mut ctxts = Vec::new();
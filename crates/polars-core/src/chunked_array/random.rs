@@ -8,6 +8,8 @@ use rand_distr::{Normal, StandardNormal, StandardUniform, Uniform};
 use crate::prelude::DataType::Float64;
 use crate::prelude::*;
 use crate::random::get_global_random_u64;
+#[cfg(feature = "dtype-uuid")]
+use crate::series::IsSorted;
 use crate::utils::NoNull;
 
 fn create_rand_index_with_replacement(n: usize, len: usize, seed: Option<u64>) -> IdxCa {
@@ -303,6 +305,43 @@ impl BooleanChunked {
     }
 }
 
+#[cfg(feature = "dtype-uuid")]
+impl UuidChunked {
+    /// Create [`UuidChunked`] with random (version 4) UUIDs.
+    pub fn rand_v4(name: PlSmallStr, length: usize, seed: Option<u64>) -> Self {
+        let mut rng = SmallRng::seed_from_u64(seed.unwrap_or_else(get_global_random_u64));
+        let values = (0..length)
+            .map(|_| {
+                ::uuid::Builder::from_random_bytes(rng.random())
+                    .as_uuid()
+                    .as_u128()
+            })
+            .collect();
+        UInt128Chunked::from_vec(name, values).into_uuid()
+    }
+
+    /// Create [`UuidChunked`] with time-ordered (version 7) UUIDs, using the current time.
+    ///
+    /// The values are sorted, so they are increasing within the result as well.
+    pub fn rand_v7(name: PlSmallStr, length: usize, seed: Option<u64>) -> Self {
+        let millis = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |d| d.as_millis() as u64);
+        let mut rng = SmallRng::seed_from_u64(seed.unwrap_or_else(get_global_random_u64));
+        let mut values: Vec<u128> = (0..length)
+            .map(|_| {
+                ::uuid::Builder::from_unix_timestamp_millis(millis, &rng.random())
+                    .as_uuid()
+                    .as_u128()
+            })
+            .collect();
+        values.sort_unstable();
+        let mut ca = UInt128Chunked::from_vec(name, values);
+        ca.set_sorted_flag(IsSorted::Ascending);
+        ca.into_uuid()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -391,4 +430,25 @@ mod test {
             .is_ok()
         );
     }
+
+    #[test]
+    #[cfg(feature = "dtype-uuid")]
+    fn test_rand_uuid() {
+        let name = PlSmallStr::from_static("id");
+        let a = UuidChunked::rand_v4(name.clone(), 100, Some(0));
+        let b = UuidChunked::rand_v4(name.clone(), 100, Some(0));
+        assert!(a.physical().equal(b.physical()).all());
+        assert_eq!(a.physical().n_unique().unwrap(), 100);
+        for v in a.physical().into_no_null_iter() {
+            let uuid = ::uuid::Uuid::from_u128(v);
+            assert_eq!(uuid.get_version_num(), 4);
+            assert_eq!(uuid.get_variant(), ::uuid::Variant::RFC4122);
+        }
+
+        let c = UuidChunked::rand_v7(name, 100, None);
+        assert!(c.physical().into_no_null_iter().is_sorted());
+        for v in c.physical().into_no_null_iter() {
+            assert_eq!(::uuid::Uuid::from_u128(v).get_version_num(), 7);
+        }
+    }
 }
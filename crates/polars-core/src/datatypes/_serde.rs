@@ -76,6 +76,16 @@ enum SerializableDataType {
     List(Box<SerializableDataType>),
     #[cfg(feature = "dtype-array")]
     Array(Box<SerializableDataType>, usize),
+    #[cfg(feature = "dtype-map")]
+    Map(Box<SerializableDataType>, Box<SerializableDataType>),
+    #[cfg(feature = "dtype-union")]
+    Union(Vec<Field>, UnionMode),
+    #[cfg(feature = "dtype-extension")]
+    Extension {
+        name: PlSmallStr,
+        metadata: Option<PlSmallStr>,
+        storage: Box<SerializableDataType>,
+    },
     Null,
     #[cfg(feature = "dtype-struct")]
     Struct(Vec<Field>),
@@ -93,6 +103,16 @@ enum SerializableDataType {
     },
     #[cfg(feature = "dtype-decimal")]
     Decimal(usize, usize),
+    #[cfg(feature = "dtype-decimal256")]
+    Decimal256(usize, usize),
+    #[cfg(feature = "dtype-interval")]
+    Interval,
+    #[cfg(feature = "dtype-f16")]
+    Float16,
+    #[cfg(feature = "dtype-uuid")]
+    Uuid,
+    #[cfg(feature = "dtype-json")]
+    Json,
     #[cfg(feature = "object")]
     Object(String),
 }
@@ -124,6 +144,23 @@ impl From<&DataType> for SerializableDataType {
             List(dt) => Self::List(Box::new(dt.as_ref().into())),
             #[cfg(feature = "dtype-array")]
             Array(dt, width) => Self::Array(Box::new(dt.as_ref().into()), *width),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => Self::Map(
+                Box::new(key.as_ref().into()),
+                Box::new(value.as_ref().into()),
+            ),
+            #[cfg(feature = "dtype-union")]
+            Union(fields, mode) => Self::Union(fields.clone(), *mode),
+            #[cfg(feature = "dtype-extension")]
+            Extension {
+                name,
+                metadata,
+                storage,
+            } => Self::Extension {
+                name: name.clone(),
+                metadata: metadata.as_deref().cloned(),
+                storage: Box::new(storage.as_ref().into()),
+            },
             Null => Self::Null,
             Unknown(kind) => Self::Unknown(*kind),
             #[cfg(feature = "dtype-struct")]
@@ -144,6 +181,16 @@ impl From<&DataType> for SerializableDataType {
             },
             #[cfg(feature = "dtype-decimal")]
             Decimal(precision, scale) => Self::Decimal(*precision, *scale),
+            #[cfg(feature = "dtype-decimal256")]
+            Decimal256(precision, scale) => Self::Decimal256(*precision, *scale),
+            #[cfg(feature = "dtype-interval")]
+            Interval => Self::Interval,
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Self::Float16,
+            #[cfg(feature = "dtype-uuid")]
+            Uuid => Self::Uuid,
+            #[cfg(feature = "dtype-json")]
+            Json => Self::Json,
             #[cfg(feature = "object")]
             Object(name) => Self::Object(name.to_string()),
         }
@@ -176,6 +223,20 @@ impl From<SerializableDataType> for DataType {
             List(dt) => Self::List(Box::new((*dt).into())),
             #[cfg(feature = "dtype-array")]
             Array(dt, width) => Self::Array(Box::new((*dt).into()), width),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => Self::Map(Box::new((*key).into()), Box::new((*value).into())),
+            #[cfg(feature = "dtype-union")]
+            Union(fields, mode) => Self::Union(fields, mode),
+            #[cfg(feature = "dtype-extension")]
+            Extension {
+                name,
+                metadata,
+                storage,
+            } => Self::Extension {
+                name,
+                metadata: metadata.map(Box::new),
+                storage: Box::new((*storage).into()),
+            },
             Null => Self::Null,
             Unknown(kind) => Self::Unknown(kind),
             #[cfg(feature = "dtype-struct")]
@@ -203,6 +264,16 @@ impl From<SerializableDataType> for DataType {
             },
             #[cfg(feature = "dtype-decimal")]
             Decimal(precision, scale) => Self::Decimal(precision, scale),
+            #[cfg(feature = "dtype-decimal256")]
+            Decimal256(precision, scale) => Self::Decimal256(precision, scale),
+            #[cfg(feature = "dtype-interval")]
+            Interval => Self::Interval,
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Self::Float16,
+            #[cfg(feature = "dtype-uuid")]
+            Uuid => Self::Uuid,
+            #[cfg(feature = "dtype-json")]
+            Json => Self::Json,
             #[cfg(feature = "object")]
             Object(_) => Self::Object("unknown"),
         }
@@ -2,6 +2,8 @@
 use std::borrow::Cow;
 
 use arrow::types::PrimitiveType;
+#[cfg(feature = "dtype-interval")]
+use arrow::types::months_days_ns;
 use polars_compute::cast::SerPrimitive;
 use polars_error::feature_gated;
 use polars_utils::total_ord::ToTotalOrd;
@@ -22,6 +24,11 @@ impl Clone for OwnedObject {
     }
 }
 
+#[cfg(feature = "dtype-decimal256")]
+use polars_compute::decimal::{
+    DEC256_MAX_PREC, dec256_add, dec256_cmp, dec256_eq, dec256_rescale, dec256_to_f64,
+    dec256_to_i256, dec256_to_ordered_bytes, f64_to_dec256, i256_to_dec256,
+};
 #[cfg(feature = "dtype-decimal")]
 use polars_compute::decimal::{
     dec128_cmp, dec128_eq, dec128_rescale, dec128_to_f64, dec128_to_i128, f64_to_dec128,
@@ -60,6 +67,12 @@ pub enum AnyValue<'a> {
     Float32(f32),
     /// A 64-bit floating point number.
     Float64(f64),
+    /// A 16-bit floating point number.
+    #[cfg(feature = "dtype-f16")]
+    Float16(f16),
+    /// A universally unique identifier, as its big-endian 128-bit integer.
+    #[cfg(feature = "dtype-uuid")]
+    Uuid(u128),
     /// A 32-bit date representing the elapsed time since UNIX epoch (1970-01-01)
     /// in days (32 bits).
     #[cfg(feature = "dtype-date")]
@@ -75,6 +88,9 @@ pub enum AnyValue<'a> {
     /// A 64-bit integer representing difference between date-times in [`TimeUnit`]
     #[cfg(feature = "dtype-duration")]
     Duration(i64, TimeUnit),
+    /// A calendar interval of months, days and nanoseconds.
+    #[cfg(feature = "dtype-interval")]
+    Interval(months_days_ns),
     /// A 64-bit time representing the elapsed time since midnight in nanoseconds
     #[cfg(feature = "dtype-time")]
     Time(i64),
@@ -110,6 +126,9 @@ pub enum AnyValue<'a> {
     /// A 128-bit fixed point decimal number with a precision and scale.
     #[cfg(feature = "dtype-decimal")]
     Decimal(i128, usize, usize),
+    /// A 256-bit fixed point decimal number with a precision and scale.
+    #[cfg(feature = "dtype-decimal256")]
+    Decimal256(arrow::types::i256, usize, usize),
 }
 
 impl AnyValue<'static> {
@@ -128,6 +147,8 @@ impl AnyValue<'static> {
             DataType::Duration(unit) => AnyValue::Duration(0, *unit),
             #[cfg(feature = "dtype-decimal")]
             DataType::Decimal(p, s) => AnyValue::Decimal(0, *p, *s),
+            #[cfg(feature = "dtype-decimal256")]
+            DataType::Decimal256(p, s) => AnyValue::Decimal256(Default::default(), *p, *s),
             _ => AnyValue::Null,
         }
     }
@@ -158,11 +179,19 @@ impl AnyValue<'static> {
             DT::Int128 => AV::Int128(numeric_to_one.into()),
             DT::Float32 => AV::Float32(numeric_to_one.into()),
             DT::Float64 => AV::Float64(numeric_to_one.into()),
+            #[cfg(feature = "dtype-f16")]
+            DT::Float16 => AV::Float16(f16::from_f32(numeric_to_one.into())),
             #[cfg(feature = "dtype-decimal")]
             DT::Decimal(p, s) => AV::Decimal(0, *p, *s),
+            #[cfg(feature = "dtype-decimal256")]
+            DT::Decimal256(p, s) => AV::Decimal256(Default::default(), *p, *s),
             DT::String => AV::String(""),
             DT::Binary => AV::Binary(&[]),
             DT::BinaryOffset => AV::Binary(&[]),
+            #[cfg(feature = "dtype-uuid")]
+            DT::Uuid => AV::Uuid(0),
+            #[cfg(feature = "dtype-json")]
+            DT::Json => AV::String("null"),
             DT::Date => feature_gated!("dtype-date", AV::Date(0)),
             DT::Datetime(time_unit, time_zone) => feature_gated!(
                 "dtype-datetime",
@@ -172,6 +201,8 @@ impl AnyValue<'static> {
                 feature_gated!("dtype-duration", AV::Duration(0, *time_unit))
             },
             DT::Time => feature_gated!("dtype-time", AV::Time(0)),
+            #[cfg(feature = "dtype-interval")]
+            DT::Interval => AV::Interval(months_days_ns::default()),
             #[cfg(feature = "dtype-array")]
             DT::Array(inner_dtype, width) => {
                 let inner_value =
@@ -183,6 +214,23 @@ impl AnyValue<'static> {
                     *width,
                 )
             },
+            #[cfg(feature = "dtype-extension")]
+            DT::Extension { storage, .. } => {
+                AnyValue::default_value(storage, numeric_to_one, num_list_values)
+            },
+            #[cfg(feature = "dtype-union")]
+            DT::Union(fields, _) => fields.first().map_or(AV::Null, |fld| {
+                AnyValue::default_value(fld.dtype(), numeric_to_one, num_list_values)
+            }),
+            // An empty map, as repeated keys wouldn't make a valid one.
+            #[cfg(feature = "dtype-map")]
+            DT::Map(_, _) => {
+                let entries = dtype.to_entries_list_type();
+                AV::List(Series::new_empty(
+                    PlSmallStr::EMPTY,
+                    entries.inner_dtype().unwrap(),
+                ))
+            },
             DT::List(inner_dtype) => AV::List(if num_list_values == 0 {
                 Series::new_empty(PlSmallStr::EMPTY, inner_dtype.as_ref())
             } else {
@@ -237,7 +285,11 @@ impl<'a> AnyValue<'a> {
             UInt64(_) => DataType::UInt64,
             UInt128(_) => DataType::UInt128,
             Float32(_) => DataType::Float32,
+            #[cfg(feature = "dtype-f16")]
+            Float16(_) => DataType::Float16,
             Float64(_) => DataType::Float64,
+            #[cfg(feature = "dtype-uuid")]
+            Uuid(_) => DataType::Uuid,
             String(_) | StringOwned(_) => DataType::String,
             Binary(_) | BinaryOwned(_) => DataType::Binary,
             #[cfg(feature = "dtype-date")]
@@ -252,6 +304,8 @@ impl<'a> AnyValue<'a> {
             },
             #[cfg(feature = "dtype-duration")]
             Duration(_, tu) => DataType::Duration(*tu),
+            #[cfg(feature = "dtype-interval")]
+            Interval(_) => DataType::Interval,
             #[cfg(feature = "dtype-categorical")]
             Categorical(_, _) | CategoricalOwned(_, _) => {
                 unimplemented!("can not get dtype of Categorical AnyValue")
@@ -267,6 +321,8 @@ impl<'a> AnyValue<'a> {
             StructOwned(payload) => DataType::Struct(payload.1.clone()),
             #[cfg(feature = "dtype-decimal")]
             Decimal(_, p, s) => DataType::Decimal(*p, *s),
+            #[cfg(feature = "dtype-decimal256")]
+            Decimal256(_, p, s) => DataType::Decimal256(*p, *s),
             #[cfg(feature = "object")]
             Object(o) => DataType::Object(o.type_name()),
             #[cfg(feature = "object")]
@@ -292,6 +348,8 @@ impl<'a> AnyValue<'a> {
             UInt128(v) => NumCast::from(*v),
             Float32(v) => NumCast::from(*v),
             Float64(v) => NumCast::from(*v),
+            #[cfg(feature = "dtype-f16")]
+            Float16(v) => NumCast::from(v.to_f32()),
             #[cfg(feature = "dtype-date")]
             Date(v) => NumCast::from(*v),
             #[cfg(feature = "dtype-datetime")]
@@ -308,6 +366,14 @@ impl<'a> AnyValue<'a> {
                     NumCast::from(dec128_to_i128(*v, *s))
                 }
             },
+            #[cfg(feature = "dtype-decimal256")]
+            Decimal256(v, _p, s) => {
+                if T::is_float() {
+                    NumCast::from(dec256_to_f64(*v, *s))
+                } else {
+                    NumCast::from(i128::try_from(dec256_to_i256(*v, *s)).ok()?)
+                }
+            },
             Boolean(v) => NumCast::from(if *v { 1 } else { 0 }),
             String(v) => {
                 if let Ok(val) = (*v).parse::<i128>() {
@@ -373,6 +439,8 @@ impl<'a> AnyValue<'a> {
         match self {
             AnyValue::Float32(f) => f.is_nan(),
             AnyValue::Float64(f) => f.is_nan(),
+            #[cfg(feature = "dtype-f16")]
+            AnyValue::Float16(f) => f.is_nan(),
             _ => false,
         }
     }
@@ -397,6 +465,26 @@ impl<'a> AnyValue<'a> {
     /// if possible.
     pub fn strict_cast(&self, dtype: &'a DataType) -> Option<AnyValue<'a>> {
         let new_av = match (self, dtype) {
+            // uuid
+            #[cfg(feature = "dtype-uuid")]
+            (AnyValue::Uuid(v), DataType::UInt128) => AnyValue::UInt128(*v),
+            #[cfg(feature = "dtype-uuid")]
+            (AnyValue::Uuid(v), DataType::String) => {
+                AnyValue::StringOwned(format_uuid(*v).to_string().into())
+            },
+            #[cfg(feature = "dtype-uuid")]
+            (AnyValue::Uuid(v), DataType::Binary) => AnyValue::BinaryOwned(v.to_be_bytes().into()),
+            #[cfg(feature = "dtype-uuid")]
+            (AnyValue::UInt128(v), DataType::Uuid) => AnyValue::Uuid(*v),
+            #[cfg(feature = "dtype-uuid")]
+            (AnyValue::String(s), DataType::Uuid) => AnyValue::Uuid(parse_uuid(s)?),
+            #[cfg(feature = "dtype-uuid")]
+            (AnyValue::StringOwned(s), DataType::Uuid) => AnyValue::Uuid(parse_uuid(s)?),
+            #[cfg(feature = "dtype-uuid")]
+            (AnyValue::Binary(b), DataType::Uuid) => {
+                AnyValue::Uuid(u128::from_be_bytes((*b).try_into().ok()?))
+            },
+
             // to numeric
             (av, DataType::UInt8) => AnyValue::UInt8(av.extract::<u8>()?),
             (av, DataType::UInt16) => AnyValue::UInt16(av.extract::<u16>()?),
@@ -410,6 +498,8 @@ impl<'a> AnyValue<'a> {
             (av, DataType::Int128) => AnyValue::Int128(av.extract::<i128>()?),
             (av, DataType::Float32) => AnyValue::Float32(av.extract::<f32>()?),
             (av, DataType::Float64) => AnyValue::Float64(av.extract::<f64>()?),
+            #[cfg(feature = "dtype-f16")]
+            (av, DataType::Float16) => AnyValue::Float16(f16::from_f32(av.extract::<f32>()?)),
 
             // to boolean
             (AnyValue::UInt8(v), DataType::Boolean) => AnyValue::Boolean(*v != u8::default()),
@@ -424,6 +514,8 @@ impl<'a> AnyValue<'a> {
             (AnyValue::Int128(v), DataType::Boolean) => AnyValue::Boolean(*v != i128::default()),
             (AnyValue::Float32(v), DataType::Boolean) => AnyValue::Boolean(*v != f32::default()),
             (AnyValue::Float64(v), DataType::Boolean) => AnyValue::Boolean(*v != f64::default()),
+            #[cfg(feature = "dtype-f16")]
+            (AnyValue::Float16(v), DataType::Boolean) => AnyValue::Boolean(v.to_f32() != 0.0),
 
             // Categorical casts.
             #[cfg(feature = "dtype-categorical")]
@@ -618,6 +710,39 @@ impl<'a> AnyValue<'a> {
                 AnyValue::Decimal(converted, *p, *s)
             },
 
+            #[cfg(feature = "dtype-decimal256")]
+            (av, DataType::Decimal256(p, s)) if av.is_integer() => {
+                let int = av.try_extract::<i128>().ok()?;
+                let dec = i256_to_dec256(arrow::types::i256(int.into()), *p, *s)?;
+                AnyValue::Decimal256(dec, *p, *s)
+            },
+
+            #[cfg(feature = "dtype-decimal256")]
+            (av, DataType::Decimal256(p, s)) if av.is_float() => {
+                let f = av.try_extract::<f64>().unwrap();
+                let dec = f64_to_dec256(f, *p, *s)?;
+                AnyValue::Decimal256(dec, *p, *s)
+            },
+
+            #[cfg(feature = "dtype-decimal256")]
+            (AnyValue::Decimal(value, _old_p, old_s), DataType::Decimal256(p, s)) => {
+                let value = arrow::types::i256((*value).into());
+                let converted = dec256_rescale(value, *old_s, *p, *s)?;
+                AnyValue::Decimal256(converted, *p, *s)
+            },
+
+            #[cfg(feature = "dtype-decimal256")]
+            (AnyValue::Decimal256(value, _old_p, old_s), DataType::Decimal256(p, s)) => {
+                let converted = dec256_rescale(*value, *old_s, *p, *s)?;
+                AnyValue::Decimal256(converted, *p, *s)
+            },
+
+            #[cfg(feature = "dtype-decimal256")]
+            (AnyValue::Decimal256(value, _old_p, old_s), DataType::Decimal(p, s)) => {
+                let converted = dec256_rescale(*value, *old_s, *p, *s)?;
+                AnyValue::Decimal(converted.try_into().ok()?, *p, *s)
+            },
+
             // to self
             (av, dtype) if av.dtype() == *dtype => self.clone(),
 
@@ -699,6 +824,12 @@ impl<'a> AnyValue<'a> {
 
             #[cfg(feature = "dtype-duration")]
             Self::Duration(v, _) => Self::Int64(v),
+            #[cfg(feature = "dtype-interval")]
+            Self::Interval(v) => Self::Int128(interval_to_physical(v)),
+            #[cfg(feature = "dtype-f16")]
+            Self::Float16(v) => Self::Int16(float16_to_physical(v)),
+            #[cfg(feature = "dtype-uuid")]
+            Self::Uuid(v) => Self::UInt128(v),
             #[cfg(feature = "dtype-time")]
             Self::Time(v) => Self::Int64(v),
 
@@ -731,6 +862,8 @@ impl<'a> AnyValue<'a> {
 
             #[cfg(feature = "dtype-decimal")]
             Self::Decimal(v, _, _) => Self::Int128(v),
+            #[cfg(feature = "dtype-decimal256")]
+            Self::Decimal256(v, _, _) => Self::BinaryOwned(dec256_to_ordered_bytes(v).to_vec()),
         }
     }
 
@@ -826,6 +959,12 @@ impl AnyValue<'_> {
                 v.hash(state);
                 tz.hash(state);
             },
+            #[cfg(feature = "dtype-interval")]
+            Interval(v) => v.hash(state),
+            #[cfg(feature = "dtype-f16")]
+            Float16(v) => v.tot_hash(state),
+            #[cfg(feature = "dtype-uuid")]
+            Uuid(v) => v.hash(state),
             #[cfg(feature = "dtype-time")]
             Time(v) => v.hash(state),
             #[cfg(feature = "dtype-categorical")]
@@ -852,6 +991,12 @@ impl AnyValue<'_> {
                 s.hash(state);
                 p.hash(state);
             },
+            #[cfg(feature = "dtype-decimal256")]
+            Decimal256(v, s, p) => {
+                v.hash(state);
+                s.hash(state);
+                p.hash(state);
+            },
             Null => {},
         }
     }
@@ -934,6 +1079,8 @@ impl<'a> AnyValue<'a> {
         match self {
             AnyValue::Float32(v) => Some((*v).into()),
             AnyValue::Float64(v) => Some(*v),
+            #[cfg(feature = "dtype-f16")]
+            AnyValue::Float16(v) => Some(v.to_f32().into()),
             _ => None,
         }
     }
@@ -968,6 +1115,19 @@ impl<'a> AnyValue<'a> {
 
                 Decimal(l + r, *lp, *ls)
             },
+            #[cfg(feature = "dtype-decimal256")]
+            (Decimal256(l, _lp, ls), Decimal256(r, _rp, rs)) => {
+                // Like the Decimal256 series arithmetic: add at the larger scale, with the
+                // maximum precision. A sum that doesn't fit is null, as this can't error.
+                let scale = *ls.max(rs);
+                let sum = dec256_rescale(*l, *ls, DEC256_MAX_PREC, scale)
+                    .zip(dec256_rescale(*r, *rs, DEC256_MAX_PREC, scale))
+                    .and_then(|(l, r)| dec256_add(l, r, DEC256_MAX_PREC));
+                match sum {
+                    Some(sum) => Decimal256(sum, DEC256_MAX_PREC, scale),
+                    None => Null,
+                }
+            },
             _ => unimplemented!(),
         }
     }
@@ -1017,6 +1177,12 @@ impl<'a> AnyValue<'a> {
             Date(v) => Date(v),
             #[cfg(feature = "dtype-duration")]
             Duration(v, tu) => Duration(v, tu),
+            #[cfg(feature = "dtype-interval")]
+            Interval(v) => Interval(v),
+            #[cfg(feature = "dtype-f16")]
+            Float16(v) => Float16(v),
+            #[cfg(feature = "dtype-uuid")]
+            Uuid(v) => Uuid(v),
             #[cfg(feature = "dtype-time")]
             Time(v) => Time(v),
             List(v) => List(v),
@@ -1047,6 +1213,8 @@ impl<'a> AnyValue<'a> {
             },
             #[cfg(feature = "dtype-decimal")]
             Decimal(val, s, p) => Decimal(val, s, p),
+            #[cfg(feature = "dtype-decimal256")]
+            Decimal256(val, s, p) => Decimal256(val, s, p),
             #[cfg(feature = "dtype-categorical")]
             Categorical(cat, map) => CategoricalOwned(cat, map.clone()),
             #[cfg(feature = "dtype-categorical")]
@@ -1170,6 +1338,10 @@ impl AnyValue<'_> {
             (Int128(l), Int128(r)) => *l == *r,
             (Float32(l), Float32(r)) => l.to_total_ord() == r.to_total_ord(),
             (Float64(l), Float64(r)) => l.to_total_ord() == r.to_total_ord(),
+            #[cfg(feature = "dtype-f16")]
+            (Float16(l), Float16(r)) => l.tot_eq(r),
+            #[cfg(feature = "dtype-uuid")]
+            (Uuid(l), Uuid(r)) => l == r,
             (String(l), String(r)) => l == r,
             (Binary(l), Binary(r)) => l == r,
             #[cfg(feature = "dtype-time")]
@@ -1207,6 +1379,8 @@ impl AnyValue<'_> {
             },
             #[cfg(feature = "dtype-duration")]
             (Duration(l, tu_l), Duration(r, tu_r)) => l == r && tu_l == tu_r,
+            #[cfg(feature = "dtype-interval")]
+            (Interval(l), Interval(r)) => l == r,
 
             #[cfg(feature = "dtype-struct")]
             (StructOwned(l), StructOwned(r)) => struct_eq_missing(
@@ -1234,6 +1408,8 @@ impl AnyValue<'_> {
             ),
             #[cfg(feature = "dtype-decimal")]
             (Decimal(lv, _lp, ls), Decimal(rv, _rp, rs)) => dec128_eq(*lv, *ls, *rv, *rs),
+            #[cfg(feature = "dtype-decimal256")]
+            (Decimal256(lv, _lp, ls), Decimal256(rv, _rp, rs)) => dec256_eq(*lv, *ls, *rv, *rs),
             #[cfg(feature = "object")]
             (Object(l), Object(r)) => l == r,
             #[cfg(feature = "dtype-array")]
@@ -1326,6 +1502,10 @@ impl PartialOrd for AnyValue<'_> {
             (Int128(l), Int128(r)) => l.partial_cmp(r),
             (Float32(l), Float32(r)) => Some(l.tot_cmp(r)),
             (Float64(l), Float64(r)) => Some(l.tot_cmp(r)),
+            #[cfg(feature = "dtype-f16")]
+            (Float16(l), Float16(r)) => Some(l.tot_cmp(r)),
+            #[cfg(feature = "dtype-uuid")]
+            (Uuid(l), Uuid(r)) => l.partial_cmp(r),
             (String(l), String(r)) => l.partial_cmp(r),
             (Binary(l), Binary(r)) => l.partial_cmp(r),
             #[cfg(feature = "dtype-date")]
@@ -1348,6 +1528,8 @@ impl PartialOrd for AnyValue<'_> {
 
                 lt.partial_cmp(rt)
             },
+            #[cfg(feature = "dtype-interval")]
+            (Interval(l), Interval(r)) => Some(l.tot_cmp(r)),
             #[cfg(feature = "dtype-time")]
             (Time(l), Time(r)) => l.partial_cmp(r),
             #[cfg(feature = "dtype-categorical")]
@@ -1383,6 +1565,10 @@ impl PartialOrd for AnyValue<'_> {
             },
             #[cfg(feature = "dtype-decimal")]
             (Decimal(lv, _lp, ls), Decimal(rv, _rp, rs)) => Some(dec128_cmp(*lv, *ls, *rv, *rs)),
+            #[cfg(feature = "dtype-decimal256")]
+            (Decimal256(lv, _lp, ls), Decimal256(rv, _rp, rs)) => {
+                Some(dec256_cmp(*lv, *ls, *rv, *rs))
+            },
 
             (_, _) => {
                 unimplemented!(
@@ -1624,9 +1810,28 @@ impl From<bool> for AnyValue<'static> {
 
 #[cfg(test)]
 mod test {
-    #[cfg(feature = "dtype-categorical")]
+    #[cfg(any(feature = "dtype-categorical", feature = "dtype-decimal256"))]
     use super::*;
 
+    #[test]
+    #[cfg(feature = "dtype-decimal256")]
+    fn test_decimal256_add() {
+        use arrow::types::i256;
+
+        // 1.50 + 0.125, at different scales
+        let l = AnyValue::Decimal256(i256::from_words(0, 150), 10, 2);
+        let r = AnyValue::Decimal256(i256::from_words(0, 125), 5, 3);
+        match l.add(&r) {
+            AnyValue::Decimal256(v, p, s) => {
+                assert_eq!((v, p, s), (i256::from_words(0, 1625), DEC256_MAX_PREC, 3))
+            },
+            av => panic!("expected a Decimal256, found {av:?}"),
+        }
+
+        let max = AnyValue::Decimal256(i256::from_words(i128::MAX, -1), DEC256_MAX_PREC, 0);
+        assert!(max.add(&max).is_null());
+    }
+
     #[test]
     #[cfg(feature = "dtype-categorical")]
     fn test_arrow_dtypes_to_polars() {
@@ -71,6 +71,39 @@ pub enum UnknownKind {
     Any,
 }
 
+/// How the values of a [`DataType::Union`] are laid out in Arrow.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(
+    any(feature = "serde", feature = "serde-lazy"),
+    derive(Serialize, Deserialize)
+)]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum UnionMode {
+    /// Every variant has a value for every row.
+    #[default]
+    Sparse,
+    /// Every variant only has values for the rows in which it is active.
+    Dense,
+}
+
+impl From<UnionMode> for arrow::datatypes::UnionMode {
+    fn from(mode: UnionMode) -> Self {
+        match mode {
+            UnionMode::Sparse => Self::Sparse,
+            UnionMode::Dense => Self::Dense,
+        }
+    }
+}
+
+impl From<arrow::datatypes::UnionMode> for UnionMode {
+    fn from(mode: arrow::datatypes::UnionMode) -> Self {
+        match mode {
+            arrow::datatypes::UnionMode::Sparse => Self::Sparse,
+            arrow::datatypes::UnionMode::Dense => Self::Dense,
+        }
+    }
+}
+
 impl UnknownKind {
     pub fn materialize(&self) -> Option<DataType> {
         let dtype = match self {
@@ -98,14 +131,27 @@ pub enum DataType {
     Int128,
     Float32,
     Float64,
+    /// A half-precision (16-bit) floating point number.
+    #[cfg(feature = "dtype-f16")]
+    Float16,
     /// Fixed point decimal type optional precision and non-negative scale.
     /// This is backed by a signed 128-bit integer which allows for up to 38 significant digits.
     /// Meaning max precision is 38.
     #[cfg(feature = "dtype-decimal")]
     Decimal(usize, usize), // (precision, scale), invariant: 1 <= precision <= 38.
+    /// Fixed point decimal type with up to 76 significant digits, backed by a signed 256-bit
+    /// integer.
+    #[cfg(feature = "dtype-decimal256")]
+    Decimal256(usize, usize), // (precision, scale), invariant: 1 <= precision <= 76.
     /// String data
     String,
     Binary,
+    /// A universally unique identifier, backed by an unsigned 128-bit integer.
+    #[cfg(feature = "dtype-uuid")]
+    Uuid,
+    /// A JSON document, stored in a pre-parsed binary encoding.
+    #[cfg(feature = "dtype-json")]
+    Json,
     BinaryOffset,
     /// A 32-bit date representing the elapsed time since UNIX epoch (1970-01-01)
     /// in days (32 bits).
@@ -115,6 +161,9 @@ pub enum DataType {
     Datetime(TimeUnit, Option<TimeZone>),
     /// 64-bit integer representing difference between times in milliseconds or nanoseconds
     Duration(TimeUnit),
+    /// A calendar interval of months, days and nanoseconds (backed by 128 bits).
+    #[cfg(feature = "dtype-interval")]
+    Interval,
     /// A 64-bit time representing the elapsed time since midnight in nanoseconds
     Time,
     /// A nested list with a fixed size in each row
@@ -122,6 +171,22 @@ pub enum DataType {
     Array(Box<DataType>, usize),
     /// A nested list with a variable size in each row
     List(Box<DataType>),
+    /// A map from keys to values, backed by a list of `{key, value}` structs.
+    #[cfg(feature = "dtype-map")]
+    Map(Box<DataType>, Box<DataType>),
+    /// A value of one of several types, backed by a struct with a field per variant, of which at
+    /// most one is valid in every row.
+    #[cfg(feature = "dtype-union")]
+    Union(Vec<Field>, UnionMode),
+    /// An Arrow extension type: a named type with optional (serialized) metadata, whose values
+    /// are held by the `storage` type.
+    #[cfg(feature = "dtype-extension")]
+    Extension {
+        name: PlSmallStr,
+        /// Boxed to keep the size of `DataType` unchanged.
+        metadata: Option<Box<PlSmallStr>>,
+        storage: Box<DataType>,
+    },
     /// A generic type that can be used in a `Series`
     /// &'static str can be used to determine/set inner type
     #[cfg(feature = "object")]
@@ -165,10 +230,31 @@ impl PartialEq for DataType {
                 (Enum(fcats_l, _), Enum(fcats_r, _)) => Arc::ptr_eq(fcats_l, fcats_r),
                 (Datetime(tu_l, tz_l), Datetime(tu_r, tz_r)) => tu_l == tu_r && tz_l == tz_r,
                 (List(left_inner), List(right_inner)) => left_inner == right_inner,
+                #[cfg(feature = "dtype-map")]
+                (Map(lk, lv), Map(rk, rv)) => lk == rk && lv == rv,
+                #[cfg(feature = "dtype-union")]
+                (Union(fields_l, mode_l), Union(fields_r, mode_r)) => {
+                    fields_l == fields_r && mode_l == mode_r
+                },
+                #[cfg(feature = "dtype-extension")]
+                (
+                    Extension {
+                        name: name_l,
+                        metadata: md_l,
+                        storage: storage_l,
+                    },
+                    Extension {
+                        name: name_r,
+                        metadata: md_r,
+                        storage: storage_r,
+                    },
+                ) => name_l == name_r && md_l == md_r && storage_l == storage_r,
                 #[cfg(feature = "dtype-duration")]
                 (Duration(tu_l), Duration(tu_r)) => tu_l == tu_r,
                 #[cfg(feature = "dtype-decimal")]
                 (Decimal(p1, s1), Decimal(p2, s2)) => (p1, s1) == (p2, s2),
+                #[cfg(feature = "dtype-decimal256")]
+                (Decimal256(p1, s1), Decimal256(p2, s2)) => (p1, s1) == (p2, s2),
                 #[cfg(feature = "object")]
                 (Object(lhs), Object(rhs)) => lhs == rhs,
                 #[cfg(feature = "dtype-struct")]
@@ -238,10 +324,25 @@ impl DataType {
         ])
     }
 
+    /// The `{key, value}` struct that holds the entries of a [`DataType::Map`].
+    #[cfg(feature = "dtype-map")]
+    pub fn _map_entries_type(key: DataType, value: DataType) -> Self {
+        DataType::Struct(vec![
+            Field::new(PlSmallStr::from_static("key"), key),
+            Field::new(PlSmallStr::from_static("value"), value),
+        ])
+    }
+
     /// Check if the whole dtype is known.
     pub fn is_known(&self) -> bool {
         match self {
             DataType::List(inner) => inner.is_known(),
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value) => key.is_known() && value.is_known(),
+            #[cfg(feature = "dtype-union")]
+            DataType::Union(fields, _) => fields.iter().all(|fld| fld.dtype().is_known()),
+            #[cfg(feature = "dtype-extension")]
+            DataType::Extension { storage, .. } => storage.is_known(),
             #[cfg(feature = "dtype-array")]
             DataType::Array(inner, _) => inner.is_known(),
             #[cfg(feature = "dtype-struct")]
@@ -268,6 +369,34 @@ impl DataType {
             DataType::List(inner) => Ok(DataType::List(Box::new(
                 inner.materialize_unknown(allow_unknown)?,
             ))),
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value) => Ok(DataType::Map(
+                Box::new(key.materialize_unknown(allow_unknown)?),
+                Box::new(value.materialize_unknown(allow_unknown)?),
+            )),
+            #[cfg(feature = "dtype-union")]
+            DataType::Union(fields, mode) => Ok(DataType::Union(
+                fields
+                    .into_iter()
+                    .map(|f| {
+                        PolarsResult::Ok(Field::new(
+                            f.name,
+                            f.dtype.materialize_unknown(allow_unknown)?,
+                        ))
+                    })
+                    .try_collect_vec()?,
+                mode,
+            )),
+            #[cfg(feature = "dtype-extension")]
+            DataType::Extension {
+                name,
+                metadata,
+                storage,
+            } => Ok(DataType::Extension {
+                name,
+                metadata,
+                storage: Box::new(storage.materialize_unknown(allow_unknown)?),
+            }),
             #[cfg(feature = "dtype-array")]
             DataType::Array(inner, size) => Ok(DataType::Array(
                 Box::new(inner.materialize_unknown(allow_unknown)?),
@@ -403,11 +532,39 @@ impl DataType {
                 dt if dt.is_primitive_numeric() => true,
                 #[cfg(feature = "dtype-decimal")]
                 D::Decimal(_, _) => true,
+                #[cfg(feature = "dtype-decimal256")]
+                D::Decimal256(_, _) => true,
                 D::String | D::Binary => true,
                 _ => false,
             },
 
+            #[cfg(feature = "dtype-uuid")]
+            (D::Uuid, dt) | (dt, D::Uuid) => {
+                matches!(dt, D::String | D::Binary | D::UInt128 | D::Null)
+            },
+            #[cfg(feature = "dtype-json")]
+            (D::Json, dt) | (dt, D::Json) => matches!(dt, D::String | D::Binary | D::Null),
+
             (D::List(from), D::List(to)) => from.can_cast_to(to)?,
+            #[cfg(feature = "dtype-map")]
+            (D::Map(from_k, from_v), D::Map(to_k, to_v)) => {
+                from_k.can_cast_to(to_k)? && from_v.can_cast_to(to_v)?
+            },
+            // A union can be cast to a type if all of its variants can.
+            #[cfg(feature = "dtype-union")]
+            (D::Union(fields, _), to) if !to.is_union() => {
+                for fld in fields {
+                    if !fld.dtype().can_cast_to(to)? {
+                        return Some(false);
+                    }
+                }
+                true
+            },
+            #[cfg(feature = "dtype-union")]
+            (_, D::Union(_, _)) | (D::Union(_, _), _) => return None,
+            // Casts of extension types can be registered at runtime, so we can't tell here.
+            #[cfg(feature = "dtype-extension")]
+            (D::Extension { .. }, _) | (_, D::Extension { .. }) => return None,
             #[cfg(feature = "dtype-array")]
             (D::Array(from, l_width), D::Array(to, r_width)) => {
                 l_width == r_width && from.can_cast_to(to)?
@@ -451,6 +608,16 @@ impl DataType {
             Time => Int64,
             #[cfg(feature = "dtype-decimal")]
             Decimal(_, _) => Int128,
+            #[cfg(feature = "dtype-decimal256")]
+            Decimal256(_, _) => Binary,
+            #[cfg(feature = "dtype-interval")]
+            Interval => Int128,
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Int16,
+            #[cfg(feature = "dtype-uuid")]
+            Uuid => UInt128,
+            #[cfg(feature = "dtype-json")]
+            Json => Binary,
             #[cfg(feature = "dtype-categorical")]
             Categorical(cats, _) => cats.physical().dtype(),
             #[cfg(feature = "dtype-categorical")]
@@ -458,6 +625,20 @@ impl DataType {
             #[cfg(feature = "dtype-array")]
             Array(dt, width) => Array(Box::new(dt.to_physical()), *width),
             List(dt) => List(Box::new(dt.to_physical())),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => List(Box::new(Self::_map_entries_type(
+                key.to_physical(),
+                value.to_physical(),
+            ))),
+            #[cfg(feature = "dtype-union")]
+            Union(fields, _) => Struct(
+                fields
+                    .iter()
+                    .map(|fld| Field::new(fld.name().clone(), fld.dtype().to_physical()))
+                    .collect(),
+            ),
+            #[cfg(feature = "dtype-extension")]
+            Extension { storage, .. } => storage.to_physical(),
             #[cfg(feature = "dtype-struct")]
             Struct(fields) => {
                 let new_fields = fields
@@ -523,7 +704,43 @@ impl DataType {
     }
 
     pub fn is_nested(&self) -> bool {
-        self.is_list() || self.is_struct() || self.is_array()
+        self.is_list() || self.is_struct() || self.is_array() || self.is_map()
+    }
+
+    /// Check if this [`DataType`] is a map.
+    pub fn is_map(&self) -> bool {
+        #[cfg(feature = "dtype-map")]
+        {
+            matches!(self, DataType::Map(_, _))
+        }
+        #[cfg(not(feature = "dtype-map"))]
+        {
+            false
+        }
+    }
+
+    /// Check if this [`DataType`] is a union.
+    pub fn is_union(&self) -> bool {
+        #[cfg(feature = "dtype-union")]
+        {
+            matches!(self, DataType::Union(_, _))
+        }
+        #[cfg(not(feature = "dtype-union"))]
+        {
+            false
+        }
+    }
+
+    /// Check if this [`DataType`] is an extension type.
+    pub fn is_extension(&self) -> bool {
+        #[cfg(feature = "dtype-extension")]
+        {
+            matches!(self, DataType::Extension { .. })
+        }
+        #[cfg(not(feature = "dtype-extension"))]
+        {
+            false
+        }
     }
 
     /// Check if this [`DataType`] is a struct
@@ -553,6 +770,50 @@ impl DataType {
         matches!(self, DataType::Duration(..))
     }
 
+    pub fn is_interval(&self) -> bool {
+        #[cfg(feature = "dtype-interval")]
+        {
+            matches!(self, DataType::Interval)
+        }
+        #[cfg(not(feature = "dtype-interval"))]
+        {
+            false
+        }
+    }
+
+    pub fn is_float16(&self) -> bool {
+        #[cfg(feature = "dtype-f16")]
+        {
+            matches!(self, DataType::Float16)
+        }
+        #[cfg(not(feature = "dtype-f16"))]
+        {
+            false
+        }
+    }
+
+    pub fn is_uuid(&self) -> bool {
+        #[cfg(feature = "dtype-uuid")]
+        {
+            matches!(self, DataType::Uuid)
+        }
+        #[cfg(not(feature = "dtype-uuid"))]
+        {
+            false
+        }
+    }
+
+    pub fn is_json(&self) -> bool {
+        #[cfg(feature = "dtype-json")]
+        {
+            matches!(self, DataType::Json)
+        }
+        #[cfg(not(feature = "dtype-json"))]
+        {
+            false
+        }
+    }
+
     pub fn is_object(&self) -> bool {
         #[cfg(feature = "object")]
         {
@@ -573,10 +834,16 @@ impl DataType {
         match self {
             Binary | String => true,
             List(inner) => inner.contains_views(),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => key.contains_views() || value.contains_views(),
+            #[cfg(feature = "dtype-union")]
+            Union(fields, _) => fields.iter().any(|field| field.dtype.contains_views()),
             #[cfg(feature = "dtype-array")]
             Array(inner, _) => inner.contains_views(),
             #[cfg(feature = "dtype-struct")]
             Struct(fields) => fields.iter().any(|field| field.dtype.contains_views()),
+            #[cfg(feature = "dtype-extension")]
+            Extension { storage, .. } => storage.contains_views(),
             _ => false,
         }
     }
@@ -587,12 +854,20 @@ impl DataType {
             #[cfg(feature = "dtype-categorical")]
             Categorical(_, _) | Enum(_, _) => true,
             List(inner) => inner.contains_categoricals(),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => key.contains_categoricals() || value.contains_categoricals(),
+            #[cfg(feature = "dtype-union")]
+            Union(fields, _) => fields
+                .iter()
+                .any(|field| field.dtype.contains_categoricals()),
             #[cfg(feature = "dtype-array")]
             Array(inner, _) => inner.contains_categoricals(),
             #[cfg(feature = "dtype-struct")]
             Struct(fields) => fields
                 .iter()
                 .any(|field| field.dtype.contains_categoricals()),
+            #[cfg(feature = "dtype-extension")]
+            Extension { storage, .. } => storage.contains_categoricals(),
             _ => false,
         }
     }
@@ -603,10 +878,16 @@ impl DataType {
             #[cfg(feature = "object")]
             Object(_) => true,
             List(inner) => inner.contains_objects(),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => key.contains_objects() || value.contains_objects(),
+            #[cfg(feature = "dtype-union")]
+            Union(fields, _) => fields.iter().any(|field| field.dtype.contains_objects()),
             #[cfg(feature = "dtype-array")]
             Array(inner, _) => inner.contains_objects(),
             #[cfg(feature = "dtype-struct")]
             Struct(fields) => fields.iter().any(|field| field.dtype.contains_objects()),
+            #[cfg(feature = "dtype-extension")]
+            Extension { storage, .. } => storage.contains_objects(),
             _ => false,
         }
     }
@@ -615,12 +896,20 @@ impl DataType {
         use DataType as D;
         match self {
             D::List(_) => true,
+            #[cfg(feature = "dtype-map")]
+            D::Map(_, _) => true,
+            #[cfg(feature = "dtype-union")]
+            D::Union(fields, _) => fields
+                .iter()
+                .any(|field| field.dtype.contains_list_recursive()),
             #[cfg(feature = "dtype-array")]
             D::Array(inner, _) => inner.contains_list_recursive(),
             #[cfg(feature = "dtype-struct")]
             D::Struct(fields) => fields
                 .iter()
                 .any(|field| field.dtype.contains_list_recursive()),
+            #[cfg(feature = "dtype-extension")]
+            D::Extension { storage, .. } => storage.contains_list_recursive(),
             _ => false,
         }
     }
@@ -630,10 +919,16 @@ impl DataType {
         match self {
             D::Unknown(_) => true,
             D::List(inner) => inner.contains_unknown(),
+            #[cfg(feature = "dtype-map")]
+            D::Map(key, value) => key.contains_unknown() || value.contains_unknown(),
+            #[cfg(feature = "dtype-union")]
+            D::Union(fields, _) => fields.iter().any(|field| field.dtype.contains_unknown()),
             #[cfg(feature = "dtype-array")]
             D::Array(inner, _) => inner.contains_unknown(),
             #[cfg(feature = "dtype-struct")]
             D::Struct(fields) => fields.iter().any(|field| field.dtype.contains_unknown()),
+            #[cfg(feature = "dtype-extension")]
+            D::Extension { storage, .. } => storage.contains_unknown(),
             _ => false,
         }
     }
@@ -643,6 +938,7 @@ impl DataType {
         let phys = self.to_physical();
         phys.is_primitive_numeric()
             || self.is_decimal()
+            || self.is_decimal256()
             || matches!(
                 phys,
                 DataType::Binary | DataType::String | DataType::Boolean
@@ -658,6 +954,15 @@ impl DataType {
         }
     }
 
+    /// Check if this [`DataType`] is a 256-bit Decimal type (of any scale/precision).
+    pub fn is_decimal256(&self) -> bool {
+        match self {
+            #[cfg(feature = "dtype-decimal256")]
+            DataType::Decimal256(_, _) => true,
+            _ => false,
+        }
+    }
+
     /// Check if this [`DataType`] is a basic floating point type (excludes Decimal).
     /// Note, this also includes `Unknown(UnknownKind::Float)`.
     pub fn is_float(&self) -> bool {
@@ -800,6 +1105,8 @@ impl DataType {
             UInt128 => Scalar::from(u128::MAX),
             Float32 => Scalar::from(f32::INFINITY),
             Float64 => Scalar::from(f64::INFINITY),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Scalar::new(Float16, AnyValue::Float16(f16::from_bits(0x7C00))),
             #[cfg(feature = "dtype-time")]
             Time => Scalar::new(Time, AnyValue::Time(NS_IN_DAY - 1)),
             dt => polars_bail!(ComputeError: "cannot determine upper bound for dtype `{}`", dt),
@@ -823,6 +1130,8 @@ impl DataType {
             UInt128 => Scalar::from(u128::MIN),
             Float32 => Scalar::from(f32::NEG_INFINITY),
             Float64 => Scalar::from(f64::NEG_INFINITY),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Scalar::new(Float16, AnyValue::Float16(f16::from_bits(0xFC00))),
             #[cfg(feature = "dtype-time")]
             Time => Scalar::new(Time, AnyValue::Time(0)),
             dt => polars_bail!(ComputeError: "cannot determine lower bound for dtype `{}`", dt),
@@ -852,12 +1161,19 @@ impl DataType {
             Int64 => Ok(ArrowDataType::Int64),
             Int128 => Ok(ArrowDataType::Int128),
             Float32 => Ok(ArrowDataType::Float32),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Ok(ArrowDataType::Float16),
             Float64 => Ok(ArrowDataType::Float64),
             #[cfg(feature = "dtype-decimal")]
             Decimal(precision, scale) => {
                 assert!(*precision >= 1 && *precision <= 38);
                 Ok(ArrowDataType::Decimal(*precision, *scale))
             },
+            #[cfg(feature = "dtype-decimal256")]
+            Decimal256(precision, scale) => {
+                assert!(*precision >= 1 && *precision <= 76);
+                Ok(ArrowDataType::Decimal256(*precision, *scale))
+            },
             String => {
                 let dt = if compat_level.0 >= 1 {
                     ArrowDataType::Utf8View
@@ -880,6 +1196,10 @@ impl DataType {
                 tz.as_deref().cloned(),
             )),
             Duration(unit) => Ok(ArrowDataType::Duration(unit.to_arrow())),
+            #[cfg(feature = "dtype-interval")]
+            Interval => Ok(ArrowDataType::Interval(
+                arrow::datatypes::IntervalUnit::MonthDayNano,
+            )),
             Time => Ok(ArrowDataType::Time64(ArrowTimeUnit::Nanosecond)),
             #[cfg(feature = "dtype-array")]
             Array(dt, size) => Ok(dt
@@ -888,6 +1208,46 @@ impl DataType {
             List(dt) => Ok(ArrowDataType::LargeList(Box::new(
                 dt.to_arrow_field(LIST_VALUES_NAME, compat_level),
             ))),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => {
+                // Map keys can't be null.
+                let mut key = key.to_arrow_field(PlSmallStr::from_static("key"), compat_level);
+                key.is_nullable = false;
+                let value = value.to_arrow_field(PlSmallStr::from_static("value"), compat_level);
+                let entries = ArrowField::new(
+                    PlSmallStr::from_static("entries"),
+                    ArrowDataType::Struct(vec![key, value]),
+                    false,
+                );
+                Ok(ArrowDataType::Map(Box::new(entries), false))
+            },
+            #[cfg(feature = "dtype-union")]
+            Union(fields, mode) => Ok(ArrowDataType::Union(Box::new(
+                arrow::datatypes::UnionType {
+                    fields: fields
+                        .iter()
+                        .map(|fld| fld.to_arrow(compat_level))
+                        .collect(),
+                    ids: None,
+                    mode: (*mode).into(),
+                },
+            ))),
+            #[cfg(feature = "dtype-extension")]
+            Extension {
+                name,
+                metadata,
+                storage,
+            } => Ok(ArrowDataType::Extension(Box::new(
+                arrow::datatypes::ExtensionType {
+                    name: name.clone(),
+                    inner: storage.try_to_arrow(compat_level)?,
+                    metadata: metadata.as_deref().cloned(),
+                },
+            ))),
+            #[cfg(feature = "dtype-uuid")]
+            Uuid => Ok(uuid_arrow_dtype()),
+            #[cfg(feature = "dtype-json")]
+            Json => Ok(json_arrow_dtype(compat_level)),
             Null => Ok(ArrowDataType::Null),
             #[cfg(feature = "object")]
             Object(_) => Ok(get_object_physical_type()),
@@ -939,6 +1299,10 @@ impl DataType {
         match self {
             Null => true,
             List(field) => field.is_nested_null(),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => key.is_nested_null() && value.is_nested_null(),
+            #[cfg(feature = "dtype-union")]
+            Union(fields, _) => fields.iter().all(|fld| fld.dtype.is_nested_null()),
             #[cfg(feature = "dtype-array")]
             Array(field, _) => field.is_nested_null(),
             #[cfg(feature = "dtype-struct")]
@@ -956,6 +1320,10 @@ impl DataType {
     pub fn matches_schema_type(&self, schema_type: &DataType) -> PolarsResult<bool> {
         match (self, schema_type) {
             (DataType::List(l), DataType::List(r)) => l.matches_schema_type(r),
+            #[cfg(feature = "dtype-map")]
+            (DataType::Map(lk, lv), DataType::Map(rk, rv)) => {
+                Ok(lk.matches_schema_type(rk)? | lv.matches_schema_type(rv)?)
+            },
             #[cfg(feature = "dtype-array")]
             (DataType::Array(l, sl), DataType::Array(r, sr)) => {
                 Ok(l.matches_schema_type(r)? && sl == sr)
@@ -974,6 +1342,10 @@ impl DataType {
             (DataType::Null, DataType::Null) => Ok(false),
             #[cfg(feature = "dtype-decimal")]
             (DataType::Decimal(p1, s1), DataType::Decimal(p2, s2)) => Ok((p1, s1) != (p2, s2)),
+            #[cfg(feature = "dtype-decimal256")]
+            (DataType::Decimal256(p1, s1), DataType::Decimal256(p2, s2)) => {
+                Ok((p1, s1) != (p2, s2))
+            },
             // We don't allow the other way around, only if our current type is
             // null and the schema isn't we allow it.
             (DataType::Null, _) => Ok(true),
@@ -1046,7 +1418,11 @@ impl DataType {
     }
 
     pub fn is_numeric(&self) -> bool {
-        self.is_integer() || self.is_float() || self.is_decimal()
+        self.is_integer()
+            || self.is_float()
+            || self.is_float16()
+            || self.is_decimal()
+            || self.is_decimal256()
     }
 }
 
@@ -1066,15 +1442,25 @@ impl Display for DataType {
             DataType::Int64 => "i64",
             DataType::Int128 => "i128",
             DataType::Float32 => "f32",
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => "f16",
             DataType::Float64 => "f64",
             #[cfg(feature = "dtype-decimal")]
             DataType::Decimal(p, s) => return write!(f, "decimal[{p},{s}]"),
+            #[cfg(feature = "dtype-decimal256")]
+            DataType::Decimal256(p, s) => return write!(f, "decimal256[{p},{s}]"),
             DataType::String => "str",
             DataType::Binary => "binary",
+            #[cfg(feature = "dtype-uuid")]
+            DataType::Uuid => "uuid",
+            #[cfg(feature = "dtype-json")]
+            DataType::Json => "json",
             DataType::Date => "date",
             DataType::Datetime(tu, None) => return write!(f, "datetime[{tu}]"),
             DataType::Datetime(tu, Some(tz)) => return write!(f, "datetime[{tu}, {tz}]"),
             DataType::Duration(tu) => return write!(f, "duration[{tu}]"),
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => "interval",
             DataType::Time => "time",
             #[cfg(feature = "dtype-array")]
             DataType::Array(_, _) => {
@@ -1089,6 +1475,12 @@ impl Display for DataType {
                 return write!(f, "array[{tp}, {shape}]");
             },
             DataType::List(tp) => return write!(f, "list[{tp}]"),
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value) => return write!(f, "map[{key}, {value}]"),
+            #[cfg(feature = "dtype-union")]
+            DataType::Union(fields, _) => return write!(f, "union[{}]", fields.len()),
+            #[cfg(feature = "dtype-extension")]
+            DataType::Extension { name, .. } => return write!(f, "ext[{name}]"),
             #[cfg(feature = "object")]
             DataType::Object(s) => s,
             #[cfg(feature = "dtype-categorical")]
@@ -1125,13 +1517,21 @@ impl std::fmt::Debug for DataType {
             Int64 => write!(f, "Int64"),
             Int128 => write!(f, "Int128"),
             Float32 => write!(f, "Float32"),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => write!(f, "Float16"),
             Float64 => write!(f, "Float64"),
             String => write!(f, "String"),
             Binary => write!(f, "Binary"),
+            #[cfg(feature = "dtype-uuid")]
+            Uuid => write!(f, "Uuid"),
+            #[cfg(feature = "dtype-json")]
+            Json => write!(f, "Json"),
             BinaryOffset => write!(f, "BinaryOffset"),
             Date => write!(f, "Date"),
             Time => write!(f, "Time"),
             Duration(unit) => write!(f, "Duration('{unit}')"),
+            #[cfg(feature = "dtype-interval")]
+            Interval => write!(f, "Interval"),
             Datetime(unit, opt_tz) => {
                 if let Some(tz) = opt_tz {
                     write!(f, "Datetime('{unit}', '{tz}')")
@@ -1141,9 +1541,28 @@ impl std::fmt::Debug for DataType {
             },
             #[cfg(feature = "dtype-decimal")]
             Decimal(p, s) => write!(f, "Decimal({p}, {s})"),
+            #[cfg(feature = "dtype-decimal256")]
+            Decimal256(p, s) => write!(f, "Decimal256({p}, {s})"),
             #[cfg(feature = "dtype-array")]
             Array(inner, size) => write!(f, "Array({inner:?}, {size})"),
             List(inner) => write!(f, "List({inner:?})"),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => write!(f, "Map({key:?}, {value:?})"),
+            #[cfg(feature = "dtype-union")]
+            Union(fields, mode) => {
+                let mut first = true;
+                write!(f, "Union({{")?;
+                for field in fields {
+                    if !first {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "'{}': {:?}", field.name(), field.dtype())?;
+                    first = false;
+                }
+                write!(f, "}}, {mode:?})")
+            },
+            #[cfg(feature = "dtype-extension")]
+            Extension { name, storage, .. } => write!(f, "Extension('{name}', {storage:?})"),
             #[cfg(feature = "dtype-struct")]
             Struct(fields) => {
                 let mut first = true;
@@ -1201,6 +1620,11 @@ pub fn merge_dtypes(left: &DataType, right: &DataType) -> PolarsResult<DataType>
             let merged = merge_dtypes(inner_l, inner_r)?;
             List(Box::new(merged))
         },
+        #[cfg(feature = "dtype-map")]
+        (Map(key_l, value_l), Map(key_r, value_r)) => Map(
+            Box::new(merge_dtypes(key_l, key_r)?),
+            Box::new(merge_dtypes(value_l, value_r)?),
+        ),
         #[cfg(feature = "dtype-struct")]
         (Struct(inner_l), Struct(inner_r)) => {
             polars_ensure!(inner_l.len() == inner_r.len(), ComputeError: "cannot combine structs with differing amounts of fields ({} != {})", inner_l.len(), inner_r.len());
@@ -1241,6 +1665,23 @@ fn collect_nested_types(
             }
             collect_nested_types(inner, result, include_compound_types);
         },
+        #[cfg(feature = "dtype-map")]
+        DataType::Map(key, value) => {
+            if include_compound_types {
+                result.insert(dtype.clone());
+            }
+            collect_nested_types(key, result, include_compound_types);
+            collect_nested_types(value, result, include_compound_types);
+        },
+        #[cfg(feature = "dtype-union")]
+        DataType::Union(fields, _) => {
+            if include_compound_types {
+                result.insert(dtype.clone());
+            }
+            for field in fields {
+                collect_nested_types(field.dtype(), result, include_compound_types);
+            }
+        },
         #[cfg(feature = "dtype-struct")]
         DataType::Struct(fields) => {
             if include_compound_types {
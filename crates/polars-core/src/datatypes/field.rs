@@ -173,6 +173,9 @@ impl DataType {
             #[cfg(feature = "dtype-i128")]
             ArrowDataType::Int128 => DataType::Int128,
             ArrowDataType::Boolean => DataType::Boolean,
+            #[cfg(feature = "dtype-f16")]
+            ArrowDataType::Float16 => DataType::Float16,
+            #[cfg(not(feature = "dtype-f16"))]
             ArrowDataType::Float16 => DataType::Float32,
             ArrowDataType::Float32 => DataType::Float32,
             ArrowDataType::Float64 => DataType::Float64,
@@ -260,8 +263,23 @@ impl DataType {
                     panic!("activate the 'object' feature to be able to load POLARS_EXTENSION_TYPE")
                 }
             },
+            #[cfg(feature = "dtype-uuid")]
+            ArrowDataType::Extension(ext) if is_uuid_extension(ext) => DataType::Uuid,
+            #[cfg(feature = "dtype-json")]
+            ArrowDataType::Extension(ext) if is_json_extension(ext) => DataType::Json,
+            #[cfg(feature = "dtype-extension")]
+            ArrowDataType::Extension(ext) => DataType::Extension {
+                name: ext.name.clone(),
+                metadata: ext.metadata.clone().map(Box::new),
+                storage: Box::new(Self::from_arrow(&ext.inner, md)),
+            },
+            // Without extension support we load the storage, e.g. JSON as plain strings.
+            #[cfg(not(feature = "dtype-extension"))]
+            ArrowDataType::Extension(ext) => Self::from_arrow(&ext.inner, md),
             #[cfg(feature = "dtype-decimal")]
             ArrowDataType::Decimal(precision, scale) => DataType::Decimal(*precision, *scale),
+            #[cfg(feature = "dtype-decimal256")]
+            ArrowDataType::Decimal256(precision, scale) => DataType::Decimal256(*precision, *scale),
             ArrowDataType::Utf8View | ArrowDataType::LargeUtf8 | ArrowDataType::Utf8 => {
                 DataType::String
             },
@@ -276,9 +294,26 @@ impl DataType {
             },
             ArrowDataType::LargeBinary | ArrowDataType::Binary => DataType::Binary,
             ArrowDataType::FixedSizeBinary(_) => DataType::Binary,
+            #[cfg(feature = "dtype-map")]
+            ArrowDataType::Map(inner, _is_sorted) => match Self::from_arrow_field(inner) {
+                DataType::Struct(fields) if fields.len() == 2 => DataType::Map(
+                    Box::new(fields[0].dtype().clone()),
+                    Box::new(fields[1].dtype().clone()),
+                ),
+                dt => DataType::List(dt.boxed()),
+            },
+            #[cfg(not(feature = "dtype-map"))]
             ArrowDataType::Map(inner, _is_sorted) => {
                 DataType::List(Self::from_arrow_field(inner).boxed())
             },
+            #[cfg(feature = "dtype-union")]
+            ArrowDataType::Union(union) => DataType::Union(
+                union.fields.iter().map(Field::from).collect(),
+                union.mode.into(),
+            ),
+            #[cfg(feature = "dtype-interval")]
+            ArrowDataType::Interval(IntervalUnit::MonthDayNano) => DataType::Interval,
+            #[cfg(not(feature = "dtype-interval"))]
             ArrowDataType::Interval(IntervalUnit::MonthDayNano) => {
                 check_allow_importing_interval_as_struct("month_day_nano_interval").unwrap();
                 feature_gated!("dtype-struct", DataType::_month_days_ns_struct_type())
@@ -35,6 +35,8 @@ use arrow::datatypes::IntegerType;
 pub use arrow::datatypes::reshape::*;
 pub use arrow::datatypes::{ArrowDataType, TimeUnit as ArrowTimeUnit};
 use arrow::types::NativeType;
+#[cfg(feature = "dtype-f16")]
+pub use arrow::types::f16;
 use bytemuck::Zeroable;
 pub use dtype::*;
 pub use field::*;
@@ -228,6 +230,17 @@ impl_polars_datatype!(BooleanType, DataType::Boolean, BooleanArray, 'a, bool, bo
 
 #[cfg(feature = "dtype-decimal")]
 impl_polars_datatype!(DecimalType, unimplemented!(), PrimitiveArray<i128>, 'a, i128, i128, i128, FalseT);
+// Stored as order-preserving bytes, see `Decimal256Chunked`.
+#[cfg(feature = "dtype-decimal256")]
+impl_polars_datatype!(Decimal256Type, unimplemented!(), BinaryViewArray, 'a, &'a [u8], Option<&'a [u8]>, Box<[u8]>, TrueT);
+#[cfg(feature = "dtype-interval")]
+impl_polars_datatype!(IntervalType, unimplemented!(), PrimitiveArray<i128>, 'a, i128, i128, i128, FalseT);
+#[cfg(feature = "dtype-f16")]
+impl_polars_datatype!(Float16Type, unimplemented!(), PrimitiveArray<i16>, 'a, i16, i16, i16, FalseT);
+#[cfg(feature = "dtype-uuid")]
+impl_polars_datatype!(UuidType, DataType::Uuid, PrimitiveArray<u128>, 'a, u128, u128, u128, FalseT);
+#[cfg(feature = "dtype-json")]
+impl_polars_datatype!(JsonType, DataType::Json, BinaryViewArray, 'a, &'a [u8], Option<&'a [u8]>, Box<[u8]>, TrueT);
 impl_polars_datatype!(DatetimeType, unimplemented!(), PrimitiveArray<i64>, 'a, i64, i64, i64, FalseT);
 impl_polars_datatype!(DurationType, unimplemented!(), PrimitiveArray<i64>, 'a, i64, i64, i64, FalseT);
 impl_polars_datatype!(CategoricalType, unimplemented!(), PrimitiveArray<u32>, 'a, u32, u32, u32, FalseT);
@@ -262,6 +275,46 @@ impl PolarsPhysicalType for ListType {
     }
 }
 
+/// Logical type of a [`DataType::Map`], physically a list of `{key, value}` structs.
+#[cfg(feature = "dtype-map")]
+pub struct MapType {}
+#[cfg(feature = "dtype-map")]
+unsafe impl PolarsDataType for MapType {
+    type Physical<'a> = Box<dyn Array>;
+    type OwnedPhysical = Box<dyn Array>;
+    type ZeroablePhysical<'a> = Option<Box<dyn Array>>;
+    type Array = ListArray<i64>;
+    type IsNested = TrueT;
+    type HasViews = FalseT;
+    type IsStruct = FalseT;
+    type IsObject = FalseT;
+
+    fn get_static_dtype() -> DataType {
+        // Null as we cannot know anything without self.
+        DataType::Map(Box::new(DataType::Null), Box::new(DataType::Null))
+    }
+}
+
+/// Logical type of a [`DataType::Union`], physically a struct with a field per variant.
+#[cfg(feature = "dtype-union")]
+pub struct UnionType {}
+#[cfg(feature = "dtype-union")]
+unsafe impl PolarsDataType for UnionType {
+    type Physical<'a> = ();
+    type OwnedPhysical = ();
+    type ZeroablePhysical<'a> = ();
+    type Array = StructArray;
+    type IsNested = TrueT;
+    type HasViews = FalseT;
+    type IsStruct = TrueT;
+    type IsObject = FalseT;
+
+    fn get_static_dtype() -> DataType {
+        // Empty as we cannot know anything without self.
+        DataType::Union(vec![], UnionMode::Sparse)
+    }
+}
+
 #[cfg(feature = "dtype-struct")]
 pub struct StructType {}
 #[cfg(feature = "dtype-struct")]
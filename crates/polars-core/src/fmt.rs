@@ -1,5 +1,5 @@
 #![allow(unsafe_op_in_unsafe_fn)]
-#[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+#[cfg(any(feature = "fmt", feature = "fmt_no_tty", feature = "dtype-extension"))]
 use std::borrow::Cow;
 use std::fmt::{Debug, Display, Formatter, Write};
 use std::str::FromStr;
@@ -12,6 +12,8 @@ use std::{fmt, str};
     feature = "dtype-time"
 ))]
 use arrow::temporal_conversions::*;
+#[cfg(feature = "dtype-interval")]
+use arrow::types::months_days_ns;
 #[cfg(feature = "dtype-datetime")]
 use chrono::NaiveDateTime;
 #[cfg(feature = "timezones")]
@@ -215,6 +217,26 @@ macro_rules! format_array {
     }};
 }
 
+/// Formats the values of an extension type with [`ExtensionChunked::str_value`], so that
+/// registered extension types get their custom display.
+#[cfg(feature = "dtype-extension")]
+struct ExtensionValues<'a>(&'a ExtensionChunked);
+
+#[cfg(feature = "dtype-extension")]
+impl ExtensionValues<'_> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn dtype(&self) -> &DataType {
+        self.0.dtype()
+    }
+
+    fn get_any_value(&self, index: usize) -> PolarsResult<Cow<'_, str>> {
+        self.0.str_value(index)
+    }
+}
+
 #[cfg(feature = "object")]
 fn format_object_array(
     f: &mut Formatter<'_>,
@@ -377,6 +399,14 @@ impl Debug for Series {
             DataType::Float64 => {
                 format_array!(f, self.f64().unwrap(), "f64", self.name(), "Series")
             },
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => {
+                format_array!(f, self.float16().unwrap(), "f16", self.name(), "Series")
+            },
+            #[cfg(feature = "dtype-uuid")]
+            DataType::Uuid => format_array!(f, self.uuid().unwrap(), "uuid", self.name(), "Series"),
+            #[cfg(feature = "dtype-json")]
+            DataType::Json => format_array!(f, self.json().unwrap(), "json", self.name(), "Series"),
             #[cfg(feature = "dtype-date")]
             DataType::Date => format_array!(f, self.date().unwrap(), "date", self.name(), "Series"),
             #[cfg(feature = "dtype-datetime")]
@@ -386,6 +416,16 @@ impl Debug for Series {
             },
             #[cfg(feature = "dtype-time")]
             DataType::Time => format_array!(f, self.time().unwrap(), "time", self.name(), "Series"),
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => {
+                format_array!(
+                    f,
+                    self.interval().unwrap(),
+                    "interval",
+                    self.name(),
+                    "Series"
+                )
+            },
             #[cfg(feature = "dtype-duration")]
             DataType::Duration(_) => {
                 let dt = format!("{}", self.dtype());
@@ -396,6 +436,11 @@ impl Debug for Series {
                 let dt = format!("{}", self.dtype());
                 format_array!(f, self.decimal().unwrap(), &dt, self.name(), "Series")
             },
+            #[cfg(feature = "dtype-decimal256")]
+            DataType::Decimal256(_, _) => {
+                let dt = format!("{}", self.dtype());
+                format_array!(f, self.decimal256().unwrap(), &dt, self.name(), "Series")
+            },
             #[cfg(feature = "dtype-array")]
             DataType::Array(_, _) => {
                 let dt = format!("{}", self.dtype());
@@ -405,6 +450,22 @@ impl Debug for Series {
                 let dt = format!("{}", self.dtype());
                 format_array!(f, self.list().unwrap(), &dt, self.name(), "Series")
             },
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(_, _) => {
+                let dt = format!("{}", self.dtype());
+                format_array!(f, self.map().unwrap(), &dt, self.name(), "Series")
+            },
+            #[cfg(feature = "dtype-union")]
+            DataType::Union(_, _) => {
+                let dt = format!("{}", self.dtype());
+                format_array!(f, self.union().unwrap(), &dt, self.name(), "Series")
+            },
+            #[cfg(feature = "dtype-extension")]
+            DataType::Extension { .. } => {
+                let dt = format!("{}", self.dtype());
+                let values = ExtensionValues(self.extension().unwrap());
+                format_array!(f, values, &dt, self.name(), "Series")
+            },
             #[cfg(feature = "object")]
             DataType::Object(_) => format_object_array(f, self, self.name(), "Series"),
             #[cfg(feature = "dtype-categorical")]
@@ -795,7 +856,7 @@ impl Display for DataFrame {
                 for (column_index, column) in table.column_iter_mut().enumerate() {
                     let dtype = fields[column_index].dtype();
                     let mut preset = str_preset.as_str();
-                    if dtype.is_primitive_numeric() || dtype.is_decimal() {
+                    if dtype.is_primitive_numeric() || dtype.is_decimal() || dtype.is_decimal256() {
                         preset = num_preset.as_str();
                     }
                     match preset {
@@ -1058,6 +1119,81 @@ pub fn fmt_duration_string<W: Write>(f: &mut W, v: i64, unit: TimeUnit) -> fmt::
     Ok(())
 }
 
+#[cfg(feature = "dtype-interval")]
+pub fn fmt_interval_string<W: Write>(f: &mut W, v: months_days_ns) -> fmt::Result {
+    // eg: "1mo 2d 3h 4m", using the same units as the `polars-time` duration strings.
+    if v.months() == 0 && v.days() == 0 && v.ns() == 0 {
+        return f.write_str("0d");
+    }
+    let mut buffer = itoa::Buffer::new();
+    let mut sep = "";
+    for (value, suffix) in [(v.months(), "mo"), (v.days(), "d")] {
+        if value != 0 {
+            f.write_str(sep)?;
+            f.write_str(buffer.format(value))?;
+            f.write_str(suffix)?;
+            sep = " ";
+        }
+    }
+    if v.ns() != 0 {
+        f.write_str(sep)?;
+        fmt_duration_string(f, v.ns(), TimeUnit::Nanoseconds)?;
+    }
+    Ok(())
+}
+
+#[cfg(feature = "dtype-interval")]
+pub fn iso_interval_string(s: &mut String, v: months_days_ns) {
+    // eg: "P1Y2M10DT2H30M"; if the parts differ in sign, each part carries its own sign.
+    let (months, days, ns) = (v.months() as i64, v.days() as i64, v.ns());
+    if months == 0 && days == 0 && ns == 0 {
+        s.push_str("PT0S");
+        return;
+    }
+    let negative = months <= 0 && days <= 0 && ns <= 0;
+    if negative {
+        s.push('-');
+    }
+    s.push('P');
+    let mut buffer = itoa::Buffer::new();
+    let mut push_part = |s: &mut String, value: i64, suffix: &str| {
+        if value != 0 {
+            if value < 0 && !negative {
+                s.push('-');
+            }
+            s.push_str(buffer.format(value.unsigned_abs()));
+            s.push_str(suffix);
+        }
+    };
+    push_part(s, months / 12, "Y");
+    push_part(s, months % 12, "M");
+    push_part(s, days, "D");
+    if ns != 0 {
+        s.push('T');
+        let sign = ns.signum();
+        let abs_ns = ns.unsigned_abs();
+        let secs = abs_ns % 60_000_000_000;
+        push_part(s, sign * (abs_ns / 3_600_000_000_000) as i64, "H");
+        push_part(
+            s,
+            sign * (abs_ns % 3_600_000_000_000 / 60_000_000_000) as i64,
+            "M",
+        );
+        if secs != 0 {
+            if sign < 0 && !negative {
+                s.push('-');
+            }
+            s.push_str(buffer.format(secs / 1_000_000_000));
+            let fractional_part = secs % 1_000_000_000;
+            if fractional_part != 0 {
+                let fraction = format!(".{fractional_part:09}");
+                s.push_str(fraction.trim_end_matches('0'));
+            }
+            s.push('S');
+        }
+    }
+}
+
 #[cfg(feature = "dtype-duration")]
 pub fn iso_duration_string(s: &mut String, mut v: i64, unit: TimeUnit) {
     if v == 0 {
@@ -1168,11 +1304,15 @@ impl Display for AnyValue<'_> {
             AnyValue::Int128(v) => feature_gated!("dtype-i128", fmt_integer(f, width, *v)),
             AnyValue::Float32(v) => fmt_float(f, width, *v),
             AnyValue::Float64(v) => fmt_float(f, width, *v),
+            #[cfg(feature = "dtype-f16")]
+            AnyValue::Float16(v) => fmt_float(f, width, v.to_f32()),
             AnyValue::Boolean(v) => write!(f, "{}", *v),
             AnyValue::String(v) => write!(f, "{}", format_args!("\"{v}\"")),
             AnyValue::StringOwned(v) => write!(f, "{}", format_args!("\"{v}\"")),
             AnyValue::Binary(d) => format_blob(f, d),
             AnyValue::BinaryOwned(d) => format_blob(f, d),
+            #[cfg(feature = "dtype-uuid")]
+            AnyValue::Uuid(v) => write!(f, "{}", format_uuid(*v)),
             #[cfg(feature = "dtype-date")]
             AnyValue::Date(v) => write!(f, "{}", date32_to_date(*v)),
             #[cfg(feature = "dtype-datetime")]
@@ -1183,6 +1323,8 @@ impl Display for AnyValue<'_> {
             },
             #[cfg(feature = "dtype-duration")]
             AnyValue::Duration(v, tu) => fmt_duration_string(f, *v, *tu),
+            #[cfg(feature = "dtype-interval")]
+            AnyValue::Interval(v) => fmt_interval_string(f, *v),
             #[cfg(feature = "dtype-time")]
             AnyValue::Time(_) => {
                 let nt: chrono::NaiveTime = self.into();
@@ -1213,6 +1355,8 @@ impl Display for AnyValue<'_> {
             AnyValue::StructOwned(payload) => fmt_struct(f, &payload.0),
             #[cfg(feature = "dtype-decimal")]
             AnyValue::Decimal(v, _prec, scale) => fmt_decimal(f, *v, *scale),
+            #[cfg(feature = "dtype-decimal256")]
+            AnyValue::Decimal256(v, _prec, scale) => fmt_decimal256(f, *v, *scale),
         }
     }
 }
@@ -1314,6 +1458,14 @@ fn fmt_decimal(f: &mut Formatter<'_>, v: i128, scale: usize) -> fmt::Result {
     f.write_str(fmt_float_string(fmt_buf.format_dec128(v, scale, trim_zeros, false)).as_str())
 }
 
+#[inline]
+#[cfg(feature = "dtype-decimal256")]
+fn fmt_decimal256(f: &mut Formatter<'_>, v: arrow::types::i256, scale: usize) -> fmt::Result {
+    let mut fmt_buf = polars_compute::decimal::Decimal256FmtBuffer::new();
+    let trim_zeros = get_trim_decimal_zeros();
+    f.write_str(fmt_float_string(fmt_buf.format_dec256(v, scale, trim_zeros, false)).as_str())
+}
+
 #[cfg(all(
     test,
     feature = "temporal",
@@ -1504,6 +1656,24 @@ Series: '' [datetime[ns]]
         );
     }
 
+    #[test]
+    #[cfg(feature = "dtype-interval")]
+    fn test_fmt_interval() {
+        let s = IntervalChunked::from_intervals(
+            PlSmallStr::from_static("a"),
+            [Some(arrow::types::months_days_ns::new(1, -2, 3)), None],
+        );
+        assert_eq!(
+            r#"shape: (2,)
+Series: 'a' [interval]
+[
+	1mo -2d 3ns
+	null
+]"#,
+            format!("{:?}", s.into_series())
+        );
+    }
+
     #[test]
     fn test_fmt_chunkedarray() {
         let ca = Int32Chunked::new(PlSmallStr::from_static("Date"), &[Some(1), None, Some(3)]);
@@ -372,6 +372,38 @@ impl Column {
     pub fn try_decimal(&self) -> Option<&DecimalChunked> {
         self.as_materialized_series().try_decimal()
     }
+    #[cfg(feature = "dtype-decimal256")]
+    pub fn try_decimal256(&self) -> Option<&Decimal256Chunked> {
+        self.as_materialized_series().try_decimal256()
+    }
+    #[cfg(feature = "dtype-interval")]
+    pub fn try_interval(&self) -> Option<&IntervalChunked> {
+        self.as_materialized_series().try_interval()
+    }
+    #[cfg(feature = "dtype-f16")]
+    pub fn try_float16(&self) -> Option<&Float16Chunked> {
+        self.as_materialized_series().try_float16()
+    }
+    #[cfg(feature = "dtype-uuid")]
+    pub fn try_uuid(&self) -> Option<&UuidChunked> {
+        self.as_materialized_series().try_uuid()
+    }
+    #[cfg(feature = "dtype-json")]
+    pub fn try_json(&self) -> Option<&JsonChunked> {
+        self.as_materialized_series().try_json()
+    }
+    #[cfg(feature = "dtype-map")]
+    pub fn try_map(&self) -> Option<&MapChunked> {
+        self.as_materialized_series().try_map()
+    }
+    #[cfg(feature = "dtype-union")]
+    pub fn try_union(&self) -> Option<&UnionChunked> {
+        self.as_materialized_series().try_union()
+    }
+    #[cfg(feature = "dtype-extension")]
+    pub fn try_extension(&self) -> Option<&ExtensionChunked> {
+        self.as_materialized_series().try_extension()
+    }
     #[cfg(feature = "dtype-array")]
     pub fn try_array(&self) -> Option<&ArrayChunked> {
         self.as_materialized_series().try_array()
@@ -470,6 +502,38 @@ impl Column {
     pub fn decimal(&self) -> PolarsResult<&DecimalChunked> {
         self.as_materialized_series().decimal()
     }
+    #[cfg(feature = "dtype-decimal256")]
+    pub fn decimal256(&self) -> PolarsResult<&Decimal256Chunked> {
+        self.as_materialized_series().decimal256()
+    }
+    #[cfg(feature = "dtype-interval")]
+    pub fn interval(&self) -> PolarsResult<&IntervalChunked> {
+        self.as_materialized_series().interval()
+    }
+    #[cfg(feature = "dtype-f16")]
+    pub fn float16(&self) -> PolarsResult<&Float16Chunked> {
+        self.as_materialized_series().float16()
+    }
+    #[cfg(feature = "dtype-uuid")]
+    pub fn uuid(&self) -> PolarsResult<&UuidChunked> {
+        self.as_materialized_series().uuid()
+    }
+    #[cfg(feature = "dtype-json")]
+    pub fn json(&self) -> PolarsResult<&JsonChunked> {
+        self.as_materialized_series().json()
+    }
+    #[cfg(feature = "dtype-map")]
+    pub fn map(&self) -> PolarsResult<&MapChunked> {
+        self.as_materialized_series().map()
+    }
+    #[cfg(feature = "dtype-union")]
+    pub fn union(&self) -> PolarsResult<&UnionChunked> {
+        self.as_materialized_series().union()
+    }
+    #[cfg(feature = "dtype-extension")]
+    pub fn extension(&self) -> PolarsResult<&ExtensionChunked> {
+        self.as_materialized_series().extension()
+    }
     #[cfg(feature = "dtype-array")]
     pub fn array(&self) -> PolarsResult<&ArrayChunked> {
         self.as_materialized_series().array()
@@ -30,6 +30,24 @@ impl Scalar {
         )
     }
 
+    #[cfg(feature = "dtype-interval")]
+    pub fn new_interval(months: i32, days: i32, nanoseconds: i64) -> Self {
+        Scalar::new(
+            DataType::Interval,
+            AnyValue::Interval(arrow::types::months_days_ns::new(months, days, nanoseconds)),
+        )
+    }
+
+    #[cfg(feature = "dtype-f16")]
+    pub fn new_float16(value: crate::prelude::f16) -> Self {
+        Scalar::new(DataType::Float16, AnyValue::Float16(value))
+    }
+
+    #[cfg(feature = "dtype-uuid")]
+    pub fn new_uuid(value: u128) -> Self {
+        Scalar::new(DataType::Uuid, AnyValue::Uuid(value))
+    }
+
     #[cfg(feature = "dtype-time")]
     pub fn new_time(value: i64) -> Self {
         Scalar::new(DataType::Time, AnyValue::Time(value))
@@ -58,6 +76,14 @@ impl Scalar {
         )
     }
 
+    #[cfg(feature = "dtype-decimal256")]
+    pub fn new_decimal256(value: arrow::types::i256, precision: usize, scale: usize) -> Self {
+        Scalar::new(
+            DataType::Decimal256(precision, scale),
+            AnyValue::Decimal256(value, precision, scale),
+        )
+    }
+
     #[cfg(feature = "dtype-categorical")]
     pub fn new_enum(
         value: polars_dtype::categorical::CatSize,
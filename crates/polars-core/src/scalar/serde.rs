@@ -79,6 +79,12 @@ pub enum SerializableScalar {
     Float32(f32),
     /// A 64-bit floating point number.
     Float64(f64),
+    /// A 16-bit floating point number, stored by its bits.
+    #[cfg(feature = "dtype-f16")]
+    Float16(u16),
+    /// A universally unique identifier.
+    #[cfg(feature = "dtype-uuid")]
+    Uuid(u128),
     /// Nested type, contains arrays that are filled with one of the datatypes.
     List(Series),
     /// A binary true or false.
@@ -116,6 +122,14 @@ pub enum SerializableScalar {
     #[cfg(feature = "dtype-decimal")]
     Decimal(i128, usize, usize),
 
+    /// A 256-bit fixed point decimal number with a scale, stored as its high and low words.
+    #[cfg(feature = "dtype-decimal256")]
+    Decimal256(i128, i128, usize, usize),
+
+    /// A calendar interval of months, days and nanoseconds.
+    #[cfg(feature = "dtype-interval")]
+    Interval(i32, i32, i64),
+
     #[cfg(feature = "dtype-categorical")]
     Categorical {
         value: PlSmallStr,
@@ -151,6 +165,10 @@ impl TryFrom<Scalar> for SerializableScalar {
             AnyValue::UInt128(v) => Self::UInt128(v),
             AnyValue::Float32(v) => Self::Float32(v),
             AnyValue::Float64(v) => Self::Float64(v),
+            #[cfg(feature = "dtype-f16")]
+            AnyValue::Float16(v) => Self::Float16(v.to_bits()),
+            #[cfg(feature = "dtype-uuid")]
+            AnyValue::Uuid(v) => Self::Uuid(v),
             AnyValue::List(series) => Self::List(series),
             AnyValue::Boolean(v) => Self::Boolean(v),
             AnyValue::String(v) => Self::String(PlSmallStr::from(v)),
@@ -258,6 +276,13 @@ impl TryFrom<Scalar> for SerializableScalar {
 
             #[cfg(feature = "dtype-decimal")]
             AnyValue::Decimal(v, prec, scale) => Self::Decimal(v, prec, scale),
+            #[cfg(feature = "dtype-decimal256")]
+            AnyValue::Decimal256(v, prec, scale) => {
+                let (hi, lo) = v.0.into_words();
+                Self::Decimal256(hi, lo, prec, scale)
+            },
+            #[cfg(feature = "dtype-interval")]
+            AnyValue::Interval(v) => Self::Interval(v.months(), v.days(), v.ns()),
         };
         Ok(out)
     }
@@ -282,6 +307,10 @@ impl TryFrom<SerializableScalar> for Scalar {
             S::UInt128(v) => Self::from(v),
             S::Float32(v) => Self::from(v),
             S::Float64(v) => Self::from(v),
+            #[cfg(feature = "dtype-f16")]
+            S::Float16(v) => Self::new_float16(crate::prelude::f16::from_bits(v)),
+            #[cfg(feature = "dtype-uuid")]
+            S::Uuid(v) => Self::new_uuid(v),
             S::List(v) => Self::new_list(v),
             S::Boolean(v) => Self::from(v),
             S::String(v) => Self::from(v),
@@ -298,6 +327,12 @@ impl TryFrom<SerializableScalar> for Scalar {
             S::Array(v, width) => Self::new_array(v, width),
             #[cfg(feature = "dtype-decimal")]
             S::Decimal(v, prec, scale) => Self::new_decimal(v, prec, scale),
+            #[cfg(feature = "dtype-decimal256")]
+            S::Decimal256(hi, lo, prec, scale) => {
+                Self::new_decimal256(arrow::types::i256::from_words(hi, lo), prec, scale)
+            },
+            #[cfg(feature = "dtype-interval")]
+            S::Interval(months, days, ns) => Self::new_interval(months, days, ns),
 
             #[cfg(feature = "dtype-categorical")]
             S::Categorical {
@@ -1,5 +1,7 @@
 use std::fmt::Write;
 
+#[cfg(feature = "dtype-json")]
+use arrow::array::MutableBinaryViewArray;
 use arrow::bitmap::MutableBitmap;
 
 #[cfg(feature = "dtype-categorical")]
@@ -75,6 +77,16 @@ impl Series {
                     }
                     DataType::Decimal(prec, scale)
                 },
+                #[cfg(feature = "dtype-decimal256")]
+                DataType::Decimal256(mut prec, mut scale) => {
+                    for v in values {
+                        if let DataType::Decimal256(p, s) = v.dtype() {
+                            prec = prec.max(p);
+                            scale = scale.max(s);
+                        }
+                    }
+                    DataType::Decimal256(prec, scale)
+                },
                 dt => dt,
             }
         } else {
@@ -134,6 +146,14 @@ impl Series {
             },
             #[cfg(feature = "dtype-duration")]
             DataType::Duration(tu) => any_values_to_duration(values, *tu, strict)?.into_series(),
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => any_values_to_interval(values, strict)?.into_series(),
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => any_values_to_f16(values, strict)?.into_series(),
+            #[cfg(feature = "dtype-uuid")]
+            DataType::Uuid => any_values_to_uuid(values, strict)?.into_series(),
+            #[cfg(feature = "dtype-json")]
+            DataType::Json => any_values_to_json(values, strict)?.into_series(),
             #[cfg(feature = "dtype-categorical")]
             dt @ (DataType::Categorical(_, _) | DataType::Enum(_, _)) => {
                 any_values_to_categorical(values, dt, strict)?
@@ -142,7 +162,27 @@ impl Series {
             DataType::Decimal(precision, scale) => {
                 any_values_to_decimal(values, *precision, *scale, strict)?.into_series()
             },
+            #[cfg(feature = "dtype-decimal256")]
+            DataType::Decimal256(precision, scale) => {
+                any_values_to_decimal256(values, *precision, *scale, strict)?.into_series()
+            },
             DataType::List(inner) => any_values_to_list(values, inner, strict)?.into_series(),
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(_, _) => {
+                let entries = dtype.to_entries_list_type();
+                any_values_to_list(values, entries.inner_dtype().unwrap(), strict)?
+                    .into_map()?
+                    .into_series()
+            },
+            #[cfg(feature = "dtype-union")]
+            DataType::Union(fields, _) => any_values_to_union(values, dtype, fields, strict)?,
+            #[cfg(feature = "dtype-extension")]
+            DataType::Extension {
+                name: ext_name,
+                metadata,
+                storage,
+            } => Series::from_any_values_and_dtype(name.clone(), values, storage, strict)?
+                .into_extension(ext_name.clone(), metadata.as_deref().cloned()),
             #[cfg(feature = "dtype-array")]
             DataType::Array(inner, size) => any_values_to_array(values, inner, strict, *size)?
                 .into_series()
@@ -210,6 +250,8 @@ fn any_values_to_f32(values: &[AnyValue], strict: bool) -> PolarsResult<Float32C
         for av in values {
             match av {
                 AnyValue::Float32(i) => builder.append_value(*i),
+                #[cfg(feature = "dtype-f16")]
+                AnyValue::Float16(i) => builder.append_value(i.to_f32()),
                 AnyValue::Null => builder.append_null(),
                 av => return Err(invalid_value_error(&DataType::Float32, av)),
             }
@@ -230,6 +272,8 @@ fn any_values_to_f64(values: &[AnyValue], strict: bool) -> PolarsResult<Float64C
             match av {
                 AnyValue::Float64(i) => builder.append_value(*i),
                 AnyValue::Float32(i) => builder.append_value(*i as f64),
+                #[cfg(feature = "dtype-f16")]
+                AnyValue::Float16(i) => builder.append_value(i.to_f32() as f64),
                 AnyValue::Null => builder.append_null(),
                 av => return Err(invalid_value_error(&DataType::Float64, av)),
             }
@@ -243,6 +287,73 @@ fn any_values_to_f64(values: &[AnyValue], strict: bool) -> PolarsResult<Float64C
     }
 }
 
+#[cfg(feature = "dtype-f16")]
+fn any_values_to_f16(values: &[AnyValue], strict: bool) -> PolarsResult<Float16Chunked> {
+    let mut builder = PrimitiveChunkedBuilder::<Int16Type>::new(PlSmallStr::EMPTY, values.len());
+    for av in values {
+        match av {
+            AnyValue::Float16(v) => builder.append_value(float16_to_physical(*v)),
+            AnyValue::Null => builder.append_null(),
+            av => {
+                if strict {
+                    return Err(invalid_value_error(&DataType::Float16, av));
+                }
+                match av.extract::<f32>() {
+                    Some(v) => builder.append_value(float16_to_physical(f16::from_f32(v))),
+                    None => builder.append_null(),
+                }
+            },
+        }
+    }
+    Ok(builder.finish().into_float16())
+}
+
+#[cfg(feature = "dtype-uuid")]
+fn any_values_to_uuid(values: &[AnyValue], strict: bool) -> PolarsResult<UuidChunked> {
+    let mut builder = PrimitiveChunkedBuilder::<UInt128Type>::new(PlSmallStr::EMPTY, values.len());
+    for av in values {
+        match av {
+            AnyValue::Uuid(v) => builder.append_value(*v),
+            AnyValue::Null => builder.append_null(),
+            av => {
+                if strict {
+                    return Err(invalid_value_error(&DataType::Uuid, av));
+                }
+                match av.strict_cast(&DataType::Uuid) {
+                    Some(AnyValue::Uuid(v)) => builder.append_value(v),
+                    _ => builder.append_null(),
+                }
+            },
+        }
+    }
+    Ok(builder.finish().into_uuid())
+}
+
+#[cfg(feature = "dtype-json")]
+fn any_values_to_json(values: &[AnyValue], strict: bool) -> PolarsResult<JsonChunked> {
+    let mut buf = Vec::new();
+    let mut builder = MutableBinaryViewArray::<[u8]>::with_capacity(values.len());
+    for av in values {
+        buf.clear();
+        let text = match av {
+            AnyValue::String(v) => Some(v.as_bytes()),
+            AnyValue::StringOwned(v) => Some(v.as_bytes()),
+            AnyValue::Binary(v) => Some(*v),
+            AnyValue::BinaryOwned(v) => Some(v.as_slice()),
+            AnyValue::Null => None,
+            av if strict => return Err(invalid_value_error(&DataType::Json, av)),
+            _ => None,
+        };
+        match text.map(|text| encode_json(text, &mut buf)) {
+            Some(Ok(())) => builder.push_value(&buf),
+            Some(Err(_)) if strict => return Err(invalid_value_error(&DataType::Json, av)),
+            _ => builder.push_null(),
+        }
+    }
+    let arr: BinaryViewArray = builder.into();
+    Ok(BinaryChunked::with_chunk(PlSmallStr::EMPTY, arr).into_json_unchecked())
+}
+
 fn any_values_to_bool(values: &[AnyValue], strict: bool) -> PolarsResult<BooleanChunked> {
     let mut builder = BooleanChunkedBuilder::new(PlSmallStr::EMPTY, values.len());
     for av in values {
@@ -455,6 +566,24 @@ fn any_values_to_duration(
     Ok(builder.finish().into_duration(time_unit))
 }
 
+#[cfg(feature = "dtype-interval")]
+fn any_values_to_interval(values: &[AnyValue], strict: bool) -> PolarsResult<IntervalChunked> {
+    let mut builder = PrimitiveChunkedBuilder::<Int128Type>::new(PlSmallStr::EMPTY, values.len());
+    for av in values {
+        match av {
+            AnyValue::Interval(v) => builder.append_value(interval_to_physical(*v)),
+            AnyValue::Null => builder.append_null(),
+            av => {
+                if strict {
+                    return Err(invalid_value_error(&DataType::Interval, av));
+                }
+                builder.append_null()
+            },
+        }
+    }
+    Ok(builder.finish().into_interval())
+}
+
 #[cfg(feature = "dtype-categorical")]
 fn any_values_to_categorical(
     values: &[AnyValue],
@@ -549,6 +678,46 @@ fn any_values_to_decimal(
     builder.finish().into_decimal(precision, scale)
 }
 
+#[cfg(feature = "dtype-decimal256")]
+fn any_values_to_decimal256(
+    values: &[AnyValue],
+    precision: usize,
+    scale: usize,
+    strict: bool,
+) -> PolarsResult<Decimal256Chunked> {
+    let target_dtype = DataType::Decimal256(precision, scale);
+
+    let mut out = Vec::with_capacity(values.len());
+    for av in values {
+        let v = match av {
+            // Allow equal or less scale, as for the 128-bit decimal.
+            AnyValue::Decimal256(v, p, s) if *s <= scale => {
+                if *p <= precision && *s == scale {
+                    Some(*v)
+                } else {
+                    match av.strict_cast(&target_dtype) {
+                        Some(AnyValue::Decimal256(i, _, _)) => Some(i),
+                        _ => None,
+                    }
+                }
+            },
+            AnyValue::Null => None,
+            av => {
+                if strict {
+                    return Err(invalid_value_error(&target_dtype, av));
+                }
+                match av.strict_cast(&target_dtype) {
+                    Some(AnyValue::Decimal256(i, _, _)) => Some(i),
+                    _ => None,
+                }
+            },
+        };
+        out.push(v);
+    }
+
+    Decimal256Chunked::from_i256_iter(PlSmallStr::EMPTY, out, precision, scale)
+}
+
 fn any_values_to_list(
     avs: &[AnyValue],
     inner_type: &DataType,
@@ -819,6 +988,54 @@ fn any_values_to_struct(
     Ok(out.into_series())
 }
 
+/// Every value goes into the first variant of its type, or else the first one it can be cast to.
+#[cfg(feature = "dtype-union")]
+fn any_values_to_union(
+    values: &[AnyValue],
+    dtype: &DataType,
+    fields: &[Field],
+    strict: bool,
+) -> PolarsResult<Series> {
+    let DataType::Union(_, mode) = dtype else {
+        unreachable!()
+    };
+    let mut variant_avs = vec![Vec::with_capacity(values.len()); fields.len()];
+    for av in values {
+        let variant = if av.is_null() {
+            None
+        } else {
+            let av_dtype = av.dtype();
+            fields
+                .iter()
+                .position(|fld| fld.dtype() == &av_dtype)
+                .map(|i| (i, av.clone()))
+                .or_else(|| {
+                    fields.iter().enumerate().find_map(|(i, fld)| {
+                        av.strict_cast(fld.dtype()).map(|av| (i, av.into_static()))
+                    })
+                })
+        };
+        if variant.is_none() && strict && !av.is_null() {
+            return Err(invalid_value_error(dtype, av));
+        }
+        for (i, avs) in variant_avs.iter_mut().enumerate() {
+            avs.push(match &variant {
+                Some((variant, av)) if *variant == i => av.clone(),
+                _ => AnyValue::Null,
+            });
+        }
+    }
+    let variants = variant_avs
+        .iter()
+        .zip(fields)
+        .map(|(avs, fld)| {
+            Series::from_any_values_and_dtype(fld.name().clone(), avs, fld.dtype(), strict)
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let ca = StructChunked::from_series(PlSmallStr::EMPTY, values.len(), variants.iter())?;
+    Ok(ca.into_union(*mode)?.into_series())
+}
+
 #[cfg(feature = "object")]
 fn any_values_to_object(values: &[AnyValue]) -> PolarsResult<Series> {
     use crate::chunked_array::object::registry;
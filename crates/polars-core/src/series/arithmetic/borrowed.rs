@@ -350,17 +350,17 @@ pub fn coerce_lhs_rhs<'a>(
         new_right_dtype = new_left_dtype.clone();
     }
 
-    let left = if lhs.dtype() == &new_left_dtype {
-        Cow::Borrowed(lhs)
-    } else {
-        Cow::Owned(lhs.cast(&new_left_dtype)?)
+    let cast = |s: &'a Series, dtype: &DataType| -> PolarsResult<Cow<'a, Series>> {
+        Ok(if s.dtype() == dtype {
+            Cow::Borrowed(s)
+        } else if dtype.is_decimal256() {
+            // A Decimal256 that doesn't fit the common scale overflows, rather than becoming null.
+            Cow::Owned(s.strict_cast(dtype)?)
+        } else {
+            Cow::Owned(s.cast(dtype)?)
+        })
     };
-    let right = if rhs.dtype() == &new_right_dtype {
-        Cow::Borrowed(rhs)
-    } else {
-        Cow::Owned(rhs.cast(&new_right_dtype)?)
-    };
-    Ok((left, right))
+    Ok((cast(lhs, &new_left_dtype)?, cast(rhs, &new_right_dtype)?))
 }
 
 // Handle (Date | Datetime) +/- (Duration) | (Duration) +/- (Date | Datetime) | (Duration) +-
@@ -635,8 +635,21 @@ impl Rem for &Series {
 
 // Series +-/* numbers instead of Series
 
+/// The representation that scalar arithmetic is applied on.
+///
+/// Float16 is computed in single precision (and rounded back in [`finish_cast`]).
+fn scalar_op_repr(s: &Series) -> Cow<'_, Series> {
+    match s.dtype() {
+        #[cfg(feature = "dtype-f16")]
+        DataType::Float16 => Cow::Owned(s.float16().unwrap().to_float32().into_series()),
+        _ => s.to_physical_repr(),
+    }
+}
+
 fn finish_cast(inp: &Series, out: Series) -> Series {
     match inp.dtype() {
+        #[cfg(feature = "dtype-f16")]
+        DataType::Float16 => out.f32().unwrap().to_float16().into_series(),
         #[cfg(feature = "dtype-date")]
         DataType::Date => out.into_date(),
         #[cfg(feature = "dtype-datetime")]
@@ -656,7 +669,7 @@ where
     type Output = Series;
 
     fn sub(self, rhs: T) -> Self::Output {
-        let s = self.to_physical_repr();
+        let s = scalar_op_repr(self);
         macro_rules! sub {
             ($ca:expr) => {{ $ca.sub(rhs).into_series() }};
         }
@@ -684,7 +697,7 @@ where
     type Output = Series;
 
     fn add(self, rhs: T) -> Self::Output {
-        let s = self.to_physical_repr();
+        let s = scalar_op_repr(self);
         macro_rules! add {
             ($ca:expr) => {{ $ca.add(rhs).into_series() }};
         }
@@ -711,7 +724,7 @@ where
     type Output = Series;
 
     fn div(self, rhs: T) -> Self::Output {
-        let s = self.to_physical_repr();
+        let s = scalar_op_repr(self);
         macro_rules! div {
             ($ca:expr) => {{ $ca.div(rhs).into_series() }};
         }
@@ -735,7 +748,7 @@ where
 // TODO: remove this, temporary band-aid.
 impl Series {
     pub fn wrapping_trunc_div_scalar<T: Num + NumCast>(&self, rhs: T) -> Self {
-        let s = self.to_physical_repr();
+        let s = scalar_op_repr(self);
         macro_rules! div {
             ($ca:expr) => {{
                 let rhs = NumCast::from(rhs).unwrap();
@@ -755,7 +768,7 @@ where
     type Output = Series;
 
     fn mul(self, rhs: T) -> Self::Output {
-        let s = self.to_physical_repr();
+        let s = scalar_op_repr(self);
         macro_rules! mul {
             ($ca:expr) => {{ $ca.mul(rhs).into_series() }};
         }
@@ -782,7 +795,7 @@ where
     type Output = Series;
 
     fn rem(self, rhs: T) -> Self::Output {
-        let s = self.to_physical_repr();
+        let s = scalar_op_repr(self);
         macro_rules! rem {
             ($ca:expr) => {{ $ca.rem(rhs).into_series() }};
         }
@@ -849,7 +862,7 @@ where
         rhs + self
     }
     fn sub(self, rhs: &Series) -> Self::Output {
-        let s = rhs.to_physical_repr();
+        let s = scalar_op_repr(rhs);
         macro_rules! sub {
             ($rhs:expr) => {{ $rhs.lhs_sub(self).into_series() }};
         }
@@ -858,7 +871,7 @@ where
         finish_cast(rhs, out)
     }
     fn div(self, rhs: &Series) -> Self::Output {
-        let s = rhs.to_physical_repr();
+        let s = scalar_op_repr(rhs);
         macro_rules! div {
             ($rhs:expr) => {{ $rhs.lhs_div(self).into_series() }};
         }
@@ -871,7 +884,7 @@ where
         rhs * self
     }
     fn rem(self, rhs: &Series) -> Self::Output {
-        let s = rhs.to_physical_repr();
+        let s = scalar_op_repr(rhs);
         macro_rules! rem {
             ($rhs:expr) => {{ $rhs.lhs_rem(self).into_series() }};
         }
@@ -7,11 +7,13 @@ use arrow::offset::OffsetsBuffer;
     feature = "dtype-duration"
 ))]
 use arrow::temporal_conversions::*;
+#[cfg(not(feature = "dtype-interval"))]
 use arrow::types::months_days_ns;
 use polars_compute::cast::cast_unchecked as cast;
-#[cfg(feature = "dtype-decimal")]
+#[cfg(all(feature = "dtype-decimal", not(feature = "dtype-decimal256")))]
 use polars_compute::decimal::dec128_fits;
 use polars_error::feature_gated;
+#[cfg(not(feature = "dtype-interval"))]
 use polars_utils::check_allow_importing_interval_as_struct;
 use polars_utils::itertools::Itertools;
 
@@ -96,6 +98,26 @@ impl Series {
             Decimal(precision, scale) => Int128Chunked::from_chunks(name, chunks)
                 .into_decimal_unchecked(*precision, *scale)
                 .into_series(),
+            #[cfg(feature = "dtype-decimal256")]
+            Decimal256(precision, scale) => BinaryChunked::from_chunks(name, chunks)
+                .into_decimal256_unchecked(*precision, *scale)
+                .into_series(),
+            #[cfg(feature = "dtype-interval")]
+            Interval => Int128Chunked::from_chunks(name, chunks)
+                .into_interval()
+                .into_series(),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Int16Chunked::from_chunks(name, chunks)
+                .into_float16()
+                .into_series(),
+            #[cfg(feature = "dtype-uuid")]
+            Uuid => UInt128Chunked::from_chunks(name, chunks)
+                .into_uuid()
+                .into_series(),
+            #[cfg(feature = "dtype-json")]
+            Json => BinaryChunked::from_chunks(name, chunks)
+                .into_json_unchecked()
+                .into_series(),
             #[cfg(feature = "dtype-array")]
             Array(_, _) => {
                 ArrayChunked::from_chunks_and_dtype_unchecked(name, chunks, dtype.clone())
@@ -103,6 +125,25 @@ impl Series {
             },
             List(_) => ListChunked::from_chunks_and_dtype_unchecked(name, chunks, dtype.clone())
                 .into_series(),
+            #[cfg(feature = "dtype-map")]
+            Map(_, _) => MapChunked::from_chunks_and_dtype_unchecked(name, chunks, dtype.clone())
+                .into_series(),
+            #[cfg(feature = "dtype-union")]
+            Union(fields, _) => {
+                let ca = StructChunked::from_chunks_and_dtype_unchecked(
+                    name,
+                    chunks,
+                    Struct(fields.clone()),
+                );
+                UnionChunked::new_logical(ca, dtype.clone()).into_series()
+            },
+            #[cfg(feature = "dtype-extension")]
+            Extension {
+                name: ext_name,
+                metadata,
+                storage,
+            } => Series::from_chunks_and_dtype_unchecked(name, chunks, storage)
+                .into_extension(ext_name.clone(), metadata.as_deref().cloned()),
             String => StringChunked::from_chunks(name, chunks).into_series(),
             Binary => BinaryChunked::from_chunks(name, chunks).into_series(),
             #[cfg(feature = "dtype-categorical")]
@@ -233,6 +274,20 @@ impl Series {
                 "dtype-i128",
                 Ok(Int128Chunked::from_chunks(name, chunks).into_series())
             ),
+            #[cfg(feature = "dtype-f16")]
+            ArrowDataType::Float16 => {
+                let chunks = chunks
+                    .iter()
+                    .map(|arr| {
+                        let arr = arr.as_any().downcast_ref().unwrap();
+                        float16_array_from_arrow(arr).to_boxed()
+                    })
+                    .collect();
+                Ok(Int16Chunked::from_chunks(name, chunks)
+                    .into_float16()
+                    .into_series())
+            },
+            #[cfg(not(feature = "dtype-f16"))]
             ArrowDataType::Float16 => {
                 let chunks =
                     cast_chunks(&chunks, &DataType::Float32, CastOptions::NonStrict).unwrap();
@@ -379,6 +434,22 @@ impl Series {
                     Ok(s)
                 })
             },
+            #[cfg(feature = "dtype-decimal256")]
+            ArrowDataType::Decimal256(precision, scale) => {
+                polars_compute::decimal::dec256_verify_prec_scale(*precision, *scale)?;
+
+                let chunks = chunks
+                    .iter()
+                    .map(|arr| {
+                        let arr = arr.as_any().downcast_ref().unwrap();
+                        decimal256_array_from_arrow(arr).to_boxed()
+                    })
+                    .collect();
+                Ok(BinaryChunked::from_chunks(name, chunks)
+                    .into_decimal256_unchecked(*precision, *scale)
+                    .into_series())
+            },
+            #[cfg(not(feature = "dtype-decimal256"))]
             ArrowDataType::Decimal256(precision, scale) => {
                 feature_gated!("dtype-decimal", {
                     use arrow::types::i256;
@@ -456,6 +527,55 @@ impl Series {
                 };
                 Ok(s)
             },
+            #[cfg(feature = "dtype-uuid")]
+            ArrowDataType::Extension(ext) if is_uuid_extension(ext) => {
+                let chunks = chunks
+                    .iter()
+                    .map(|arr| {
+                        let arr = arr.as_any().downcast_ref().unwrap();
+                        Ok(uuid_array_from_arrow(arr)?.to_boxed())
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                Ok(UInt128Chunked::from_chunks(name, chunks)
+                    .into_uuid()
+                    .into_series())
+            },
+            #[cfg(feature = "dtype-json")]
+            ArrowDataType::Extension(ext) if is_json_extension(ext) => {
+                let chunks = chunks
+                    .iter()
+                    .map(|arr| arrow::array::with_dtype(arr.as_ref(), ext.inner.clone()))
+                    .collect();
+                let text = Series::_try_from_arrow_unchecked_with_md(name, chunks, &ext.inner, md)?;
+                let json = text.cast(&DataType::Json)?;
+                polars_ensure!(
+                    json.null_count() == text.null_count(),
+                    ComputeError: "column '{}' of type {} contains invalid JSON",
+                    json.name(), JSON_EXTENSION_NAME
+                );
+                Ok(json)
+            },
+            #[cfg(feature = "dtype-extension")]
+            ArrowDataType::Extension(ext) if ext.name != EXTENSION_NAME => {
+                let chunks = chunks
+                    .iter()
+                    .map(|arr| arrow::array::with_dtype(arr.as_ref(), ext.inner.clone()))
+                    .collect();
+                let storage =
+                    Series::_try_from_arrow_unchecked_with_md(name, chunks, &ext.inner, md)?;
+                Ok(storage.into_extension(ext.name.clone(), ext.metadata.clone()))
+            },
+            // Without extension support we load the storage, e.g. JSON as plain strings.
+            #[cfg(not(feature = "dtype-extension"))]
+            ArrowDataType::Extension(ext) if ext.name != EXTENSION_NAME => {
+                let chunks = chunks
+                    .iter()
+                    .map(|arr| arrow::array::with_dtype(arr.as_ref(), ext.inner.clone()))
+                    .collect();
+                Series::_try_from_arrow_unchecked_with_md(name, chunks, &ext.inner, md)
+            },
+            #[cfg(feature = "dtype-union")]
+            ArrowDataType::Union(union) => union_arrays_to_series(name, &chunks, union),
             #[cfg(feature = "dtype-struct")]
             ArrowDataType::Struct(_) => {
                 let (chunks, dtype) = to_physical_and_dtype(chunks, md);
@@ -490,7 +610,7 @@ impl Series {
                         let arr = arr.as_any().downcast_ref::<MapArray>().unwrap();
                         let offsets: &OffsetsBuffer<i32> = arr.offsets();
 
-                        let validity = values.validity().cloned();
+                        let validity = arr.validity().cloned();
 
                         Box::from(ListArray::<i64>::new(
                             ListArray::<i64>::default_datatype(values.dtype().clone()),
@@ -501,16 +621,36 @@ impl Series {
                     })
                     .collect();
 
-                unsafe {
-                    let out = ListChunked::from_chunks_and_dtype_unchecked(
+                let out = unsafe {
+                    ListChunked::from_chunks_and_dtype_unchecked(
                         name,
                         chunks,
                         DataType::List(Box::new(dtype)),
-                    );
+                    )
+                };
 
-                    Ok(out.into_series())
+                // Maps with anything but `{key, value}` entries stay lists, matching
+                // `DataType::from_arrow_dtype`.
+                #[cfg(feature = "dtype-map")]
+                if matches!(out.inner_dtype(), DataType::Struct(fields) if fields.len() == 2) {
+                    return Ok(out.into_map()?.into_series());
                 }
+                Ok(out.into_series())
             },
+            #[cfg(feature = "dtype-interval")]
+            ArrowDataType::Interval(IntervalUnit::MonthDayNano) => {
+                let chunks = chunks
+                    .iter()
+                    .map(|arr| {
+                        let arr = arr.as_any().downcast_ref().unwrap();
+                        interval_array_from_arrow(arr).to_boxed()
+                    })
+                    .collect();
+                Ok(Int128Chunked::from_chunks(name, chunks)
+                    .into_interval()
+                    .into_series())
+            },
+            #[cfg(not(feature = "dtype-interval"))]
             ArrowDataType::Interval(IntervalUnit::MonthDayNano) => {
                 check_allow_importing_interval_as_struct("month_day_nano_interval")?;
 
@@ -693,6 +833,49 @@ unsafe fn to_physical_and_dtype(
             let dtype = s.dtype().clone();
             (std::mem::take(s.chunks_mut()), dtype)
         },
+        #[cfg(feature = "dtype-f16")]
+        dt @ ArrowDataType::Float16 => {
+            let dt = dt.clone();
+            let mut s = Series::_try_from_arrow_unchecked(PlSmallStr::EMPTY, arrays, &dt).unwrap();
+            let dtype = s.dtype().clone();
+            (std::mem::take(s.chunks_mut()), dtype)
+        },
+        #[cfg(feature = "dtype-decimal256")]
+        dt @ ArrowDataType::Decimal256(_, _) => {
+            let dt = dt.clone();
+            let mut s = Series::_try_from_arrow_unchecked(PlSmallStr::EMPTY, arrays, &dt).unwrap();
+            let dtype = s.dtype().clone();
+            (std::mem::take(s.chunks_mut()), dtype)
+        },
+        #[cfg(feature = "dtype-interval")]
+        dt @ ArrowDataType::Interval(IntervalUnit::MonthDayNano) => {
+            let dt = dt.clone();
+            let mut s = Series::_try_from_arrow_unchecked(PlSmallStr::EMPTY, arrays, &dt).unwrap();
+            let dtype = s.dtype().clone();
+            (std::mem::take(s.chunks_mut()), dtype)
+        },
+        #[cfg(feature = "dtype-uuid")]
+        dt @ ArrowDataType::Extension(ext) if is_uuid_extension(ext) => {
+            let dt = dt.clone();
+            let mut s = Series::_try_from_arrow_unchecked(PlSmallStr::EMPTY, arrays, &dt).unwrap();
+            let dtype = s.dtype().clone();
+            (std::mem::take(s.chunks_mut()), dtype)
+        },
+        dt @ ArrowDataType::Extension(ext) if ext.name != EXTENSION_NAME => {
+            let dt = dt.clone();
+            let mut s =
+                Series::_try_from_arrow_unchecked_with_md(PlSmallStr::EMPTY, arrays, &dt, md)
+                    .unwrap();
+            let dtype = s.dtype().clone();
+            (std::mem::take(s.chunks_mut()), dtype)
+        },
+        #[cfg(feature = "dtype-union")]
+        dt @ ArrowDataType::Union(_) => {
+            let dt = dt.clone();
+            let mut s = Series::_try_from_arrow_unchecked(PlSmallStr::EMPTY, arrays, &dt).unwrap();
+            let dtype = s.dtype().clone();
+            (std::mem::take(s.chunks_mut()), dtype)
+        },
         dt => {
             let dtype = DataType::from_arrow(dt, md);
             (arrays, dtype)
@@ -789,7 +972,7 @@ unsafe fn import_arrow_dictionary_array(
     }
 }
 
-#[cfg(feature = "dtype-struct")]
+#[cfg(all(feature = "dtype-struct", not(feature = "dtype-interval")))]
 fn convert_month_day_nano_to_struct(chunk: Box<dyn Array>) -> PolarsResult<Box<dyn Array>> {
     let arr: &PrimitiveArray<months_days_ns> = chunk.as_any().downcast_ref().unwrap();
 
@@ -876,7 +1059,13 @@ impl TryFrom<(&ArrowField, Vec<ArrayRef>)> for Series {
     fn try_from(field_arr: (&ArrowField, Vec<ArrayRef>)) -> PolarsResult<Self> {
         let (field, chunks) = field_arr;
 
-        let dtype = check_types(&chunks)?;
+        let mut dtype = check_types(&chunks)?;
+        // View arrays always report their storage type, so take extension types from the field.
+        if let ArrowDataType::Extension(ext) = field.dtype() {
+            if ext.inner == dtype {
+                dtype = field.dtype().clone();
+            }
+        }
 
         // SAFETY:
         // dtype is checked
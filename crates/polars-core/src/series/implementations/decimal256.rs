@@ -0,0 +1,479 @@
+use arrow::types::i256;
+use polars_compute::decimal::{dec256_fits, dec256_from_ordered_bytes, dec256_to_ordered_bytes};
+use polars_compute::rolling::QuantileMethod;
+use rayon::prelude::*;
+
+use super::*;
+use crate::prelude::*;
+
+unsafe impl IntoSeries for Decimal256Chunked {
+    fn into_series(self) -> Series {
+        Series(Arc::new(SeriesWrap(self)))
+    }
+}
+
+impl private::PrivateSeriesNumeric for SeriesWrap<Decimal256Chunked> {
+    fn bit_repr(&self) -> Option<BitRepr> {
+        None
+    }
+}
+
+/// Sums the (non-null) values, returning None if the sum doesn't fit in the precision.
+fn sum_i256<'a>(values: impl Iterator<Item = Option<&'a [u8]>>, precision: usize) -> Option<i256> {
+    let mut sum = i256::default();
+    for v in values.flatten() {
+        sum = i256(sum.0.checked_add(dec256_from_ordered_bytes(v).0)?);
+    }
+    dec256_fits(sum, precision).then_some(sum)
+}
+
+impl SeriesWrap<Decimal256Chunked> {
+    fn apply_physical_to_s<F: Fn(&BinaryChunked) -> BinaryChunked>(&self, f: F) -> Series {
+        f(self.0.physical())
+            .into_decimal256_unchecked(self.0.precision(), self.0.scale())
+            .into_series()
+    }
+
+    fn to_float64_series(&self) -> Series {
+        self.0.to_float64().into_series()
+    }
+
+    fn decimal256_scalar(&self, v: Option<i256>) -> Scalar {
+        let av = v.map_or(AnyValue::Null, |v| {
+            AnyValue::Decimal256(v, self.0.precision(), self.0.scale())
+        });
+        Scalar::new(self.dtype().clone(), av)
+    }
+
+    fn binary_scalar_to_decimal256(&self, scalar: Scalar) -> Scalar {
+        let v = match scalar.value() {
+            AnyValue::Binary(v) => Some(dec256_from_ordered_bytes(v)),
+            AnyValue::BinaryOwned(v) => Some(dec256_from_ordered_bytes(v)),
+            _ => None,
+        };
+        self.decimal256_scalar(v)
+    }
+
+    fn with_physical(&self, s: Series) -> Series {
+        s.binary()
+            .unwrap()
+            .clone()
+            .into_decimal256_unchecked(self.0.precision(), self.0.scale())
+            .into_series()
+    }
+}
+
+impl private::PrivateSeries for SeriesWrap<Decimal256Chunked> {
+    fn compute_len(&mut self) {
+        self.0.physical_mut().compute_len()
+    }
+
+    fn _field(&self) -> Cow<'_, Field> {
+        Cow::Owned(self.0.field())
+    }
+
+    fn _dtype(&self) -> &DataType {
+        self.0.dtype()
+    }
+    fn _get_flags(&self) -> StatisticsFlags {
+        self.0.physical().get_flags()
+    }
+    fn _set_flags(&mut self, flags: StatisticsFlags) {
+        self.0.physical_mut().set_flags(flags)
+    }
+
+    #[cfg(feature = "zip_with")]
+    fn zip_with_same_type(&self, mask: &BooleanChunked, other: &Series) -> PolarsResult<Series> {
+        let other = other.decimal256()?;
+        Ok(self
+            .0
+            .physical()
+            .zip_with(mask, other.physical())?
+            .into_decimal256_unchecked(self.0.precision(), self.0.scale())
+            .into_series())
+    }
+    fn into_total_eq_inner<'a>(&'a self) -> Box<dyn TotalEqInner + 'a> {
+        self.0.physical().into_total_eq_inner()
+    }
+    fn into_total_ord_inner<'a>(&'a self) -> Box<dyn TotalOrdInner + 'a> {
+        self.0.physical().into_total_ord_inner()
+    }
+
+    fn vec_hash(
+        &self,
+        random_state: PlSeedableRandomStateQuality,
+        buf: &mut Vec<u64>,
+    ) -> PolarsResult<()> {
+        self.0.physical().vec_hash(random_state, buf)?;
+        Ok(())
+    }
+
+    fn vec_hash_combine(
+        &self,
+        build_hasher: PlSeedableRandomStateQuality,
+        hashes: &mut [u64],
+    ) -> PolarsResult<()> {
+        self.0.physical().vec_hash_combine(build_hasher, hashes)?;
+        Ok(())
+    }
+
+    // The physical values order as the decimals do, so min and max are exact.
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_min(&self, groups: &GroupsType) -> Series {
+        self.with_physical(self.0.physical().agg_min(groups))
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_max(&self, groups: &GroupsType) -> Series {
+        self.with_physical(self.0.physical().agg_max(groups))
+    }
+
+    // Sums of groups that overflow the precision are null.
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_sum(&self, groups: &GroupsType) -> Series {
+        let precision = self.0.precision();
+        let ca = self.0.physical().rechunk();
+        let arr = ca.downcast_as_array();
+        let phys: BinaryChunked = match groups {
+            GroupsType::Idx(groups) => POOL.install(|| {
+                groups
+                    .into_par_iter()
+                    .map(|(_, idx)| {
+                        let values = idx.iter().map(|i| arr.get_unchecked(*i as usize));
+                        sum_i256(values, precision).map(dec256_to_ordered_bytes)
+                    })
+                    .collect()
+            }),
+            GroupsType::Slice { groups, .. } => POOL.install(|| {
+                groups
+                    .par_iter()
+                    .map(|[first, len]| {
+                        let values = (*first..*first + *len).map(|i| arr.get_unchecked(i as usize));
+                        sum_i256(values, precision).map(dec256_to_ordered_bytes)
+                    })
+                    .collect()
+            }),
+        };
+        phys.with_name(self.name().clone())
+            .into_decimal256_unchecked(precision, self.0.scale())
+            .into_series()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_std(&self, groups: &GroupsType, ddof: u8) -> Series {
+        self.to_float64_series().agg_std(groups, ddof)
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_var(&self, groups: &GroupsType, ddof: u8) -> Series {
+        self.to_float64_series().agg_var(groups, ddof)
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_list(&self, groups: &GroupsType) -> Series {
+        let out = self.0.physical().agg_list(groups);
+        let ca = out.list().unwrap();
+        ListChunked::from_chunks_and_dtype_unchecked(
+            ca.name().clone(),
+            ca.chunks().clone(),
+            DataType::List(Box::new(self.dtype().clone())),
+        )
+        .into_series()
+    }
+
+    fn subtract(&self, rhs: &Series) -> PolarsResult<Series> {
+        let rhs = rhs.decimal256()?;
+        ((&self.0) - rhs).map(|ca| ca.into_series())
+    }
+    fn add_to(&self, rhs: &Series) -> PolarsResult<Series> {
+        let rhs = rhs.decimal256()?;
+        ((&self.0) + rhs).map(|ca| ca.into_series())
+    }
+    fn multiply(&self, rhs: &Series) -> PolarsResult<Series> {
+        let rhs = rhs.decimal256()?;
+        ((&self.0) * rhs).map(|ca| ca.into_series())
+    }
+    fn divide(&self, rhs: &Series) -> PolarsResult<Series> {
+        let rhs = rhs.decimal256()?;
+        ((&self.0) / rhs).map(|ca| ca.into_series())
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn group_tuples(&self, multithreaded: bool, sorted: bool) -> PolarsResult<GroupsType> {
+        self.0.physical().group_tuples(multithreaded, sorted)
+    }
+
+    fn arg_sort_multiple(
+        &self,
+        by: &[Column],
+        options: &SortMultipleOptions,
+    ) -> PolarsResult<IdxCa> {
+        self.0.physical().arg_sort_multiple(by, options)
+    }
+}
+
+impl SeriesTrait for SeriesWrap<Decimal256Chunked> {
+    fn rename(&mut self, name: PlSmallStr) {
+        self.0.rename(name)
+    }
+
+    fn chunk_lengths(&self) -> ChunkLenIter<'_> {
+        self.0.physical().chunk_lengths()
+    }
+
+    fn name(&self) -> &PlSmallStr {
+        self.0.name()
+    }
+
+    fn chunks(&self) -> &Vec<ArrayRef> {
+        self.0.physical().chunks()
+    }
+    unsafe fn chunks_mut(&mut self) -> &mut Vec<ArrayRef> {
+        self.0.physical_mut().chunks_mut()
+    }
+
+    fn slice(&self, offset: i64, length: usize) -> Series {
+        self.apply_physical_to_s(|ca| ca.slice(offset, length))
+    }
+
+    fn split_at(&self, offset: i64) -> (Series, Series) {
+        let (a, b) = self.0.split_at(offset);
+        (a.into_series(), b.into_series())
+    }
+
+    fn append(&mut self, other: &Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), append);
+        let mut other = other.to_physical_repr().into_owned();
+        self.0
+            .physical_mut()
+            .append_owned(std::mem::take(other._get_inner_mut().as_mut()))
+    }
+    fn append_owned(&mut self, mut other: Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), append);
+        self.0.physical_mut().append_owned(std::mem::take(
+            &mut other
+                ._get_inner_mut()
+                .as_any_mut()
+                .downcast_mut::<Decimal256Chunked>()
+                .unwrap()
+                .phys,
+        ))
+    }
+
+    fn extend(&mut self, other: &Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), extend);
+        let other = other.to_physical_repr();
+        self.0
+            .physical_mut()
+            .extend(other.as_ref().as_ref().as_ref())?;
+        Ok(())
+    }
+
+    fn filter(&self, filter: &BooleanChunked) -> PolarsResult<Series> {
+        Ok(self
+            .0
+            .physical()
+            .filter(filter)?
+            .into_decimal256_unchecked(self.0.precision(), self.0.scale())
+            .into_series())
+    }
+
+    fn take(&self, indices: &IdxCa) -> PolarsResult<Series> {
+        Ok(self
+            .0
+            .physical()
+            .take(indices)?
+            .into_decimal256_unchecked(self.0.precision(), self.0.scale())
+            .into_series())
+    }
+
+    unsafe fn take_unchecked(&self, indices: &IdxCa) -> Series {
+        self.0
+            .physical()
+            .take_unchecked(indices)
+            .into_decimal256_unchecked(self.0.precision(), self.0.scale())
+            .into_series()
+    }
+
+    fn take_slice(&self, indices: &[IdxSize]) -> PolarsResult<Series> {
+        Ok(self
+            .0
+            .physical()
+            .take(indices)?
+            .into_decimal256_unchecked(self.0.precision(), self.0.scale())
+            .into_series())
+    }
+
+    unsafe fn take_slice_unchecked(&self, indices: &[IdxSize]) -> Series {
+        self.0
+            .physical()
+            .take_unchecked(indices)
+            .into_decimal256_unchecked(self.0.precision(), self.0.scale())
+            .into_series()
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn rechunk(&self) -> Series {
+        let ca = self.0.physical().rechunk().into_owned();
+        ca.into_decimal256_unchecked(self.0.precision(), self.0.scale())
+            .into_series()
+    }
+
+    fn new_from_index(&self, index: usize, length: usize) -> Series {
+        self.0
+            .physical()
+            .new_from_index(index, length)
+            .into_decimal256_unchecked(self.0.precision(), self.0.scale())
+            .into_series()
+    }
+
+    fn cast(&self, dtype: &DataType, cast_options: CastOptions) -> PolarsResult<Series> {
+        self.0.cast_with_options(dtype, cast_options)
+    }
+
+    #[inline]
+    unsafe fn get_unchecked(&self, index: usize) -> AnyValue<'_> {
+        self.0.get_any_value_unchecked(index)
+    }
+
+    fn sort_with(&self, options: SortOptions) -> PolarsResult<Series> {
+        Ok(self
+            .0
+            .physical()
+            .sort_with(options)
+            .into_decimal256_unchecked(self.0.precision(), self.0.scale())
+            .into_series())
+    }
+
+    fn arg_sort(&self, options: SortOptions) -> IdxCa {
+        self.0.physical().arg_sort(options)
+    }
+
+    fn null_count(&self) -> usize {
+        self.0.null_count()
+    }
+
+    fn has_nulls(&self) -> bool {
+        self.0.has_nulls()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn unique(&self) -> PolarsResult<Series> {
+        Ok(self.apply_physical_to_s(|ca| ca.unique().unwrap()))
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn n_unique(&self) -> PolarsResult<usize> {
+        self.0.physical().n_unique()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn arg_unique(&self) -> PolarsResult<IdxCa> {
+        self.0.physical().arg_unique()
+    }
+
+    fn is_null(&self) -> BooleanChunked {
+        self.0.is_null()
+    }
+
+    fn is_not_null(&self) -> BooleanChunked {
+        self.0.is_not_null()
+    }
+
+    fn reverse(&self) -> Series {
+        self.apply_physical_to_s(|ca| ca.reverse())
+    }
+
+    fn shift(&self, periods: i64) -> Series {
+        self.apply_physical_to_s(|ca| ca.shift(periods))
+    }
+
+    #[cfg(feature = "approx_unique")]
+    fn approx_n_unique(&self) -> PolarsResult<IdxSize> {
+        Ok(ChunkApproxNUnique::approx_n_unique(self.0.physical()))
+    }
+
+    fn clone_inner(&self) -> Arc<dyn SeriesTrait> {
+        Arc::new(SeriesWrap(Clone::clone(&self.0)))
+    }
+
+    fn sum_reduce(&self) -> PolarsResult<Scalar> {
+        let sum = sum_i256(self.0.physical().iter(), self.0.precision());
+        polars_ensure!(
+            sum.is_some(),
+            ComputeError: "overflow in sum of {}", self.dtype()
+        );
+        Ok(self.decimal256_scalar(sum))
+    }
+
+    fn min_reduce(&self) -> PolarsResult<Scalar> {
+        Ok(self.binary_scalar_to_decimal256(self.0.physical().min_reduce()))
+    }
+
+    fn max_reduce(&self) -> PolarsResult<Scalar> {
+        Ok(self.binary_scalar_to_decimal256(self.0.physical().max_reduce()))
+    }
+
+    fn _sum_as_f64(&self) -> f64 {
+        self.to_float64_series()._sum_as_f64()
+    }
+
+    fn mean(&self) -> Option<f64> {
+        self.to_float64_series().mean()
+    }
+
+    fn mean_reduce(&self) -> PolarsResult<Scalar> {
+        Ok(Scalar::new(DataType::Float64, self.mean().into()))
+    }
+
+    fn median(&self) -> Option<f64> {
+        self.to_float64_series().median()
+    }
+
+    fn median_reduce(&self) -> PolarsResult<Scalar> {
+        self.to_float64_series().median_reduce()
+    }
+
+    fn std(&self, ddof: u8) -> Option<f64> {
+        self.to_float64_series().std(ddof)
+    }
+
+    fn std_reduce(&self, ddof: u8) -> PolarsResult<Scalar> {
+        self.to_float64_series().std_reduce(ddof)
+    }
+
+    fn var(&self, ddof: u8) -> Option<f64> {
+        self.to_float64_series().var(ddof)
+    }
+
+    fn var_reduce(&self, ddof: u8) -> PolarsResult<Scalar> {
+        self.to_float64_series().var_reduce(ddof)
+    }
+
+    fn quantile_reduce(&self, quantile: f64, method: QuantileMethod) -> PolarsResult<Scalar> {
+        self.to_float64_series().quantile_reduce(quantile, method)
+    }
+
+    fn find_validity_mismatch(&self, other: &Series, idxs: &mut Vec<IdxSize>) {
+        self.0.physical().find_validity_mismatch(other, idxs)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        &self.0
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        &mut self.0
+    }
+
+    fn as_phys_any(&self) -> &dyn Any {
+        self.0.physical()
+    }
+
+    fn as_arc_any(self: Arc<Self>) -> Arc<dyn Any + Send + Sync> {
+        self as _
+    }
+}
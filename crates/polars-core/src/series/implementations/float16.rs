@@ -0,0 +1,458 @@
+use polars_compute::float_sum::sum_arr_as_f32;
+use polars_compute::min_max::MinMaxKernel;
+use polars_utils::min_max::MinMax;
+
+use super::*;
+use crate::prelude::*;
+
+unsafe impl IntoSeries for Float16Chunked {
+    fn into_series(self) -> Series {
+        Series(Arc::new(SeriesWrap(self)))
+    }
+}
+
+impl private::PrivateSeriesNumeric for SeriesWrap<Float16Chunked> {
+    fn bit_repr(&self) -> Option<BitRepr> {
+        Some(self.0.physical().to_bit_repr())
+    }
+}
+
+impl SeriesWrap<Float16Chunked> {
+    fn apply_physical_to_s<F: Fn(&Int16Chunked) -> Int16Chunked>(&self, f: F) -> Series {
+        f(self.0.physical()).into_float16().into_series()
+    }
+
+    fn float32_arithmetic(
+        &self,
+        rhs: &Series,
+        op: impl Fn(&Series, &Series) -> PolarsResult<Series>,
+    ) -> PolarsResult<Series> {
+        let rhs = rhs.cast(&DataType::Float32)?;
+        op(&self.to_float32_series(), &rhs)?.cast(&DataType::Float16)
+    }
+
+    fn to_float32_series(&self) -> Series {
+        self.0.to_float32().into_series()
+    }
+
+    fn float16_scalar(v: Option<f16>) -> Scalar {
+        Scalar::new(
+            DataType::Float16,
+            v.map_or(AnyValue::Null, AnyValue::Float16),
+        )
+    }
+}
+
+impl private::PrivateSeries for SeriesWrap<Float16Chunked> {
+    fn compute_len(&mut self) {
+        self.0.physical_mut().compute_len()
+    }
+
+    fn _field(&self) -> Cow<'_, Field> {
+        Cow::Owned(self.0.field())
+    }
+
+    fn _dtype(&self) -> &DataType {
+        self.0.dtype()
+    }
+    fn _get_flags(&self) -> StatisticsFlags {
+        self.0.physical().get_flags()
+    }
+    fn _set_flags(&mut self, flags: StatisticsFlags) {
+        self.0.physical_mut().set_flags(flags)
+    }
+
+    #[cfg(feature = "zip_with")]
+    fn zip_with_same_type(&self, mask: &BooleanChunked, other: &Series) -> PolarsResult<Series> {
+        let other = other.float16()?;
+        Ok(self
+            .0
+            .physical()
+            .zip_with(mask, other.physical())?
+            .into_float16()
+            .into_series())
+    }
+    fn into_total_eq_inner<'a>(&'a self) -> Box<dyn TotalEqInner + 'a> {
+        self.0.physical().into_total_eq_inner()
+    }
+    fn into_total_ord_inner<'a>(&'a self) -> Box<dyn TotalOrdInner + 'a> {
+        self.0.physical().into_total_ord_inner()
+    }
+
+    fn vec_hash(
+        &self,
+        random_state: PlSeedableRandomStateQuality,
+        buf: &mut Vec<u64>,
+    ) -> PolarsResult<()> {
+        self.0.physical().vec_hash(random_state, buf)?;
+        Ok(())
+    }
+
+    fn vec_hash_combine(
+        &self,
+        build_hasher: PlSeedableRandomStateQuality,
+        hashes: &mut [u64],
+    ) -> PolarsResult<()> {
+        self.0.physical().vec_hash_combine(build_hasher, hashes)?;
+        Ok(())
+    }
+
+    // Aggregations compute in single precision, which is exact for min and max.
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_min(&self, groups: &GroupsType) -> Series {
+        self.to_float32_series()
+            .agg_min(groups)
+            .f32()
+            .unwrap()
+            .to_float16()
+            .into_series()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_max(&self, groups: &GroupsType) -> Series {
+        self.to_float32_series()
+            .agg_max(groups)
+            .f32()
+            .unwrap()
+            .to_float16()
+            .into_series()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_sum(&self, groups: &GroupsType) -> Series {
+        self.to_float32_series().agg_sum(groups)
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_std(&self, groups: &GroupsType, ddof: u8) -> Series {
+        self.to_float32_series().agg_std(groups, ddof)
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_var(&self, groups: &GroupsType, ddof: u8) -> Series {
+        self.to_float32_series().agg_var(groups, ddof)
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_list(&self, groups: &GroupsType) -> Series {
+        // The physical values are kept as is, casting would convert them numerically.
+        let out = self.0.physical().agg_list(groups);
+        let ca = out.list().unwrap();
+        ListChunked::from_chunks_and_dtype_unchecked(
+            ca.name().clone(),
+            ca.chunks().clone(),
+            DataType::List(Box::new(self.dtype().clone())),
+        )
+        .into_series()
+    }
+
+    // Arithmetic is done in single precision and rounded back.
+    fn subtract(&self, rhs: &Series) -> PolarsResult<Series> {
+        self.float32_arithmetic(rhs, |l, r| l - r)
+    }
+    fn add_to(&self, rhs: &Series) -> PolarsResult<Series> {
+        self.float32_arithmetic(rhs, |l, r| l + r)
+    }
+    fn multiply(&self, rhs: &Series) -> PolarsResult<Series> {
+        self.float32_arithmetic(rhs, |l, r| l * r)
+    }
+    fn divide(&self, rhs: &Series) -> PolarsResult<Series> {
+        self.float32_arithmetic(rhs, |l, r| l / r)
+    }
+    fn remainder(&self, rhs: &Series) -> PolarsResult<Series> {
+        self.float32_arithmetic(rhs, |l, r| l % r)
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn group_tuples(&self, multithreaded: bool, sorted: bool) -> PolarsResult<GroupsType> {
+        self.0.physical().group_tuples(multithreaded, sorted)
+    }
+
+    fn arg_sort_multiple(
+        &self,
+        by: &[Column],
+        options: &SortMultipleOptions,
+    ) -> PolarsResult<IdxCa> {
+        self.0.physical().arg_sort_multiple(by, options)
+    }
+}
+
+impl SeriesTrait for SeriesWrap<Float16Chunked> {
+    fn rename(&mut self, name: PlSmallStr) {
+        self.0.rename(name)
+    }
+
+    fn chunk_lengths(&self) -> ChunkLenIter<'_> {
+        self.0.physical().chunk_lengths()
+    }
+
+    fn name(&self) -> &PlSmallStr {
+        self.0.name()
+    }
+
+    fn chunks(&self) -> &Vec<ArrayRef> {
+        self.0.physical().chunks()
+    }
+    unsafe fn chunks_mut(&mut self) -> &mut Vec<ArrayRef> {
+        self.0.physical_mut().chunks_mut()
+    }
+
+    fn slice(&self, offset: i64, length: usize) -> Series {
+        self.apply_physical_to_s(|ca| ca.slice(offset, length))
+    }
+
+    fn split_at(&self, offset: i64) -> (Series, Series) {
+        let (a, b) = self.0.split_at(offset);
+        (a.into_series(), b.into_series())
+    }
+
+    fn append(&mut self, other: &Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), append);
+        let mut other = other.to_physical_repr().into_owned();
+        self.0
+            .physical_mut()
+            .append_owned(std::mem::take(other._get_inner_mut().as_mut()))
+    }
+    fn append_owned(&mut self, mut other: Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), append);
+        self.0.physical_mut().append_owned(std::mem::take(
+            &mut other
+                ._get_inner_mut()
+                .as_any_mut()
+                .downcast_mut::<Float16Chunked>()
+                .unwrap()
+                .phys,
+        ))
+    }
+
+    fn extend(&mut self, other: &Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), extend);
+        let other = other.to_physical_repr();
+        self.0
+            .physical_mut()
+            .extend(other.as_ref().as_ref().as_ref())?;
+        Ok(())
+    }
+
+    fn filter(&self, filter: &BooleanChunked) -> PolarsResult<Series> {
+        Ok(self
+            .0
+            .physical()
+            .filter(filter)?
+            .into_float16()
+            .into_series())
+    }
+
+    fn take(&self, indices: &IdxCa) -> PolarsResult<Series> {
+        Ok(self
+            .0
+            .physical()
+            .take(indices)?
+            .into_float16()
+            .into_series())
+    }
+
+    unsafe fn take_unchecked(&self, indices: &IdxCa) -> Series {
+        self.0
+            .physical()
+            .take_unchecked(indices)
+            .into_float16()
+            .into_series()
+    }
+
+    fn take_slice(&self, indices: &[IdxSize]) -> PolarsResult<Series> {
+        Ok(self
+            .0
+            .physical()
+            .take(indices)?
+            .into_float16()
+            .into_series())
+    }
+
+    unsafe fn take_slice_unchecked(&self, indices: &[IdxSize]) -> Series {
+        self.0
+            .physical()
+            .take_unchecked(indices)
+            .into_float16()
+            .into_series()
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn rechunk(&self) -> Series {
+        self.0
+            .physical()
+            .rechunk()
+            .into_owned()
+            .into_float16()
+            .into_series()
+    }
+
+    fn new_from_index(&self, index: usize, length: usize) -> Series {
+        self.0
+            .physical()
+            .new_from_index(index, length)
+            .into_float16()
+            .into_series()
+    }
+
+    fn cast(&self, dtype: &DataType, cast_options: CastOptions) -> PolarsResult<Series> {
+        self.0.cast_with_options(dtype, cast_options)
+    }
+
+    #[inline]
+    unsafe fn get_unchecked(&self, index: usize) -> AnyValue<'_> {
+        self.0.get_any_value_unchecked(index)
+    }
+
+    fn sort_with(&self, options: SortOptions) -> PolarsResult<Series> {
+        Ok(self
+            .0
+            .physical()
+            .sort_with(options)
+            .into_float16()
+            .into_series())
+    }
+
+    fn arg_sort(&self, options: SortOptions) -> IdxCa {
+        self.0.physical().arg_sort(options)
+    }
+
+    fn null_count(&self) -> usize {
+        self.0.null_count()
+    }
+
+    fn has_nulls(&self) -> bool {
+        self.0.has_nulls()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn unique(&self) -> PolarsResult<Series> {
+        Ok(self.apply_physical_to_s(|ca| ca.unique().unwrap()))
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn n_unique(&self) -> PolarsResult<usize> {
+        self.0.physical().n_unique()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn arg_unique(&self) -> PolarsResult<IdxCa> {
+        self.0.physical().arg_unique()
+    }
+
+    fn is_null(&self) -> BooleanChunked {
+        self.0.is_null()
+    }
+
+    fn is_not_null(&self) -> BooleanChunked {
+        self.0.is_not_null()
+    }
+
+    fn reverse(&self) -> Series {
+        self.apply_physical_to_s(|ca| ca.reverse())
+    }
+
+    fn shift(&self, periods: i64) -> Series {
+        self.apply_physical_to_s(|ca| ca.shift(periods))
+    }
+
+    #[cfg(feature = "approx_unique")]
+    fn approx_n_unique(&self) -> PolarsResult<IdxSize> {
+        Ok(ChunkApproxNUnique::approx_n_unique(self.0.physical()))
+    }
+
+    fn clone_inner(&self) -> Arc<dyn SeriesTrait> {
+        Arc::new(SeriesWrap(Clone::clone(&self.0)))
+    }
+
+    fn _sum_as_f64(&self) -> f64 {
+        self.0
+            .f16_chunks()
+            .map(|arr| sum_arr_as_f32(&arr) as f64)
+            .sum()
+    }
+
+    fn mean(&self) -> Option<f64> {
+        let count = self.len() - self.null_count();
+        (count > 0).then(|| self._sum_as_f64() / count as f64)
+    }
+
+    fn median(&self) -> Option<f64> {
+        self.to_float32_series().median()
+    }
+
+    fn std(&self, ddof: u8) -> Option<f64> {
+        self.to_float32_series().std(ddof)
+    }
+
+    fn var(&self, ddof: u8) -> Option<f64> {
+        self.to_float32_series().var(ddof)
+    }
+
+    fn sum_reduce(&self) -> PolarsResult<Scalar> {
+        let sum: f32 = self.0.f16_chunks().map(|arr| sum_arr_as_f32(&arr)).sum();
+        Ok(Scalar::new(DataType::Float32, sum.into()))
+    }
+
+    fn min_reduce(&self) -> PolarsResult<Scalar> {
+        let min = self
+            .0
+            .f16_chunks()
+            .filter_map(|arr| arr.min_ignore_nan_kernel())
+            .reduce(MinMax::min_ignore_nan);
+        Ok(Self::float16_scalar(min))
+    }
+
+    fn max_reduce(&self) -> PolarsResult<Scalar> {
+        let max = self
+            .0
+            .f16_chunks()
+            .filter_map(|arr| arr.max_ignore_nan_kernel())
+            .reduce(MinMax::max_ignore_nan);
+        Ok(Self::float16_scalar(max))
+    }
+
+    fn mean_reduce(&self) -> PolarsResult<Scalar> {
+        let mean = self.mean().map(|m| m as f32);
+        Ok(Scalar::new(DataType::Float32, mean.into()))
+    }
+
+    fn median_reduce(&self) -> PolarsResult<Scalar> {
+        self.to_float32_series().median_reduce()
+    }
+
+    fn var_reduce(&self, ddof: u8) -> PolarsResult<Scalar> {
+        self.to_float32_series().var_reduce(ddof)
+    }
+
+    fn std_reduce(&self, ddof: u8) -> PolarsResult<Scalar> {
+        self.to_float32_series().std_reduce(ddof)
+    }
+
+    fn quantile_reduce(&self, quantile: f64, method: QuantileMethod) -> PolarsResult<Scalar> {
+        self.to_float32_series().quantile_reduce(quantile, method)
+    }
+
+    fn find_validity_mismatch(&self, other: &Series, idxs: &mut Vec<IdxSize>) {
+        self.0.physical().find_validity_mismatch(other, idxs)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        &self.0
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        &mut self.0
+    }
+
+    fn as_phys_any(&self) -> &dyn Any {
+        self.0.physical()
+    }
+
+    fn as_arc_any(self: Arc<Self>) -> Arc<dyn Any + Send + Sync> {
+        self as _
+    }
+}
@@ -85,6 +85,29 @@ impl Series {
                 );
                 Box::new(arr)
             },
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(_, _) => {
+                let entries = self.map().unwrap().entries().clone().into_series();
+                let arr = entries.to_arrow(chunk_idx, compat_level);
+                let arr = arr.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+                crate::chunked_array::logical::entries_array_to_map_array(
+                    arr,
+                    self.dtype().to_arrow(compat_level),
+                )
+                .boxed()
+            },
+            #[cfg(feature = "dtype-union")]
+            DataType::Union(_, _) => self
+                .union()
+                .unwrap()
+                .to_union_array(chunk_idx, compat_level)
+                .boxed(),
+            #[cfg(feature = "dtype-extension")]
+            DataType::Extension { .. } => {
+                let storage = self.extension().unwrap().storage();
+                let arr = storage.to_arrow(chunk_idx, compat_level);
+                arrow::array::with_dtype(arr.as_ref(), self.dtype().to_arrow(compat_level))
+            },
             #[cfg(feature = "dtype-array")]
             DataType::Array(inner, width) => {
                 let ca = self.array().unwrap();
@@ -157,6 +180,57 @@ impl Series {
                 .clone()
                 .to(self.dtype().to_arrow(CompatLevel::newest()))
                 .to_boxed(),
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => {
+                let arr = self
+                    .interval()
+                    .unwrap()
+                    .physical()
+                    .downcast_get(chunk_idx)
+                    .unwrap();
+                crate::chunked_array::logical::interval_array_to_arrow(arr).to_boxed()
+            },
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => {
+                let arr = self
+                    .float16()
+                    .unwrap()
+                    .physical()
+                    .downcast_get(chunk_idx)
+                    .unwrap();
+                crate::chunked_array::logical::float16_array_to_arrow(arr).to_boxed()
+            },
+            #[cfg(feature = "dtype-uuid")]
+            DataType::Uuid => {
+                let arr = self
+                    .uuid()
+                    .unwrap()
+                    .physical()
+                    .downcast_get(chunk_idx)
+                    .unwrap();
+                crate::chunked_array::logical::uuid_array_to_arrow(arr).to_boxed()
+            },
+            #[cfg(feature = "dtype-json")]
+            DataType::Json => {
+                let arr = self
+                    .json()
+                    .unwrap()
+                    .physical()
+                    .downcast_get(chunk_idx)
+                    .unwrap();
+                crate::chunked_array::logical::json_array_to_arrow(arr, compat_level)
+            },
+            #[cfg(feature = "dtype-decimal256")]
+            DataType::Decimal256(precision, scale) => {
+                let arr = self
+                    .decimal256()
+                    .unwrap()
+                    .physical()
+                    .downcast_get(chunk_idx)
+                    .unwrap();
+                crate::chunked_array::logical::decimal256_array_to_arrow(arr, *precision, *scale)
+                    .to_boxed()
+            },
             #[cfg(feature = "object")]
             DataType::Object(_) => {
                 use crate::chunked_array::object::builder::object_series_to_arrow_array;
@@ -465,7 +465,17 @@ impl Series {
             opt => opt,
         };
 
-        let out = slf.0.cast(dtype, new_options)?;
+        let out = match dtype {
+            #[cfg(feature = "dtype-extension")]
+            D::Extension { .. } if !slf.dtype().is_extension() => {
+                crate::chunked_array::logical::cast_into_extension(&slf, dtype, new_options)?
+            },
+            #[cfg(feature = "dtype-union")]
+            D::Union(_, _) if !slf.dtype().is_union() => {
+                crate::chunked_array::logical::cast_into_union(&slf, dtype, new_options)?
+            },
+            _ => slf.0.cast(dtype, new_options)?,
+        };
         if options.is_strict() {
             handle_casting_failures(slf.as_ref(), &out)?;
         }
@@ -515,6 +525,28 @@ impl Series {
                     .into_decimal_unchecked(*precision, *scale)
                     .into_series())
             },
+            #[cfg(feature = "dtype-interval")]
+            (D::Int128, D::Interval) => {
+                Ok(self.i128().unwrap().clone().into_interval().into_series())
+            },
+            #[cfg(feature = "dtype-f16")]
+            (D::Int16, D::Float16) => Ok(self.i16().unwrap().clone().into_float16().into_series()),
+            #[cfg(feature = "dtype-uuid")]
+            (D::UInt128, D::Uuid) => Ok(self.u128().unwrap().clone().into_uuid().into_series()),
+            #[cfg(feature = "dtype-json")]
+            (D::Binary, D::Json) => Ok(self
+                .binary()
+                .unwrap()
+                .clone()
+                .into_json_unchecked()
+                .into_series()),
+            #[cfg(feature = "dtype-decimal256")]
+            (D::Binary, D::Decimal256(precision, scale)) => Ok(self
+                .binary()
+                .unwrap()
+                .clone()
+                .into_decimal256_unchecked(*precision, *scale)
+                .into_series()),
 
             #[cfg(feature = "dtype-categorical")]
             (phys, D::Categorical(cats, _)) if &cats.physical().dtype() == phys => {
@@ -557,6 +589,20 @@ impl Series {
                     .from_physical_unchecked(to.as_ref().clone())
                     .map(|ca| ca.into_series())
             },
+            #[cfg(feature = "dtype-map")]
+            (D::List(_), D::Map(_, _)) => unsafe {
+                let entries = dtype.to_entries_list_type();
+                let ca = self
+                    .list()
+                    .unwrap()
+                    .from_physical_unchecked(entries.inner_dtype().unwrap().clone())?;
+                Ok(MapChunked::new_logical(ca, dtype.clone()).into_series())
+            },
+            #[cfg(feature = "dtype-union")]
+            (D::Struct(_), D::Union(fields, _)) => unsafe {
+                let ca = self.struct_().unwrap().from_physical_unchecked(fields)?;
+                Ok(UnionChunked::new_logical(ca, dtype.clone()).into_series())
+            },
             #[cfg(feature = "dtype-array")]
             (D::Array(_, lw), D::Array(to, rw)) if lw == rw => unsafe {
                 self.array()
@@ -571,6 +617,17 @@ impl Series {
                     .from_physical_unchecked(to.as_slice())
                     .map(|ca| ca.into_series())
             },
+            #[cfg(feature = "dtype-extension")]
+            (
+                _,
+                D::Extension {
+                    name,
+                    metadata,
+                    storage,
+                },
+            ) => Ok(self
+                .from_physical_unchecked(storage)?
+                .into_extension(name.clone(), metadata.as_deref().cloned())),
 
             _ => panic!("invalid from_physical({dtype:?}) for {:?}", self.dtype()),
         }
@@ -636,6 +693,8 @@ impl Series {
         match self.dtype() {
             DataType::Float32 => Ok(self.f32().unwrap().is_nan()),
             DataType::Float64 => Ok(self.f64().unwrap().is_nan()),
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => Ok(self.float16().unwrap().to_float32().is_nan()),
             DataType::Null => Ok(BooleanChunked::full_null(self.name().clone(), self.len())),
             dt if dt.is_primitive_numeric() => {
                 let arr = BooleanArray::full(self.len(), false, ArrowDataType::Boolean)
@@ -651,6 +710,8 @@ impl Series {
         match self.dtype() {
             DataType::Float32 => Ok(self.f32().unwrap().is_not_nan()),
             DataType::Float64 => Ok(self.f64().unwrap().is_not_nan()),
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => Ok(self.float16().unwrap().to_float32().is_not_nan()),
             dt if dt.is_primitive_numeric() => {
                 let arr = BooleanArray::full(self.len(), true, ArrowDataType::Boolean)
                     .with_validity(self.rechunk_validity());
@@ -665,6 +726,8 @@ impl Series {
         match self.dtype() {
             DataType::Float32 => Ok(self.f32().unwrap().is_finite()),
             DataType::Float64 => Ok(self.f64().unwrap().is_finite()),
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => Ok(self.float16().unwrap().to_float32().is_finite()),
             DataType::Null => Ok(BooleanChunked::full_null(self.name().clone(), self.len())),
             dt if dt.is_primitive_numeric() => {
                 let arr = BooleanArray::full(self.len(), true, ArrowDataType::Boolean)
@@ -680,6 +743,8 @@ impl Series {
         match self.dtype() {
             DataType::Float32 => Ok(self.f32().unwrap().is_infinite()),
             DataType::Float64 => Ok(self.f64().unwrap().is_infinite()),
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => Ok(self.float16().unwrap().to_float32().is_infinite()),
             DataType::Null => Ok(BooleanChunked::full_null(self.name().clone(), self.len())),
             dt if dt.is_primitive_numeric() => {
                 let arr = BooleanArray::full(self.len(), false, ArrowDataType::Boolean)
@@ -706,11 +771,19 @@ impl Series {
     /// * Datetime -> Int64
     /// * Duration -> Int64
     /// * Decimal -> Int128
+    /// * Decimal256 -> Binary
+    /// * Interval -> Int128
+    /// * Float16 -> Int16
+    /// * Uuid -> UInt128
+    /// * Json -> Binary
     /// * Time -> Int64
     /// * Categorical -> U8/U16/U32
     /// * List(inner) -> List(physical of inner)
+    /// * Map(key, value) -> List(Struct{key: physical of key, value: physical of value})
     /// * Array(inner) -> Array(physical of inner)
     /// * Struct -> Struct with physical repr of each struct column
+    /// * Union -> Struct with physical repr of each variant
+    /// * Extension -> physical repr of the storage
     pub fn to_physical_repr(&self) -> Cow<'_, Series> {
         use DataType::*;
         match self.dtype() {
@@ -733,10 +806,38 @@ impl Series {
             },
             #[cfg(feature = "dtype-decimal")]
             Decimal(_, _) => Cow::Owned(self.decimal().unwrap().phys.clone().into_series()),
+            #[cfg(feature = "dtype-decimal256")]
+            Decimal256(_, _) => Cow::Owned(self.decimal256().unwrap().phys.clone().into_series()),
+            #[cfg(feature = "dtype-interval")]
+            Interval => Cow::Owned(self.interval().unwrap().phys.clone().into_series()),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Cow::Owned(self.float16().unwrap().phys.clone().into_series()),
+            #[cfg(feature = "dtype-uuid")]
+            Uuid => Cow::Owned(self.uuid().unwrap().phys.clone().into_series()),
+            #[cfg(feature = "dtype-json")]
+            Json => Cow::Owned(self.json().unwrap().phys.clone().into_series()),
             List(_) => match self.list().unwrap().to_physical_repr() {
                 Cow::Borrowed(_) => Cow::Borrowed(self),
                 Cow::Owned(ca) => Cow::Owned(ca.into_series()),
             },
+            #[cfg(feature = "dtype-map")]
+            Map(_, _) => {
+                let entries = self.map().unwrap().entries();
+                Cow::Owned(entries.to_physical_repr().into_owned().into_series())
+            },
+            #[cfg(feature = "dtype-union")]
+            Union(_, _) => {
+                let ca = self.union().unwrap().to_struct();
+                Cow::Owned(ca.to_physical_repr().into_owned().into_series())
+            },
+            #[cfg(feature = "dtype-extension")]
+            Extension { .. } => Cow::Owned(
+                self.extension()
+                    .unwrap()
+                    .storage()
+                    .to_physical_repr()
+                    .into_owned(),
+            ),
             #[cfg(feature = "dtype-array")]
             Array(_, _) => match self.array().unwrap().to_physical_repr() {
                 Cow::Borrowed(_) => Cow::Borrowed(self),
@@ -928,6 +1029,10 @@ impl Series {
 
     // used for formatting
     pub fn str_value(&self, index: usize) -> PolarsResult<Cow<'_, str>> {
+        #[cfg(feature = "dtype-extension")]
+        if let Some(ca) = self.try_extension() {
+            return ca.str_value(index);
+        }
         Ok(self.0.get(index)?.str_value())
     }
     /// Get the head of the Series.
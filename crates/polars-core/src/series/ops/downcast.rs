@@ -148,12 +148,60 @@ impl Series {
         try_unpack_chunked!(self, DataType::Duration(_) => DurationChunked)
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Interval`]
+    #[cfg(feature = "dtype-interval")]
+    pub fn try_interval(&self) -> Option<&IntervalChunked> {
+        try_unpack_chunked!(self, DataType::Interval => IntervalChunked)
+    }
+
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Float16`]
+    #[cfg(feature = "dtype-f16")]
+    pub fn try_float16(&self) -> Option<&Float16Chunked> {
+        try_unpack_chunked!(self, DataType::Float16 => Float16Chunked)
+    }
+
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Uuid`]
+    #[cfg(feature = "dtype-uuid")]
+    pub fn try_uuid(&self) -> Option<&UuidChunked> {
+        try_unpack_chunked!(self, DataType::Uuid => UuidChunked)
+    }
+
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Json`]
+    #[cfg(feature = "dtype-json")]
+    pub fn try_json(&self) -> Option<&JsonChunked> {
+        try_unpack_chunked!(self, DataType::Json => JsonChunked)
+    }
+
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Map`]
+    #[cfg(feature = "dtype-map")]
+    pub fn try_map(&self) -> Option<&MapChunked> {
+        try_unpack_chunked!(self, DataType::Map(_, _) => MapChunked)
+    }
+
+    /// Unpack to [`UnionChunked`] of dtype [`DataType::Union`]
+    #[cfg(feature = "dtype-union")]
+    pub fn try_union(&self) -> Option<&UnionChunked> {
+        try_unpack_chunked!(self, DataType::Union(_, _) => UnionChunked)
+    }
+
+    /// Unpack to [`ExtensionChunked`] of dtype [`DataType::Extension`]
+    #[cfg(feature = "dtype-extension")]
+    pub fn try_extension(&self) -> Option<&ExtensionChunked> {
+        try_unpack_chunked!(self, DataType::Extension { .. } => ExtensionChunked)
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype [`DataType::Decimal`]
     #[cfg(feature = "dtype-decimal")]
     pub fn try_decimal(&self) -> Option<&DecimalChunked> {
         try_unpack_chunked!(self, DataType::Decimal(_, _) => DecimalChunked)
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Decimal256`]
+    #[cfg(feature = "dtype-decimal256")]
+    pub fn try_decimal256(&self) -> Option<&Decimal256Chunked> {
+        try_unpack_chunked!(self, DataType::Decimal256(_, _) => Decimal256Chunked)
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype list
     pub fn try_list(&self) -> Option<&ListChunked> {
         try_unpack_chunked!(self, DataType::List(_) => ListChunked)
@@ -343,6 +391,55 @@ impl Series {
             .ok_or_else(|| unpack_chunked_err!(self => "Duration"))
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Interval`]
+    #[cfg(feature = "dtype-interval")]
+    pub fn interval(&self) -> PolarsResult<&IntervalChunked> {
+        self.try_interval()
+            .ok_or_else(|| unpack_chunked_err!(self => "Interval"))
+    }
+
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Float16`]
+    #[cfg(feature = "dtype-f16")]
+    pub fn float16(&self) -> PolarsResult<&Float16Chunked> {
+        self.try_float16()
+            .ok_or_else(|| unpack_chunked_err!(self => "Float16"))
+    }
+
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Uuid`]
+    #[cfg(feature = "dtype-uuid")]
+    pub fn uuid(&self) -> PolarsResult<&UuidChunked> {
+        self.try_uuid()
+            .ok_or_else(|| unpack_chunked_err!(self => "Uuid"))
+    }
+
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Json`]
+    #[cfg(feature = "dtype-json")]
+    pub fn json(&self) -> PolarsResult<&JsonChunked> {
+        self.try_json()
+            .ok_or_else(|| unpack_chunked_err!(self => "Json"))
+    }
+
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Map`]
+    #[cfg(feature = "dtype-map")]
+    pub fn map(&self) -> PolarsResult<&MapChunked> {
+        self.try_map()
+            .ok_or_else(|| unpack_chunked_err!(self => "Map"))
+    }
+
+    /// Unpack to [`UnionChunked`] of dtype [`DataType::Union`]
+    #[cfg(feature = "dtype-union")]
+    pub fn union(&self) -> PolarsResult<&UnionChunked> {
+        self.try_union()
+            .ok_or_else(|| unpack_chunked_err!(self => "Union"))
+    }
+
+    /// Unpack to [`ExtensionChunked`] of dtype [`DataType::Extension`]
+    #[cfg(feature = "dtype-extension")]
+    pub fn extension(&self) -> PolarsResult<&ExtensionChunked> {
+        self.try_extension()
+            .ok_or_else(|| unpack_chunked_err!(self => "Extension"))
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype [`DataType::Decimal`]
     #[cfg(feature = "dtype-decimal")]
     pub fn decimal(&self) -> PolarsResult<&DecimalChunked> {
@@ -350,6 +447,13 @@ impl Series {
             .ok_or_else(|| unpack_chunked_err!(self => "Decimal"))
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Decimal256`]
+    #[cfg(feature = "dtype-decimal256")]
+    pub fn decimal256(&self) -> PolarsResult<&Decimal256Chunked> {
+        self.try_decimal256()
+            .ok_or_else(|| unpack_chunked_err!(self => "Decimal256"))
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype list
     pub fn list(&self) -> PolarsResult<&ListChunked> {
         self.try_list()
@@ -13,6 +13,28 @@ impl Series {
             DataType::List(inner_dtype) => {
                 ListChunked::full_null_with_dtype(name, size, inner_dtype).into_series()
             },
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(_, _) => {
+                let entries = dtype.to_entries_list_type();
+                let ca =
+                    ListChunked::full_null_with_dtype(name, size, entries.inner_dtype().unwrap());
+                // SAFETY: the inner type is the map's `{key, value}` struct.
+                unsafe { MapChunked::new_logical(ca, dtype.clone()) }.into_series()
+            },
+            #[cfg(feature = "dtype-union")]
+            DataType::Union(fields, _) => {
+                let ca = Series::full_null(name, size, &DataType::Struct(fields.clone()));
+                // SAFETY: all variants are null.
+                unsafe { UnionChunked::new_logical(ca.struct_().unwrap().clone(), dtype.clone()) }
+                    .into_series()
+            },
+            #[cfg(feature = "dtype-extension")]
+            DataType::Extension {
+                name: ext_name,
+                metadata,
+                storage,
+            } => Series::full_null(name, size, storage)
+                .into_extension(ext_name.clone(), metadata.as_deref().cloned()),
             #[cfg(feature = "dtype-array")]
             DataType::Array(inner_dtype, width) => {
                 ArrayChunked::full_null_with_dtype(name, size, inner_dtype, *width).into_series()
@@ -44,10 +66,30 @@ impl Series {
             DataType::Time => Int64Chunked::full_null(name, size)
                 .into_time()
                 .into_series(),
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => Int128Chunked::full_null(name, size)
+                .into_interval()
+                .into_series(),
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => Int16Chunked::full_null(name, size)
+                .into_float16()
+                .into_series(),
+            #[cfg(feature = "dtype-uuid")]
+            DataType::Uuid => UInt128Chunked::full_null(name, size)
+                .into_uuid()
+                .into_series(),
+            #[cfg(feature = "dtype-json")]
+            DataType::Json => BinaryChunked::full_null(name, size)
+                .into_json_unchecked()
+                .into_series(),
             #[cfg(feature = "dtype-decimal")]
             DataType::Decimal(precision, scale) => Int128Chunked::full_null(name, size)
                 .into_decimal_unchecked(*precision, *scale)
                 .into_series(),
+            #[cfg(feature = "dtype-decimal256")]
+            DataType::Decimal256(precision, scale) => BinaryChunked::full_null(name, size)
+                .into_decimal256_unchecked(*precision, *scale)
+                .into_series(),
             #[cfg(feature = "dtype-struct")]
             DataType::Struct(fields) => {
                 let fields = fields
@@ -1,5 +1,7 @@
 use bitflags::bitflags;
 use num_traits::Signed;
+#[cfg(feature = "dtype-decimal256")]
+use polars_compute::decimal::DEC256_MAX_PREC;
 #[cfg(feature = "dtype-decimal")]
 use polars_compute::decimal::{DEC128_MAX_PREC, i128_to_dec128};
 
@@ -287,6 +289,14 @@ pub fn get_supertype_with_options(
 
             (Float64, Float32) => Some(Float64),
 
+            // Float16 is only widened as far as needed to hold the other type exactly.
+            #[cfg(feature = "dtype-f16")]
+            (Float16, Boolean | Int8 | UInt8) => Some(Float16),
+            #[cfg(feature = "dtype-f16")]
+            (Float16, Int16 | UInt16 | Float32) => Some(Float32),
+            #[cfg(feature = "dtype-f16")]
+            (Float16, Int32 | UInt32 | Int64 | UInt64 | Int128 | UInt128 | Float64) => Some(Float64),
+
             // Time related dtypes
             #[cfg(feature = "dtype-date")]
             (Date, UInt32) => Some(Int64),
@@ -364,6 +374,8 @@ pub fn get_supertype_with_options(
             (Duration(_), Date) | (Date, Duration(_)) => Some(Date),
             #[cfg(feature = "dtype-duration")]
             (Duration(lu), Duration(ru)) => Some(Duration(get_time_units(lu, ru))),
+            #[cfg(feature = "dtype-interval")]
+            (Duration(_), Interval) => Some(Interval),
 
             // both None or both Some("<tz>") timezones
             // we cast from more precision to higher precision as that always fits with occasional loss of precision
@@ -380,6 +392,12 @@ pub fn get_supertype_with_options(
                 let st = get_supertype(inner_left, inner_right)?;
                 Some(List(Box::new(st)))
             }
+            #[cfg(feature = "dtype-map")]
+            (Map(key_left, value_left), Map(key_right, value_right)) => {
+                let key = get_supertype(key_left, key_right)?;
+                let value = get_supertype(value_left, value_right)?;
+                Some(Map(Box::new(key), Box::new(value)))
+            }
             #[cfg(feature = "dtype-array")]
             (List(inner_left), Array(inner_right, _)) | (Array(inner_left, _), List(inner_right)) => {
                 let st = get_supertype(inner_left, inner_right)?;
@@ -416,13 +434,17 @@ pub fn get_supertype_with_options(
                         }
                     },
                     // Materialize float to float
-                    UnknownKind::Float | UnknownKind::Int(_) if dt.is_float() => Some(dt.clone()),
+                    UnknownKind::Float | UnknownKind::Int(_) if dt.is_float() || dt.is_float16() => Some(dt.clone()),
                     UnknownKind::Float if dt.is_integer() | dt.is_decimal() => Some(Unknown(UnknownKind::Float)),
                     // Materialize str
                     UnknownKind::Str if dt.is_string() | dt.is_enum() => Some(dt.clone()),
                     // Materialize str
                     #[cfg(feature = "dtype-categorical")]
                     UnknownKind::Str if dt.is_categorical() => Some(dt.clone()),
+                    // Parse str literals as UUIDs
+                    UnknownKind::Str if dt.is_uuid() => Some(dt.clone()),
+                    // Parse str literals as JSON
+                    UnknownKind::Str if dt.is_json() => Some(dt.clone()),
                     // Keep unknown
                     dynam if dt.is_null() => Some(Unknown(*dynam)),
                     // Find integers sizes
@@ -455,6 +477,11 @@ pub fn get_supertype_with_options(
                         let DataType::Decimal(_prec, scale) = dt else { unreachable!() };
                         Some(DataType::Decimal(DEC128_MAX_PREC, *scale))
                     }
+                    #[cfg(feature = "dtype-decimal256")]
+                    UnknownKind::Int(_) if dt.is_decimal256() => {
+                        let DataType::Decimal256(_prec, scale) = dt else { unreachable!() };
+                        Some(DataType::Decimal256(DEC256_MAX_PREC, *scale))
+                    }
                     _ => Some(Unknown(UnknownKind::Any))
                 }
             },
@@ -499,6 +526,16 @@ pub fn get_supertype_with_options(
                     Some(Decimal(DEC128_MAX_PREC, *scale))
                 }
             }
+            #[cfg(feature = "dtype-decimal256")]
+            (Decimal256(p1, s1), Decimal256(p2, s2) | Decimal(p2, s2)) => {
+                Some(Decimal256((*p1).max(*p2), (*s1).max(*s2)))
+            },
+            #[cfg(feature = "dtype-decimal256")]
+            (Decimal256(_, _), Float32 | Float64) => Some(Float64),
+            #[cfg(feature = "dtype-decimal256")]
+            (Decimal256(_, scale), dt) if dt.is_signed_integer() || dt.is_unsigned_integer() => {
+                Some(Decimal256(DEC256_MAX_PREC, *scale))
+            }
             _ => None,
         }
     }
@@ -16,6 +16,7 @@ mod slice;
 mod sort;
 mod sortby;
 mod ternary;
+mod time_weighted;
 mod window;
 
 use std::borrow::Cow;
@@ -43,6 +44,7 @@ pub(crate) use slice::*;
 pub(crate) use sort::*;
 pub(crate) use sortby::*;
 pub(crate) use ternary::*;
+pub(crate) use time_weighted::*;
 pub use window::window_function_format_order_by;
 pub(crate) use window::*;
 
@@ -1,6 +1,7 @@
-use polars_time::{PolarsTemporalGroupby, RollingGroupOptions};
+use polars_time::{PolarsTemporalGroupby, RollingGroupOptions, rolling_bounds};
 
 use super::*;
+use crate::state::TimeWindows;
 
 pub(crate) struct RollingExpr {
     /// the root column that the Function will be applied on.
@@ -13,6 +14,8 @@ pub(crate) struct RollingExpr {
     /// A function Expr. i.e. Mean, Median, Max, etc.
     pub(crate) phys_function: Arc<dyn PhysicalExpr>,
     pub(crate) options: RollingGroupOptions,
+    /// Whether the function has a time-weighted aggregation that needs the window bounds.
+    pub(crate) time_weighted: bool,
     pub(crate) expr: Expr,
     pub(crate) output_field: Field,
 }
@@ -38,9 +41,24 @@ impl PhysicalExpr for RollingExpr {
             },
         };
 
+        let state = if self.time_weighted {
+            let mut state = state.clone();
+            let time = df.column(&self.options.index_column)?;
+            let (lower, upper) = rolling_bounds(time, self.options.offset, self.options.period)?;
+            state.time_windows = Some(Arc::new(TimeWindows {
+                index_column: self.options.index_column.clone(),
+                lower,
+                upper,
+                partition_start: None,
+            }));
+            Cow::Owned(state)
+        } else {
+            Cow::Borrowed(state)
+        };
+
         let out = self
             .phys_function
-            .evaluate_on_groups(df, &groups, state)?
+            .evaluate_on_groups(df, &groups, &state)?
             .finalize();
         polars_ensure!(out.len() == groups.len(), agg_len = out.len(), groups.len());
         Ok(out.into_column())
@@ -0,0 +1,97 @@
+use polars_core::prelude::*;
+
+use super::*;
+use crate::expressions::AggState::AggregatedScalar;
+use crate::reduce::{Windows, time_weighted_reduce};
+
+pub struct TimeWeightedExpr {
+    pub(crate) input: Arc<dyn PhysicalExpr>,
+    pub(crate) by: Arc<dyn PhysicalExpr>,
+    pub(crate) method: TimeWeightedMethod,
+    pub(crate) output_field: Field,
+}
+
+/// The physical times of a temporal or integer column.
+fn to_physical_times(by: &Column) -> PolarsResult<Int64Chunked> {
+    let dtype = by.dtype();
+    polars_ensure!(
+        dtype.is_temporal() || dtype.is_integer(),
+        InvalidOperation: "time-weighted aggregations need a temporal or integer 'by' column, got {}", dtype
+    );
+    let times = by.to_physical_repr().cast(&DataType::Int64)?;
+    Ok(times.i64()?.clone())
+}
+
+impl TimeWeightedExpr {
+    fn finish(&self, out: Column) -> PolarsResult<Column> {
+        out.cast(self.output_field.dtype())
+    }
+}
+
+impl PhysicalExpr for TimeWeightedExpr {
+    fn as_expression(&self) -> Option<&Expr> {
+        None
+    }
+
+    fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> PolarsResult<Column> {
+        let input = self.input.evaluate(df, state)?;
+        let by = self.by.evaluate(df, state)?;
+        let groups = GroupsType::Slice {
+            groups: vec![[0, input.len() as IdxSize]],
+            rolling: false,
+        };
+        let out =
+            time_weighted_reduce(&input, &to_physical_times(&by)?, &groups, None, self.method)?;
+        self.finish(out)
+    }
+
+    #[allow(clippy::ptr_arg)]
+    fn evaluate_on_groups<'a>(
+        &self,
+        df: &DataFrame,
+        groups: &'a GroupPositions,
+        state: &ExecutionState,
+    ) -> PolarsResult<AggregationContext<'a>> {
+        let mut ac = self.input.evaluate_on_groups(df, groups, state)?;
+        let ac_by = self.by.evaluate_on_groups(df, groups, state)?;
+        let input = ac.flat_naive().into_owned();
+        let by = ac_by.flat_naive().into_owned();
+        let times = to_physical_times(&by)?;
+
+        // Only the frame that the windows were computed on can carry values into them.
+        let bounds = match state.time_windows.as_deref() {
+            Some(windows)
+                if by.name() == &windows.index_column
+                    && input.len() == df.height()
+                    && windows.lower.len() == groups.len() =>
+            {
+                Some((
+                    windows,
+                    to_physical_times(&windows.lower.cast(by.dtype())?)?,
+                    to_physical_times(&windows.upper.cast(by.dtype())?)?,
+                ))
+            },
+            _ => None,
+        };
+        let windows = bounds.as_ref().map(|(windows, lower, upper)| Windows {
+            lower,
+            upper,
+            partition_start: windows.partition_start.as_deref(),
+        });
+
+        let out = time_weighted_reduce(&input, &times, ac.groups(), windows, self.method)?;
+        let out = self.finish(out)?;
+        Ok(AggregationContext::from_agg_state(
+            AggregatedScalar(out),
+            Cow::Borrowed(groups),
+        ))
+    }
+
+    fn to_field(&self, _input_schema: &Schema) -> PolarsResult<Field> {
+        Ok(self.output_field.clone())
+    }
+
+    fn is_scalar(&self) -> bool {
+        true
+    }
+}
@@ -18,6 +18,14 @@ pub fn get_expr_depth_limit() -> PolarsResult<u16> {
     Ok(depth)
 }
 
+/// Whether the expression contains a time-weighted aggregation, which needs the window bounds of
+/// a dynamic or rolling group-by.
+pub fn has_time_weighted_agg(node: Node, expr_arena: &Arena<AExpr>) -> bool {
+    expr_arena
+        .iter(node)
+        .any(|(_, e)| matches!(e, AExpr::Agg(IRAggExpr::TimeWeighted { .. })))
+}
+
 fn ok_checker(_i: usize, _state: &ExpressionConversionState) -> PolarsResult<()> {
     Ok(())
 }
@@ -272,6 +280,7 @@ fn create_physical_expr_inner(
                 WindowType::Rolling(options) => Ok(Arc::new(RollingExpr {
                     phys_function,
                     options: options.clone(),
+                    time_weighted: has_time_weighted_agg(function, expr_arena),
                     expr,
                     output_field,
                 })),
@@ -361,7 +370,12 @@ fn create_physical_expr_inner(
             let allow_threading = state.allow_threading;
 
             match ctxt {
-                Context::Default if !matches!(agg, IRAggExpr::Quantile { .. }) => {
+                Context::Default
+                    if !matches!(
+                        agg,
+                        IRAggExpr::Quantile { .. } | IRAggExpr::TimeWeighted { .. }
+                    ) =>
+                {
                     use {GroupByMethod as GBM, IRAggExpr as I};
 
                     let output_field = expr_arena
@@ -378,7 +392,7 @@ fn create_physical_expr_inner(
                         I::Last(_) => GBM::Last,
                         I::Mean(_) => GBM::Mean,
                         I::Implode(_) => GBM::Implode,
-                        I::Quantile { .. } => unreachable!(),
+                        I::Quantile { .. } | I::TimeWeighted { .. } => unreachable!(),
                         I::Sum(_) => GBM::Sum,
                         I::Count {
                             input: _,
@@ -415,6 +429,18 @@ fn create_physical_expr_inner(
                             create_physical_expr_inner(*quantile, ctxt, expr_arena, schema, state)?;
                         return Ok(Arc::new(AggQuantileExpr::new(input, quantile, *interpol)));
                     }
+                    if let IRAggExpr::TimeWeighted { by, method, .. } = agg {
+                        let by = create_physical_expr_inner(*by, ctxt, expr_arena, schema, state)?;
+                        let output_field = expr_arena
+                            .get(expression)
+                            .to_field(&ToFieldContext::new(expr_arena, schema))?;
+                        return Ok(Arc::new(TimeWeightedExpr {
+                            input,
+                            by,
+                            method: *method,
+                            output_field,
+                        }));
+                    }
 
                     let mut output_field = expr_arena
                         .get(expression)
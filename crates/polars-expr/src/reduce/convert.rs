@@ -61,6 +61,10 @@ pub fn into_reduction(
             IRAggExpr::NUnique(_) => todo!(),
             IRAggExpr::Implode(_) => todo!(),
             IRAggExpr::AggGroups(_) => todo!(),
+            // These need the windows of the group-by, which only the in-memory engine has.
+            IRAggExpr::TimeWeighted { .. } => polars_bail!(
+                InvalidOperation: "time-weighted aggregations are not supported by the streaming engine"
+            ),
         },
         AExpr::Len => {
             if let Some(first_column) = schema.iter_names().next() {
@@ -9,6 +9,7 @@ mod len;
 mod mean;
 mod min_max;
 mod sum;
+mod time_weighted;
 mod var_std;
 
 use std::any::Any;
@@ -20,6 +21,7 @@ use arrow::bitmap::{Bitmap, BitmapBuilder, MutableBitmap};
 pub use convert::into_reduction;
 pub use min_max::{new_max_reduction, new_min_reduction};
 use polars_core::prelude::*;
+pub use time_weighted::{TimeWeightedState, Windows, time_weighted_reduce};
 
 use crate::EvictIdx;
 
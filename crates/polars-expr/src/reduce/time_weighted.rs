@@ -0,0 +1,181 @@
+use arrow::array::PrimitiveArray;
+use polars_plan::dsl::TimeWeightedMethod;
+
+use super::*;
+
+/// The running state of a time-weighted reduction over samples `(t, v)` that arrive in time order.
+///
+/// The value between two samples is interpolated linearly. If a sample from before the window is
+/// carried in, the integral starts at the lower bound of the window, with the value there
+/// interpolated between the carried in sample and the first sample in the window. If the window
+/// has an upper bound, the integral ends there, with the last value held until then.
+#[derive(Default)]
+pub struct TimeWeightedState {
+    /// The last sample before the window and the lower bound of the window.
+    carry_in: Option<((i64, f64), i64)>,
+    /// The time the integral starts at.
+    start: i64,
+    last: Option<(i64, f64)>,
+    integral: f64,
+}
+
+impl TimeWeightedState {
+    pub fn new(carry_in: Option<(i64, f64)>, lower: Option<i64>) -> Self {
+        Self {
+            carry_in: carry_in.zip(lower),
+            ..Default::default()
+        }
+    }
+
+    pub fn update(&mut self, t: i64, v: f64) {
+        match self.last {
+            Some((last_t, last_v)) => {
+                self.integral += (last_v + v) / 2.0 * (t - last_t) as f64;
+            },
+            None => {
+                self.start = t;
+                if let Some(((carry_t, carry_v), lower)) = self.carry_in {
+                    let lower = lower.max(carry_t);
+                    if lower < t {
+                        let frac = (lower - carry_t) as f64 / (t - carry_t) as f64;
+                        let lower_v = carry_v + (v - carry_v) * frac;
+                        self.integral += (lower_v + v) / 2.0 * (t - lower) as f64;
+                        self.start = lower;
+                    }
+                }
+            },
+        }
+        self.last = Some((t, v));
+    }
+
+    /// The result of a `Mean` or `Integral` over a window ending at `upper`, which is `None` if
+    /// no sample was seen.
+    ///
+    /// The mean of a window that spans no time is the value of its sample.
+    pub fn finish(&self, method: TimeWeightedMethod, upper: Option<i64>) -> Option<f64> {
+        let (last_t, last_v) = self.last?;
+        let end = upper.map_or(last_t, |upper| upper.max(last_t));
+        let integral = self.integral + last_v * (end - last_t) as f64;
+        match method {
+            TimeWeightedMethod::Integral => Some(integral),
+            TimeWeightedMethod::Mean if end > self.start => {
+                Some(integral / (end - self.start) as f64)
+            },
+            TimeWeightedMethod::Mean => Some(last_v),
+            TimeWeightedMethod::Last => unreachable!(),
+        }
+    }
+}
+
+/// The windows of the groups, see [`time_weighted_reduce`].
+pub struct Windows<'a> {
+    /// The lower bound of every group, in the unit of the times.
+    pub lower: &'a Int64Chunked,
+    /// The upper bound of every group, in the unit of the times.
+    pub upper: &'a Int64Chunked,
+    /// The first row that may be carried into every group, `0` if `None`.
+    pub partition_start: Option<&'a [IdxSize]>,
+}
+
+/// Computes a time-weighted reduction of `values` for every group, where `times` are the physical
+/// times of the samples. Rows where either is null are skipped.
+///
+/// The times must be sorted within every group. With `windows`, the last sample before the first
+/// row of a group is carried in, and the last sample of a group is held until its upper bound.
+/// `Last` falls back to the carried in sample for groups without samples.
+pub fn time_weighted_reduce(
+    values: &Column,
+    times: &Int64Chunked,
+    groups: &GroupsType,
+    windows: Option<Windows<'_>>,
+    method: TimeWeightedMethod,
+) -> PolarsResult<Column> {
+    polars_ensure!(
+        values.len() == times.len(),
+        ShapeMismatch: "time-weighted aggregations need the 'by' column to have the same length as the values, got {} and {}",
+        times.len(), values.len()
+    );
+    let dtype = values.dtype();
+    polars_ensure!(
+        method == TimeWeightedMethod::Last || dtype.is_primitive_numeric() || dtype.is_bool(),
+        InvalidOperation: "time-weighted {} is not supported for dtype {}", <&str>::from(method), dtype
+    );
+    let times = times.rechunk();
+    let times = times.downcast_as_array();
+    let floats = values
+        .cast(&DataType::Float64)?
+        .as_materialized_series()
+        .rechunk();
+    let floats = floats.f64().unwrap().downcast_as_array();
+    let lower = windows.as_ref().map(|w| w.lower.rechunk());
+    let lower = lower.as_ref().map(|l| l.downcast_as_array());
+    let upper = windows.as_ref().map(|w| w.upper.rechunk());
+    let upper = upper.as_ref().map(|u| u.downcast_as_array());
+
+    let sample = |i: usize| times.get(i).zip(floats.get(i));
+    let carried_in = |group_idx: usize, first: usize| -> Option<(usize, (i64, f64))> {
+        let windows = windows.as_ref()?;
+        let partition_start = windows
+            .partition_start
+            .map_or(0, |starts| starts[group_idx] as usize);
+        (partition_start..first)
+            .rev()
+            .find_map(|i| sample(i).map(|s| (i, s)))
+    };
+    let ensure_sorted = |prev: Option<(i64, f64)>, t: i64| {
+        polars_ensure!(
+            prev.is_none_or(|(prev_t, _)| prev_t <= t),
+            InvalidOperation: "time-weighted aggregations need the 'by' column to be sorted within every group"
+        );
+        Ok(())
+    };
+
+    let name = values.name().clone();
+    if method == TimeWeightedMethod::Last {
+        let idx = groups
+            .iter()
+            .enumerate()
+            .map(|(group_idx, g)| {
+                let mut last = None;
+                for i in group_rows(&g) {
+                    if let Some(s) = sample(i) {
+                        ensure_sorted(last.map(|(_, s)| s), s.0)?;
+                        last = Some((i, s));
+                    }
+                }
+                if last.is_none() {
+                    last = carried_in(group_idx, g.first() as usize);
+                }
+                Ok(last.map(|(i, _)| i as IdxSize))
+            })
+            .collect::<PolarsResult<IdxCa>>()?;
+        // SAFETY: the indices are in bounds.
+        return Ok(unsafe { values.take_unchecked(&idx) });
+    }
+
+    let out: PrimitiveArray<f64> = groups
+        .iter()
+        .enumerate()
+        .map(|(group_idx, g)| {
+            let mut state = TimeWeightedState::new(
+                carried_in(group_idx, g.first() as usize).map(|(_, s)| s),
+                lower.and_then(|l| l.get(group_idx)),
+            );
+            for i in group_rows(&g) {
+                if let Some((t, v)) = sample(i) {
+                    ensure_sorted(state.last, t)?;
+                    state.update(t, v);
+                }
+            }
+            Ok(state.finish(method, upper.and_then(|u| u.get(group_idx))))
+        })
+        .collect::<PolarsResult<_>>()?;
+    Ok(Float64Chunked::with_chunk(name, out).into_column())
+}
+
+fn group_rows<'a>(g: &'a GroupsIndicator<'_>) -> Box<dyn Iterator<Item = usize> + 'a> {
+    match g {
+        GroupsIndicator::Idx((_, idx)) => Box::new(idx.iter().map(|i| *i as usize)),
+        GroupsIndicator::Slice([first, len]) => Box::new(*first as usize..(*first + *len) as usize),
+    }
+}
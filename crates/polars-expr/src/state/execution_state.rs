@@ -99,6 +99,21 @@ impl From<u8> for StateFlags {
     }
 }
 
+/// The windows of the dynamic or rolling group-by that is being aggregated. Used by time-weighted
+/// aggregations to carry in the last value from before each window and to hold the last value
+/// until its end.
+pub struct TimeWindows {
+    /// Time or index column of the group-by.
+    pub index_column: PlSmallStr,
+    /// The lower bound of every window, in the dtype of the index column.
+    pub lower: Column,
+    /// The upper bound of every window, in the dtype of the index column.
+    pub upper: Column,
+    /// The first row of the partition that every window belongs to. Nothing is carried in from
+    /// before it. `None` if the group-by has no keys.
+    pub partition_start: Option<Vec<IdxSize>>,
+}
+
 struct CachedValue {
     /// The number of times the cache will still be read.
     /// Zero means that there will be no more reads and the cache can be dropped.
@@ -118,6 +133,8 @@ pub struct ExecutionState {
     pub branch_idx: usize,
     pub flags: RelaxedCell<u8>,
     pub ext_contexts: Arc<Vec<DataFrame>>,
+    /// Set while the aggregations of a dynamic or rolling group-by are evaluated.
+    pub time_windows: Option<Arc<TimeWindows>>,
     node_timer: Option<NodeTimer>,
     stop: Arc<RelaxedCell<bool>>,
 }
@@ -135,6 +152,7 @@ impl ExecutionState {
             branch_idx: 0,
             flags: RelaxedCell::from(StateFlags::init().as_u8()),
             ext_contexts: Default::default(),
+            time_windows: None,
             node_timer: None,
             stop: Arc::new(RelaxedCell::from(false)),
         }
@@ -199,6 +217,8 @@ impl ExecutionState {
             branch_idx: self.branch_idx,
             flags: self.flags.clone(),
             ext_contexts: self.ext_contexts.clone(),
+            // Expressions split the state while evaluating the aggregations of a window.
+            time_windows: self.time_windows.clone(),
             node_timer: self.node_timer.clone(),
             stop: self.stop.clone(),
         }
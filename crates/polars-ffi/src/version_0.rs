@@ -175,4 +175,31 @@ mod test {
             assert_eq!(import_series(e).unwrap(), s);
         };
     }
+
+    #[test]
+    fn test_ffi_extension() {
+        let s = Series::new("a".into(), [Some(1i64), None])
+            .into_extension("my.ext".into(), Some("{}".into()));
+        let e = export_series(&s);
+
+        let out = unsafe { import_series(e).unwrap() };
+        assert_eq!(out.dtype(), s.dtype());
+        assert!(out.equals_missing(&s));
+    }
+
+    #[test]
+    fn test_ffi_union() {
+        let a = Series::new("a".into(), [Some(1i64), None, None]);
+        let b = Series::new("b".into(), [None, Some("x"), None]);
+        let s = StructChunked::from_series("u".into(), 3, [a, b].iter())
+            .unwrap()
+            .into_union(UnionMode::Sparse)
+            .unwrap()
+            .into_series();
+        let e = export_series(&s);
+
+        let out = unsafe { import_series(e).unwrap() };
+        assert_eq!(out.dtype(), s.dtype());
+        assert!(out.equals_missing(&s));
+    }
 }
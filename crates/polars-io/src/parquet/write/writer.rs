@@ -203,7 +203,7 @@ fn to_column_write_options_rec(
                 encoding: encoding_map(field.dtype()),
             });
         },
-        List | FixedSizeList | LargeList => {
+        List | FixedSizeList | LargeList | Map => {
             let child_overwrites = overwrites.and_then(|o| match &o.children {
                 ChildFieldOverwrites::None => None,
                 ChildFieldOverwrites::ListLike(child_overwrites) => Some(child_overwrites.as_ref()),
@@ -217,6 +217,8 @@ fn to_column_write_options_rec(
                 to_column_write_options_rec(inner, child_overwrites)
             } else if let ArrowDataType::FixedSizeList(inner, _) = a {
                 to_column_write_options_rec(inner, child_overwrites)
+            } else if let ArrowDataType::Map(inner, _) = a {
+                to_column_write_options_rec(inner, child_overwrites)
             } else {
                 unreachable!()
             };
@@ -253,7 +255,7 @@ fn to_column_write_options_rec(
             }
         },
 
-        Map | Union => unreachable!(),
+        Union => unreachable!(),
     }
 
     column_options
@@ -286,7 +288,9 @@ fn encoding_map(dtype: &ArrowDataType) -> Encoding {
         PhysicalType::Primitive(dt) => {
             use arrow::types::PrimitiveType::*;
             match dt {
-                Float32 | Float64 | Float16 => Encoding::Plain,
+                // Intervals and 256-bit decimals are written as fixed-size binary, which isn't
+                // dictionary encoded.
+                Float32 | Float64 | Float16 | MonthDayNano | Int256 => Encoding::Plain,
                 _ => Encoding::RleDictionary,
             }
         },
@@ -76,6 +76,90 @@ pub fn concat<L: AsRef<[LazyFrame]>>(inputs: L, args: UnionArgs) -> PolarsResult
     concat_impl(inputs, args)
 }
 
+/// Arguments for [`concat_recursive`].
+#[derive(Clone, Copy, Debug)]
+pub struct RecursiveUnionArgs {
+    /// Drop rows that were already produced by an earlier iteration (`UNION` vs `UNION ALL`).
+    pub distinct: bool,
+    /// Bail if no fixpoint is reached after this many iterations.
+    pub max_iterations: usize,
+}
+
+impl Default for RecursiveUnionArgs {
+    fn default() -> Self {
+        Self {
+            distinct: false,
+            max_iterations: 1000,
+        }
+    }
+}
+
+/// Iteratively union the output of `step` onto `base` until a fixpoint is reached.
+///
+/// `step` is called with the rows produced by the previous iteration (starting with `base`)
+/// and returns the rows of the next iteration; iteration stops once it produces no (new) rows.
+/// The output of `step` is matched to the schema of `base` by position. This is the building
+/// block for recursive common table expressions; every iteration is materialized.
+pub fn concat_recursive<F>(
+    base: LazyFrame,
+    mut step: F,
+    args: RecursiveUnionArgs,
+) -> PolarsResult<LazyFrame>
+where
+    F: FnMut(LazyFrame) -> PolarsResult<LazyFrame>,
+{
+    let mut result = base.collect()?;
+    if args.distinct {
+        result = result
+            .lazy()
+            .unique_stable(None, UniqueKeepStrategy::First)
+            .collect()?;
+    }
+    let schema = result.schema().clone();
+    let mut working = result.clone();
+    let mut iterations = 0;
+
+    while working.height() > 0 {
+        polars_ensure!(
+            iterations < args.max_iterations,
+            ComputeError: "recursive query did not reach a fixpoint within {} iterations",
+            args.max_iterations
+        );
+        iterations += 1;
+
+        let mut lf = step(working.lazy())?;
+        let step_schema = lf.collect_schema()?;
+        polars_ensure!(
+            step_schema.len() == schema.len(),
+            SchemaMismatch: "recursive step returned {} columns; expected {}",
+            step_schema.len(), schema.len()
+        );
+        let exprs = step_schema
+            .iter_names()
+            .zip(schema.iter())
+            .map(|(step_name, (name, dtype))| {
+                col(step_name.clone())
+                    .cast(dtype.clone())
+                    .alias(name.clone())
+            })
+            .collect::<Vec<_>>();
+        let new = lf.select(exprs).collect()?;
+
+        if args.distinct {
+            let offset = result.height() as i64;
+            result = concat([result.lazy(), new.lazy()], UnionArgs::default())?
+                .unique_stable(None, UniqueKeepStrategy::First)
+                .collect()?;
+            working = result.slice(offset, usize::MAX);
+        } else {
+            result.vstack_mut(&new)?;
+            working = new;
+        }
+    }
+    result.rechunk_mut();
+    Ok(result.lazy())
+}
+
 /// Collect all [`LazyFrame`] computations.
 pub fn collect_all<I>(lfs: I) -> PolarsResult<Vec<DataFrame>>
 where
@@ -92,6 +176,26 @@ mod test {
     #[allow(unused_imports)]
     use super::*;
 
+    #[test]
+    fn test_concat_recursive() -> PolarsResult<()> {
+        let base = df!["n" => [1i64]]?.lazy();
+        let step = |lf: LazyFrame| {
+            Ok(lf
+                .select([col("n") + lit(1i64)])
+                .filter(col("n").lt_eq(lit(5i64))))
+        };
+
+        let out = concat_recursive(base.clone(), step, RecursiveUnionArgs::default())?.collect()?;
+        assert!(out.equals(&df!["n" => [1i64, 2, 3, 4, 5]]?));
+
+        let args = RecursiveUnionArgs {
+            max_iterations: 2,
+            ..Default::default()
+        };
+        assert!(concat_recursive(base, step, args).is_err());
+        Ok(())
+    }
+
     #[test]
     #[cfg(feature = "diagonal_concat")]
     fn test_diag_concat_lf() -> PolarsResult<()> {
@@ -1272,6 +1272,7 @@ impl LazyFrame {
                 maintain_order: false,
                 dynamic_options: None,
                 rolling_options: None,
+                session_options: None,
             }
         }
 
@@ -1320,6 +1321,7 @@ impl LazyFrame {
             maintain_order: true,
             dynamic_options: None,
             rolling_options: Some(options),
+            session_options: None,
         }
     }
 
@@ -1365,6 +1367,48 @@ impl LazyFrame {
             maintain_order: true,
             dynamic_options: Some(options),
             rolling_options: None,
+            session_options: None,
+        }
+    }
+
+    /// Group rows into sessions based on a time value (or index value of type UInt32,
+    /// UInt64, Int32, or Int64).
+    ///
+    /// A session is a run of consecutive rows in which every value is at most `gap` after
+    /// the previous one; a larger gap starts a new session. The index column in the
+    /// output holds the first value of every session. Set `include_boundaries` to also
+    /// get the first value of the session as `_lower_boundary` and the last value plus
+    /// `gap` as `_upper_boundary`.
+    ///
+    /// The index column must be sorted (within every group if `group_by` is given).
+    #[cfg(feature = "dynamic_group_by")]
+    pub fn group_by_session<E: AsRef<[Expr]>>(
+        mut self,
+        index_column: Expr,
+        group_by: E,
+        mut options: SessionGroupOptions,
+    ) -> LazyGroupBy {
+        if let Expr::Column(name) = index_column {
+            options.index_column = name;
+        } else {
+            let output_field = index_column
+                .to_field(&self.collect_schema().unwrap())
+                .unwrap();
+            return self.with_column(index_column).group_by_session(
+                Expr::Column(output_field.name().clone()),
+                group_by,
+                options,
+            );
+        }
+        let opt_state = self.get_opt_state();
+        LazyGroupBy {
+            logical_plan: self.logical_plan,
+            opt_state,
+            keys: group_by.as_ref().to_vec(),
+            maintain_order: true,
+            dynamic_options: None,
+            rolling_options: None,
+            session_options: Some(options),
         }
     }
 
@@ -1386,6 +1430,7 @@ impl LazyFrame {
                 maintain_order: true,
                 dynamic_options: None,
                 rolling_options: None,
+                session_options: None,
             }
         }
 
@@ -1952,6 +1997,30 @@ impl LazyFrame {
         Self::from_logical_plan(lp, opt_state)
     }
 
+    /// Upsample the DataFrame at a regular frequency.
+    ///
+    /// Generates the time grid from the first to the last value of `index_column`, for every
+    /// group of `by` if given, and joins the existing rows onto it. The new rows get the keys of
+    /// their group and their other columns are filled according to `fill`.
+    ///
+    /// The index column has to be sorted within the groups. See
+    /// [`PolarsUpsample`](polars_time::PolarsUpsample) for the `every` string language.
+    #[cfg(feature = "upsample")]
+    pub fn upsample(
+        self,
+        index_column: &str,
+        by: impl IntoVec<PlSmallStr>,
+        every: Duration,
+        fill: UpsampleFill,
+    ) -> LazyFrame {
+        self.map_private(DslFunction::Upsample(UpsampleArgs {
+            index_column: index_column.into(),
+            by: by.into_vec().into(),
+            every,
+            fill,
+        }))
+    }
+
     /// Limit the DataFrame to the first `n` rows.
     pub fn limit(self, n: IdxSize) -> LazyFrame {
         self.slice(0, n)
@@ -2099,6 +2168,8 @@ pub struct LazyGroupBy {
     dynamic_options: Option<DynamicGroupOptions>,
     #[cfg(feature = "dynamic_group_by")]
     rolling_options: Option<RollingGroupOptions>,
+    #[cfg(feature = "dynamic_group_by")]
+    session_options: Option<SessionGroupOptions>,
 }
 
 impl From<LazyGroupBy> for LazyFrame {
@@ -2134,25 +2205,25 @@ impl LazyGroupBy {
     /// }
     /// ```
     pub fn agg<E: AsRef<[Expr]>>(self, aggs: E) -> LazyFrame {
-        #[cfg(feature = "dynamic_group_by")]
-        let lp = DslBuilder::from(self.logical_plan)
-            .group_by(
-                self.keys,
-                aggs,
-                None,
-                self.maintain_order,
-                self.dynamic_options,
-                self.rolling_options,
-            )
-            .build();
-
-        #[cfg(not(feature = "dynamic_group_by"))]
+        let options = self.options();
         let lp = DslBuilder::from(self.logical_plan)
-            .group_by(self.keys, aggs, None, self.maintain_order)
+            .group_by(self.keys, aggs, None, self.maintain_order, options)
             .build();
         LazyFrame::from_logical_plan(lp, self.opt_state)
     }
 
+    fn options(&self) -> GroupbyOptions {
+        GroupbyOptions {
+            #[cfg(feature = "dynamic_group_by")]
+            dynamic: self.dynamic_options.clone(),
+            #[cfg(feature = "dynamic_group_by")]
+            rolling: self.rolling_options.clone(),
+            #[cfg(feature = "dynamic_group_by")]
+            session: self.session_options.clone(),
+            slice: None,
+        }
+    }
+
     /// Return first n rows of each group
     pub fn head(self, n: Option<usize>) -> LazyFrame {
         let keys = self
@@ -2182,16 +2253,7 @@ impl LazyGroupBy {
     /// **It is not recommended that you use this as materializing the DataFrame is very
     /// expensive.**
     pub fn apply(self, f: PlanCallback<DataFrame, DataFrame>, schema: SchemaRef) -> LazyFrame {
-        #[cfg(feature = "dynamic_group_by")]
-        let options = GroupbyOptions {
-            dynamic: self.dynamic_options,
-            rolling: self.rolling_options,
-            slice: None,
-        };
-
-        #[cfg(not(feature = "dynamic_group_by"))]
-        let options = GroupbyOptions { slice: None };
-
+        let options = self.options();
         let lp = DslPlan::GroupBy {
             input: Arc::new(self.logical_plan),
             keys: self.keys,
@@ -14,12 +14,16 @@ pub use polars_ops::prelude::{RankMethod, RankOptions};
 pub use polars_plan::client::prepare_cloud_plan;
 pub use polars_plan::dsl::AnonymousScanOptions;
 pub use polars_plan::plans::{AnonymousScan, AnonymousScanArgs, Literal, LiteralValue, NULL, Null};
+#[cfg(feature = "upsample")]
+pub use polars_plan::plans::{UpsampleArgs, UpsampleFill};
 pub(crate) use polars_plan::prelude::*;
 pub use polars_plan::prelude::{PlanCallback, UnionArgs};
-#[cfg(feature = "rolling_window_by")]
+#[cfg(any(feature = "rolling_window_by", feature = "upsample"))]
 pub use polars_time::Duration;
 #[cfg(feature = "dynamic_group_by")]
-pub use polars_time::{DynamicGroupOptions, PolarsTemporalGroupby, RollingGroupOptions};
+pub use polars_time::{
+    DynamicGroupOptions, PolarsTemporalGroupby, RollingGroupOptions, SessionGroupOptions,
+};
 pub(crate) use polars_utils::arena::{Arena, Node};
 
 pub use crate::dsl::*;
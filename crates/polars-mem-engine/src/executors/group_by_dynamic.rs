@@ -9,6 +9,8 @@ pub(crate) struct GroupByDynamicExec {
     pub(crate) aggs: Vec<Arc<dyn PhysicalExpr>>,
     #[cfg(feature = "dynamic_group_by")]
     pub(crate) options: DynamicGroupOptions,
+    /// Whether the aggregations need the window bounds, see [`TimeWindows`].
+    pub(crate) time_weighted: bool,
     pub(crate) input_schema: SchemaRef,
     pub(crate) slice: Option<(i64, usize)>,
     pub(crate) apply: Option<PlanCallback<DataFrame, DataFrame>>,
@@ -21,7 +23,9 @@ impl GroupByDynamicExec {
         state: &ExecutionState,
         mut df: DataFrame,
     ) -> PolarsResult<DataFrame> {
-        use crate::executors::group_by_rolling::sort_and_groups;
+        use crate::executors::group_by_rolling::{
+            partition_starts, sort_and_groups, state_with_time_windows,
+        };
 
         df.as_single_chunk_par();
 
@@ -37,7 +41,32 @@ impl GroupByDynamicExec {
             None
         };
 
-        let (mut time_key, bounds, groups) = df.group_by_dynamic(group_by, &self.options)?;
+        // Time-weighted aggregations need the bounds of the windows.
+        let options = if self.time_weighted && !self.options.include_boundaries {
+            Cow::Owned(DynamicGroupOptions {
+                include_boundaries: true,
+                ..self.options.clone()
+            })
+        } else {
+            Cow::Borrowed(&self.options)
+        };
+        let partitions = self.time_weighted.then(|| group_by.clone()).flatten();
+
+        let (mut time_key, mut bounds, groups) = df.group_by_dynamic(group_by, &options)?;
+        let windows = match bounds.as_slice() {
+            [lower, upper] if self.time_weighted => Some(TimeWindows {
+                index_column: self.options.index_column.clone(),
+                lower: lower.clone(),
+                upper: upper.clone(),
+                partition_start: partitions.map(|partitions| {
+                    partition_starts(&partitions, groups.iter().map(|g| g.first()))
+                }),
+            }),
+            _ => None,
+        };
+        if !self.options.include_boundaries {
+            bounds.clear();
+        }
         POOL.install(|| {
             keys.iter_mut().for_each(|key| {
                 unsafe { *key = key.agg_first(&groups) };
@@ -73,7 +102,8 @@ impl GroupByDynamicExec {
             }
         }
 
-        let agg_columns = evaluate_aggs(&df, &self.aggs, groups, state)?;
+        let state = state_with_time_windows(state, windows, self.slice);
+        let agg_columns = evaluate_aggs(&df, &self.aggs, groups, &state)?;
 
         let mut columns = Vec::with_capacity(agg_columns.len() + 1 + keys.len());
         columns.extend_from_slice(&keys);
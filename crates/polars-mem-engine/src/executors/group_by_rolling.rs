@@ -1,3 +1,5 @@
+#[cfg(feature = "dynamic_group_by")]
+use polars_core::utils::slice_offsets;
 use polars_utils::unique_column_name;
 
 use super::*;
@@ -9,6 +11,8 @@ pub(crate) struct GroupByRollingExec {
     pub(crate) aggs: Vec<Arc<dyn PhysicalExpr>>,
     #[cfg(feature = "dynamic_group_by")]
     pub(crate) options: RollingGroupOptions,
+    /// Whether the aggregations need the window bounds, see [`TimeWindows`].
+    pub(crate) time_weighted: bool,
     pub(crate) input_schema: SchemaRef,
     pub(crate) slice: Option<(i64, usize)>,
     pub(crate) apply: Option<PlanCallback<DataFrame, DataFrame>>,
@@ -58,6 +62,48 @@ pub(super) fn sort_and_groups(
     Ok(groups)
 }
 
+/// The first row of the partition of every group, found by walking the sorted partitions along
+/// the first rows of the groups.
+#[cfg(feature = "dynamic_group_by")]
+pub(super) fn partition_starts(
+    partitions: &[[IdxSize; 2]],
+    firsts: impl Iterator<Item = IdxSize>,
+) -> Vec<IdxSize> {
+    let mut p = 0;
+    firsts
+        .map(|first| {
+            while p + 1 < partitions.len() && partitions[p + 1][0] <= first {
+                p += 1;
+            }
+            partitions[p][0]
+        })
+        .collect()
+}
+
+/// Returns the state the aggregations are evaluated with, which holds the window bounds if
+/// `windows` is set.
+#[cfg(feature = "dynamic_group_by")]
+pub(super) fn state_with_time_windows(
+    state: &ExecutionState,
+    windows: Option<TimeWindows>,
+    slice: Option<(i64, usize)>,
+) -> Cow<'_, ExecutionState> {
+    let Some(mut windows) = windows else {
+        return Cow::Borrowed(state);
+    };
+    if let Some((offset, len)) = slice {
+        windows.lower = windows.lower.slice(offset, len);
+        windows.upper = windows.upper.slice(offset, len);
+        if let Some(starts) = &mut windows.partition_start {
+            let (offset, len) = slice_offsets(offset, len, starts.len());
+            *starts = starts[offset..offset + len].to_vec();
+        }
+    }
+    let mut state = state.clone();
+    state.time_windows = Some(Arc::new(windows));
+    Cow::Owned(state)
+}
+
 impl GroupByRollingExec {
     #[cfg(feature = "dynamic_group_by")]
     fn execute_impl(
@@ -79,6 +125,25 @@ impl GroupByRollingExec {
             None
         };
 
+        let windows = if self.time_weighted {
+            let time = df.column(&self.options.index_column)?;
+            let partition_start = group_by.as_ref().map(|partitions| {
+                partitions
+                    .iter()
+                    .flat_map(|[start, len]| std::iter::repeat_n(*start, *len as usize))
+                    .collect()
+            });
+            let (lower, upper) = rolling_bounds(time, self.options.offset, self.options.period)?;
+            Some(TimeWindows {
+                index_column: self.options.index_column.clone(),
+                lower,
+                upper,
+                partition_start,
+            })
+        } else {
+            None
+        };
+
         let (mut time_key, groups) = df.rolling(group_by, &self.options)?;
 
         if let Some(f) = &self.apply {
@@ -106,7 +171,8 @@ impl GroupByRollingExec {
             }
         }
 
-        let agg_columns = evaluate_aggs(&df, &self.aggs, groups, state)?;
+        let state = state_with_time_windows(state, windows, self.slice);
+        let agg_columns = evaluate_aggs(&df, &self.aggs, groups, &state)?;
 
         let mut columns = Vec::with_capacity(agg_columns.len() + 1 + keys.len());
         columns.extend_from_slice(&keys);
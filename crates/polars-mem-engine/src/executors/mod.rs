@@ -6,6 +6,7 @@ mod group_by;
 mod group_by_dynamic;
 mod group_by_partitioned;
 pub(super) mod group_by_rolling;
+mod group_by_session;
 mod hconcat;
 mod join;
 #[cfg(feature = "merge_sorted")]
@@ -39,6 +40,8 @@ pub(super) use self::group_by_dynamic::*;
 pub(super) use self::group_by_partitioned::*;
 #[cfg(feature = "dynamic_group_by")]
 pub(super) use self::group_by_rolling::GroupByRollingExec;
+#[cfg(feature = "dynamic_group_by")]
+pub(super) use self::group_by_session::*;
 pub(super) use self::hconcat::*;
 pub(super) use self::join::*;
 #[cfg(feature = "merge_sorted")]
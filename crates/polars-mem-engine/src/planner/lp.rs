@@ -657,6 +657,10 @@ fn create_physical_plan_impl(
 
             let _slice = options.slice;
             #[cfg(feature = "dynamic_group_by")]
+            let time_weighted = aggs
+                .iter()
+                .any(|e| polars_expr::planner::has_time_weighted_agg(e.node(), expr_arena));
+            #[cfg(feature = "dynamic_group_by")]
             if let Some(options) = options.dynamic {
                 let input = recurse!(input, state)?;
                 return Ok(Box::new(executors::GroupByDynamicExec {
@@ -664,6 +668,7 @@ fn create_physical_plan_impl(
                     keys: phys_keys,
                     aggs: phys_aggs,
                     options,
+                    time_weighted,
                     input_schema,
                     slice: _slice,
                     apply,
@@ -674,6 +679,21 @@ fn create_physical_plan_impl(
             if let Some(options) = options.rolling {
                 let input = recurse!(input, state)?;
                 return Ok(Box::new(executors::GroupByRollingExec {
+                    input,
+                    keys: phys_keys,
+                    aggs: phys_aggs,
+                    options,
+                    time_weighted,
+                    input_schema,
+                    slice: _slice,
+                    apply,
+                }));
+            }
+
+            #[cfg(feature = "dynamic_group_by")]
+            if let Some(options) = options.session {
+                let input = recurse!(input, state)?;
+                return Ok(Box::new(executors::GroupBySessionExec {
                     input,
                     keys: phys_keys,
                     aggs: phys_aggs,
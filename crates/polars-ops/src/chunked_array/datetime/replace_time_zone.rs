@@ -4,11 +4,25 @@ use arrow::legacy::kernels::convert_to_naive_local;
 use arrow::temporal_conversions::{
     timestamp_ms_to_datetime, timestamp_ns_to_datetime, timestamp_us_to_datetime,
 };
-use chrono::NaiveDateTime;
+use chrono::{NaiveDateTime, TimeZone as _};
 use chrono_tz::UTC;
-use polars_core::chunked_array::ops::arity::try_binary_elementwise;
+use polars_core::chunked_array::ops::arity::{
+    broadcast_try_binary_elementwise, try_binary_elementwise, try_ternary_elementwise,
+};
 use polars_core::prelude::*;
 
+type TimestampToDatetime = fn(i64) -> NaiveDateTime;
+type DatetimeToTimestamp = fn(NaiveDateTime) -> i64;
+
+/// The conversions between timestamps in the given time unit and naive datetimes.
+fn timestamp_conversions(time_unit: TimeUnit) -> (TimestampToDatetime, DatetimeToTimestamp) {
+    match time_unit {
+        TimeUnit::Milliseconds => (timestamp_ms_to_datetime, datetime_to_timestamp_ms),
+        TimeUnit::Microseconds => (timestamp_us_to_datetime, datetime_to_timestamp_us),
+        TimeUnit::Nanoseconds => (timestamp_ns_to_datetime, datetime_to_timestamp_ns),
+    }
+}
+
 pub fn replace_time_zone(
     datetime: &Logical<DatetimeType, Int64Type>,
     time_zone: Option<&TimeZone>,
@@ -36,16 +50,8 @@ pub fn replace_time_zone(
             .set_sorted_flag(datetime.physical().is_sorted_flag());
         return Ok(out);
     }
-    let timestamp_to_datetime: fn(i64) -> NaiveDateTime = match datetime.time_unit() {
-        TimeUnit::Milliseconds => timestamp_ms_to_datetime,
-        TimeUnit::Microseconds => timestamp_us_to_datetime,
-        TimeUnit::Nanoseconds => timestamp_ns_to_datetime,
-    };
-    let datetime_to_timestamp: fn(NaiveDateTime) -> i64 = match datetime.time_unit() {
-        TimeUnit::Milliseconds => datetime_to_timestamp_ms,
-        TimeUnit::Microseconds => datetime_to_timestamp_us,
-        TimeUnit::Nanoseconds => datetime_to_timestamp_ns,
-    };
+    let (timestamp_to_datetime, datetime_to_timestamp) =
+        timestamp_conversions(datetime.time_unit());
 
     let out = if ambiguous.len() == 1
         && ambiguous.get(0) != Some("null")
@@ -162,3 +168,128 @@ pub fn impl_replace_time_zone(
         ),
     }
 }
+
+fn parse_row_time_zone(
+    cache: &mut PlHashMap<PlSmallStr, chrono_tz::Tz>,
+    time_zone: &str,
+) -> PolarsResult<chrono_tz::Tz> {
+    if let Some(tz) = cache.get(time_zone) {
+        return Ok(*tz);
+    }
+    let tz = match TimeZone::opt_try_new(Some(time_zone))? {
+        Some(tz) => tz.to_chrono()?,
+        None => chrono_tz::UTC,
+    };
+    cache.insert(PlSmallStr::from_str(time_zone), tz);
+    Ok(tz)
+}
+
+fn broadcast_str(ca: &StringChunked, len: usize) -> StringChunked {
+    if ca.len() == len {
+        ca.clone()
+    } else {
+        ca.new_from_index(0, len)
+    }
+}
+
+/// Replace the time zone of each datetime by the time zone in the same row of `time_zones`,
+/// keeping the wall time.
+///
+/// As a column can only have a single time zone, the result is in UTC. Rows without a time
+/// zone are null.
+pub fn replace_time_zone_by(
+    datetime: &Logical<DatetimeType, Int64Type>,
+    time_zones: &StringChunked,
+    ambiguous: &StringChunked,
+    non_existent: NonExistent,
+) -> PolarsResult<DatetimeChunked> {
+    let len = [datetime.len(), time_zones.len(), ambiguous.len()]
+        .into_iter()
+        .max()
+        .unwrap();
+    for other_len in [datetime.len(), time_zones.len(), ambiguous.len()] {
+        polars_ensure!(
+            other_len == len || other_len == 1,
+            length_mismatch = "dt.replace_time_zone_by",
+            len,
+            other_len
+        );
+    }
+    let from_tz = datetime
+        .time_zone()
+        .clone()
+        .unwrap_or(TimeZone::UTC)
+        .to_chrono()?;
+    let (timestamp_to_datetime, datetime_to_timestamp) =
+        timestamp_conversions(datetime.time_unit());
+
+    let phys = if datetime.len() == len {
+        datetime.physical().clone()
+    } else {
+        datetime.physical().new_from_index(0, len)
+    };
+    let mut cache = PlHashMap::new();
+    let out: PolarsResult<Int64Chunked> = try_ternary_elementwise(
+        &phys,
+        &broadcast_str(time_zones, len),
+        &broadcast_str(ambiguous, len),
+        |timestamp_opt, time_zone_opt, ambiguous_opt| match (
+            timestamp_opt,
+            time_zone_opt,
+            ambiguous_opt,
+        ) {
+            (Some(timestamp), Some(time_zone), Some(ambiguous)) => {
+                let to_tz = parse_row_time_zone(&mut cache, time_zone)?;
+                Ok(convert_to_naive_local(
+                    &from_tz,
+                    &to_tz,
+                    timestamp_to_datetime(timestamp),
+                    Ambiguous::from_str(ambiguous)?,
+                    non_existent,
+                )?
+                .map(datetime_to_timestamp))
+            },
+            _ => Ok(None),
+        },
+    );
+    Ok(out?
+        .with_name(datetime.name().clone())
+        .into_datetime(datetime.time_unit(), Some(TimeZone::UTC)))
+}
+
+/// Convert each datetime to the wall time in the time zone in the same row of `time_zones`.
+///
+/// As a column can only have a single time zone, the result is time zone naive; use it to
+/// extract local components such as the hour or the date. Time zone naive input is taken to
+/// be in UTC. Rows without a time zone are null.
+pub fn convert_time_zone_by(
+    datetime: &Logical<DatetimeType, Int64Type>,
+    time_zones: &StringChunked,
+) -> PolarsResult<DatetimeChunked> {
+    let (timestamp_to_datetime, datetime_to_timestamp) =
+        timestamp_conversions(datetime.time_unit());
+    polars_ensure!(
+        datetime.len() == time_zones.len() || datetime.len() == 1 || time_zones.len() == 1,
+        length_mismatch = "dt.convert_time_zone_by",
+        datetime.len(),
+        time_zones.len()
+    );
+    let mut cache = PlHashMap::new();
+    let out: PolarsResult<Int64Chunked> = broadcast_try_binary_elementwise(
+        datetime.physical(),
+        time_zones,
+        |timestamp_opt, time_zone_opt| match (timestamp_opt, time_zone_opt) {
+            (Some(timestamp), Some(time_zone)) => {
+                let tz = parse_row_time_zone(&mut cache, time_zone)?;
+                let ndt = timestamp_to_datetime(timestamp);
+                Ok(Some(datetime_to_timestamp(
+                    tz.from_utc_datetime(&ndt).naive_local(),
+                )))
+            },
+            _ => Ok(None),
+        },
+    );
+    Ok(out?
+        .with_name(datetime.name().clone())
+        .into_datetime(datetime.time_unit(), None))
+}
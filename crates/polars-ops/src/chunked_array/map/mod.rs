@@ -0,0 +1,126 @@
+use arrow::array::{Array, ListArray, StructArray};
+use polars_core::prelude::*;
+
+pub trait AsMap {
+    fn as_map(&self) -> &MapChunked;
+}
+
+impl AsMap for MapChunked {
+    fn as_map(&self) -> &MapChunked {
+        self
+    }
+}
+
+/// Get the list of one of the fields (0 for the keys, 1 for the values) of the map entries.
+fn entries_field(ca: &MapChunked, field_idx: usize, dtype: &DataType) -> ListChunked {
+    let chunks = ca
+        .entries()
+        .downcast_iter()
+        .map(|arr| {
+            let values = arr.values().as_any().downcast_ref::<StructArray>().unwrap();
+            let field = values.values()[field_idx].clone();
+            ListArray::<i64>::new(
+                ListArray::<i64>::default_datatype(field.dtype().clone()),
+                arr.offsets().clone(),
+                field,
+                arr.validity().cloned(),
+            )
+            .boxed()
+        })
+        .collect();
+    // SAFETY: the fields of the entries are of the key and value types.
+    unsafe {
+        ListChunked::from_chunks_and_dtype(
+            ca.name().clone(),
+            chunks,
+            DataType::List(Box::new(dtype.clone())),
+        )
+    }
+}
+
+/// Find, for every map, the position in the flattened entries of the value stored under the
+/// corresponding `key`. Null if the key isn't in the map, or if the map or key is null.
+fn lookup_indices(ca: &MapChunked, key: &Series) -> PolarsResult<(IdxCa, Series)> {
+    polars_ensure!(
+        key.len() == 1 || key.len() == ca.len(),
+        length_mismatch = "map.get",
+        ca.len(),
+        key.len()
+    );
+    let key = key.strict_cast(ca.key_dtype())?;
+    let entries = ca.entries().rechunk();
+    let arr = entries.downcast_as_array();
+    let fields = entries.get_inner().struct_()?.fields_as_series();
+    let (keys, values) = (&fields[0], &fields[1]);
+
+    let mask = if key.len() == 1 {
+        keys.equal(&key)?
+    } else {
+        // Broadcast the keys to look up to the entries of their map.
+        let mut owners = vec![0 as IdxSize; keys.len()];
+        for (i, (start, len)) in arr.offsets().offset_and_length_iter().enumerate() {
+            owners[start..start + len].fill(i as IdxSize);
+        }
+        let owners = IdxCa::from_vec(PlSmallStr::EMPTY, owners);
+        keys.equal(&key.take(&owners)?)?
+    };
+    let mask = mask.rechunk();
+    let mask = mask.downcast_as_array();
+
+    let idx: IdxCa = arr
+        .offsets()
+        .offset_and_length_iter()
+        .enumerate()
+        .map(|(i, (start, len))| {
+            if !arr.is_valid(i) {
+                return None;
+            }
+            (start..start + len)
+                .find(|&j| mask.get(j) == Some(true))
+                .map(|j| j as IdxSize)
+        })
+        .collect();
+    Ok((idx, values.clone()))
+}
+
+pub trait MapNameSpace: AsMap {
+    /// Get the value stored under `key`, or null if the map doesn't contain it.
+    fn map_get(&self, key: &Series) -> PolarsResult<Series> {
+        let ca = self.as_map();
+        let (idx, values) = lookup_indices(ca, key)?;
+        let mut out = values.take(&idx)?;
+        out.rename(ca.name().clone());
+        Ok(out)
+    }
+
+    /// Check whether the map contains `key`.
+    fn map_contains_key(&self, key: &Series) -> PolarsResult<BooleanChunked> {
+        let ca = self.as_map();
+        let (idx, _) = lookup_indices(ca, key)?;
+        let out: BooleanChunked = idx
+            .iter()
+            .zip(ca.entries().iter())
+            .map(|(idx, map)| map.map(|_| idx.is_some()))
+            .collect();
+        Ok(out.with_name(ca.name().clone()))
+    }
+
+    /// Get the keys of every map as a list.
+    fn map_keys(&self) -> ListChunked {
+        let ca = self.as_map();
+        entries_field(ca, 0, ca.key_dtype())
+    }
+
+    /// Get the values of every map as a list.
+    fn map_values(&self) -> ListChunked {
+        let ca = self.as_map();
+        entries_field(ca, 1, ca.value_dtype())
+    }
+
+    /// Get the entries of every map as a list of `{key, value}` structs.
+    fn map_to_entries(&self) -> ListChunked {
+        self.as_map().entries().clone()
+    }
+}
+
+impl MapNameSpace for MapChunked {}
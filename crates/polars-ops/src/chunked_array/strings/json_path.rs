@@ -139,6 +139,93 @@ pub trait Utf8JsonPathImpl: AsString {
 
 impl Utf8JsonPathImpl for StringChunked {}
 
+/// A JSON path, either one we can follow on the binary JSON encoding or one that needs a
+/// `serde_json` value.
+#[cfg(feature = "dtype-json")]
+enum JsonPath<'a> {
+    Simple(Vec<JsonPathElement>),
+    Compiled(PathCompiled<'a>),
+}
+
+#[cfg(feature = "dtype-json")]
+impl<'a> JsonPath<'a> {
+    fn compile(path: &'a str) -> PolarsResult<Self> {
+        if let Some(path) = parse_simple_json_path(path) {
+            return Ok(Self::Simple(path));
+        }
+        PathCompiled::compile(path)
+            .map(Self::Compiled)
+            .map_err(|e| polars_err!(ComputeError: "error compiling JSON path expression {}", e))
+    }
+
+    fn extract(&self, json: JsonRef<'_>) -> Option<String> {
+        match self {
+            Self::Simple(path) => {
+                let value = json.get_path(path)?;
+                match value.as_str() {
+                    Some(s) => Some(s.to_string()),
+                    None if value.is_null() => None,
+                    None => Some(value.to_json_string()),
+                }
+            },
+            Self::Compiled(path) => {
+                let value = json.to_value();
+                let result = path.select(&value).ok()?;
+                match *result.first()? {
+                    Value::String(s) => Some(s.clone()),
+                    Value::Null => None,
+                    v => Some(v.to_string()),
+                }
+            },
+        }
+    }
+}
+
+#[cfg(feature = "dtype-json")]
+pub trait JsonJsonPathImpl {
+    /// Extract json path, first match, like [`Utf8JsonPathImpl::json_path_match`].
+    ///
+    /// Paths of only keys and indices are followed on the binary encoding, other paths
+    /// convert the documents to `serde_json` values, but never reparse text.
+    fn json_path_match(&self, json_path: &StringChunked) -> PolarsResult<StringChunked>;
+}
+
+#[cfg(feature = "dtype-json")]
+impl JsonJsonPathImpl for JsonChunked {
+    fn json_path_match(&self, json_path: &StringChunked) -> PolarsResult<StringChunked> {
+        let ca = self.physical();
+        match (ca.len(), json_path.len()) {
+            (_, 1) => {
+                // SAFETY: `json_path` was verified to have exactly 1 element.
+                let opt_path = unsafe { json_path.get_unchecked(0) };
+                let out = if let Some(path) = opt_path {
+                    let path = JsonPath::compile(path)?;
+                    let iter = ca
+                        .iter()
+                        .map(|opt_v| opt_v.and_then(|v| path.extract(JsonRef::new(v))));
+                    StringChunked::from_iter_options(ca.name().clone(), iter)
+                } else {
+                    StringChunked::full_null(ca.name().clone(), ca.len())
+                };
+                Ok(out)
+            },
+            (len_ca, len_path) if len_ca == 1 || len_ca == len_path => {
+                broadcast_try_binary_elementwise(ca, json_path, |opt_v, opt_path| {
+                    match (opt_v, opt_path) {
+                        (Some(v), Some(path)) => {
+                            Ok(JsonPath::compile(path)?.extract(JsonRef::new(v)))
+                        },
+                        _ => Ok(None),
+                    }
+                })
+            },
+            (len_ca, len_path) => {
+                polars_bail!(ComputeError: "The length of `ca` and `json_path` should either 1 or the same, but `{}`, `{}` founded", len_ca, len_path)
+            },
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use arrow::bitmap::Bitmap;
@@ -281,6 +368,44 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(feature = "dtype-json")]
+    fn test_json_path_match_json() {
+        let s = Series::new(
+            "json".into(),
+            [
+                None,
+                Some(r#"{"a":1,"b":[{"c":"x"},{"c":null}]}"#),
+                Some(r#"{"a":2.5,"b":[{"c":{"d":true}}]}"#),
+            ],
+        );
+        let json = s.cast(&DataType::Json).unwrap();
+        let json = json.json().unwrap();
+        let strings = s.str().unwrap();
+
+        for path in [
+            "$.a",
+            "$.b[0].c",
+            "$['b'][1].c",
+            "$.b[:].c",
+            "$..c",
+            "$.missing",
+        ] {
+            let path = StringChunked::new("".into(), [path]);
+            let expected = strings.json_path_match(&path).unwrap();
+            assert!(
+                json.json_path_match(&path)
+                    .unwrap()
+                    .into_series()
+                    .equals_missing(&expected.into_series())
+            );
+        }
+
+        let paths = StringChunked::new("".into(), ["$.a", "$.b[0].c", "$.b[0].c.d"]);
+        let out = json.json_path_match(&paths).unwrap();
+        assert_eq!(Vec::from(&out), &[None, Some("x"), Some("true")]);
+    }
+
     #[test]
     fn test_json_path_extract() {
         let s = Series::new(
@@ -0,0 +1,395 @@
+use chrono::{Datelike, NaiveDate, Weekday};
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::business::{
+    add_business_days_impl, business_day_count_impl, get_day_of_week, normalise_holidays,
+};
+
+/// Number of days from 0001-01-01 (CE) to 1970-01-01.
+const EPOCH_DAYS_FROM_CE: i32 = 719_163;
+
+/// How a holiday that falls on a non-business day is observed.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum Observance {
+    /// The holiday is not observed on another day.
+    #[default]
+    None,
+    /// Observe the holiday on the next business day.
+    Next,
+    /// Observe the holiday on the previous business day.
+    Previous,
+    /// Observe the holiday on the closest business day, preferring the next one on ties. With a
+    /// Monday to Friday week this moves Saturday holidays to Friday and Sunday holidays to Monday.
+    Nearest,
+}
+
+/// A holiday that recurs every year.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum HolidayRule {
+    /// A fixed day of a month, e.g. December 25th.
+    Fixed {
+        month: u8,
+        day: u8,
+        observance: Observance,
+    },
+    /// The `n`-th `weekday` (0 is Monday) of a month, e.g. the fourth Thursday of November.
+    /// Negative values of `n` count from the end of the month.
+    NthWeekday { month: u8, weekday: u8, n: i8 },
+    /// A number of days relative to (western) Easter Sunday, e.g. `-2` for Good Friday.
+    Easter { offset: i16 },
+}
+
+impl HolidayRule {
+    fn validate(&self) -> PolarsResult<()> {
+        match *self {
+            HolidayRule::Fixed { month, day, .. } => polars_ensure!(
+                (1..=12).contains(&month) && (1..=31).contains(&day),
+                InvalidOperation: "invalid holiday rule: month {} day {}", month, day
+            ),
+            HolidayRule::NthWeekday { month, weekday, n } => polars_ensure!(
+                (1..=12).contains(&month) && weekday < 7 && n != 0 && (-5..=5).contains(&n),
+                InvalidOperation: "invalid holiday rule: weekday {} number {} of month {}", weekday, n, month
+            ),
+            HolidayRule::Easter { .. } => {},
+        }
+        Ok(())
+    }
+
+    /// The date of the holiday in `year` before observance shifting, as days since the epoch.
+    fn date_in_year(&self, year: i32) -> Option<i32> {
+        let date = match *self {
+            HolidayRule::Fixed { month, day, .. } => {
+                NaiveDate::from_ymd_opt(year, month as u32, day as u32)?
+            },
+            HolidayRule::NthWeekday { month, weekday, n } => {
+                let weekday = Weekday::try_from(weekday).ok()?;
+                if n > 0 {
+                    NaiveDate::from_weekday_of_month_opt(year, month as u32, weekday, n as u8)?
+                } else {
+                    let last = NaiveDate::from_ymd_opt(year, month as u32, 1)?
+                        .checked_add_months(chrono::Months::new(1))?
+                        .pred_opt()?;
+                    let back = (last.weekday().num_days_from_monday() + 7
+                        - weekday.num_days_from_monday())
+                        % 7;
+                    let date = last - chrono::Duration::days((back + 7 * (-n - 1) as u32) as i64);
+                    if date.month() != month as u32 {
+                        return None;
+                    }
+                    date
+                }
+            },
+            HolidayRule::Easter { offset } => {
+                easter_sunday(year)? + chrono::Duration::days(offset as i64)
+            },
+        };
+        Some(date.num_days_from_ce() - EPOCH_DAYS_FROM_CE)
+    }
+}
+
+/// Western Easter Sunday, using the anonymous Gregorian algorithm.
+fn easter_sunday(year: i32) -> Option<NaiveDate> {
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    NaiveDate::from_ymd_opt(year, month as u32, day as u32)
+}
+
+fn year_of(date: i32) -> i32 {
+    NaiveDate::from_num_days_from_ce_opt(date.saturating_add(EPOCH_DAYS_FROM_CE))
+        .map_or(if date < 0 { -262_000 } else { 262_000 }, |d| d.year())
+}
+
+/// A set of business days: the days of the week that are business days, minus holidays.
+///
+/// Holidays are either explicit dates or yearly [`HolidayRule`]s.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct BusinessCalendar {
+    week_mask: [bool; 7],
+    /// Sorted days since the epoch.
+    holidays: Vec<i32>,
+    rules: Vec<HolidayRule>,
+}
+
+impl Default for BusinessCalendar {
+    /// Monday to Friday without holidays.
+    fn default() -> Self {
+        Self {
+            week_mask: [true, true, true, true, true, false, false],
+            holidays: Vec::new(),
+            rules: Vec::new(),
+        }
+    }
+}
+
+impl BusinessCalendar {
+    /// Create a calendar without holidays. `week_mask` starts at Monday.
+    pub fn new(week_mask: [bool; 7]) -> PolarsResult<Self> {
+        polars_ensure!(
+            week_mask.iter().any(|&x| x),
+            ComputeError: "`week_mask` must have at least one business day"
+        );
+        Ok(Self {
+            week_mask,
+            holidays: Vec::new(),
+            rules: Vec::new(),
+        })
+    }
+
+    /// Add holidays, given as days since the UNIX epoch.
+    pub fn with_holidays(mut self, holidays: impl IntoIterator<Item = i32>) -> Self {
+        self.holidays.extend(holidays);
+        self.holidays.sort_unstable();
+        self.holidays.dedup();
+        self
+    }
+
+    /// Add a yearly holiday.
+    pub fn with_rule(mut self, rule: HolidayRule) -> PolarsResult<Self> {
+        rule.validate()?;
+        self.rules.push(rule);
+        Ok(self)
+    }
+
+    pub fn week_mask(&self) -> [bool; 7] {
+        self.week_mask
+    }
+
+    fn is_business_weekday(&self, date: i32) -> bool {
+        self.week_mask[get_day_of_week(date)]
+    }
+
+    fn observe(&self, date: i32, observance: Observance) -> i32 {
+        if self.is_business_weekday(date) {
+            return date;
+        }
+        let next = (1..)
+            .map(|d| date + d)
+            .find(|&d| self.is_business_weekday(d));
+        let previous = (1..)
+            .map(|d| date - d)
+            .find(|&d| self.is_business_weekday(d));
+        // The week mask has at least one business day, so both exist.
+        let (next, previous) = (next.unwrap(), previous.unwrap());
+        match observance {
+            Observance::None => date,
+            Observance::Next => next,
+            Observance::Previous => previous,
+            Observance::Nearest if next - date <= date - previous => next,
+            Observance::Nearest => previous,
+        }
+    }
+
+    /// The holidays that fall on business days of the week between `start` and `end`
+    /// (inclusive), sorted, as days since the UNIX epoch.
+    pub fn holidays_between(&self, start: i32, end: i32) -> Vec<i32> {
+        let lo = self.holidays.partition_point(|&h| h < start);
+        let hi = self.holidays.partition_point(|&h| h <= end);
+        let mut holidays = self.holidays[lo..hi].to_vec();
+        if !self.rules.is_empty() {
+            // Observance can move a holiday into the neighbouring year.
+            for year in year_of(start) - 1..=year_of(end) + 1 {
+                for rule in &self.rules {
+                    let Some(date) = rule.date_in_year(year) else {
+                        continue;
+                    };
+                    let date = match rule {
+                        HolidayRule::Fixed { observance, .. } => self.observe(date, *observance),
+                        _ => date,
+                    };
+                    if (start..=end).contains(&date) {
+                        holidays.push(date);
+                    }
+                }
+            }
+        }
+        normalise_holidays(&holidays, &self.week_mask)
+    }
+
+    pub fn is_business_day(&self, date: i32) -> bool {
+        self.is_business_weekday(date) && self.holidays_between(date, date).is_empty()
+    }
+
+    /// The first business day on or after `date`.
+    pub fn roll_forward(&self, date: i32) -> i32 {
+        // Any 370 days contain a business day unless every one of them is a holiday.
+        let holidays = self.holidays_between(date, date.saturating_add(370));
+        (date..)
+            .find(|d| self.is_business_weekday(*d) && holidays.binary_search(d).is_err())
+            .unwrap()
+    }
+
+    /// The last business day on or before `date`.
+    pub fn roll_backward(&self, date: i32) -> i32 {
+        let holidays = self.holidays_between(date.saturating_sub(370), date);
+        (0..)
+            .map(|d| date - d)
+            .find(|d| self.is_business_weekday(*d) && holidays.binary_search(d).is_err())
+            .unwrap()
+    }
+
+    /// Move `n` business days away from `date`. A `date` that is not a business day counts as
+    /// the position right before (or after, for negative `n`) the business day it rolls to, so
+    /// moving one business day from a Saturday gives the Monday.
+    pub fn offset(&self, date: i32, n: i64) -> PolarsResult<i32> {
+        if n == 0 {
+            return Ok(date);
+        }
+        let n_business_days_in_week_mask = self.week_mask.iter().filter(|&x| *x).count() as i32;
+        let (start, n) = if n > 0 {
+            let start = self.roll_forward(date);
+            (start, if start == date { n } else { n - 1 })
+        } else {
+            let start = self.roll_backward(date);
+            (start, if start == date { n } else { n + 1 })
+        };
+        if n == 0 {
+            return Ok(start);
+        }
+        let n = i32::try_from(n)
+            .map_err(|_| polars_err!(ComputeError: "business-day offset {} is out of range", n))?;
+
+        // Holidays only push the result further away, so widen the window until the result
+        // lands inside of it.
+        let mut margin = (n.abs() / n_business_days_in_week_mask + 1) as i64 * 7 + 366;
+        loop {
+            let (lo, hi) = if n > 0 {
+                (start as i64, start as i64 + margin)
+            } else {
+                (start as i64 - margin, start as i64)
+            };
+            polars_ensure!(
+                lo >= i32::MIN as i64 && hi <= i32::MAX as i64,
+                ComputeError: "business-day offset {} is out of range", n
+            );
+            let holidays = self.holidays_between(lo as i32, hi as i32);
+            let out = add_business_days_impl(
+                start,
+                get_day_of_week(start),
+                n,
+                &self.week_mask,
+                n_business_days_in_week_mask,
+                &holidays,
+            );
+            if (lo as i32..=hi as i32).contains(&out) && out != lo as i32 && out != hi as i32 {
+                return Ok(out);
+            }
+            margin *= 2;
+        }
+    }
+
+    /// Count the business days from `start` (inclusive) to `end` (exclusive), negative if `end`
+    /// is before `start`.
+    pub fn count(&self, start: i32, end: i32) -> i64 {
+        let n_business_days_in_week_mask = self.week_mask.iter().filter(|&x| *x).count() as i32;
+        let holidays = self.holidays_between(start.min(end), start.max(end));
+        business_day_count_impl(
+            start,
+            end,
+            &self.week_mask,
+            n_business_days_in_week_mask,
+            &holidays,
+        ) as i64
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> i32 {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap().num_days_from_ce() - EPOCH_DAYS_FROM_CE
+    }
+
+    fn us_calendar() -> BusinessCalendar {
+        BusinessCalendar::new([true, true, true, true, true, false, false])
+            .unwrap()
+            .with_rule(HolidayRule::Fixed {
+                month: 7,
+                day: 4,
+                observance: Observance::Nearest,
+            })
+            .unwrap()
+            .with_rule(HolidayRule::Fixed {
+                month: 12,
+                day: 25,
+                observance: Observance::Nearest,
+            })
+            .unwrap()
+            .with_rule(HolidayRule::NthWeekday {
+                month: 11,
+                weekday: 3,
+                n: 4,
+            })
+            .unwrap()
+            .with_rule(HolidayRule::NthWeekday {
+                month: 5,
+                weekday: 0,
+                n: -1,
+            })
+            .unwrap()
+            .with_rule(HolidayRule::Easter { offset: -2 })
+            .unwrap()
+    }
+
+    #[test]
+    fn test_holiday_rules() {
+        let cal = us_calendar();
+        let holidays = cal.holidays_between(date(2026, 1, 1), date(2026, 12, 31));
+        assert_eq!(
+            holidays,
+            [
+                // Good Friday.
+                date(2026, 4, 3),
+                // Last Monday of May.
+                date(2026, 5, 25),
+                // July 4th is a Saturday.
+                date(2026, 7, 3),
+                // Fourth Thursday of November.
+                date(2026, 11, 26),
+                date(2026, 12, 25),
+            ]
+        );
+        // 2022-12-25 is a Sunday, observed on Monday.
+        assert!(!cal.is_business_day(date(2022, 12, 26)));
+        assert!(cal.is_business_day(date(2022, 12, 23)));
+    }
+
+    #[test]
+    fn test_offset_and_count() {
+        let cal = us_calendar();
+        // Thursday before Good Friday.
+        assert_eq!(cal.offset(date(2026, 4, 2), 1).unwrap(), date(2026, 4, 6));
+        assert_eq!(cal.offset(date(2026, 4, 6), -1).unwrap(), date(2026, 4, 2));
+        // From a Saturday.
+        assert_eq!(cal.offset(date(2026, 4, 4), 1).unwrap(), date(2026, 4, 6));
+        assert_eq!(cal.offset(date(2026, 4, 4), -1).unwrap(), date(2026, 4, 2));
+        assert_eq!(cal.roll_backward(date(2026, 4, 5)), date(2026, 4, 2));
+
+        let start = date(2026, 1, 1);
+        for n in [1, 7, 250, 1000] {
+            let end = cal.offset(start, n).unwrap();
+            // 2026-01-01 is a Thursday that isn't a holiday in this calendar.
+            assert_eq!(cal.count(start, end), n);
+        }
+    }
+}
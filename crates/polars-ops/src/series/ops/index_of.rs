@@ -154,6 +154,22 @@ pub fn index_of(series: &Series, needle: Scalar) -> PolarsResult<Option<usize>>
         DT::Decimal(..) => unreachable!(),
         #[cfg(feature = "dtype-categorical")]
         DT::Categorical(..) | DT::Enum(..) => unreachable!(),
+        #[cfg(feature = "dtype-interval")]
+        DT::Interval => unreachable!(),
+        #[cfg(feature = "dtype-map")]
+        DT::Map(..) => unreachable!(),
+        #[cfg(feature = "dtype-f16")]
+        DT::Float16 => unreachable!(),
+        #[cfg(feature = "dtype-extension")]
+        DT::Extension { .. } => unreachable!(),
+        #[cfg(feature = "dtype-decimal256")]
+        DT::Decimal256(..) => unreachable!(),
+        #[cfg(feature = "dtype-uuid")]
+        DT::Uuid => unreachable!(),
+        #[cfg(feature = "dtype-union")]
+        DT::Union(..) => unreachable!(),
+        #[cfg(feature = "dtype-json")]
+        DT::Json => unreachable!(),
         DT::Date | DT::Datetime(..) | DT::Duration(..) | DT::Time => unreachable!(),
 
         #[cfg(feature = "object")]
@@ -4,6 +4,8 @@ mod arg_min_max;
 mod bitwise;
 #[cfg(feature = "business")]
 mod business;
+#[cfg(feature = "business")]
+mod business_calendar;
 mod clip;
 #[cfg(feature = "cum_agg")]
 mod cum_agg;
@@ -73,6 +75,8 @@ pub use arg_min_max::ArgAgg;
 pub use bitwise::*;
 #[cfg(feature = "business")]
 pub use business::*;
+#[cfg(feature = "business")]
+pub use business_calendar::*;
 pub use clip::*;
 #[cfg(feature = "cum_agg")]
 pub use cum_agg::*;
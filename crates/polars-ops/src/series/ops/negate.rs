@@ -27,6 +27,15 @@ pub fn negate(s: &Series) -> PolarsResult<Series> {
             let out = ca.wrapping_neg().into_series();
             out.cast(s.dtype())?
         },
+        #[cfg(feature = "dtype-interval")]
+        Interval => {
+            let ca = s.interval().unwrap();
+            IntervalChunked::from_intervals(
+                ca.name().clone(),
+                ca.iter_intervals().map(|v| v.map(|v| -v)),
+            )
+            .into_series()
+        },
         dt => polars_bail!(opq = neg, dt),
     };
     Ok(out)
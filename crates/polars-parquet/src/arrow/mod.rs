@@ -6,3 +6,7 @@ pub mod write;
 pub use crate::parquet::bloom_filter;
 
 const ARROW_SCHEMA_META_KEY: &str = "ARROW:schema";
+/// Name of the canonical Arrow extension type that maps to the parquet `UUID` logical type.
+const UUID_EXTENSION_NAME: &str = "arrow.uuid";
+/// Name of the canonical Arrow extension type that maps to the parquet `JSON` logical type.
+const JSON_EXTENSION_NAME: &str = "arrow.json";
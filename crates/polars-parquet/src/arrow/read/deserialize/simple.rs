@@ -4,7 +4,7 @@ use arrow::datatypes::{
     ArrowDataType, DTYPE_CATEGORICAL_LEGACY, DTYPE_CATEGORICAL_NEW, DTYPE_ENUM_VALUES_LEGACY,
     DTYPE_ENUM_VALUES_NEW, Field, IntegerType, IntervalUnit, TimeUnit,
 };
-use arrow::types::{NativeType, days_ms, i256};
+use arrow::types::{NativeType, days_ms, f16, i256, months_days_ns};
 use ethnum::I256;
 use polars_compute::cast::CastOptionsImpl;
 use polars_utils::pl_str::PlSmallStr;
@@ -212,6 +212,43 @@ pub fn page_iter_to_array(
 
             (nested, out, ptm)
         },
+        (PhysicalType::FixedLenByteArray(16), Interval(IntervalUnit::MonthDayNano)) => {
+            let n = 16;
+            let (nested, array, ptm) = PageDecoder::new(
+                &field.name,
+                pages,
+                ArrowDataType::FixedSizeBinary(n),
+                fixed_size_binary::BinaryDecoder { size: n },
+                init_nested,
+            )?
+            .collect(filter)?;
+
+            let array = array
+                .into_iter()
+                .map(|array| {
+                    let values = array
+                        .values()
+                        .chunks_exact(n)
+                        .map(|value: &[u8]| {
+                            months_days_ns::new(
+                                i32::from_le_bytes(value[..4].try_into().unwrap()),
+                                i32::from_le_bytes(value[4..8].try_into().unwrap()),
+                                i64::from_le_bytes(value[8..].try_into().unwrap()),
+                            )
+                        })
+                        .collect::<Vec<_>>();
+                    let validity = array.validity().cloned();
+                    Ok(PrimitiveArray::<months_days_ns>::try_new(
+                        dtype.clone(),
+                        values.into(),
+                        validity,
+                    )?
+                    .to_boxed())
+                })
+                .collect::<ParquetResult<Vec<Box<dyn Array>>>>()?;
+
+            (nested, array, ptm)
+        },
         (PhysicalType::FixedLenByteArray(16), UInt128) => {
             let n = 16;
             let (nested, array, ptm) = PageDecoder::new(
@@ -439,6 +476,32 @@ pub fn page_iter_to_array(
         .collect_boxed(filter)?,
 
         // Float16
+        (PhysicalType::FixedLenByteArray(2), Float16) => {
+            let (nested, array, ptm) = PageDecoder::new(
+                &field.name,
+                pages,
+                ArrowDataType::FixedSizeBinary(2),
+                fixed_size_binary::BinaryDecoder { size: 2 },
+                init_nested,
+            )?
+            .collect(filter)?;
+
+            let array = array
+                .into_iter()
+                .map(|mut fsb_array| {
+                    let validity = fsb_array.take_validity();
+                    let values = fsb_array
+                        .values()
+                        .as_slice()
+                        .chunks_exact(2)
+                        .map(|v| f16::from_le_bytes([v[0], v[1]]))
+                        .collect();
+                    Ok(PrimitiveArray::<f16>::new(dtype.clone(), values, validity).to_boxed())
+                })
+                .collect::<ParquetResult<Vec<Box<dyn Array>>>>()?;
+
+            (nested, array, ptm)
+        },
         (PhysicalType::FixedLenByteArray(2), Float32) => {
             // @NOTE: To reduce code bloat, we just use the FixedSizeBinary decoder.
 
@@ -1,11 +1,14 @@
 //! This module has entry points, [`parquet_to_arrow_schema`] and the more configurable [`parquet_to_arrow_schema_with_options`].
 use std::sync::Arc;
 
-use arrow::datatypes::{ArrowDataType, ArrowSchema, Field, IntervalUnit, Metadata, TimeUnit};
+use arrow::datatypes::{
+    ArrowDataType, ArrowSchema, ExtensionType, Field, IntervalUnit, Metadata, TimeUnit,
+};
 use polars_utils::format_pl_smallstr;
 use polars_utils::pl_str::PlSmallStr;
 
 use crate::arrow::read::schema::SchemaInferenceOptions;
+use crate::arrow::{JSON_EXTENSION_NAME, UUID_EXTENSION_NAME};
 use crate::parquet::schema::Repetition;
 use crate::parquet::schema::types::{
     FieldInfo, GroupConvertedType, GroupLogicalType, IntegerType, ParquetType, PhysicalType,
@@ -156,10 +159,15 @@ fn from_byte_array(
 ) -> ArrowDataType {
     match (logical_type, converted_type) {
         (Some(PrimitiveLogicalType::String), _) => ArrowDataType::Utf8View,
-        (Some(PrimitiveLogicalType::Json), _) => ArrowDataType::BinaryView,
+        (Some(PrimitiveLogicalType::Json), _) | (_, Some(PrimitiveConvertedType::Json)) => {
+            ArrowDataType::Extension(Box::new(ExtensionType {
+                name: PlSmallStr::from_static(JSON_EXTENSION_NAME),
+                inner: ArrowDataType::Utf8View,
+                metadata: None,
+            }))
+        },
         (Some(PrimitiveLogicalType::Bson), _) => ArrowDataType::BinaryView,
         (Some(PrimitiveLogicalType::Enum), _) => ArrowDataType::BinaryView,
-        (_, Some(PrimitiveConvertedType::Json)) => ArrowDataType::BinaryView,
         (_, Some(PrimitiveConvertedType::Bson)) => ArrowDataType::BinaryView,
         (_, Some(PrimitiveConvertedType::Enum)) => ArrowDataType::BinaryView,
         (_, Some(PrimitiveConvertedType::Utf8)) => ArrowDataType::Utf8View,
@@ -173,15 +181,25 @@ fn from_fixed_len_byte_array(
     converted_type: Option<PrimitiveConvertedType>,
 ) -> ArrowDataType {
     match (logical_type, converted_type) {
-        (Some(PrimitiveLogicalType::Decimal(precision, scale)), _) => {
-            ArrowDataType::Decimal(precision, scale)
-        },
-        (None, Some(PrimitiveConvertedType::Decimal(precision, scale))) => {
-            ArrowDataType::Decimal(precision, scale)
+        (Some(PrimitiveLogicalType::Decimal(precision, scale)), _)
+        | (None, Some(PrimitiveConvertedType::Decimal(precision, scale))) => {
+            if precision > 38 {
+                ArrowDataType::Decimal256(precision, scale)
+            } else {
+                ArrowDataType::Decimal(precision, scale)
+            }
         },
         (None, Some(PrimitiveConvertedType::Interval)) => {
             ArrowDataType::Interval(IntervalUnit::MonthDayMillis)
         },
+        (Some(PrimitiveLogicalType::Float16), _) if length == 2 => ArrowDataType::Float16,
+        (Some(PrimitiveLogicalType::Uuid), _) if length == 16 => {
+            ArrowDataType::Extension(Box::new(ExtensionType {
+                name: PlSmallStr::from_static(UUID_EXTENSION_NAME),
+                inner: ArrowDataType::FixedSizeBinary(16),
+                metadata: None,
+            }))
+        },
         _ => ArrowDataType::FixedSizeBinary(length),
     }
 }
@@ -244,7 +262,7 @@ fn non_repeated_group(
     match (logical_type, converted_type) {
         (Some(GroupLogicalType::List), _) => to_list(fields, parent_name, options),
         (None, Some(GroupConvertedType::List)) => to_list(fields, parent_name, options),
-        (Some(GroupLogicalType::Map), _) => to_list(fields, parent_name, options),
+        (Some(GroupLogicalType::Map), _) => to_map(fields, options),
         (None, Some(GroupConvertedType::Map) | Some(GroupConvertedType::MapKeyValue)) => {
             to_map(fields, options)
         },
@@ -266,10 +284,16 @@ fn to_struct(fields: &[ParquetType], options: &SchemaInferenceOptions) -> Option
     }
 }
 
-/// Converts a parquet group type to an arrow [`ArrowDataType::Struct`].
+/// Converts a parquet map group type to an arrow [`ArrowDataType::Map`].
 /// Returns [`None`] if all its fields are empty
 fn to_map(fields: &[ParquetType], options: &SchemaInferenceOptions) -> Option<ArrowDataType> {
-    let inner = to_field(&fields[0], options)?;
+    let ParquetType::GroupType {
+        field_info, fields, ..
+    } = &fields[0]
+    else {
+        return None;
+    };
+    let inner = Field::new(field_info.name.clone(), to_struct(fields, options)?, false);
     Some(ArrowDataType::Map(Box::new(inner), false))
 }
 
@@ -78,7 +78,6 @@ fn convert_dtype(mut dtype: ArrowDataType) -> ArrowDataType {
                 convert_field(field);
             }
         },
-        Float16 => dtype = Float32,
         Binary | LargeBinary => dtype = BinaryView,
         Utf8 | LargeUtf8 => dtype = Utf8View,
         Dictionary(_, ref mut dtype, _) => {
@@ -88,12 +87,10 @@ fn convert_dtype(mut dtype: ArrowDataType) -> ArrowDataType {
         Extension(ref mut ext) => {
             ext.inner = convert_dtype(std::mem::take(&mut ext.inner));
         },
-        Map(mut field, _ordered) => {
-            // Polars doesn't support Map.
-            // A map is physically a `List<Struct<K, V>>`
-            // So we read as list.
+        // Maps stay maps here. Polars reads them as `Map` with the `dtype-map` feature, and as a
+        // `List<Struct<K, V>>` of their entries without it.
+        Map(ref mut field, _ordered) => {
             convert_field(field.as_mut());
-            dtype = LargeList(field);
         },
         _ => {},
     }
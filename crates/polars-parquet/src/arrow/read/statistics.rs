@@ -217,7 +217,10 @@ impl ColumnStatistics {
                 })
             },
 
-            // Read Float16, since we don't have a f16 type in Polars we read it to a Float32.
+            (D::Float16, PPT::FixedLenByteArray(2)) => {
+                rmap!(expect_fixedlen, @prim Vec<u8>, |v| f16::from_le_bytes([v[0], v[1]]))
+            },
+            // Read Float16 that is requested as a Float32.
             (_, PPT::FixedLenByteArray(2))
                 if matches!(
                     self.logical_type.as_ref(),
@@ -247,7 +250,7 @@ impl ColumnStatistics {
             (D::Decimal256(_, _), PPT::Int64) => {
                 rmap!(expect_int64, @prim i64, |x: i64| i256(I256::new(x.into())))
             },
-            (D::Decimal256(_, _), PPT::FixedLenByteArray(n)) if *n > 16 => {
+            (D::Decimal256(_, _), PPT::FixedLenByteArray(n)) if *n > 32 => {
                 return Err(ParquetError::not_supported(format!(
                     "Can't decode Decimal256 type from Fixed Size Byte Array of len {n:?}",
                 )));
@@ -468,7 +471,10 @@ pub fn deserialize_all(
                     })
                 },
 
-                // Read Float16, since we don't have a f16 type in Polars we read it to a Float32.
+                (D::Float16, PPT::FixedLenByteArray(2)) => {
+                    rmap!(expect_fixedlen, MutablePrimitiveArray::<f16>, @prim Vec<u8>, |v| f16::from_le_bytes([v[0], v[1]]))
+                },
+                // Read Float16 that is requested as a Float32.
                 (_, PPT::FixedLenByteArray(2))
                     if matches!(logical_type.as_ref(), Some(PrimitiveLogicalType::Float16)) =>
                 {
@@ -500,7 +506,7 @@ pub fn deserialize_all(
                 (D::Decimal256(_, _), PPT::Int64) => {
                     rmap!(expect_int64, MutablePrimitiveArray::<i256>, @prim i64, |x: i64| i256(I256::new(x.into())))
                 },
-                (D::Decimal256(_, _), PPT::FixedLenByteArray(n)) if *n > 16 => {
+                (D::Decimal256(_, _), PPT::FixedLenByteArray(n)) if *n > 32 => {
                     return Err(ParquetError::not_supported(format!(
                         "Can't decode Decimal256 type from Fixed Size Byte Array of len {n:?}",
                     )));
@@ -2,9 +2,10 @@ mod basic;
 mod nested;
 
 use arrow::array::{Array, FixedSizeBinaryArray, PrimitiveArray};
-use arrow::types::{NativeType, i256};
+use arrow::types::{NativeType, f16, i256};
 pub use basic::array_to_page;
 pub use nested::array_to_page as nested_array_to_page;
+use polars_utils::total_ord::TotalOrd;
 
 use super::binary::ord_binary;
 use super::{EncodeNullability, StatisticsOptions};
@@ -60,6 +61,36 @@ pub(super) fn build_statistics(
     }
 }
 
+/// The statistics of a `FLOAT16` column are compared as floats, not as bytes. NaNs are excluded.
+pub(super) fn build_statistics_float16(
+    array: &PrimitiveArray<f16>,
+    primitive_type: PrimitiveType,
+    options: &StatisticsOptions,
+) -> FixedLenStatistics {
+    let values = || array.iter().flatten().filter(|x| !x.is_nan());
+    FixedLenStatistics {
+        primitive_type,
+        null_count: options.null_count.then_some(array.null_count() as i64),
+        distinct_count: None,
+        max_value: options
+            .max_value
+            .then(|| {
+                values()
+                    .max_by(|x, y| x.tot_cmp(y))
+                    .map(|x| x.to_le_bytes().to_vec())
+            })
+            .flatten(),
+        min_value: options
+            .min_value
+            .then(|| {
+                values()
+                    .min_by(|x, y| x.tot_cmp(y))
+                    .map(|x| x.to_le_bytes().to_vec())
+            })
+            .flatten(),
+    }
+}
+
 pub(super) fn build_statistics_decimal<T>(
     array: &PrimitiveArray<T>,
     primitive_type: PrimitiveType,
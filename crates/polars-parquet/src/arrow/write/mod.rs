@@ -27,7 +27,7 @@ mod utils;
 
 use arrow::array::*;
 use arrow::datatypes::*;
-use arrow::types::{NativeType, days_ms, i256};
+use arrow::types::{NativeType, days_ms, f16, i256, months_days_ns};
 pub use nested::{num_values, write_rep_and_def};
 pub use pages::{to_leaves, to_nested, to_parquet_leaves};
 use polars_utils::pl_str::PlSmallStr;
@@ -637,6 +637,19 @@ pub fn array_to_page_simple(
             };
             fixed_size_binary::array_to_page(&array, options, type_, statistics)
         },
+        ArrowDataType::Interval(IntervalUnit::MonthDayNano) => {
+            let array = month_day_nano_to_fixed_size_binary(array.as_any().downcast_ref().unwrap());
+            let statistics = if options.has_statistics() {
+                Some(fixed_size_binary::build_statistics(
+                    &array,
+                    type_.clone(),
+                    &options.statistics,
+                ))
+            } else {
+                None
+            };
+            fixed_size_binary::array_to_page(&array, options, type_, statistics)
+        },
         ArrowDataType::FixedSizeBinary(_) => {
             let array = array.as_any().downcast_ref().unwrap();
             let statistics = if options.has_statistics() {
@@ -831,6 +844,24 @@ pub fn array_to_page_simple(
             );
             fixed_size_binary::array_to_page(&array, options, type_, statistics)
         },
+        ArrowDataType::Float16 => {
+            let array: &PrimitiveArray<f16> = array.as_any().downcast_ref().unwrap();
+            let statistics = if options.has_statistics() {
+                Some(fixed_size_binary::build_statistics_float16(
+                    array,
+                    type_.clone(),
+                    &options.statistics,
+                ))
+            } else {
+                None
+            };
+            let array = FixedSizeBinaryArray::new(
+                ArrowDataType::FixedSizeBinary(2),
+                array.values().clone().try_transmute().unwrap(),
+                array.validity().cloned(),
+            );
+            fixed_size_binary::array_to_page(&array, options, type_, statistics)
+        },
         ArrowDataType::Int128 => {
             let array: &PrimitiveArray<i128> = array.as_any().downcast_ref().unwrap();
             let statistics = if options.has_statistics() {
@@ -856,6 +887,24 @@ pub fn array_to_page_simple(
     .map(Page::Data)
 }
 
+/// Lay out `Interval(MonthDayNano)` values as 16 little-endian bytes each: the months, the days
+/// and the nanoseconds.
+fn month_day_nano_to_fixed_size_binary(
+    array: &PrimitiveArray<months_days_ns>,
+) -> FixedSizeBinaryArray {
+    let mut values = Vec::<u8>::with_capacity(16 * array.len());
+    array.values().iter().for_each(|x| {
+        values.extend_from_slice(&x.months().to_le_bytes());
+        values.extend_from_slice(&x.days().to_le_bytes());
+        values.extend_from_slice(&x.ns().to_le_bytes());
+    });
+    FixedSizeBinaryArray::new(
+        ArrowDataType::FixedSizeBinary(16),
+        values.into(),
+        array.validity().cloned(),
+    )
+}
+
 fn array_to_page_nested(
     array: &dyn Array,
     type_: ParquetPrimitiveType,
@@ -1090,6 +1139,19 @@ fn array_to_page_nested(
                 fixed_size_binary::nested_array_to_page(&array, options, type_, nested, statistics)
             }
         },
+        Interval(IntervalUnit::MonthDayNano) => {
+            let array = month_day_nano_to_fixed_size_binary(array.as_any().downcast_ref().unwrap());
+            let statistics = if options.has_statistics() {
+                Some(fixed_size_binary::build_statistics(
+                    &array,
+                    type_.clone(),
+                    &options.statistics,
+                ))
+            } else {
+                None
+            };
+            fixed_size_binary::nested_array_to_page(&array, options, type_, nested, statistics)
+        },
         Int128 => {
             let array: &PrimitiveArray<i128> = array.as_any().downcast_ref().unwrap();
             let statistics = if options.has_statistics() {
@@ -1110,6 +1172,24 @@ fn array_to_page_nested(
             );
             fixed_size_binary::nested_array_to_page(&array, options, type_, nested, statistics)
         },
+        Float16 => {
+            let array: &PrimitiveArray<f16> = array.as_any().downcast_ref().unwrap();
+            let statistics = if options.has_statistics() {
+                Some(fixed_size_binary::build_statistics_float16(
+                    array,
+                    type_.clone(),
+                    &options.statistics,
+                ))
+            } else {
+                None
+            };
+            let array = FixedSizeBinaryArray::new(
+                ArrowDataType::FixedSizeBinary(2),
+                array.values().clone().try_transmute().unwrap(),
+                array.validity().cloned(),
+            );
+            fixed_size_binary::nested_array_to_page(&array, options, type_, nested, statistics)
+        },
         UInt128 => {
             let array: &PrimitiveArray<u128> = array.as_any().downcast_ref().unwrap();
             let statistics = if options.has_statistics() {
@@ -9,6 +9,7 @@ use polars_error::{PolarsResult, polars_bail};
 use super::{ColumnWriteOptions, WriteOptions, array_to_pages};
 use crate::arrow::read::schema::is_nullable;
 use crate::parquet::page::Page;
+use crate::parquet::schema::Repetition;
 use crate::parquet::schema::types::{ParquetType, PrimitiveType as ParquetPrimitiveType};
 use crate::write::DynIter;
 
@@ -239,26 +240,25 @@ fn to_nested_recursive(
         },
         Map => {
             let array = array.as_any().downcast_ref::<MapArray>().unwrap();
-            let type_ = if let ParquetType::GroupType { fields, .. } = type_ {
-                if let ParquetType::GroupType { fields, .. } = &fields[0] {
-                    &fields[0]
-                } else {
-                    polars_bail!(InvalidOperation:
-                        "Parquet type must be a group for a map array",
-                    )
-                }
+            // The entries struct is the repeated `key_value` group itself, whose repetition is
+            // already accounted for by the list nesting.
+            let mut type_ = if let ParquetType::GroupType { fields, .. } = type_ {
+                fields[0].clone()
             } else {
                 polars_bail!(InvalidOperation:
                     "Parquet type must be a group for a map array",
                 )
             };
+            if let ParquetType::GroupType { field_info, .. } = &mut type_ {
+                field_info.repetition = Repetition::Required;
+            }
 
             parents.push(Nested::List(ListNested::new(
                 array.offsets().clone(),
                 array.validity().cloned(),
                 is_optional,
             )));
-            to_nested_recursive(array.field().as_ref(), type_, nested, parents)?;
+            to_nested_recursive(array.field().as_ref(), &type_, nested, parents)?;
         },
         _ => {
             parents.push(Nested::Primitive(PrimitiveNested {
@@ -276,21 +276,34 @@ fn expand_list_validity<'a, O: Offset>(
     array: &'a ListArray<O>,
     validity: BitmapState,
     array_stack: &mut Vec<(&'a dyn Array, BitmapState)>,
+) {
+    expand_offsets_validity(
+        array.offsets().buffer(),
+        array.values().as_ref(),
+        validity,
+        array_stack,
+    )
+}
+
+fn expand_offsets_validity<'a, O: Offset>(
+    offsets: &[O],
+    values: &'a dyn Array,
+    validity: BitmapState,
+    array_stack: &mut Vec<(&'a dyn Array, BitmapState)>,
 ) {
     let BitmapState::SomeSet(list_validity) = validity else {
         array_stack.push((
-            array.values().as_ref(),
+            values,
             match validity {
                 BitmapState::AllSet => BitmapState::AllSet,
                 BitmapState::SomeSet(_) => unreachable!(),
-                BitmapState::AllUnset(_) => BitmapState::AllUnset(array.values().len()),
+                BitmapState::AllUnset(_) => BitmapState::AllUnset(values.len()),
             },
         ));
         return;
     };
 
-    let offsets = array.offsets().buffer();
-    let mut validity = MutableBitmap::with_capacity(array.values().len());
+    let mut validity = MutableBitmap::with_capacity(values.len());
     let mut list_validity_iter = list_validity.iter();
 
     // @NOTE: We need to take into account here that the list might only point to a slice of the
@@ -312,13 +325,13 @@ fn expand_list_validity<'a, O: Offset>(
 
         idx += num_zeros;
     }
-    validity.extend_constant(array.values().len() - validity.len(), false);
+    validity.extend_constant(values.len() - validity.len(), false);
 
-    debug_assert_eq!(idx, array.len());
+    debug_assert_eq!(idx, offsets.len() - 1);
     let validity = validity.freeze();
 
-    debug_assert_eq!(validity.len(), array.values().len());
-    array_stack.push((array.values().as_ref(), BitmapState::SomeSet(validity)));
+    debug_assert_eq!(validity.len(), values.len());
+    array_stack.push((values, BitmapState::SomeSet(validity)));
 }
 
 #[derive(Clone)]
@@ -459,7 +472,12 @@ pub fn to_leaves(array: &dyn Array, leaves: &mut Vec<Box<dyn Array>>) {
             },
             P::Map => {
                 let array = array.as_any().downcast_ref::<MapArray>().unwrap();
-                array_stack.push((array.field().as_ref(), validity));
+                expand_offsets_validity(
+                    array.offsets().buffer(),
+                    array.field().as_ref(),
+                    validity,
+                    &mut array_stack,
+                );
             },
             P::Null
             | P::Boolean
@@ -894,8 +912,8 @@ mod tests {
 
         let type_ = ParquetType::GroupType {
             field_info: FieldInfo {
-                name: "kv".into(),
-                repetition: Repetition::Optional,
+                name: "key_value".into(),
+                repetition: Repetition::Repeated,
                 id: None,
             },
             logical_type: None,
@@ -932,16 +950,7 @@ mod tests {
             },
             logical_type: Some(GroupLogicalType::Map),
             converted_type: None,
-            fields: vec![ParquetType::GroupType {
-                field_info: FieldInfo {
-                    name: "map".into(),
-                    repetition: Repetition::Repeated,
-                    id: None,
-                },
-                logical_type: None,
-                converted_type: None,
-                fields: vec![type_],
-            }],
+            fields: vec![type_],
         };
 
         let a = to_nested(&array, &type_).unwrap();
@@ -955,7 +964,7 @@ mod tests {
                         offsets: vec![0, 2, 3, 4, 6].try_into().unwrap(),
                         validity: None,
                     }),
-                    Nested::structure(None, true, 6),
+                    Nested::structure(None, false, 6),
                     Nested::primitive(None, false, 6),
                 ],
                 vec![
@@ -964,7 +973,7 @@ mod tests {
                         offsets: vec![0, 2, 3, 4, 6].try_into().unwrap(),
                         validity: None,
                     }),
-                    Nested::structure(None, true, 6),
+                    Nested::structure(None, false, 6),
                     Nested::primitive(None, false, 6),
                 ],
             ]
@@ -1,14 +1,14 @@
 use std::borrow::Cow;
 use std::sync::Arc;
 
-use arrow::datatypes::{ArrowDataType, ArrowSchema, ExtensionType, Field, TimeUnit};
+use arrow::datatypes::{ArrowDataType, ArrowSchema, ExtensionType, Field, IntervalUnit, TimeUnit};
 use arrow::io::ipc::write::{default_ipc_fields, schema_to_bytes};
 use base64::Engine as _;
 use base64::engine::general_purpose;
 use polars_error::{PolarsResult, polars_bail};
 use polars_utils::pl_str::PlSmallStr;
 
-use super::super::ARROW_SCHEMA_META_KEY;
+use super::super::{ARROW_SCHEMA_META_KEY, JSON_EXTENSION_NAME, UUID_EXTENSION_NAME};
 use super::ColumnWriteOptions;
 use crate::arrow::write::decimal_length_from_precision;
 use crate::parquet::metadata::KeyValue;
@@ -32,6 +32,7 @@ fn convert_dtype(dtype: ArrowDataType) -> ArrowDataType {
     use ArrowDataType as D;
     match dtype {
         D::LargeList(field) => D::LargeList(Box::new(convert_field(*field))),
+        D::Map(field, sorted) => D::Map(Box::new(convert_field(*field)), sorted),
         D::Struct(mut fields) => {
             for field in &mut fields {
                 *field = convert_field(std::mem::take(field))
@@ -115,7 +116,7 @@ fn insert_field_metadata(field: &mut Cow<Field>, options: &ColumnWriteOptions) {
                 .to_mut()
                 .map_dtype_mut(|dtype| *dtype = D::Struct(new_fields));
         },
-        D::List(f) | D::FixedSizeList(f, _) | D::LargeList(f) => {
+        D::List(f) | D::FixedSizeList(f, _) | D::LargeList(f) | D::Map(f, _) => {
             let ChildWriteOptions::ListLike(o) = &options.children else {
                 unreachable!();
             };
@@ -130,6 +131,7 @@ fn insert_field_metadata(field: &mut Cow<Field>, options: &ColumnWriteOptions) {
                         D::List(_) => D::List(child_field),
                         D::LargeList(_) => D::LargeList(child_field),
                         D::FixedSizeList(_, width) => D::FixedSizeList(child_field, *width),
+                        D::Map(_, sorted) => D::Map(child_field, *sorted),
                         _ => unreachable!(),
                     }
                 });
@@ -212,8 +214,27 @@ pub fn to_parquet_type(field: &Field, options: &ColumnWriteOptions) -> PolarsRes
         // no natural representation in parquet; leave it as is.
         // arrow consumers MAY use the arrow schema in the metadata to parse them.
         ArrowDataType::Date64 => (PhysicalType::Int64, None, None),
+        ArrowDataType::Float16 => (
+            PhysicalType::FixedLenByteArray(2),
+            None,
+            Some(PrimitiveLogicalType::Float16),
+        ),
         ArrowDataType::Float32 => (PhysicalType::Float, None, None),
         ArrowDataType::Float64 => (PhysicalType::Double, None, None),
+        ArrowDataType::Binary
+        | ArrowDataType::LargeBinary
+        | ArrowDataType::BinaryView
+        | ArrowDataType::Utf8
+        | ArrowDataType::LargeUtf8
+        | ArrowDataType::Utf8View
+            if matches!(field.dtype(), ArrowDataType::Extension(ext) if ext.name == JSON_EXTENSION_NAME) =>
+        {
+            (
+                PhysicalType::ByteArray,
+                Some(PrimitiveConvertedType::Json),
+                Some(PrimitiveLogicalType::Json),
+            )
+        },
         ArrowDataType::Binary | ArrowDataType::LargeBinary | ArrowDataType::BinaryView => {
             (PhysicalType::ByteArray, None, None)
         },
@@ -328,6 +349,13 @@ pub fn to_parquet_type(field: &Field, options: &ColumnWriteOptions) -> PolarsRes
             let dict_field = Field::new(name, value.as_ref().clone(), field.is_nullable);
             return to_parquet_type(&dict_field, options);
         },
+        ArrowDataType::FixedSizeBinary(16) if matches!(field.dtype(), ArrowDataType::Extension(ext) if ext.name == UUID_EXTENSION_NAME) => {
+            (
+                PhysicalType::FixedLenByteArray(16),
+                None,
+                Some(PrimitiveLogicalType::Uuid),
+            )
+        },
         ArrowDataType::FixedSizeBinary(size) => {
             (PhysicalType::FixedLenByteArray(*size), None, None)
         },
@@ -375,9 +403,18 @@ pub fn to_parquet_type(field: &Field, options: &ColumnWriteOptions) -> PolarsRes
                     logical_type,
                 )
             } else {
-                (PhysicalType::FixedLenByteArray(32), None, None)
+                (
+                    PhysicalType::FixedLenByteArray(32),
+                    Some(PrimitiveConvertedType::Decimal(precision, scale)),
+                    logical_type,
+                )
             }
         },
+        // Parquet's INTERVAL has millisecond precision and unsigned components; store the full
+        // value instead and rely on the embedded arrow schema to restore the type.
+        ArrowDataType::Interval(IntervalUnit::MonthDayNano) => {
+            (PhysicalType::FixedLenByteArray(16), None, None)
+        },
         ArrowDataType::Interval(_) => (
             PhysicalType::FixedLenByteArray(12),
             Some(PrimitiveConvertedType::Interval),
@@ -412,6 +449,40 @@ pub fn to_parquet_type(field: &Field, options: &ColumnWriteOptions) -> PolarsRes
                 field_id,
             ));
         },
+        ArrowDataType::Map(f, _) => {
+            let ArrowDataType::Struct(entries) = f.dtype.to_logical_type() else {
+                polars_bail!(InvalidOperation: "the entries of a map must be a struct")
+            };
+            let ChildWriteOptions::ListLike(map_write_options) = &options.children else {
+                unreachable!();
+            };
+            let ChildWriteOptions::Struct(entries_write_options) =
+                &map_write_options.child.children
+            else {
+                unreachable!();
+            };
+
+            let fields = entries
+                .iter()
+                .zip(entries_write_options.children.as_slice())
+                .map(|(f, c)| to_parquet_type(f, c))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            return Ok(ParquetType::from_group(
+                name,
+                repetition,
+                Some(GroupConvertedType::Map),
+                Some(GroupLogicalType::Map),
+                vec![ParquetType::from_group(
+                    PlSmallStr::from_static("key_value"),
+                    Repetition::Repeated,
+                    None,
+                    None,
+                    fields,
+                    None,
+                )],
+                field_id,
+            ));
+        },
         other => polars_bail!(nyi = "Writing the data type {other:?} is not yet implemented"),
     };
 
@@ -234,17 +234,9 @@ impl DslBuilder {
         aggs: E,
         apply: Option<(PlanCallback<DataFrame, DataFrame>, SchemaRef)>,
         maintain_order: bool,
-        #[cfg(feature = "dynamic_group_by")] dynamic_options: Option<DynamicGroupOptions>,
-        #[cfg(feature = "dynamic_group_by")] rolling_options: Option<RollingGroupOptions>,
+        options: GroupbyOptions,
     ) -> Self {
         let aggs = aggs.as_ref().to_vec();
-        let options = GroupbyOptions {
-            #[cfg(feature = "dynamic_group_by")]
-            dynamic: dynamic_options,
-            #[cfg(feature = "dynamic_group_by")]
-            rolling: rolling_options,
-            slice: None,
-        };
 
         DslPlan::GroupBy {
             input: Arc::new(self.0),
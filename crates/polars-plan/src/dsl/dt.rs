@@ -33,6 +33,14 @@ impl DateLikeNameSpace {
             )))
     }
 
+    /// Format a Duration or Interval as an ISO 8601 duration string, e.g. `P1Y2M10DT2H30M`.
+    ///
+    /// Shorthand for `to_string("iso")`.
+    #[cfg(feature = "dtype-duration")]
+    pub fn to_iso_duration(self) -> Expr {
+        self.to_string("iso")
+    }
+
     /// Convert from Date/Time/Datetime into String with the given format.
     /// See [chrono strftime/strptime](https://docs.rs/chrono/0.4.19/chrono/format/strftime/index.html).
     ///
@@ -218,8 +226,14 @@ impl DateLikeNameSpace {
 
     /// Truncate the Datetime/Date range into buckets.
     pub fn truncate(self, every: Expr) -> Expr {
+        self.truncate_in(every, DurationCalendars::default())
+    }
+
+    /// Truncate the Datetime/Date range into buckets, counting business days and fiscal periods in
+    /// `calendars`.
+    pub fn truncate_in(self, every: Expr, calendars: DurationCalendars) -> Expr {
         self.0.map_binary(
-            FunctionExpr::TemporalExpr(TemporalFunction::Truncate),
+            FunctionExpr::TemporalExpr(TemporalFunction::Truncate(calendars)),
             every,
         )
     }
@@ -238,6 +252,42 @@ impl DateLikeNameSpace {
             .map_unary(FunctionExpr::TemporalExpr(TemporalFunction::MonthEnd))
     }
 
+    /// Get the fiscal year of the given fiscal calendar.
+    #[cfg(feature = "fiscal")]
+    pub fn fiscal_year(self, calendar: FiscalCalendar) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::TemporalExpr(TemporalFunction::FiscalYear(
+                calendar,
+            )))
+    }
+
+    /// Get the fiscal quarter of the given fiscal calendar, from 1 to 4.
+    #[cfg(feature = "fiscal")]
+    pub fn fiscal_quarter(self, calendar: FiscalCalendar) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::TemporalExpr(TemporalFunction::FiscalQuarter(
+                calendar,
+            )))
+    }
+
+    /// Get the fiscal period of the given fiscal calendar, from 1 to 12.
+    #[cfg(feature = "fiscal")]
+    pub fn fiscal_period(self, calendar: FiscalCalendar) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::TemporalExpr(TemporalFunction::FiscalPeriod(
+                calendar,
+            )))
+    }
+
+    /// Get the fiscal week of the given fiscal calendar, from 1 to 53.
+    #[cfg(feature = "fiscal")]
+    pub fn fiscal_week(self, calendar: FiscalCalendar) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::TemporalExpr(TemporalFunction::FiscalWeek(
+                calendar,
+            )))
+    }
+
     /// Get the base offset from UTC.
     #[cfg(feature = "timezones")]
     pub fn base_utc_offset(self) -> Expr {
@@ -262,8 +312,17 @@ impl DateLikeNameSpace {
     /// This will take leap years/ months into account.
     #[cfg(feature = "offset_by")]
     pub fn offset_by(self, by: Expr) -> Expr {
-        self.0
-            .map_binary(FunctionExpr::TemporalExpr(TemporalFunction::OffsetBy), by)
+        self.offset_by_in(by, DurationCalendars::default())
+    }
+
+    /// Offset this `Date/Datetime` by a given offset [`Duration`], counting business days and
+    /// fiscal periods in `calendars`.
+    #[cfg(feature = "offset_by")]
+    pub fn offset_by_in(self, by: Expr, calendars: DurationCalendars) -> Expr {
+        self.0.map_binary(
+            FunctionExpr::TemporalExpr(TemporalFunction::OffsetBy(calendars)),
+            by,
+        )
     }
 
     #[cfg(feature = "timezones")]
@@ -279,6 +338,34 @@ impl DateLikeNameSpace {
         )
     }
 
+    /// Replace the time zone of each value by the time zone in the same row of `time_zone`,
+    /// keeping the wall time. The result is in UTC, as a column holds a single time zone.
+    #[cfg(feature = "timezones")]
+    pub fn replace_time_zone_by(
+        self,
+        time_zone: Expr,
+        ambiguous: Expr,
+        non_existent: NonExistent,
+    ) -> Expr {
+        self.0.map_ternary(
+            FunctionExpr::TemporalExpr(TemporalFunction::ReplaceTimeZoneBy(non_existent)),
+            time_zone,
+            ambiguous,
+        )
+    }
+
+    /// Convert each value to the wall time in the time zone in the same row of `time_zone`.
+    ///
+    /// The result is time zone naive, so the local hour, date and other components follow
+    /// the time zone of each row, e.g. `col("ts").dt().convert_time_zone_by(col("tz")).dt().hour()`.
+    #[cfg(feature = "timezones")]
+    pub fn convert_time_zone_by(self, time_zone: Expr) -> Expr {
+        self.0.map_binary(
+            FunctionExpr::TemporalExpr(TemporalFunction::ConvertTimeZoneBy),
+            time_zone,
+        )
+    }
+
     /// Combine an existing Date/Datetime with a Time, creating a new Datetime value.
     pub fn combine(self, time: Expr, tu: TimeUnit) -> Expr {
         self.0.map_binary(
@@ -52,6 +52,11 @@ pub enum AggExpr {
     AggGroups(Arc<Expr>),
     Std(Arc<Expr>, u8),
     Var(Arc<Expr>, u8),
+    TimeWeighted {
+        input: Arc<Expr>,
+        by: Arc<Expr>,
+        method: TimeWeightedMethod,
+    },
 }
 
 impl AsRef<Expr> for AggExpr {
@@ -72,6 +77,7 @@ impl AsRef<Expr> for AggExpr {
             AggGroups(e) => e,
             Std(e, _) => e,
             Var(e, _) => e,
+            TimeWeighted { input, .. } => input,
         }
     }
 }
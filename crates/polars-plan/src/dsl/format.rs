@@ -127,6 +127,11 @@ impl fmt::Debug for Expr {
                     Var(expr, _) => write!(f, "{expr:?}.var()"),
                     Std(expr, _) => write!(f, "{expr:?}.std()"),
                     Quantile { expr, .. } => write!(f, "{expr:?}.quantile()"),
+                    TimeWeighted { input, by, method } => write!(
+                        f,
+                        "{input:?}.time_weighted_{}(by={by:?})",
+                        <&'static str>::from(method)
+                    ),
                 }
             },
             Cast {
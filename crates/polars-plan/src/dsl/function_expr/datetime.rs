@@ -60,14 +60,24 @@ pub enum TemporalFunction {
     WithTimeUnit(TimeUnit),
     #[cfg(feature = "timezones")]
     ConvertTimeZone(TimeZone),
+    #[cfg(feature = "timezones")]
+    ConvertTimeZoneBy,
     TimeStamp(TimeUnit),
-    Truncate,
+    Truncate(DurationCalendars),
     #[cfg(feature = "offset_by")]
-    OffsetBy,
+    OffsetBy(DurationCalendars),
     #[cfg(feature = "month_start")]
     MonthStart,
     #[cfg(feature = "month_end")]
     MonthEnd,
+    #[cfg(feature = "fiscal")]
+    FiscalYear(FiscalCalendar),
+    #[cfg(feature = "fiscal")]
+    FiscalQuarter(FiscalCalendar),
+    #[cfg(feature = "fiscal")]
+    FiscalPeriod(FiscalCalendar),
+    #[cfg(feature = "fiscal")]
+    FiscalWeek(FiscalCalendar),
     #[cfg(feature = "timezones")]
     BaseUtcOffset,
     #[cfg(feature = "timezones")]
@@ -76,6 +86,8 @@ pub enum TemporalFunction {
     Replace,
     #[cfg(feature = "timezones")]
     ReplaceTimeZone(Option<TimeZone>, NonExistent),
+    #[cfg(feature = "timezones")]
+    ReplaceTimeZoneBy(NonExistent),
     Combine(TimeUnit),
     DatetimeFunction {
         time_unit: TimeUnit,
@@ -127,16 +139,26 @@ impl Display for TemporalFunction {
             ToString(_) => "to_string",
             #[cfg(feature = "timezones")]
             ConvertTimeZone(_) => "convert_time_zone",
+            #[cfg(feature = "timezones")]
+            ConvertTimeZoneBy => "convert_time_zone_by",
             CastTimeUnit(_) => "cast_time_unit",
             WithTimeUnit(_) => "with_time_unit",
             TimeStamp(tu) => return write!(f, "dt.timestamp({tu})"),
-            Truncate => "truncate",
+            Truncate(_) => "truncate",
             #[cfg(feature = "offset_by")]
-            OffsetBy => "offset_by",
+            OffsetBy(_) => "offset_by",
             #[cfg(feature = "month_start")]
             MonthStart => "month_start",
             #[cfg(feature = "month_end")]
             MonthEnd => "month_end",
+            #[cfg(feature = "fiscal")]
+            FiscalYear(_) => "fiscal_year",
+            #[cfg(feature = "fiscal")]
+            FiscalQuarter(_) => "fiscal_quarter",
+            #[cfg(feature = "fiscal")]
+            FiscalPeriod(_) => "fiscal_period",
+            #[cfg(feature = "fiscal")]
+            FiscalWeek(_) => "fiscal_week",
             #[cfg(feature = "timezones")]
             BaseUtcOffset => "base_utc_offset",
             #[cfg(feature = "timezones")]
@@ -145,6 +167,8 @@ impl Display for TemporalFunction {
             Replace => "replace",
             #[cfg(feature = "timezones")]
             ReplaceTimeZone(_, _) => "replace_time_zone",
+            #[cfg(feature = "timezones")]
+            ReplaceTimeZoneBy(_) => "replace_time_zone_by",
             DatetimeFunction { .. } => return write!(f, "dt.datetime"),
             Combine(_) => "combine",
         };
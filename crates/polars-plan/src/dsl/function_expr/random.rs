@@ -15,6 +15,10 @@ pub enum RandomMethod {
         with_replacement: bool,
         shuffle: bool,
     },
+    #[cfg(feature = "dtype-uuid")]
+    Uuid4,
+    #[cfg(feature = "dtype-uuid")]
+    Uuid7,
 }
 
 impl Hash for RandomMethod {
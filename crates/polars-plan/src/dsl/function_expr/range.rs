@@ -3,7 +3,7 @@ use std::fmt;
 use polars_core::prelude::*;
 use polars_ops::series::ClosedInterval;
 #[cfg(feature = "temporal")]
-use polars_time::{ClosedWindow, Duration};
+use polars_time::{ClosedWindow, Duration, DurationCalendars};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -31,11 +31,13 @@ pub enum RangeFunction {
     DateRange {
         interval: Duration,
         closed: ClosedWindow,
+        calendars: DurationCalendars,
     },
     #[cfg(feature = "dtype-date")]
     DateRanges {
         interval: Duration,
         closed: ClosedWindow,
+        calendars: DurationCalendars,
     },
     #[cfg(feature = "dtype-datetime")]
     DatetimeRange {
@@ -61,6 +63,12 @@ pub enum RangeFunction {
         interval: Duration,
         closed: ClosedWindow,
     },
+    #[cfg(feature = "rrule")]
+    RRuleRange {
+        rule: PlSmallStr,
+        time_unit: Option<TimeUnit>,
+        time_zone: Option<TimeZone>,
+    },
 }
 
 impl From<RangeFunction> for FunctionExpr {
@@ -89,6 +97,8 @@ impl fmt::Display for RangeFunction {
             TimeRange { .. } => "time_range",
             #[cfg(feature = "dtype-time")]
             TimeRanges { .. } => "time_ranges",
+            #[cfg(feature = "rrule")]
+            RRuleRange { .. } => "rrule_range",
         };
         write!(f, "{s}")
     }
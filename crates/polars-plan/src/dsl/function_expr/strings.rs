@@ -95,11 +95,18 @@ pub enum StringFunction {
     SplitN(usize),
     #[cfg(feature = "temporal")]
     Strptime(DataTypeExpr, StrptimeOptions),
+    #[cfg(feature = "temporal")]
+    StrptimeMany(DataTypeExpr, StrptimeManyOptions),
     Split(bool),
     #[cfg(feature = "dtype-decimal")]
     ToDecimal {
         scale: usize,
     },
+    #[cfg(feature = "dtype-duration")]
+    ToDuration {
+        time_unit: TimeUnit,
+        strict: bool,
+    },
     #[cfg(feature = "nightly")]
     Titlecase,
     Uppercase,
@@ -193,6 +200,8 @@ impl Display for StringFunction {
             SplitN(_) => "splitn",
             #[cfg(feature = "temporal")]
             Strptime(_, _) => "strptime",
+            #[cfg(feature = "temporal")]
+            StrptimeMany(_, _) => "strptime_many",
             Split(inclusive) => {
                 if *inclusive {
                     "split_inclusive"
@@ -204,6 +213,8 @@ impl Display for StringFunction {
             Titlecase => "titlecase",
             #[cfg(feature = "dtype-decimal")]
             ToDecimal { .. } => "to_decimal",
+            #[cfg(feature = "dtype-duration")]
+            ToDuration { .. } => "to_duration",
             Uppercase => "uppercase",
             #[cfg(feature = "string_pad")]
             ZFill => "zfill",
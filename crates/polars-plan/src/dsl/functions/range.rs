@@ -35,8 +35,25 @@ pub fn int_ranges(start: Expr, end: Expr, step: Expr, dtype: impl Into<DataTypeE
 /// Create a date range from a `start` and `stop` expression.
 #[cfg(feature = "temporal")]
 pub fn date_range(start: Expr, end: Expr, interval: Duration, closed: ClosedWindow) -> Expr {
+    date_range_in(start, end, interval, closed, DurationCalendars::default())
+}
+
+/// Create a date range from a `start` and `stop` expression, counting business days and fiscal
+/// periods in `calendars`.
+#[cfg(feature = "temporal")]
+pub fn date_range_in(
+    start: Expr,
+    end: Expr,
+    interval: Duration,
+    closed: ClosedWindow,
+    calendars: DurationCalendars,
+) -> Expr {
     Expr::n_ary(
-        RangeFunction::DateRange { interval, closed },
+        RangeFunction::DateRange {
+            interval,
+            closed,
+            calendars,
+        },
         vec![start, end],
     )
 }
@@ -44,8 +61,25 @@ pub fn date_range(start: Expr, end: Expr, interval: Duration, closed: ClosedWind
 /// Create a column of date ranges from a `start` and `stop` expression.
 #[cfg(feature = "temporal")]
 pub fn date_ranges(start: Expr, end: Expr, interval: Duration, closed: ClosedWindow) -> Expr {
+    date_ranges_in(start, end, interval, closed, DurationCalendars::default())
+}
+
+/// Create a column of date ranges from a `start` and `stop` expression, counting business days and
+/// fiscal periods in `calendars`.
+#[cfg(feature = "temporal")]
+pub fn date_ranges_in(
+    start: Expr,
+    end: Expr,
+    interval: Duration,
+    closed: ClosedWindow,
+    calendars: DurationCalendars,
+) -> Expr {
     Expr::n_ary(
-        RangeFunction::DateRanges { interval, closed },
+        RangeFunction::DateRanges {
+            interval,
+            closed,
+            calendars,
+        },
         vec![start, end],
     )
 }
@@ -110,6 +144,29 @@ pub fn time_ranges(start: Expr, end: Expr, interval: Duration, closed: ClosedWin
     )
 }
 
+/// Generate the occurrences of an RFC 5545 recurrence rule, such as `"FREQ=MONTHLY;BYDAY=3FR"`,
+/// from `start` up to and including `end`.
+///
+/// `end` may be null if the rule has a `COUNT` or `UNTIL`. The rule is evaluated in the wall time
+/// of the time zone, so occurrences keep their local time across daylight saving time changes.
+#[cfg(feature = "rrule")]
+pub fn rrule_range(
+    start: Expr,
+    end: Expr,
+    rule: &str,
+    time_unit: Option<TimeUnit>,
+    time_zone: Option<TimeZone>,
+) -> Expr {
+    Expr::n_ary(
+        RangeFunction::RRuleRange {
+            rule: rule.into(),
+            time_unit,
+            time_zone,
+        },
+        vec![start, end],
+    )
+}
+
 /// Generate a series of equally-spaced points.
 pub fn linear_space(start: Expr, end: Expr, num_samples: Expr, closed: ClosedInterval) -> Expr {
     Expr::n_ary(
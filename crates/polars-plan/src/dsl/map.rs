@@ -0,0 +1,44 @@
+use crate::dsl::function_expr::MapFunction;
+use crate::prelude::*;
+
+/// Specialized expressions for [`Series`] of [`DataType::Map`].
+pub struct MapNameSpace(pub Expr);
+
+impl MapNameSpace {
+    /// Get the value stored under `key` in every map, or null if the map doesn't contain it.
+    pub fn get(self, key: Expr) -> Expr {
+        self.0
+            .map_binary(FunctionExpr::MapExpr(MapFunction::Get), key)
+    }
+
+    /// Check whether every map contains `key`.
+    pub fn contains_key(self, key: Expr) -> Expr {
+        self.0
+            .map_binary(FunctionExpr::MapExpr(MapFunction::ContainsKey), key)
+    }
+
+    /// Get the keys of every map as a list.
+    pub fn keys(self) -> Expr {
+        self.0.map_unary(FunctionExpr::MapExpr(MapFunction::Keys))
+    }
+
+    /// Get the values of every map as a list.
+    pub fn values(self) -> Expr {
+        self.0.map_unary(FunctionExpr::MapExpr(MapFunction::Values))
+    }
+
+    /// Get the entries of every map as a list of `{key, value}` structs.
+    #[allow(clippy::wrong_self_convention)]
+    pub fn to_entries(self) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::MapExpr(MapFunction::ToEntries))
+    }
+
+    /// Interpret a list of two-field structs as a map, the first field holding the keys and the
+    /// second the values.
+    #[allow(clippy::wrong_self_convention)]
+    pub fn from_entries(self) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::MapExpr(MapFunction::FromEntries))
+    }
+}
@@ -26,6 +26,8 @@ mod from;
 pub mod function_expr;
 pub mod functions;
 mod list;
+#[cfg(feature = "dtype-map")]
+mod map;
 mod match_to_schema;
 #[cfg(feature = "meta")]
 mod meta;
@@ -189,6 +191,32 @@ impl Expr {
         .into()
     }
 
+    fn time_weighted(self, by: Expr, method: TimeWeightedMethod) -> Self {
+        AggExpr::TimeWeighted {
+            input: Arc::new(self),
+            by: Arc::new(by),
+            method,
+        }
+        .into()
+    }
+
+    /// Compute the time-weighted mean per group, with `by` as the time of each sample.
+    ///
+    /// See [`TimeWeightedMethod`] for how windows of a dynamic or rolling group-by are handled.
+    pub fn time_weighted_mean(self, by: Expr) -> Self {
+        self.time_weighted(by, TimeWeightedMethod::Mean)
+    }
+
+    /// Compute the time-weighted integral per group, with `by` as the time of each sample.
+    pub fn time_weighted_integral(self, by: Expr) -> Self {
+        self.time_weighted(by, TimeWeightedMethod::Integral)
+    }
+
+    /// Get the last value as of the end of each window, with `by` as the time of each sample.
+    pub fn time_weighted_last(self, by: Expr) -> Self {
+        self.time_weighted(by, TimeWeightedMethod::Last)
+    }
+
     /// Get the group indexes of the group by operation.
     pub fn agg_groups(self) -> Self {
         AggExpr::AggGroups(Arc::new(self)).into()
@@ -1167,6 +1195,72 @@ impl Expr {
         self.rolling_quantile_by(by, QuantileMethod::Linear, 0.5, options)
     }
 
+    #[cfg(feature = "dynamic_group_by")]
+    fn finish_rolling_time_weighted_by(
+        self,
+        by: PlSmallStr,
+        window_size: Duration,
+        closed_window: ClosedWindow,
+        method: TimeWeightedMethod,
+    ) -> Expr {
+        self.time_weighted(col(by.clone()), method)
+            .rolling(RollingGroupOptions {
+                index_column: by,
+                period: window_size,
+                offset: -window_size,
+                closed_window,
+            })
+    }
+
+    /// Apply a rolling time-weighted mean over the windows that end at every value of the
+    /// sorted column `by`, carrying in the last value from before every window.
+    #[cfg(feature = "dynamic_group_by")]
+    pub fn rolling_time_weighted_mean_by(
+        self,
+        by: impl Into<PlSmallStr>,
+        window_size: Duration,
+        closed_window: ClosedWindow,
+    ) -> Expr {
+        self.finish_rolling_time_weighted_by(
+            by.into(),
+            window_size,
+            closed_window,
+            TimeWeightedMethod::Mean,
+        )
+    }
+
+    /// Apply a rolling time-weighted integral based on the sorted column `by`.
+    #[cfg(feature = "dynamic_group_by")]
+    pub fn rolling_time_weighted_integral_by(
+        self,
+        by: impl Into<PlSmallStr>,
+        window_size: Duration,
+        closed_window: ClosedWindow,
+    ) -> Expr {
+        self.finish_rolling_time_weighted_by(
+            by.into(),
+            window_size,
+            closed_window,
+            TimeWeightedMethod::Integral,
+        )
+    }
+
+    /// Get the last value as of the end of every rolling window based on the sorted column `by`.
+    #[cfg(feature = "dynamic_group_by")]
+    pub fn rolling_time_weighted_last_by(
+        self,
+        by: impl Into<PlSmallStr>,
+        window_size: Duration,
+        closed_window: ClosedWindow,
+    ) -> Expr {
+        self.finish_rolling_time_weighted_by(
+            by.into(),
+            window_size,
+            closed_window,
+            TimeWeightedMethod::Last,
+        )
+    }
+
     /// Apply a rolling minimum.
     ///
     /// See: [`RollingAgg::rolling_min`]
@@ -1602,6 +1696,12 @@ impl Expr {
         cat::CategoricalNameSpace(self)
     }
 
+    /// Get the [`map::MapNameSpace`].
+    #[cfg(feature = "dtype-map")]
+    pub fn map_(self) -> map::MapNameSpace {
+        map::MapNameSpace(self)
+    }
+
     /// Get the [`struct_::StructNameSpace`].
     #[cfg(feature = "dtype-struct")]
     pub fn struct_(self) -> struct_::StructNameSpace {
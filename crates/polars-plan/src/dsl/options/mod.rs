@@ -24,6 +24,8 @@ use polars_ops::prelude::{JoinArgs, JoinType};
 use polars_time::DynamicGroupOptions;
 #[cfg(feature = "dynamic_group_by")]
 use polars_time::RollingGroupOptions;
+#[cfg(feature = "dynamic_group_by")]
+use polars_time::SessionGroupOptions;
 use polars_utils::IdxSize;
 use polars_utils::pl_str::PlSmallStr;
 #[cfg(feature = "serde")]
@@ -69,6 +71,29 @@ impl Default for StrptimeOptions {
     }
 }
 
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct StrptimeManyOptions {
+    /// Formatting strings, tried in order for each value. If empty, the format is inferred
+    /// for each value separately.
+    pub formats: Vec<PlSmallStr>,
+    /// If set then polars will return an error listing the rows that fail to parse
+    pub strict: bool,
+    /// use a cache of unique, converted dates to apply the datetime conversion.
+    pub cache: bool,
+}
+
+impl Default for StrptimeManyOptions {
+    fn default() -> Self {
+        StrptimeManyOptions {
+            formats: vec![],
+            strict: true,
+            cache: true,
+        }
+    }
+}
+
 #[derive(Clone, PartialEq, Eq, IntoStaticStr, Debug)]
 #[cfg_attr(feature = "ir_serde", derive(Serialize, Deserialize))]
 #[strum(serialize_all = "snake_case")]
@@ -191,6 +216,31 @@ impl Default for WindowType {
     }
 }
 
+/// How samples are reduced by a time-weighted aggregation.
+///
+/// The value between two samples is interpolated linearly (the trapezoidal rule). In a dynamic
+/// or rolling group-by, the last sample before a window is carried in: the value at the start of
+/// the window is interpolated between it and the first sample in the window. The last sample in
+/// the window holds until the end of the window.
+///
+/// Time-weighted aggregations have no streaming implementation; the streaming engine evaluates
+/// them with the in-memory engine.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, IntoStaticStr)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+#[strum(serialize_all = "snake_case")]
+pub enum TimeWeightedMethod {
+    /// The integral divided by the duration it spans (the window in a dynamic or rolling
+    /// group-by).
+    Mean,
+    /// The area under the samples, in the physical unit of the time column (e.g. microseconds
+    /// for `Datetime("us")` or days for `Date`).
+    Integral,
+    /// The last value as of the end of the window, which is the carried in value if the window
+    /// doesn't have one.
+    Last,
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Hash, IntoStaticStr)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
@@ -305,6 +355,8 @@ pub struct GroupbyOptions {
     pub dynamic: Option<DynamicGroupOptions>,
     #[cfg(feature = "dynamic_group_by")]
     pub rolling: Option<RollingGroupOptions>,
+    #[cfg(feature = "dynamic_group_by")]
+    pub session: Option<SessionGroupOptions>,
     /// Take only a slice of the result
     pub slice: Option<(i64, usize)>,
 }
@@ -331,6 +383,17 @@ impl GroupbyOptions {
             false
         }
     }
+
+    pub(crate) fn is_session(&self) -> bool {
+        #[cfg(feature = "dynamic_group_by")]
+        {
+            self.session.is_some()
+        }
+        #[cfg(not(feature = "dynamic_group_by"))]
+        {
+            false
+        }
+    }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Default, Hash)]
@@ -8,6 +8,26 @@ impl Expr {
         })
     }
 
+    /// Generate a random (version 4) UUID for every row of this expression.
+    #[cfg(feature = "dtype-uuid")]
+    pub fn uuid4(self, seed: Option<u64>) -> Self {
+        self.map_unary(FunctionExpr::Random {
+            method: RandomMethod::Uuid4,
+            seed,
+        })
+    }
+
+    /// Generate a time-ordered (version 7) UUID for every row of this expression.
+    ///
+    /// All values share the current timestamp and are increasing within the result.
+    #[cfg(feature = "dtype-uuid")]
+    pub fn uuid7(self, seed: Option<u64>) -> Self {
+        self.map_unary(FunctionExpr::Random {
+            method: RandomMethod::Uuid7,
+            seed,
+        })
+    }
+
     pub fn sample_n(
         self,
         n: Expr,
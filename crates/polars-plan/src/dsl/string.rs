@@ -262,6 +262,24 @@ impl StringNameSpace {
             .map_binary(StringFunction::Strptime(dtype.into(), options), ambiguous)
     }
 
+    /// Convert a String column into a Date/Datetime column, trying each of the given formats in
+    /// order for every value, or inferring the format of every value if none are given.
+    ///
+    /// With `strict`, values that match none of the formats raise an error listing their rows;
+    /// otherwise they become null.
+    #[cfg(feature = "temporal")]
+    pub fn strptime_many(
+        self,
+        dtype: impl Into<DataTypeExpr>,
+        options: StrptimeManyOptions,
+        ambiguous: Expr,
+    ) -> Expr {
+        self.0.map_binary(
+            StringFunction::StrptimeMany(dtype.into(), options),
+            ambiguous,
+        )
+    }
+
     /// Convert a String column into a Date column.
     #[cfg(feature = "dtype-date")]
     pub fn to_date(self, options: StrptimeOptions) -> Expr {
@@ -301,6 +319,17 @@ impl StringNameSpace {
         self.strptime(DataType::Time, options, lit("raise"))
     }
 
+    /// Convert a String column into a Duration column.
+    ///
+    /// Values are written like `"1d12h"` or as ISO 8601 durations like `"P1DT12H"`. Durations
+    /// whose length depends on the calendar, such as months, cannot be represented and fail to
+    /// parse. If `strict` is false, values that fail to parse become null instead of raising.
+    #[cfg(feature = "dtype-duration")]
+    pub fn to_duration(self, time_unit: TimeUnit, strict: bool) -> Expr {
+        self.0
+            .map_unary(StringFunction::ToDuration { time_unit, strict })
+    }
+
     /// Convert a String column into a Decimal column.
     #[cfg(feature = "dtype-decimal")]
     pub fn to_decimal(self, scale: usize) -> Expr {
@@ -104,6 +104,7 @@ impl IRAggExpr {
             A::Count { input: _, include_nulls: l_include_nulls } => matches!(other, A::Count { input: _, include_nulls: r_include_nulls } if l_include_nulls == r_include_nulls),
             A::Std(_, l_ddof) => matches!(other, A::Std(_, r_ddof) if l_ddof == r_ddof),
             A::Var(_, l_ddof) => matches!(other, A::Var(_, r_ddof) if l_ddof == r_ddof),
+            A::TimeWeighted { input: _, by: _, method: l_method } => matches!(other, A::TimeWeighted { input: _, by: _, method: r_method } if l_method == r_method),
 
             // Discriminant check done above.
             A::Median(_) |
@@ -70,14 +70,24 @@ pub enum IRTemporalFunction {
     WithTimeUnit(TimeUnit),
     #[cfg(feature = "timezones")]
     ConvertTimeZone(TimeZone),
+    #[cfg(feature = "timezones")]
+    ConvertTimeZoneBy,
     TimeStamp(TimeUnit),
-    Truncate,
+    Truncate(DurationCalendars),
     #[cfg(feature = "offset_by")]
-    OffsetBy,
+    OffsetBy(DurationCalendars),
     #[cfg(feature = "month_start")]
     MonthStart,
     #[cfg(feature = "month_end")]
     MonthEnd,
+    #[cfg(feature = "fiscal")]
+    FiscalYear(FiscalCalendar),
+    #[cfg(feature = "fiscal")]
+    FiscalQuarter(FiscalCalendar),
+    #[cfg(feature = "fiscal")]
+    FiscalPeriod(FiscalCalendar),
+    #[cfg(feature = "fiscal")]
+    FiscalWeek(FiscalCalendar),
     #[cfg(feature = "timezones")]
     BaseUtcOffset,
     #[cfg(feature = "timezones")]
@@ -86,6 +96,8 @@ pub enum IRTemporalFunction {
     Replace,
     #[cfg(feature = "timezones")]
     ReplaceTimeZone(Option<TimeZone>, NonExistent),
+    #[cfg(feature = "timezones")]
+    ReplaceTimeZoneBy(NonExistent),
     Combine(TimeUnit),
     DatetimeFunction {
         time_unit: TimeUnit,
@@ -128,6 +140,11 @@ impl IRTemporalFunction {
                 DataType::Datetime(tu, _) => Ok(DataType::Datetime(*tu, Some(tz.clone()))),
                 dtype => polars_bail!(ComputeError: "expected Datetime, got {}", dtype),
             }),
+            #[cfg(feature = "timezones")]
+            ConvertTimeZoneBy => mapper.try_map_dtype(|dt| match dt {
+                DataType::Datetime(tu, _) => Ok(DataType::Datetime(*tu, None)),
+                dtype => polars_bail!(ComputeError: "expected Datetime, got {}", dtype),
+            }),
             TimeStamp(_) => mapper.with_dtype(DataType::Int64),
             IsLeapYear => mapper.with_dtype(DataType::Boolean),
             Time => mapper.with_dtype(DataType::Time),
@@ -138,13 +155,17 @@ impl IRTemporalFunction {
                 DataType::Datetime(tu, _) => Ok(DataType::Datetime(*tu, None)),
                 dtype => polars_bail!(ComputeError: "expected Datetime, got {}", dtype),
             }),
-            Truncate => mapper.with_same_dtype(),
+            Truncate(_) => mapper.with_same_dtype(),
             #[cfg(feature = "offset_by")]
-            OffsetBy => mapper.with_same_dtype(),
+            OffsetBy(_) => mapper.with_same_dtype(),
             #[cfg(feature = "month_start")]
             MonthStart => mapper.with_same_dtype(),
             #[cfg(feature = "month_end")]
             MonthEnd => mapper.with_same_dtype(),
+            #[cfg(feature = "fiscal")]
+            FiscalYear(_) => mapper.with_dtype(DataType::Int32),
+            #[cfg(feature = "fiscal")]
+            FiscalQuarter(_) | FiscalPeriod(_) | FiscalWeek(_) => mapper.with_dtype(DataType::Int8),
             #[cfg(feature = "timezones")]
             BaseUtcOffset => mapper.with_dtype(DataType::Duration(TimeUnit::Milliseconds)),
             #[cfg(feature = "timezones")]
@@ -153,6 +174,10 @@ impl IRTemporalFunction {
             Replace => mapper.with_same_dtype(),
             #[cfg(feature = "timezones")]
             ReplaceTimeZone(tz, _non_existent) => mapper.map_datetime_dtype_timezone(tz.as_ref()),
+            #[cfg(feature = "timezones")]
+            ReplaceTimeZoneBy(_non_existent) => {
+                mapper.map_datetime_dtype_timezone(Some(&TimeZone::UTC))
+            },
             DatetimeFunction {
                 time_unit,
                 time_zone,
@@ -207,22 +232,26 @@ impl IRTemporalFunction {
             | T::TotalMicroseconds { .. }
             | T::TotalNanoseconds { .. } => FunctionOptions::elementwise(),
             #[cfg(feature = "timezones")]
-            T::ConvertTimeZone(_) => FunctionOptions::elementwise(),
+            T::ConvertTimeZone(_) | T::ConvertTimeZoneBy => FunctionOptions::elementwise(),
             #[cfg(feature = "month_start")]
             T::MonthStart => FunctionOptions::elementwise(),
             #[cfg(feature = "month_end")]
             T::MonthEnd => FunctionOptions::elementwise(),
+            #[cfg(feature = "fiscal")]
+            T::FiscalYear(_) | T::FiscalQuarter(_) | T::FiscalPeriod(_) | T::FiscalWeek(_) => {
+                FunctionOptions::elementwise()
+            },
             #[cfg(feature = "timezones")]
             T::BaseUtcOffset | T::DSTOffset => FunctionOptions::elementwise(),
-            T::Truncate => FunctionOptions::elementwise(),
+            T::Truncate(_) => FunctionOptions::elementwise(),
             #[cfg(feature = "offset_by")]
-            T::OffsetBy => FunctionOptions::elementwise(),
+            T::OffsetBy(_) => FunctionOptions::elementwise(),
             T::Round => FunctionOptions::elementwise(),
             T::Replace => FunctionOptions::elementwise(),
             #[cfg(feature = "dtype-duration")]
             T::Duration(_) => FunctionOptions::elementwise(),
             #[cfg(feature = "timezones")]
-            T::ReplaceTimeZone(_, _) => FunctionOptions::elementwise(),
+            T::ReplaceTimeZone(_, _) | T::ReplaceTimeZoneBy(_) => FunctionOptions::elementwise(),
             T::Combine(_) => FunctionOptions::elementwise(),
             T::DatetimeFunction { .. } => {
                 FunctionOptions::elementwise().with_flags(|f| f | FunctionFlags::ALLOW_RENAME)
@@ -275,16 +304,26 @@ impl Display for IRTemporalFunction {
             ToString(_) => "to_string",
             #[cfg(feature = "timezones")]
             ConvertTimeZone(_) => "convert_time_zone",
+            #[cfg(feature = "timezones")]
+            ConvertTimeZoneBy => "convert_time_zone_by",
             CastTimeUnit(_) => "cast_time_unit",
             WithTimeUnit(_) => "with_time_unit",
             TimeStamp(tu) => return write!(f, "dt.timestamp({tu})"),
-            Truncate => "truncate",
+            Truncate(_) => "truncate",
             #[cfg(feature = "offset_by")]
-            OffsetBy => "offset_by",
+            OffsetBy(_) => "offset_by",
             #[cfg(feature = "month_start")]
             MonthStart => "month_start",
             #[cfg(feature = "month_end")]
             MonthEnd => "month_end",
+            #[cfg(feature = "fiscal")]
+            FiscalYear(_) => "fiscal_year",
+            #[cfg(feature = "fiscal")]
+            FiscalQuarter(_) => "fiscal_quarter",
+            #[cfg(feature = "fiscal")]
+            FiscalPeriod(_) => "fiscal_period",
+            #[cfg(feature = "fiscal")]
+            FiscalWeek(_) => "fiscal_week",
             #[cfg(feature = "timezones")]
             BaseUtcOffset => "base_utc_offset",
             #[cfg(feature = "timezones")]
@@ -293,6 +332,8 @@ impl Display for IRTemporalFunction {
             Replace => "replace",
             #[cfg(feature = "timezones")]
             ReplaceTimeZone(_, _) => "replace_time_zone",
+            #[cfg(feature = "timezones")]
+            ReplaceTimeZoneBy(_) => "replace_time_zone_by",
             DatetimeFunction { .. } => return write!(f, "dt.datetime"),
             Combine(_) => "combine",
         };
@@ -586,7 +627,7 @@ pub(super) fn cast_time_unit(s: &Column, tu: TimeUnit) -> PolarsResult<Column> {
     }
 }
 
-pub(super) fn truncate(s: &[Column]) -> PolarsResult<Column> {
+pub(super) fn truncate(s: &[Column], calendars: &DurationCalendars) -> PolarsResult<Column> {
     let time_series = &s[0];
     let every = s[1].str()?;
 
@@ -595,11 +636,17 @@ pub(super) fn truncate(s: &[Column]) -> PolarsResult<Column> {
             #[cfg(feature = "timezones")]
             Some(tz) => time_series
                 .datetime()?
-                .truncate(tz.parse::<Tz>().ok().as_ref(), every)?
+                .truncate(tz.parse::<Tz>().ok().as_ref(), every, calendars)?
+                .into_column(),
+            _ => time_series
+                .datetime()?
+                .truncate(None, every, calendars)?
                 .into_column(),
-            _ => time_series.datetime()?.truncate(None, every)?.into_column(),
         },
-        DataType::Date => time_series.date()?.truncate(None, every)?.into_column(),
+        DataType::Date => time_series
+            .date()?
+            .truncate(None, every, calendars)?
+            .into_column(),
         dt => polars_bail!(opq = round, got = dt, expected = "date/datetime"),
     };
     out.set_sorted_flag(time_series.is_sorted_flag());
@@ -607,8 +654,13 @@ pub(super) fn truncate(s: &[Column]) -> PolarsResult<Column> {
 }
 
 #[cfg(feature = "offset_by")]
-pub(super) fn offset_by(s: &[Column]) -> PolarsResult<Column> {
-    impl_offset_by(s[0].as_materialized_series(), s[1].as_materialized_series()).map(Column::from)
+pub(super) fn offset_by(s: &[Column], calendars: &DurationCalendars) -> PolarsResult<Column> {
+    impl_offset_by(
+        s[0].as_materialized_series(),
+        s[1].as_materialized_series(),
+        calendars,
+    )
+    .map(Column::from)
 }
 
 #[cfg(feature = "month_start")]
@@ -645,6 +697,30 @@ pub(super) fn month_end(s: &Column) -> PolarsResult<Column> {
     })
 }
 
+#[cfg(feature = "fiscal")]
+pub(super) fn fiscal_year(s: &Column, calendar: &FiscalCalendar) -> PolarsResult<Column> {
+    let s = date(s)?;
+    polars_time::fiscal_year(s.date()?, calendar).map(|ca| ca.into_column())
+}
+
+#[cfg(feature = "fiscal")]
+pub(super) fn fiscal_quarter(s: &Column, calendar: &FiscalCalendar) -> PolarsResult<Column> {
+    let s = date(s)?;
+    polars_time::fiscal_quarter(s.date()?, calendar).map(|ca| ca.into_column())
+}
+
+#[cfg(feature = "fiscal")]
+pub(super) fn fiscal_period(s: &Column, calendar: &FiscalCalendar) -> PolarsResult<Column> {
+    let s = date(s)?;
+    polars_time::fiscal_period(s.date()?, calendar).map(|ca| ca.into_column())
+}
+
+#[cfg(feature = "fiscal")]
+pub(super) fn fiscal_week(s: &Column, calendar: &FiscalCalendar) -> PolarsResult<Column> {
+    let s = date(s)?;
+    polars_time::fiscal_week(s.date()?, calendar).map(|ca| ca.into_column())
+}
+
 #[cfg(feature = "timezones")]
 pub(super) fn base_utc_offset(s: &Column) -> PolarsResult<Column> {
     match s.dtype() {
@@ -69,6 +69,27 @@ pub(super) fn replace_time_zone(
     Ok(polars_ops::prelude::replace_time_zone(ca, time_zone, s2, non_existent)?.into_column())
 }
 
+#[cfg(feature = "timezones")]
+pub(super) fn replace_time_zone_by(
+    s: &[Column],
+    non_existent: NonExistent,
+) -> PolarsResult<Column> {
+    let ca = s[0].datetime()?;
+    let time_zones = s[1].str()?;
+    let ambiguous = s[2].str()?;
+    Ok(
+        polars_ops::prelude::replace_time_zone_by(ca, time_zones, ambiguous, non_existent)?
+            .into_column(),
+    )
+}
+
+#[cfg(feature = "timezones")]
+pub(super) fn convert_time_zone_by(s: &[Column]) -> PolarsResult<Column> {
+    let ca = s[0].datetime()?;
+    let time_zones = s[1].str()?;
+    Ok(polars_ops::prelude::convert_time_zone_by(ca, time_zones)?.into_column())
+}
+
 #[cfg(feature = "dtype-struct")]
 pub(super) fn value_counts(
     s: &Column,
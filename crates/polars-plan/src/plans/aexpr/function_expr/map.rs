@@ -0,0 +1,124 @@
+use polars_ops::chunked_array::map::MapNameSpace;
+
+use super::*;
+use crate::{map, map_as_slice};
+
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "ir_serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IRMapFunction {
+    Get,
+    ContainsKey,
+    Keys,
+    Values,
+    ToEntries,
+    FromEntries,
+}
+
+impl IRMapFunction {
+    pub(super) fn get_field(&self, mapper: FieldsMapper) -> PolarsResult<Field> {
+        use IRMapFunction::*;
+        match self {
+            Get => mapper.try_map_dtype(|dt| Ok(map_key_value_dtypes(dt)?.1.clone())),
+            ContainsKey => mapper.with_dtype(DataType::Boolean),
+            Keys => mapper.try_map_dtype(|dt| {
+                Ok(DataType::List(Box::new(
+                    map_key_value_dtypes(dt)?.0.clone(),
+                )))
+            }),
+            Values => mapper.try_map_dtype(|dt| {
+                Ok(DataType::List(Box::new(
+                    map_key_value_dtypes(dt)?.1.clone(),
+                )))
+            }),
+            ToEntries => mapper.try_map_dtype(|dt| {
+                let (key, value) = map_key_value_dtypes(dt)?;
+                Ok(DataType::List(Box::new(DataType::_map_entries_type(
+                    key.clone(),
+                    value.clone(),
+                ))))
+            }),
+            FromEntries => mapper.try_map_dtype(|dt| match dt {
+                DataType::List(inner) => match inner.as_ref() {
+                    DataType::Struct(fields) if fields.len() == 2 => Ok(DataType::Map(
+                        Box::new(fields[0].dtype().clone()),
+                        Box::new(fields[1].dtype().clone()),
+                    )),
+                    _ => polars_bail!(
+                        InvalidOperation: "expected a list of {{key, value}} structs, got: {dt}"
+                    ),
+                },
+                _ => polars_bail!(
+                    InvalidOperation: "expected a list of {{key, value}} structs, got: {dt}"
+                ),
+            }),
+        }
+    }
+
+    pub fn function_options(&self) -> FunctionOptions {
+        FunctionOptions::elementwise()
+    }
+}
+
+fn map_key_value_dtypes(dtype: &DataType) -> PolarsResult<(&DataType, &DataType)> {
+    match dtype {
+        DataType::Map(key, value) => Ok((key, value)),
+        dt => polars_bail!(InvalidOperation: "expected Map type, got: {dt}"),
+    }
+}
+
+impl Display for IRMapFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use IRMapFunction::*;
+        let name = match self {
+            Get => "get",
+            ContainsKey => "contains_key",
+            Keys => "keys",
+            Values => "values",
+            ToEntries => "to_entries",
+            FromEntries => "from_entries",
+        };
+        write!(f, "map.{name}")
+    }
+}
+
+impl From<IRMapFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
+    fn from(func: IRMapFunction) -> Self {
+        use IRMapFunction::*;
+        match func {
+            Get => map_as_slice!(get),
+            ContainsKey => map_as_slice!(contains_key),
+            Keys => map!(keys),
+            Values => map!(values),
+            ToEntries => map!(to_entries),
+            FromEntries => map!(from_entries),
+        }
+    }
+}
+
+pub(super) fn get(s: &[Column]) -> PolarsResult<Column> {
+    let ca = s[0].map()?;
+    let key = s[1].as_materialized_series();
+    ca.map_get(key).map(Column::from)
+}
+
+pub(super) fn contains_key(s: &[Column]) -> PolarsResult<Column> {
+    let ca = s[0].map()?;
+    let key = s[1].as_materialized_series();
+    Ok(ca.map_contains_key(key)?.into_column())
+}
+
+pub(super) fn keys(s: &Column) -> PolarsResult<Column> {
+    Ok(s.map()?.map_keys().into_column())
+}
+
+pub(super) fn values(s: &Column) -> PolarsResult<Column> {
+    Ok(s.map()?.map_values().into_column())
+}
+
+pub(super) fn to_entries(s: &Column) -> PolarsResult<Column> {
+    Ok(s.map()?.map_to_entries().into_column())
+}
+
+pub(super) fn from_entries(s: &Column) -> PolarsResult<Column> {
+    Ok(s.list()?.clone().into_map()?.into_column())
+}
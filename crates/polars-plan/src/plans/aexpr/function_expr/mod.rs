@@ -39,6 +39,8 @@ mod index_of;
 mod list;
 #[cfg(feature = "log")]
 mod log;
+#[cfg(feature = "dtype-map")]
+mod map;
 mod nan;
 #[cfg(feature = "peaks")]
 mod peaks;
@@ -85,6 +87,8 @@ pub use correlation::IRCorrelationMethod;
 #[cfg(feature = "fused")]
 pub use fused::FusedOperator;
 pub use list::IRListFunction;
+#[cfg(feature = "dtype-map")]
+pub use map::IRMapFunction;
 pub use polars_core::datatypes::ReshapeDimension;
 use polars_core::prelude::*;
 use polars_core::series::IsSorted;
@@ -130,6 +134,8 @@ pub enum IRFunctionExpr {
     #[cfg(feature = "dtype-categorical")]
     Categorical(IRCategoricalFunction),
     ListExpr(IRListFunction),
+    #[cfg(feature = "dtype-map")]
+    MapExpr(IRMapFunction),
     #[cfg(feature = "strings")]
     StringExpr(IRStringFunction),
     #[cfg(feature = "dtype-struct")]
@@ -423,6 +429,8 @@ impl Hash for IRFunctionExpr {
             // Namespaces
             #[cfg(feature = "dtype-array")]
             ArrayExpr(f) => f.hash(state),
+            #[cfg(feature = "dtype-map")]
+            MapExpr(f) => f.hash(state),
             BinaryExpr(f) => f.hash(state),
             #[cfg(feature = "dtype-categorical")]
             Categorical(f) => f.hash(state),
@@ -723,6 +731,8 @@ impl Display for IRFunctionExpr {
             // Namespaces
             #[cfg(feature = "dtype-array")]
             ArrayExpr(func) => return write!(f, "{func}"),
+            #[cfg(feature = "dtype-map")]
+            MapExpr(func) => return write!(f, "{func}"),
             BinaryExpr(func) => return write!(f, "{func}"),
             #[cfg(feature = "dtype-categorical")]
             Categorical(func) => return write!(f, "{func}"),
@@ -1010,6 +1020,8 @@ impl From<IRFunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
             // Namespaces
             #[cfg(feature = "dtype-array")]
             ArrayExpr(func) => func.into(),
+            #[cfg(feature = "dtype-map")]
+            MapExpr(func) => func.into(),
             BinaryExpr(func) => func.into(),
             #[cfg(feature = "dtype-categorical")]
             Categorical(func) => func.into(),
@@ -1299,6 +1311,10 @@ impl From<IRFunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
                             map_as_slice!(random::sample_n, with_replacement, shuffle, seed)
                         }
                     },
+                    #[cfg(feature = "dtype-uuid")]
+                    Uuid4 => map!(random::uuid4, seed),
+                    #[cfg(feature = "dtype-uuid")]
+                    Uuid7 => map!(random::uuid7, seed),
                 }
             },
             SetSortedFlag(sorted) => map!(dispatch::set_sorted_flag, sorted),
@@ -1406,6 +1422,8 @@ impl IRFunctionExpr {
         match self {
             #[cfg(feature = "dtype-array")]
             F::ArrayExpr(e) => e.function_options(),
+            #[cfg(feature = "dtype-map")]
+            F::MapExpr(e) => e.function_options(),
             F::BinaryExpr(e) => e.function_options(),
             #[cfg(feature = "dtype-categorical")]
             F::Categorical(e) => e.function_options(),
@@ -1583,6 +1601,11 @@ impl IRFunctionExpr {
                 method: IRRandomMethod::Shuffle,
                 ..
             } => FunctionOptions::length_preserving(),
+            #[cfg(all(feature = "random", feature = "dtype-uuid"))]
+            F::Random {
+                method: IRRandomMethod::Uuid4 | IRRandomMethod::Uuid7,
+                ..
+            } => FunctionOptions::length_preserving(),
             F::SetSortedFlag(_) => FunctionOptions::elementwise(),
             #[cfg(feature = "ffi_plugin")]
             F::FfiPlugin { flags, .. } => *flags,
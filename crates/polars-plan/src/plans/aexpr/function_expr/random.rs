@@ -13,6 +13,10 @@ pub enum IRRandomMethod {
         with_replacement: bool,
         shuffle: bool,
     },
+    #[cfg(feature = "dtype-uuid")]
+    Uuid4,
+    #[cfg(feature = "dtype-uuid")]
+    Uuid7,
 }
 
 impl Hash for IRRandomMethod {
@@ -25,6 +29,16 @@ pub(super) fn shuffle(s: &Column, seed: Option<u64>) -> PolarsResult<Column> {
     Ok(s.shuffle(seed))
 }
 
+#[cfg(feature = "dtype-uuid")]
+pub(super) fn uuid4(s: &Column, seed: Option<u64>) -> PolarsResult<Column> {
+    Ok(UuidChunked::rand_v4(s.name().clone(), s.len(), seed).into_column())
+}
+
+#[cfg(feature = "dtype-uuid")]
+pub(super) fn uuid7(s: &Column, seed: Option<u64>) -> PolarsResult<Column> {
+    Ok(UuidChunked::rand_v7(s.name().clone(), s.len(), seed).into_column())
+}
+
 pub(super) fn sample_frac(
     s: &[Column],
     with_replacement: bool,
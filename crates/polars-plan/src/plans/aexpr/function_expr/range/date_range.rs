@@ -1,6 +1,6 @@
 use polars_core::prelude::*;
 use polars_core::utils::arrow::temporal_conversions::MICROSECONDS_IN_DAY;
-use polars_time::{ClosedWindow, Duration, datetime_range_impl};
+use polars_time::{ClosedWindow, Duration, DurationCalendars, datetime_range_impl};
 
 use super::utils::{
     ensure_range_bounds_contain_exactly_one_value, temporal_ranges_impl_broadcast,
@@ -13,6 +13,7 @@ pub(super) fn date_range(
     s: &[Column],
     interval: Duration,
     closed: ClosedWindow,
+    calendars: &DurationCalendars,
 ) -> PolarsResult<Column> {
     let start = &s[0];
     let end = &s[1];
@@ -41,6 +42,7 @@ pub(super) fn date_range(
         closed,
         TimeUnit::Microseconds,
         None,
+        calendars,
     )?;
 
     let to_type = DataType::Date;
@@ -51,6 +53,7 @@ pub(super) fn date_ranges(
     s: &[Column],
     interval: Duration,
     closed: ClosedWindow,
+    calendars: &DurationCalendars,
 ) -> PolarsResult<Column> {
     let start = &s[0];
     let end = &s[1];
@@ -82,6 +85,7 @@ pub(super) fn date_ranges(
             closed,
             TimeUnit::Microseconds,
             None,
+            calendars,
         )?;
         let rng = rng.cast(&DataType::Date).unwrap();
         let rng = rng.to_physical_repr();
@@ -1,7 +1,7 @@
 #[cfg(feature = "timezones")]
 use polars_core::prelude::time_zone::parse_time_zone;
 use polars_core::prelude::*;
-use polars_time::{ClosedWindow, Duration, datetime_range_impl};
+use polars_time::{ClosedWindow, Duration, DurationCalendars, datetime_range_impl};
 
 use super::utils::{
     ensure_range_bounds_contain_exactly_one_value, temporal_ranges_impl_broadcast,
@@ -95,7 +95,16 @@ pub(super) fn datetime_range(
                 Some(tz) => Some(parse_time_zone(tz)?),
                 _ => None,
             };
-            datetime_range_impl(name.clone(), start, end, interval, closed, tu, tz.as_ref())?
+            datetime_range_impl(
+                name.clone(),
+                start,
+                end,
+                interval,
+                closed,
+                tu,
+                tz.as_ref(),
+                &DurationCalendars::default(),
+            )?
         },
         _ => unimplemented!(),
     };
@@ -209,6 +218,7 @@ pub(super) fn datetime_ranges(
                     closed,
                     tu,
                     tz.as_ref(),
+                    &DurationCalendars::default(),
                 )?;
                 builder.append_slice(rng.physical().cont_slice().unwrap());
                 Ok(())
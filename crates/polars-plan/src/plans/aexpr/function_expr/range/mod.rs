@@ -4,6 +4,8 @@ mod date_range;
 mod datetime_range;
 mod int_range;
 mod linear_space;
+#[cfg(feature = "rrule")]
+mod rrule_range;
 #[cfg(feature = "dtype-time")]
 mod time_range;
 mod utils;
@@ -13,7 +15,7 @@ use std::fmt::{Display, Formatter};
 use polars_core::prelude::*;
 use polars_ops::series::ClosedInterval;
 #[cfg(feature = "temporal")]
-use polars_time::{ClosedWindow, Duration};
+use polars_time::{ClosedWindow, Duration, DurationCalendars};
 
 use super::{FunctionOptions, IRFunctionExpr};
 use crate::dsl::SpecialEq;
@@ -42,11 +44,13 @@ pub enum IRRangeFunction {
     DateRange {
         interval: Duration,
         closed: ClosedWindow,
+        calendars: DurationCalendars,
     },
     #[cfg(feature = "dtype-date")]
     DateRanges {
         interval: Duration,
         closed: ClosedWindow,
+        calendars: DurationCalendars,
     },
     #[cfg(feature = "dtype-datetime")]
     DatetimeRange {
@@ -72,6 +76,12 @@ pub enum IRRangeFunction {
         interval: Duration,
         closed: ClosedWindow,
     },
+    #[cfg(feature = "rrule")]
+    RRuleRange {
+        rule: PlSmallStr,
+        time_unit: Option<TimeUnit>,
+        time_zone: Option<TimeZone>,
+    },
 }
 
 fn map_linspace_dtype(mapper: &FieldsMapper) -> PolarsResult<DataType> {
@@ -148,6 +158,16 @@ impl IRRangeFunction {
             TimeRange { .. } => mapper.with_dtype(DataType::Time),
             #[cfg(feature = "dtype-time")]
             TimeRanges { .. } => mapper.with_dtype(DataType::List(Box::new(DataType::Time))),
+            #[cfg(feature = "rrule")]
+            RRuleRange {
+                rule: _,
+                time_unit,
+                time_zone,
+            } => {
+                let dtype =
+                    mapper.map_to_rrule_range_dtype(time_unit.as_ref(), time_zone.as_ref())?;
+                mapper.with_dtype(dtype)
+            },
         }
     }
 
@@ -172,6 +192,10 @@ impl IRRangeFunction {
             R::TimeRange { .. } => {
                 FunctionOptions::row_separable().with_flags(|f| f | FunctionFlags::ALLOW_RENAME)
             },
+            #[cfg(feature = "rrule")]
+            R::RRuleRange { .. } => FunctionOptions::row_separable()
+                .with_flags(|f| f | FunctionFlags::ALLOW_RENAME)
+                .with_supertyping(Default::default()),
             R::IntRanges { .. } => {
                 FunctionOptions::elementwise().with_flags(|f| f | FunctionFlags::ALLOW_RENAME)
             },
@@ -214,6 +238,8 @@ impl Display for IRRangeFunction {
             TimeRange { .. } => "time_range",
             #[cfg(feature = "dtype-time")]
             TimeRanges { .. } => "time_ranges",
+            #[cfg(feature = "rrule")]
+            RRuleRange { .. } => "rrule_range",
         };
         write!(f, "{s}")
     }
@@ -239,12 +265,20 @@ impl From<IRRangeFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
                 map_as_slice!(linear_space::linear_spaces, closed, array_width)
             },
             #[cfg(feature = "dtype-date")]
-            DateRange { interval, closed } => {
-                map_as_slice!(date_range::date_range, interval, closed)
+            DateRange {
+                interval,
+                closed,
+                calendars,
+            } => {
+                map_as_slice!(date_range::date_range, interval, closed, &calendars)
             },
             #[cfg(feature = "dtype-date")]
-            DateRanges { interval, closed } => {
-                map_as_slice!(date_range::date_ranges, interval, closed)
+            DateRanges {
+                interval,
+                closed,
+                calendars,
+            } => {
+                map_as_slice!(date_range::date_ranges, interval, closed, &calendars)
             },
             #[cfg(feature = "dtype-datetime")]
             DatetimeRange {
@@ -284,6 +318,19 @@ impl From<IRRangeFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             TimeRanges { interval, closed } => {
                 map_as_slice!(time_range::time_ranges, interval, closed)
             },
+            #[cfg(feature = "rrule")]
+            RRuleRange {
+                rule,
+                time_unit,
+                time_zone,
+            } => {
+                map_as_slice!(
+                    rrule_range::rrule_range,
+                    &rule,
+                    time_unit,
+                    time_zone.clone()
+                )
+            },
         }
     }
 }
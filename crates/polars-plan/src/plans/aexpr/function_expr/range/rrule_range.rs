@@ -0,0 +1,108 @@
+use arrow::temporal_conversions::{
+    timestamp_ms_to_datetime, timestamp_ns_to_datetime, timestamp_us_to_datetime,
+};
+#[cfg(feature = "timezones")]
+use polars_core::prelude::time_zone::parse_time_zone;
+use polars_core::prelude::*;
+use polars_time::RRule;
+
+use super::utils::{ensure_range_bounds_contain_exactly_one_value, temporal_series_to_i64_scalar};
+use crate::plans::aexpr::function_expr::FieldsMapper;
+
+/// The bound as a wall time in `time_zone`, or `None` if it is null.
+fn to_local_datetime(
+    bound: &Column,
+    time_zone: Option<&TimeZone>,
+) -> PolarsResult<Option<chrono::NaiveDateTime>> {
+    #[allow(unused_mut)] // `bound` is mutated within a "feature = timezones" block.
+    let mut bound = match bound.dtype() {
+        DataType::Date => bound.cast(&DataType::Datetime(TimeUnit::Microseconds, None))?,
+        DataType::Datetime(_, _) => bound.clone(),
+        dt => polars_bail!(InvalidOperation: "expected a temporal datatype, got {}", dt),
+    };
+    // A time zone aware bound is converted to the wall time of the output time zone.
+    #[cfg(feature = "timezones")]
+    if let DataType::Datetime(tu, Some(tz)) = bound.dtype() {
+        let tz = time_zone.unwrap_or(tz);
+        let dt = bound.cast(&DataType::Datetime(*tu, Some(tz.clone())))?;
+        bound = polars_ops::prelude::replace_time_zone(
+            dt.datetime().unwrap(),
+            None,
+            &StringChunked::from_iter(std::iter::once("raise")),
+            NonExistent::Raise,
+        )?
+        .into_column();
+    }
+    #[cfg(not(feature = "timezones"))]
+    let _ = time_zone;
+
+    let DataType::Datetime(tu, _) = bound.dtype() else {
+        unreachable!()
+    };
+    let func = match tu {
+        TimeUnit::Nanoseconds => timestamp_ns_to_datetime,
+        TimeUnit::Microseconds => timestamp_us_to_datetime,
+        TimeUnit::Milliseconds => timestamp_ms_to_datetime,
+    };
+    Ok(temporal_series_to_i64_scalar(&bound).map(func))
+}
+
+pub(super) fn rrule_range(
+    s: &[Column],
+    rule: &str,
+    time_unit: Option<TimeUnit>,
+    time_zone: Option<TimeZone>,
+) -> PolarsResult<Column> {
+    let start = &s[0];
+    let end = &s[1];
+    ensure_range_bounds_contain_exactly_one_value(start, end)?;
+    let rule = RRule::parse(rule)?;
+
+    let dtype = match (start.dtype(), time_unit, &time_zone) {
+        (DataType::Date, None, None) => {
+            polars_ensure!(
+                rule.is_daily_or_coarser(),
+                InvalidOperation: "a recurrence rule with a frequency finer than daily, or with BYHOUR, BYMINUTE or BYSECOND, needs a Datetime `start` or a `time_unit`"
+            );
+            DataType::Date
+        },
+        (DataType::Date, tu, tz) => {
+            DataType::Datetime(tu.unwrap_or(TimeUnit::Microseconds), tz.clone())
+        },
+        (DataType::Datetime(stu, stz), tu, tz) => {
+            DataType::Datetime(tu.unwrap_or(*stu), tz.clone().or_else(|| stz.clone()))
+        },
+        (dt, _, _) => polars_bail!(InvalidOperation: "expected a temporal datatype, got {}", dt),
+    };
+
+    let name = start.name().clone();
+    let start = to_local_datetime(start, time_zone.as_ref())?
+        .ok_or_else(|| polars_err!(ComputeError: "`start` of a recurrence rule cannot be null"))?;
+    let end = to_local_datetime(end, time_zone.as_ref())?;
+
+    let (tu, tz) = match &dtype {
+        DataType::Datetime(tu, tz) => (*tu, tz.as_ref()),
+        _ => (TimeUnit::Milliseconds, None),
+    };
+    let tz = match tz {
+        #[cfg(feature = "timezones")]
+        Some(tz) => Some(parse_time_zone(tz)?),
+        _ => None,
+    };
+    let out = polars_time::rrule_range_impl(name, &rule, start, end, tu, tz.as_ref())?;
+    Ok(out.cast(&dtype)?.into_column())
+}
+
+impl FieldsMapper<'_> {
+    pub(super) fn map_to_rrule_range_dtype(
+        &self,
+        time_unit: Option<&TimeUnit>,
+        time_zone: Option<&TimeZone>,
+    ) -> PolarsResult<DataType> {
+        let data_dtype = self.map_to_supertype()?.dtype;
+        match (data_dtype, time_unit, time_zone) {
+            (DataType::Date, None, None) => Ok(DataType::Date),
+            _ => self.map_to_datetime_range_dtype(time_unit, time_zone),
+        }
+    }
+}
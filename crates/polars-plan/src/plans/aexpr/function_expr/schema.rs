@@ -15,6 +15,8 @@ impl IRFunctionExpr {
             // Namespaces
             #[cfg(feature = "dtype-array")]
             ArrayExpr(func) => func.get_field(mapper),
+            #[cfg(feature = "dtype-map")]
+            MapExpr(func) => func.get_field(mapper),
             BinaryExpr(s) => s.get_field(mapper),
             #[cfg(feature = "dtype-categorical")]
             Categorical(func) => func.get_field(mapper),
@@ -312,6 +314,11 @@ impl IRFunctionExpr {
             #[cfg(feature = "rle")]
             RLEID => mapper.with_dtype(IDX_DTYPE),
             ToPhysical => mapper.to_physical_type(),
+            #[cfg(all(feature = "random", feature = "dtype-uuid"))]
+            Random {
+                method: IRRandomMethod::Uuid4 | IRRandomMethod::Uuid7,
+                ..
+            } => mapper.with_dtype(DataType::Uuid),
             #[cfg(feature = "random")]
             Random { .. } => mapper.with_same_dtype(),
             SetSortedFlag(_) => mapper.with_same_dtype(),
@@ -495,6 +502,8 @@ impl<'a> FieldsMapper<'a> {
             DataType::Boolean => DataType::Float64,
             DataType::Float32 => DataType::Float32,
             DataType::Float64 => DataType::Float64,
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => DataType::Float32,
             dt if dt.is_primitive_numeric() => DataType::Float64,
             #[cfg(feature = "dtype-datetime")]
             dt @ DataType::Datetime(_, _) => dt.clone(),
@@ -504,6 +513,8 @@ impl<'a> FieldsMapper<'a> {
             dt @ DataType::Time => dt.clone(),
             #[cfg(feature = "dtype-decimal")]
             DataType::Decimal(..) => DataType::Float64,
+            #[cfg(feature = "dtype-decimal256")]
+            DataType::Decimal256(..) => DataType::Float64,
 
             // All other types get mapped to a single `null` of the same type.
             dt => dt.clone(),
@@ -530,8 +541,12 @@ impl<'a> FieldsMapper<'a> {
         self.map_dtype(|dt| {
             let should_coerce = match dt {
                 DataType::Float32 => false,
+                #[cfg(feature = "dtype-f16")]
+                DataType::Float16 => return DataType::Float32,
                 #[cfg(feature = "dtype-decimal")]
                 DataType::Decimal(..) => coerce_decimal,
+                #[cfg(feature = "dtype-decimal256")]
+                DataType::Decimal256(..) => coerce_decimal,
                 DataType::Boolean => true,
                 dt => dt.is_primitive_numeric(),
             };
@@ -109,11 +109,19 @@ pub enum IRStringFunction {
     #[cfg(feature = "temporal")]
     // DataType can only be Date/Datetime/Time
     Strptime(DataType, StrptimeOptions),
+    #[cfg(feature = "temporal")]
+    // DataType can only be Date/Datetime
+    StrptimeMany(DataType, StrptimeManyOptions),
     Split(bool),
     #[cfg(feature = "dtype-decimal")]
     ToDecimal {
         scale: usize,
     },
+    #[cfg(feature = "dtype-duration")]
+    ToDuration {
+        time_unit: TimeUnit,
+        strict: bool,
+    },
     #[cfg(feature = "nightly")]
     Titlecase,
     Uppercase,
@@ -189,11 +197,25 @@ impl IRStringFunction {
                 },
                 _ => mapper.with_dtype(dtype.clone()),
             },
+            #[cfg(feature = "temporal")]
+            StrptimeMany(dtype, options) => match dtype {
+                #[cfg(feature = "dtype-datetime")]
+                DataType::Datetime(time_unit, None)
+                    if options.formats.iter().any(|format| {
+                        polars_time::chunkedarray::string::is_tz_aware_format(format)
+                    }) =>
+                {
+                    mapper.with_dtype(DataType::Datetime(*time_unit, Some(TimeZone::UTC)))
+                },
+                _ => mapper.with_dtype(dtype.clone()),
+            },
             Split(_) => mapper.with_dtype(DataType::List(Box::new(DataType::String))),
             #[cfg(feature = "nightly")]
             Titlecase => mapper.with_same_dtype(),
             #[cfg(feature = "dtype-decimal")]
             ToDecimal { scale } => mapper.with_dtype(DataType::Decimal(DEC128_MAX_PREC, *scale)),
+            #[cfg(feature = "dtype-duration")]
+            ToDuration { time_unit, .. } => mapper.with_dtype(DataType::Duration(*time_unit)),
             #[cfg(feature = "string_encoding")]
             HexEncode => mapper.with_same_dtype(),
             #[cfg(feature = "binary_encoding")]
@@ -271,11 +293,15 @@ impl IRStringFunction {
             S::Strptime(_, options) if options.format.is_some() => FunctionOptions::elementwise(),
             #[cfg(feature = "temporal")]
             S::Strptime(_, _) => FunctionOptions::elementwise_with_infer(),
+            #[cfg(feature = "temporal")]
+            S::StrptimeMany(_, _) => FunctionOptions::elementwise(),
             S::Split(_) => FunctionOptions::elementwise(),
             #[cfg(feature = "nightly")]
             S::Titlecase => FunctionOptions::elementwise(),
             #[cfg(feature = "dtype-decimal")]
             S::ToDecimal { .. } => FunctionOptions::elementwise(),
+            #[cfg(feature = "dtype-duration")]
+            S::ToDuration { .. } => FunctionOptions::elementwise(),
             #[cfg(feature = "string_encoding")]
             S::HexEncode | S::Base64Encode => FunctionOptions::elementwise(),
             #[cfg(feature = "binary_encoding")]
@@ -375,6 +401,8 @@ impl Display for IRStringFunction {
             SplitN(_) => "splitn",
             #[cfg(feature = "temporal")]
             Strptime(_, _) => "strptime",
+            #[cfg(feature = "temporal")]
+            StrptimeMany(_, _) => "strptime_many",
             Split(inclusive) => {
                 if *inclusive {
                     "split_inclusive"
@@ -386,6 +414,8 @@ impl Display for IRStringFunction {
             Titlecase => "titlecase",
             #[cfg(feature = "dtype-decimal")]
             ToDecimal { .. } => "to_decimal",
+            #[cfg(feature = "dtype-duration")]
+            ToDuration { .. } => "to_duration",
             Uppercase => "uppercase",
             #[cfg(feature = "string_pad")]
             ZFill => "zfill",
@@ -443,6 +473,10 @@ impl From<IRStringFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             Strptime(dtype, options) => {
                 map_as_slice!(strings::strptime, dtype.clone(), &options)
             },
+            #[cfg(feature = "temporal")]
+            StrptimeMany(dtype, options) => {
+                map_as_slice!(strings::strptime_many, &dtype, &options)
+            },
             Split(inclusive) => {
                 map_as_slice!(strings::split, inclusive)
             },
@@ -492,6 +526,8 @@ impl From<IRStringFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             Base64Decode(strict) => map!(strings::base64_decode, strict),
             #[cfg(feature = "dtype-decimal")]
             ToDecimal { scale } => map!(strings::to_decimal, scale),
+            #[cfg(feature = "dtype-duration")]
+            ToDuration { time_unit, strict } => map!(strings::to_duration, time_unit, strict),
             #[cfg(feature = "extract_jsonpath")]
             JsonDecode(dtype) => map!(strings::json_decode, dtype.clone()),
             #[cfg(feature = "extract_jsonpath")]
@@ -791,6 +827,64 @@ pub(super) fn strptime(
     }
 }
 
+#[cfg(feature = "temporal")]
+pub(super) fn strptime_many(
+    s: &[Column],
+    dtype: &DataType,
+    options: &StrptimeManyOptions,
+) -> PolarsResult<Column> {
+    let ca = s[0].str()?;
+    let ambiguous = s[1].str()?;
+    let formats: Vec<&str> = options.formats.iter().map(|f| f.as_str()).collect();
+    let out = match dtype {
+        #[cfg(feature = "dtype-date")]
+        DataType::Date => ca.as_date_many(&formats, options.cache)?.into_column(),
+        #[cfg(feature = "dtype-datetime")]
+        DataType::Datetime(time_unit, time_zone) => {
+            polars_ensure!(
+                ca.len() == ambiguous.len() || ca.len() == 1 || ambiguous.len() == 1,
+                length_mismatch = "str.strptime_many",
+                ca.len(),
+                ambiguous.len()
+            );
+            ca.as_datetime_many(
+                &formats,
+                *time_unit,
+                options.cache,
+                time_zone.as_ref(),
+                ambiguous,
+            )?
+            .into_column()
+        },
+        dt => polars_bail!(ComputeError: "not implemented for dtype {}", dt),
+    };
+
+    if options.strict && ca.null_count() != out.null_count() {
+        let num_failures = out.null_count() - ca.null_count();
+        let failed_rows: Vec<_> = ca
+            .iter()
+            .zip(out.as_materialized_series().is_null().iter())
+            .enumerate()
+            .filter_map(|(i, (opt_s, is_null))| match (opt_s, is_null) {
+                (Some(s), Some(true)) => Some(format!("{i} ({s:?})")),
+                _ => None,
+            })
+            .take(10)
+            .collect();
+        polars_bail!(
+            InvalidOperation:
+            "could not parse {} out of {} values in column '{}' with any of the formats {:?}; failed rows: {}{}",
+            num_failures,
+            ca.len(),
+            ca.name(),
+            options.formats,
+            failed_rows.join(", "),
+            if num_failures > failed_rows.len() { ", ..." } else { "" },
+        );
+    }
+    Ok(out)
+}
+
 #[cfg(feature = "dtype-struct")]
 pub(super) fn split_exact(s: &[Column], n: usize, inclusive: bool) -> PolarsResult<Column> {
     let ca = s[0].str()?;
@@ -1206,8 +1300,24 @@ pub(super) fn to_decimal(s: &Column, scale: usize) -> PolarsResult<Column> {
     ca.to_decimal(DEC128_MAX_PREC, scale).map(Column::from)
 }
 
+#[cfg(feature = "dtype-duration")]
+pub(super) fn to_duration(s: &Column, time_unit: TimeUnit, strict: bool) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    let out = ca.as_duration(time_unit).into_column();
+
+    if strict && ca.null_count() != out.null_count() {
+        handle_casting_failures(s.as_materialized_series(), out.as_materialized_series())?;
+    }
+    Ok(out)
+}
+
 #[cfg(feature = "extract_jsonpath")]
 pub(super) fn json_decode(s: &Column, dtype: DataType) -> PolarsResult<Column> {
+    #[cfg(feature = "dtype-json")]
+    if s.dtype() == &DataType::Json {
+        let ca = s.json()?.to_string();
+        return ca.json_decode(Some(dtype), None).map(Column::from);
+    }
     let ca = s.str()?;
     ca.json_decode(Some(dtype), None).map(Column::from)
 }
@@ -1215,8 +1325,13 @@ pub(super) fn json_decode(s: &Column, dtype: DataType) -> PolarsResult<Column> {
 #[cfg(feature = "extract_jsonpath")]
 pub(super) fn json_path_match(s: &[Column]) -> PolarsResult<Column> {
     _check_same_length(s, "json_path_match")?;
-    let ca = s[0].str()?;
     let pat = s[1].str()?;
+    // JSON columns are already parsed, so don't go through the text.
+    #[cfg(feature = "dtype-json")]
+    if s[0].dtype() == &DataType::Json {
+        return Ok(s[0].json()?.json_path_match(pat)?.into_column());
+    }
+    let ca = s[0].str()?;
     Ok(ca.json_path_match(pat)?.into_column())
 }
 
@@ -60,19 +60,29 @@ impl From<IRTemporalFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             TimeStamp(tu) => map!(datetime::timestamp, tu),
             #[cfg(feature = "timezones")]
             ConvertTimeZone(tz) => map!(datetime::convert_time_zone, &tz),
+            #[cfg(feature = "timezones")]
+            ConvertTimeZoneBy => map_as_slice!(dispatch::convert_time_zone_by),
             WithTimeUnit(tu) => map!(datetime::with_time_unit, tu),
             CastTimeUnit(tu) => map!(datetime::cast_time_unit, tu),
-            Truncate => {
-                map_as_slice!(datetime::truncate)
+            Truncate(calendars) => {
+                map_as_slice!(datetime::truncate, &calendars)
             },
             #[cfg(feature = "offset_by")]
-            OffsetBy => {
-                map_as_slice!(datetime::offset_by)
+            OffsetBy(calendars) => {
+                map_as_slice!(datetime::offset_by, &calendars)
             },
             #[cfg(feature = "month_start")]
             MonthStart => map!(datetime::month_start),
             #[cfg(feature = "month_end")]
             MonthEnd => map!(datetime::month_end),
+            #[cfg(feature = "fiscal")]
+            FiscalYear(calendar) => map!(datetime::fiscal_year, &calendar),
+            #[cfg(feature = "fiscal")]
+            FiscalQuarter(calendar) => map!(datetime::fiscal_quarter, &calendar),
+            #[cfg(feature = "fiscal")]
+            FiscalPeriod(calendar) => map!(datetime::fiscal_period, &calendar),
+            #[cfg(feature = "fiscal")]
+            FiscalWeek(calendar) => map!(datetime::fiscal_week, &calendar),
             #[cfg(feature = "timezones")]
             BaseUtcOffset => map!(datetime::base_utc_offset),
             #[cfg(feature = "timezones")]
@@ -83,6 +93,10 @@ impl From<IRTemporalFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             ReplaceTimeZone(tz, non_existent) => {
                 map_as_slice!(dispatch::replace_time_zone, tz.as_ref(), non_existent)
             },
+            #[cfg(feature = "timezones")]
+            ReplaceTimeZoneBy(non_existent) => {
+                map_as_slice!(dispatch::replace_time_zone_by, non_existent)
+            },
             Combine(tu) => map_as_slice!(temporal::combine, tu),
             DatetimeFunction {
                 time_unit,
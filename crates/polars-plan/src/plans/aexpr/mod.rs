@@ -63,6 +63,11 @@ pub enum IRAggExpr {
     Std(Node, u8),
     Var(Node, u8),
     AggGroups(Node),
+    TimeWeighted {
+        input: Node,
+        by: Node,
+        method: TimeWeightedMethod,
+    },
 }
 
 impl Hash for IRAggExpr {
@@ -85,6 +90,7 @@ impl Hash for IRAggExpr {
                 input: _,
                 include_nulls,
             } => include_nulls.hash(state),
+            Self::TimeWeighted { method, .. } => method.hash(state),
             _ => {},
         }
     }
@@ -113,6 +119,7 @@ impl IRAggExpr {
             (Quantile { method: l, .. }, Quantile { method: r, .. }) => l == r,
             (Std(_, l), Std(_, r)) => l == r,
             (Var(_, l), Var(_, r)) => l == r,
+            (TimeWeighted { method: l, .. }, TimeWeighted { method: r, .. }) => l == r,
             _ => std::mem::discriminant(self) == std::mem::discriminant(other),
         }
     }
@@ -156,7 +163,7 @@ impl From<IRAggExpr> for GroupByMethod {
             Std(_, ddof) => GroupByMethod::Std(ddof),
             Var(_, ddof) => GroupByMethod::Var(ddof),
             AggGroups(_) => GroupByMethod::Groups,
-            Quantile { .. } => unreachable!(),
+            Quantile { .. } | TimeWeighted { .. } => unreachable!(),
         }
     }
 }
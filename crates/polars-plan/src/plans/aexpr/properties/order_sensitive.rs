@@ -149,5 +149,10 @@ pub fn is_order_sensitive_agg_top_level(agg: &IRAggExpr) -> bool {
         IRAggExpr::Std(_, _) => false,
         IRAggExpr::Var(_, _) => false,
         IRAggExpr::AggGroups(_) => true,
+        IRAggExpr::TimeWeighted {
+            input: _,
+            by: _,
+            method: _,
+        } => true,
     }
 }
@@ -1,5 +1,7 @@
 #[cfg(feature = "dtype-decimal")]
 use polars_compute::decimal::DEC128_MAX_PREC;
+#[cfg(feature = "dtype-decimal256")]
+use polars_compute::decimal::DEC256_MAX_PREC;
 use polars_utils::format_pl_smallstr;
 use recursive::recursive;
 
@@ -139,6 +141,8 @@ impl AExpr {
                         let dt = match field.dtype() {
                             Boolean => Some(IDX_DTYPE),
                             UInt8 | Int8 | Int16 | UInt16 => Some(Int64),
+                            #[cfg(feature = "dtype-f16")]
+                            Float16 => Some(Float32),
                             _ => None,
                         };
                         if let Some(dt) = dt {
@@ -205,6 +209,17 @@ impl AExpr {
                         let mapper = FieldsMapper::new(&field);
                         mapper.map_numeric_to_float_dtype(true)
                     },
+                    TimeWeighted { input, method, .. } => {
+                        let field = ctx.arena.get(*input).to_field_impl(ctx)?;
+                        match method {
+                            TimeWeightedMethod::Last => Ok(field),
+                            TimeWeightedMethod::Mean | TimeWeightedMethod::Integral => {
+                                let field = [field];
+                                let mapper = FieldsMapper::new(&field);
+                                mapper.map_numeric_to_float_dtype(true)
+                            },
+                        }
+                    },
                 }
             },
             Cast { expr, dtype, .. } => {
@@ -323,7 +338,8 @@ impl AExpr {
             | Agg(NUnique(expr))
             | Agg(Count { input: expr, .. })
             | Agg(AggGroups(expr))
-            | Agg(Quantile { expr, .. }) => expr_arena.get(*expr).to_name(expr_arena),
+            | Agg(Quantile { expr, .. })
+            | Agg(TimeWeighted { input: expr, .. }) => expr_arena.get(*expr).to_name(expr_arena),
             AnonymousFunction { input, fmt_str, .. } => {
                 if input.is_empty() {
                     fmt_str.as_ref().clone()
@@ -390,6 +406,12 @@ fn get_arithmetic_field(
                 (Struct(_), r) if r.is_numeric() => {
                     return Ok(left_field);
                 },
+                #[cfg(feature = "dtype-interval")]
+                (Date | Datetime(_, _), Interval) => return Ok(left_field),
+                #[cfg(feature = "dtype-interval")]
+                (Interval, _) | (_, Interval) => {
+                    polars_bail!(InvalidOperation: "{} not allowed on {} and {}", op, left_field.dtype, right_type)
+                },
                 (Duration(_), Datetime(_, _))
                 | (Datetime(_, _), Duration(_))
                 | (Duration(_), Date)
@@ -447,6 +469,14 @@ fn get_arithmetic_field(
                 (Decimal(_, scale_left), Decimal(_, scale_right)) => {
                     Decimal(DEC128_MAX_PREC, *scale_left.max(scale_right))
                 },
+                #[cfg(feature = "dtype-decimal256")]
+                (
+                    Decimal256(_, scale_left),
+                    Decimal256(_, scale_right) | Decimal(_, scale_right),
+                )
+                | (Decimal(_, scale_left), Decimal256(_, scale_right)) => {
+                    Decimal256(DEC256_MAX_PREC, *scale_left.max(scale_right))
+                },
                 (left, right) => try_get_supertype(left, right)?,
             }
         },
@@ -462,6 +492,14 @@ fn get_arithmetic_field(
                 (Struct(_), r) if r.is_numeric() => {
                     return Ok(left_field);
                 },
+                #[cfg(feature = "dtype-interval")]
+                (Date | Datetime(_, _), Interval) => return Ok(left_field),
+                #[cfg(feature = "dtype-interval")]
+                (Interval, Date | Datetime(_, _)) => right_type,
+                #[cfg(feature = "dtype-interval")]
+                (Interval, _) | (_, Interval) => {
+                    polars_bail!(InvalidOperation: "{} not allowed on {} and {}", op, left_field.dtype, right_type)
+                },
                 (Duration(_), Datetime(_, _))
                 | (Datetime(_, _), Duration(_))
                 | (Duration(_), Date)
@@ -509,6 +547,14 @@ fn get_arithmetic_field(
                 (Decimal(_, scale_left), Decimal(_, scale_right)) => {
                     Decimal(DEC128_MAX_PREC, *scale_left.max(scale_right))
                 },
+                #[cfg(feature = "dtype-decimal256")]
+                (
+                    Decimal256(_, scale_left),
+                    Decimal256(_, scale_right) | Decimal(_, scale_right),
+                )
+                | (Decimal(_, scale_left), Decimal256(_, scale_right)) => {
+                    Decimal256(DEC256_MAX_PREC, *scale_left.max(scale_right))
+                },
                 (left, right) => try_get_supertype(left, right)?,
             }
         },
@@ -525,6 +571,10 @@ fn get_arithmetic_field(
                 (Struct(_), r) if r.is_numeric() => {
                     return Ok(left_field);
                 },
+                #[cfg(feature = "dtype-interval")]
+                (Interval, _) | (_, Interval) => {
+                    polars_bail!(InvalidOperation: "{} not allowed on {} and {}", op, left_field.dtype, right_type)
+                },
                 (Datetime(_, _), _)
                 | (_, Datetime(_, _))
                 | (Time, _)
@@ -560,6 +610,16 @@ fn get_arithmetic_field(
                     left_field.coerce(dtype);
                     return Ok(left_field);
                 },
+                #[cfg(feature = "dtype-decimal256")]
+                (
+                    Decimal256(_, scale_left),
+                    Decimal256(_, scale_right) | Decimal(_, scale_right),
+                )
+                | (Decimal(_, scale_left), Decimal256(_, scale_right)) => {
+                    let dtype = Decimal256(DEC256_MAX_PREC, *scale_left.max(scale_right));
+                    left_field.coerce(dtype);
+                    return Ok(left_field);
+                },
 
                 (l @ List(a), r @ List(b))
                     if ![a, b]
@@ -695,6 +755,8 @@ fn get_truediv_dtype(left_dtype: &DataType, right_dtype: &DataType) -> PolarsRes
             let dtype = get_truediv_dtype(list_dtype.leaf_dtype(), other_dtype.leaf_dtype())?;
             list_dtype.cast_leaf(dtype)
         },
+        #[cfg(feature = "dtype-f16")]
+        (Float16, _) | (_, Float16) => try_get_supertype(left_dtype, right_dtype)?,
         (Boolean, Float32) => Float32,
         (Boolean, b) if b.is_numeric() => Float64,
         (Boolean, Boolean) => Float64,
@@ -713,6 +775,11 @@ fn get_truediv_dtype(left_dtype: &DataType, right_dtype: &DataType) -> PolarsRes
         (Decimal(_, scale_left), Decimal(_, scale_right)) => {
             Decimal(DEC128_MAX_PREC, *scale_left.max(scale_right))
         },
+        #[cfg(feature = "dtype-decimal256")]
+        (Decimal256(_, scale_left), Decimal256(_, scale_right) | Decimal(_, scale_right))
+        | (Decimal(_, scale_left), Decimal256(_, scale_right)) => {
+            Decimal256(DEC256_MAX_PREC, *scale_left.max(scale_right))
+        },
         #[cfg(feature = "dtype-u8")]
         (UInt8 | Int8, Float32) => Float32,
         #[cfg(feature = "dtype-u16")]
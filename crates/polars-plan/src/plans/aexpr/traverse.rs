@@ -178,6 +178,10 @@ impl AExpr {
                         *expr = inputs[0];
                         *quantile = inputs[1];
                     },
+                    IRAggExpr::TimeWeighted { input, by, .. } => {
+                        *input = inputs[0];
+                        *by = inputs[1];
+                    },
                     _ => {
                         a.set_input(inputs[0]);
                     },
@@ -260,6 +264,7 @@ impl IRAggExpr {
             Std(input, _) => Single(*input),
             Var(input, _) => Single(*input),
             AggGroups(input) => Single(*input),
+            TimeWeighted { input, by, .. } => Many(vec![*input, *by]),
         }
     }
     pub fn set_input(&mut self, input: Node) {
@@ -279,6 +284,7 @@ impl IRAggExpr {
             Std(input, _) => input,
             Var(input, _) => input,
             AggGroups(input) => input,
+            TimeWeighted { input, .. } => input,
         };
         *node = input;
     }
@@ -530,6 +530,20 @@ fn expand_expression_rec(
                         })
                     },
                 )?,
+                AggExpr::TimeWeighted { input, by, method } => expand_expression_by_combination(
+                    &[input.as_ref().clone(), by.as_ref().clone()],
+                    ignored_selector_columns,
+                    schema,
+                    out,
+                    opt_flags,
+                    |e| {
+                        Expr::Agg(AggExpr::TimeWeighted {
+                            input: Arc::new(e[0].clone()),
+                            by: Arc::new(e[1].clone()),
+                            method: *method,
+                        })
+                    },
+                )?,
             }
         },
         Expr::Ternary {
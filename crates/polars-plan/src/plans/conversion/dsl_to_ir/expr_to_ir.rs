@@ -304,6 +304,11 @@ pub(super) fn to_aexpr_impl(
                     let (input, output_name) = to_aexpr_mat_lit_arc!(input)?;
                     (IRAggExpr::AggGroups(input), output_name)
                 },
+                AggExpr::TimeWeighted { input, by, method } => {
+                    let (input, output_name) = to_aexpr_mat_lit_arc!(input)?;
+                    let (by, _) = to_aexpr_mat_lit_arc!(by)?;
+                    (IRAggExpr::TimeWeighted { input, by, method }, output_name)
+                },
             };
             (AExpr::Agg(a_agg), output_name)
         },
@@ -58,6 +58,18 @@ pub(super) fn convert_functions(
 
     // Return before converting inputs
     let ir_function = match function {
+        #[cfg(feature = "dtype-map")]
+        F::MapExpr(map_function) => {
+            use {IRMapFunction as IM, MapFunction as M};
+            I::MapExpr(match map_function {
+                M::Get => IM::Get,
+                M::ContainsKey => IM::ContainsKey,
+                M::Keys => IM::Keys,
+                M::Values => IM::Values,
+                M::ToEntries => IM::ToEntries,
+                M::FromEntries => IM::FromEntries,
+            })
+        },
         #[cfg(feature = "dtype-array")]
         F::ArrayExpr(array_function) => {
             use {ArrayFunction as A, IRArrayFunction as IA};
@@ -287,9 +299,20 @@ pub(super) fn convert_functions(
                     );
                     IS::Strptime(dtype, strptime_options)
                 },
+                #[cfg(feature = "temporal")]
+                S::StrptimeMany(data_type, options) => {
+                    let dtype = data_type.into_datatype(ctx.schema)?;
+                    polars_ensure!(
+                        matches!(dtype, DataType::Date | DataType::Datetime(_, _)),
+                        InvalidOperation: "`strptime_many` expects a `date` or `datetime` got {dtype}"
+                    );
+                    IS::StrptimeMany(dtype, options)
+                },
                 S::Split(v) => IS::Split(v),
                 #[cfg(feature = "dtype-decimal")]
                 S::ToDecimal { scale } => IS::ToDecimal { scale },
+                #[cfg(feature = "dtype-duration")]
+                S::ToDuration { time_unit, strict } => IS::ToDuration { time_unit, strict },
                 #[cfg(feature = "nightly")]
                 S::Titlecase => IS::Titlecase,
                 S::Uppercase => IS::Uppercase,
@@ -388,14 +411,24 @@ pub(super) fn convert_functions(
                 T::WithTimeUnit(time_unit) => IT::WithTimeUnit(time_unit),
                 #[cfg(feature = "timezones")]
                 T::ConvertTimeZone(time_zone) => IT::ConvertTimeZone(time_zone),
+                #[cfg(feature = "timezones")]
+                T::ConvertTimeZoneBy => IT::ConvertTimeZoneBy,
                 T::TimeStamp(time_unit) => IT::TimeStamp(time_unit),
-                T::Truncate => IT::Truncate,
+                T::Truncate(calendars) => IT::Truncate(calendars),
                 #[cfg(feature = "offset_by")]
-                T::OffsetBy => IT::OffsetBy,
+                T::OffsetBy(calendars) => IT::OffsetBy(calendars),
                 #[cfg(feature = "month_start")]
                 T::MonthStart => IT::MonthStart,
                 #[cfg(feature = "month_end")]
                 T::MonthEnd => IT::MonthEnd,
+                #[cfg(feature = "fiscal")]
+                T::FiscalYear(calendar) => IT::FiscalYear(calendar),
+                #[cfg(feature = "fiscal")]
+                T::FiscalQuarter(calendar) => IT::FiscalQuarter(calendar),
+                #[cfg(feature = "fiscal")]
+                T::FiscalPeriod(calendar) => IT::FiscalPeriod(calendar),
+                #[cfg(feature = "fiscal")]
+                T::FiscalWeek(calendar) => IT::FiscalWeek(calendar),
                 #[cfg(feature = "timezones")]
                 T::BaseUtcOffset => IT::BaseUtcOffset,
                 #[cfg(feature = "timezones")]
@@ -406,6 +439,8 @@ pub(super) fn convert_functions(
                 T::ReplaceTimeZone(time_zone, non_existent) => {
                     IT::ReplaceTimeZone(time_zone, non_existent)
                 },
+                #[cfg(feature = "timezones")]
+                T::ReplaceTimeZoneBy(non_existent) => IT::ReplaceTimeZoneBy(non_existent),
                 T::Combine(time_unit) => IT::Combine(time_unit),
                 T::DatetimeFunction {
                     time_unit,
@@ -604,14 +639,28 @@ pub(super) fn convert_functions(
                 array_width,
             },
             #[cfg(feature = "dtype-date")]
-            RangeFunction::DateRange { interval, closed } => {
+            RangeFunction::DateRange {
+                interval,
+                closed,
+                calendars,
+            } => {
                 polars_ensure!(e[0].is_scalar(ctx.arena), ShapeMismatch: "non-scalar start passed to `date_range`");
                 polars_ensure!(e[1].is_scalar(ctx.arena), ShapeMismatch: "non-scalar end passed to `date_range`");
-                IRRangeFunction::DateRange { interval, closed }
+                IRRangeFunction::DateRange {
+                    interval,
+                    closed,
+                    calendars,
+                }
             },
             #[cfg(feature = "dtype-date")]
-            RangeFunction::DateRanges { interval, closed } => {
-                IRRangeFunction::DateRanges { interval, closed }
+            RangeFunction::DateRanges {
+                interval,
+                closed,
+                calendars,
+            } => IRRangeFunction::DateRanges {
+                interval,
+                closed,
+                calendars,
             },
             #[cfg(feature = "dtype-datetime")]
             RangeFunction::DatetimeRange {
@@ -651,6 +700,21 @@ pub(super) fn convert_functions(
             RangeFunction::TimeRanges { interval, closed } => {
                 IRRangeFunction::TimeRanges { interval, closed }
             },
+            #[cfg(feature = "rrule")]
+            RangeFunction::RRuleRange {
+                rule,
+                time_unit,
+                time_zone,
+            } => {
+                polars_ensure!(e[0].is_scalar(ctx.arena), ShapeMismatch: "non-scalar start passed to `rrule_range`");
+                polars_ensure!(e[1].is_scalar(ctx.arena), ShapeMismatch: "non-scalar end passed to `rrule_range`");
+                polars_time::RRule::parse(&rule)?;
+                IRRangeFunction::RRuleRange {
+                    rule,
+                    time_unit,
+                    time_zone,
+                }
+            },
         }),
         #[cfg(feature = "trigonometry")]
         F::Trigonometry(trigonometric_function) => {
@@ -915,6 +979,10 @@ pub(super) fn convert_functions(
                         with_replacement,
                         shuffle,
                     },
+                    #[cfg(feature = "dtype-uuid")]
+                    R::Uuid4 => IR::Uuid4,
+                    #[cfg(feature = "dtype-uuid")]
+                    R::Uuid7 => IR::Uuid7,
                 },
                 seed,
             }
@@ -468,7 +468,10 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                 to_alp_impl(owned(input), ctxt).map_err(|e| e.context(failed_here!(group_by)))?;
 
             // Rolling + group-by sorts the whole table, so remove unneeded columns
-            if ctxt.opt_flags.eager() && options.is_rolling() && !keys.is_empty() {
+            if ctxt.opt_flags.eager()
+                && (options.is_rolling() || options.is_session())
+                && !keys.is_empty()
+            {
                 ctxt.opt_flags.insert(OptFlags::PROJECTION_PUSHDOWN)
             }
 
@@ -1165,6 +1168,17 @@ fn resolve_group_by(
                 output_schema.with_column("_upper_boundary".into(), dtype.clone());
             }
             output_schema.with_column(name.clone(), dtype.clone());
+        } else if let Some(options) = _options.session.as_ref() {
+            let name = options.index_column.clone();
+            keys.push(col(name.clone()));
+            key_names.insert(name.clone());
+            pop_keys = true;
+            let dtype = input_schema.try_get(name.as_str())?;
+            if options.include_boundaries {
+                output_schema.with_column("_lower_boundary".into(), dtype.clone());
+                output_schema.with_column("_upper_boundary".into(), dtype.clone());
+            }
+            output_schema.with_column(name.clone(), dtype.clone());
         }
     }
     let keys_index_len = output_schema.len();
@@ -155,6 +155,16 @@ pub fn node_to_expr(node: Node, expr_arena: &Arena<AExpr>) -> Expr {
                 let exp = node_to_expr(expr, expr_arena);
                 AggExpr::AggGroups(Arc::new(exp)).into()
             },
+            IRAggExpr::TimeWeighted { input, by, method } => {
+                let input = node_to_expr(input, expr_arena);
+                let by = node_to_expr(by, expr_arena);
+                AggExpr::TimeWeighted {
+                    input: Arc::new(input),
+                    by: Arc::new(by),
+                    method,
+                }
+                .into()
+            },
             IRAggExpr::Count {
                 input,
                 include_nulls,
@@ -248,6 +258,18 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
     use {FunctionExpr as F, IRFunctionExpr as IF};
 
     let function = match function {
+        #[cfg(feature = "dtype-map")]
+        IF::MapExpr(f) => {
+            use {IRMapFunction as IM, MapFunction as M};
+            F::MapExpr(match f {
+                IM::Get => M::Get,
+                IM::ContainsKey => M::ContainsKey,
+                IM::Keys => M::Keys,
+                IM::Values => M::Values,
+                IM::ToEntries => M::ToEntries,
+                IM::FromEntries => M::FromEntries,
+            })
+        },
         #[cfg(feature = "dtype-array")]
         IF::ArrayExpr(f) => {
             use {ArrayFunction as A, IRArrayFunction as IA};
@@ -453,9 +475,13 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IB::Strptime(dtype, strptime_options) => {
                     B::Strptime(dtype.into(), strptime_options)
                 },
+                #[cfg(feature = "temporal")]
+                IB::StrptimeMany(dtype, options) => B::StrptimeMany(dtype.into(), options),
                 IB::Split(v) => B::Split(v),
                 #[cfg(feature = "dtype-decimal")]
                 IB::ToDecimal { scale } => B::ToDecimal { scale },
+                #[cfg(feature = "dtype-duration")]
+                IB::ToDuration { time_unit, strict } => B::ToDuration { time_unit, strict },
                 #[cfg(feature = "nightly")]
                 IB::Titlecase => B::Titlecase,
                 IB::Uppercase => B::Uppercase,
@@ -553,14 +579,24 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IB::WithTimeUnit(time_unit) => B::WithTimeUnit(time_unit),
                 #[cfg(feature = "timezones")]
                 IB::ConvertTimeZone(time_zone) => B::ConvertTimeZone(time_zone),
+                #[cfg(feature = "timezones")]
+                IB::ConvertTimeZoneBy => B::ConvertTimeZoneBy,
                 IB::TimeStamp(time_unit) => B::TimeStamp(time_unit),
-                IB::Truncate => B::Truncate,
+                IB::Truncate(calendars) => B::Truncate(calendars),
                 #[cfg(feature = "offset_by")]
-                IB::OffsetBy => B::OffsetBy,
+                IB::OffsetBy(calendars) => B::OffsetBy(calendars),
                 #[cfg(feature = "month_start")]
                 IB::MonthStart => B::MonthStart,
                 #[cfg(feature = "month_end")]
                 IB::MonthEnd => B::MonthEnd,
+                #[cfg(feature = "fiscal")]
+                IB::FiscalYear(calendar) => B::FiscalYear(calendar),
+                #[cfg(feature = "fiscal")]
+                IB::FiscalQuarter(calendar) => B::FiscalQuarter(calendar),
+                #[cfg(feature = "fiscal")]
+                IB::FiscalPeriod(calendar) => B::FiscalPeriod(calendar),
+                #[cfg(feature = "fiscal")]
+                IB::FiscalWeek(calendar) => B::FiscalWeek(calendar),
                 #[cfg(feature = "timezones")]
                 IB::BaseUtcOffset => B::BaseUtcOffset,
                 #[cfg(feature = "timezones")]
@@ -571,6 +607,8 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IB::ReplaceTimeZone(time_zone, non_existent) => {
                     B::ReplaceTimeZone(time_zone, non_existent)
                 },
+                #[cfg(feature = "timezones")]
+                IB::ReplaceTimeZoneBy(non_existent) => B::ReplaceTimeZoneBy(non_existent),
                 IB::Combine(time_unit) => B::Combine(time_unit),
                 IB::DatetimeFunction {
                     time_unit,
@@ -713,9 +751,25 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                     array_width,
                 },
                 #[cfg(feature = "dtype-date")]
-                IR::DateRange { interval, closed } => R::DateRange { interval, closed },
+                IR::DateRange {
+                    interval,
+                    closed,
+                    calendars,
+                } => R::DateRange {
+                    interval,
+                    closed,
+                    calendars,
+                },
                 #[cfg(feature = "dtype-date")]
-                IR::DateRanges { interval, closed } => R::DateRanges { interval, closed },
+                IR::DateRanges {
+                    interval,
+                    closed,
+                    calendars,
+                } => R::DateRanges {
+                    interval,
+                    closed,
+                    calendars,
+                },
                 #[cfg(feature = "dtype-datetime")]
                 IR::DatetimeRange {
                     interval,
@@ -744,6 +798,16 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IR::TimeRange { interval, closed } => R::TimeRange { interval, closed },
                 #[cfg(feature = "dtype-time")]
                 IR::TimeRanges { interval, closed } => R::TimeRanges { interval, closed },
+                #[cfg(feature = "rrule")]
+                IR::RRuleRange {
+                    rule,
+                    time_unit,
+                    time_zone,
+                } => R::RRuleRange {
+                    rule,
+                    time_unit,
+                    time_zone,
+                },
             })
         },
         #[cfg(feature = "trigonometry")]
@@ -988,6 +1052,10 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                         with_replacement,
                         shuffle,
                     },
+                    #[cfg(feature = "dtype-uuid")]
+                    IR::Uuid4 => R::Uuid4,
+                    #[cfg(feature = "dtype-uuid")]
+                    IR::Uuid7 => R::Uuid7,
                 },
                 seed,
             }
@@ -115,6 +115,29 @@ fn err_date_str_compare() -> PolarsResult<()> {
     }
 }
 
+/// Rewrite `Date/Datetime ± Interval` into a calendar-aware `offset_by`.
+#[cfg(feature = "dtype-interval")]
+fn process_interval_arithmetic(
+    expr_arena: &mut Arena<AExpr>,
+    node_temporal: Node,
+    node_interval: Node,
+    negate: bool,
+) -> AExpr {
+    let mut interval = AExprBuilder::new_from_node(node_interval);
+    if negate {
+        interval = interval.negate(expr_arena);
+    }
+    let function = IRFunctionExpr::TemporalExpr(IRTemporalFunction::OffsetBy(Default::default()));
+    AExpr::Function {
+        input: vec![
+            ExprIR::from_node(node_temporal, expr_arena),
+            ExprIR::from_node(interval.node(), expr_arena),
+        ],
+        options: function.function_options(),
+        function,
+    }
+}
+
 pub(super) fn process_binary(
     expr_arena: &mut Arena<AExpr>,
     input_schema: &Schema,
@@ -207,6 +230,26 @@ pub(super) fn process_binary(
         _ => {},
     }
 
+    #[cfg(feature = "dtype-interval")]
+    match (&type_left, &type_right, op) {
+        (Date | Datetime(_, _), Interval, Operator::Plus | Operator::Minus) => {
+            return Ok(Some(process_interval_arithmetic(
+                expr_arena,
+                node_left,
+                node_right,
+                op == Operator::Minus,
+            )));
+        },
+        (Interval, Date | Datetime(_, _), Operator::Plus) => {
+            return Ok(Some(process_interval_arithmetic(
+                expr_arena, node_right, node_left, false,
+            )));
+        },
+        // Intervals have no fixed length; other arithmetic is left to the engine to reject.
+        (Interval, _, op) | (_, Interval, op) if op.is_arithmetic() => return Ok(None),
+        _ => {},
+    }
+
     if op.is_arithmetic() {
         match (&type_left, &type_right) {
             (Duration(_), Duration(_)) => return Ok(None),
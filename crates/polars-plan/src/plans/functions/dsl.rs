@@ -39,6 +39,8 @@ pub enum DslFunction {
     Unpivot {
         args: UnpivotArgsDSL,
     },
+    #[cfg(feature = "upsample")]
+    Upsample(UpsampleArgs),
     Rename {
         existing: Arc<[PlSmallStr]>,
         new: Arc<[PlSmallStr]>,
@@ -106,6 +108,13 @@ impl DslFunction {
                     schema: Default::default(),
                 }
             },
+            #[cfg(feature = "upsample")]
+            DslFunction::Upsample(args) => {
+                args.validate(input_schema)?;
+                FunctionIR::Upsample {
+                    args: Arc::new(args),
+                }
+            },
             DslFunction::FunctionIR(func) => func,
             DslFunction::RowIndex { name, offset } => FunctionIR::RowIndex {
                 name,
@@ -3,6 +3,8 @@ mod dsl;
 #[cfg(feature = "python")]
 mod python_udf;
 mod schema;
+#[cfg(feature = "upsample")]
+mod upsample;
 
 use std::borrow::Cow;
 use std::fmt::{Debug, Display, Formatter};
@@ -17,6 +19,8 @@ use polars_utils::pl_str::PlSmallStr;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use strum_macros::IntoStaticStr;
+#[cfg(feature = "upsample")]
+pub use upsample::*;
 
 #[cfg(feature = "python")]
 use crate::dsl::python_dsl::PythonFunction;
@@ -59,6 +63,10 @@ pub enum FunctionIR {
         #[cfg_attr(feature = "ir_serde", serde(skip))]
         schema: CachedSchema,
     },
+    #[cfg(feature = "upsample")]
+    Upsample {
+        args: Arc<UpsampleArgs>,
+    },
     #[cfg_attr(feature = "ir_serde", serde(skip))]
     Opaque {
         function: Arc<dyn DataFrameUdf>,
@@ -91,6 +99,8 @@ impl PartialEq for FunctionIR {
             (Explode { columns: l, .. }, Explode { columns: r, .. }) => l == r,
             #[cfg(feature = "pivot")]
             (Unpivot { args: l, .. }, Unpivot { args: r, .. }) => l == r,
+            #[cfg(feature = "upsample")]
+            (Upsample { args: l }, Upsample { args: r }) => l == r,
             (RowIndex { name: l, .. }, RowIndex { name: r, .. }) => l == r,
             _ => false,
         }
@@ -120,6 +130,8 @@ impl Hash for FunctionIR {
             FunctionIR::Explode { columns, schema: _ } => columns.hash(state),
             #[cfg(feature = "pivot")]
             FunctionIR::Unpivot { args, schema: _ } => args.hash(state),
+            #[cfg(feature = "upsample")]
+            FunctionIR::Upsample { args } => args.hash(state),
             FunctionIR::RowIndex {
                 name,
                 schema: _,
@@ -145,6 +157,8 @@ impl FunctionIR {
             #[cfg(feature = "python")]
             OpaquePython(OpaquePythonUdf { streamable, .. }) => *streamable,
             RowIndex { .. } => false,
+            #[cfg(feature = "upsample")]
+            Upsample { .. } => false,
         }
     }
 
@@ -154,6 +168,8 @@ impl FunctionIR {
         match self {
             #[cfg(feature = "pivot")]
             Unpivot { .. } => true,
+            #[cfg(feature = "upsample")]
+            Upsample { .. } => true,
             Explode { .. } => true,
             _ => false,
         }
@@ -167,6 +183,8 @@ impl FunctionIR {
             OpaquePython(OpaquePythonUdf { predicate_pd, .. }) => *predicate_pd,
             #[cfg(feature = "pivot")]
             Unpivot { .. } => true,
+            #[cfg(feature = "upsample")]
+            Upsample { .. } => true,
             Rechunk | Unnest { .. } | Explode { .. } => true,
             RowIndex { .. } | FastCount { .. } => false,
         }
@@ -181,6 +199,8 @@ impl FunctionIR {
             Rechunk | FastCount { .. } | Unnest { .. } | Explode { .. } => true,
             #[cfg(feature = "pivot")]
             Unpivot { .. } => true,
+            #[cfg(feature = "upsample")]
+            Upsample { .. } => true,
             RowIndex { .. } => true,
         }
     }
@@ -190,6 +210,13 @@ impl FunctionIR {
         match self {
             Unnest { columns } => Cow::Borrowed(columns.as_ref()),
             Explode { columns, .. } => Cow::Borrowed(columns.as_ref()),
+            #[cfg(feature = "upsample")]
+            Upsample { args } => {
+                let mut columns = Vec::with_capacity(args.by.len() + 1);
+                columns.push(args.index_column.clone());
+                columns.extend(args.by.iter().cloned());
+                Cow::Owned(columns)
+            },
             _ => Cow::Borrowed(&[]),
         }
     }
@@ -225,6 +252,8 @@ impl FunctionIR {
                 let args = (**args).clone();
                 df.unpivot2(args)
             },
+            #[cfg(feature = "upsample")]
+            Upsample { args } => args.evaluate(df),
             RowIndex { name, offset, .. } => df.with_row_index(name.clone(), *offset),
         }
     }
@@ -240,6 +269,8 @@ impl FunctionIR {
             FunctionIR::Explode { .. } => true,
             #[cfg(feature = "pivot")]
             FunctionIR::Unpivot { .. } => true,
+            #[cfg(feature = "upsample")]
+            FunctionIR::Upsample { .. } => true,
             FunctionIR::Opaque { .. } => true,
         }
     }
@@ -251,6 +282,8 @@ impl FunctionIR {
             Self::OpaquePython(..) => false,
             #[cfg(feature = "pivot")]
             Self::Unpivot { .. } => false,
+            #[cfg(feature = "upsample")]
+            Self::Upsample { .. } => false,
             Self::RowIndex { .. }
             | Self::FastCount { .. }
             | Self::Rechunk
@@ -271,6 +304,8 @@ impl FunctionIR {
             Self::OpaquePython(..) => false,
             #[cfg(feature = "pivot")]
             Self::Unpivot { .. } => false,
+            #[cfg(feature = "upsample")]
+            Self::Upsample { .. } => false,
             Self::RowIndex { .. }
             | Self::FastCount { .. }
             | Self::Explode { .. }
@@ -311,6 +346,8 @@ impl Display for FunctionIR {
                     ScanSourcesDisplay(sources)
                 )
             },
+            #[cfg(feature = "upsample")]
+            Upsample { args } => write!(f, "{args}"),
             v => {
                 let s: &str = v.into();
                 write!(f, "{s}")
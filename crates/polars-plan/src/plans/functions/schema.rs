@@ -90,6 +90,8 @@ impl FunctionIR {
             Explode { schema, columns } => explode_schema(schema, input_schema, columns),
             #[cfg(feature = "pivot")]
             Unpivot { schema, args } => unpivot_schema(args, schema, input_schema),
+            #[cfg(feature = "upsample")]
+            Upsample { args } => Ok(Cow::Owned(args.schema(input_schema))),
         }
     }
 }
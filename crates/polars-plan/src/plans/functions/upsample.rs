@@ -0,0 +1,187 @@
+use polars_core::chunked_array::cast::CastOptions;
+use polars_ops::series::{InterpolationMethod, interpolate, interpolate_by};
+use polars_time::prelude::*;
+
+use super::*;
+
+/// How the rows introduced by an upsample are filled.
+#[derive(Clone, Debug, Default, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum UpsampleFill {
+    /// Leave the new rows null.
+    #[default]
+    None,
+    /// Carry the previous value forward, up to `limit` rows.
+    Forward(FillNullLimit),
+    /// Carry the next value backward, up to `limit` rows.
+    Backward(FillNullLimit),
+    /// Interpolate linearly between the neighbouring values, ignoring the index.
+    Linear,
+    /// Interpolate linearly, weighted by the distance on the index column.
+    Time,
+    /// Fill with a constant. Numeric values fill all numeric columns, other values only fill
+    /// columns of the same dtype.
+    Value(Scalar),
+}
+
+#[derive(Clone, Debug, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct UpsampleArgs {
+    pub index_column: PlSmallStr,
+    pub by: Arc<[PlSmallStr]>,
+    pub every: Duration,
+    pub fill: UpsampleFill,
+}
+
+impl UpsampleArgs {
+    /// Whether the fill strategy is applied to a column of this dtype.
+    fn fills(&self, dtype: &DataType) -> bool {
+        match &self.fill {
+            UpsampleFill::None => false,
+            UpsampleFill::Forward(_) | UpsampleFill::Backward(_) => true,
+            UpsampleFill::Linear | UpsampleFill::Time => dtype.is_primitive_numeric(),
+            UpsampleFill::Value(value) => {
+                value.dtype() == dtype
+                    || (value.dtype().is_primitive_numeric() && dtype.is_primitive_numeric())
+            },
+        }
+    }
+
+    fn is_value_column(&self, name: &str) -> bool {
+        name != self.index_column && !self.by.iter().any(|k| k == name)
+    }
+
+    pub(super) fn validate(&self, input_schema: &Schema) -> PolarsResult<()> {
+        let index_dtype = input_schema.try_get(&self.index_column)?;
+        ensure_duration_matches_dtype(self.every, index_dtype, "every")?;
+        polars_ensure!(
+            !self.every.negative() && !self.every.is_zero(),
+            InvalidOperation: "`every` must be positive in upsample, got {}", self.every
+        );
+        for key in self.by.iter() {
+            input_schema.try_get(key)?;
+        }
+        Ok(())
+    }
+
+    pub(super) fn schema(&self, input_schema: &SchemaRef) -> SchemaRef {
+        if !matches!(self.fill, UpsampleFill::Linear | UpsampleFill::Time) {
+            return input_schema.clone();
+        }
+        // Interpolated integers become floats.
+        let schema = input_schema
+            .iter()
+            .map(|(name, dtype)| {
+                let dtype = if self.is_value_column(name) && dtype.is_integer() {
+                    DataType::Float64
+                } else {
+                    dtype.clone()
+                };
+                (name.clone(), dtype)
+            })
+            .collect::<Schema>();
+        Arc::new(schema)
+    }
+
+    pub(super) fn evaluate(&self, df: DataFrame) -> PolarsResult<DataFrame> {
+        let schema = self.schema(df.schema());
+        if df.height() == 0 {
+            return Ok(DataFrame::empty_with_schema(&schema));
+        }
+        let names = df.get_column_names_owned();
+
+        let out = if self.by.is_empty() {
+            self.upsample_group(&df, &[])?
+        } else {
+            let key_idx = self
+                .by
+                .iter()
+                .map(|k| df.try_get_column_index(k))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            // Don't parallelize this, this may SO on large data.
+            df.group_by_stable(self.by.iter().cloned())?
+                .apply(|df| self.upsample_group(&df, &key_idx))?
+        };
+        out.select(names)
+    }
+
+    /// Upsamples a single group and fills the new rows. The keys are taken from the first row of
+    /// the group.
+    fn upsample_group(&self, df: &DataFrame, key_idx: &[usize]) -> PolarsResult<DataFrame> {
+        let out = df.upsample_stable(Vec::<PlSmallStr>::new(), &self.index_column, self.every)?;
+        let height = out.height();
+        let index = out.column(&self.index_column)?.clone();
+
+        let columns = out
+            .take_columns()
+            .into_iter()
+            .map(|c| {
+                let name = c.name().clone();
+                if let Some(i) = self.by.iter().position(|k| k == &name) {
+                    let key = &df.get_columns()[key_idx[i]];
+                    return Ok(key.new_from_index(0, height));
+                }
+                if !self.is_value_column(&name) || !self.fills(c.dtype()) {
+                    return Ok(c);
+                }
+                self.fill_column(c, &index)
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        DataFrame::new(columns)
+    }
+
+    fn fill_column(&self, c: Column, index: &Column) -> PolarsResult<Column> {
+        let c = match (&self.fill, c.dtype()) {
+            (UpsampleFill::Linear | UpsampleFill::Time, dtype) if dtype.is_integer() => {
+                c.cast(&DataType::Float64)?
+            },
+            _ => c,
+        };
+        match &self.fill {
+            UpsampleFill::None => Ok(c),
+            UpsampleFill::Forward(limit) => c.fill_null(FillNullStrategy::Forward(*limit)),
+            UpsampleFill::Backward(limit) => c.fill_null(FillNullStrategy::Backward(*limit)),
+            UpsampleFill::Linear => {
+                Ok(interpolate(c.as_materialized_series(), InterpolationMethod::Linear).into())
+            },
+            UpsampleFill::Time => {
+                let index = match index.dtype() {
+                    DataType::Datetime(_, _) | DataType::Date => index.clone(),
+                    _ => index.cast(&DataType::Int64)?,
+                };
+                interpolate_by(&c, &index, true)
+            },
+            UpsampleFill::Value(value) => {
+                let value = value
+                    .clone()
+                    .cast_with_options(c.dtype(), CastOptions::Strict)?;
+                let value = Column::new_scalar(c.name().clone(), value, c.len());
+                c.zip_with(&c.is_not_null(), &value)
+            },
+        }
+    }
+}
+
+impl Display for UpsampleArgs {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "UPSAMPLE every {} on \"{}\"",
+            self.every, self.index_column
+        )?;
+        if !self.by.is_empty() {
+            write!(f, " by ")?;
+            fmt_column_delimited(f, self.by.as_ref(), "[", "]")?;
+        }
+        match &self.fill {
+            UpsampleFill::None => Ok(()),
+            UpsampleFill::Forward(_) => write!(f, " fill: forward"),
+            UpsampleFill::Backward(_) => write!(f, " fill: backward"),
+            UpsampleFill::Linear => write!(f, " fill: linear"),
+            UpsampleFill::Time => write!(f, " fill: time"),
+            UpsampleFill::Value(value) => write!(f, " fill: {:?}", value.value()),
+        }
+    }
+}
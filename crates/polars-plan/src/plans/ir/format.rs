@@ -476,6 +476,13 @@ impl Display for ExprIRDisplay<'_> {
                         self.with_root(quantile),
                         <&'static str>::from(method),
                     ),
+                    TimeWeighted { input, by, method } => write!(
+                        f,
+                        "{}.time_weighted_{}(by={})",
+                        self.with_root(input),
+                        <&'static str>::from(method),
+                        self.with_root(by),
+                    ),
                 }
             },
             Cast {
@@ -55,6 +55,11 @@ macro_rules! push_expr {
                     AggGroups(e) => $push($c, e),
                     Std(e, _) => $push($c, e),
                     Var(e, _) => $push($c, e),
+                    TimeWeighted { input, by, .. } => {
+                        $push($c, by);
+                        // latest, so that it is popped first
+                        $push($c, input);
+                    },
                 }
             },
             Ternary {
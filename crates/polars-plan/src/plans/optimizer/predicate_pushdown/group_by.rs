@@ -17,7 +17,8 @@ pub(super) fn process_group_by(
     use IR::*;
 
     #[cfg(feature = "dynamic_group_by")]
-    let no_push = { options.rolling.is_some() || options.dynamic.is_some() };
+    let no_push =
+        { options.rolling.is_some() || options.dynamic.is_some() || options.session.is_some() };
 
     #[cfg(not(feature = "dynamic_group_by"))]
     let no_push = false;
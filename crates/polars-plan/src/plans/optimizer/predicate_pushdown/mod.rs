@@ -530,6 +530,31 @@ impl PredicatePushDown<'_> {
                                 expr_arena,
                             ))
                         },
+                        #[cfg(feature = "upsample")]
+                        FunctionIR::Upsample { args } => {
+                            // Only predicates on the keys filter whole groups, other predicates
+                            // would change the generated time grid.
+                            let condition = |name: &PlSmallStr| !args.by.contains(name);
+                            let local_predicates = transfer_to_local_by_name(
+                                expr_arena,
+                                &mut acc_predicates,
+                                condition,
+                            );
+
+                            let lp = self.pushdown_and_continue(
+                                lp,
+                                acc_predicates,
+                                lp_arena,
+                                expr_arena,
+                                false,
+                            )?;
+                            Ok(self.optional_apply_predicate(
+                                lp,
+                                local_predicates,
+                                lp_arena,
+                                expr_arena,
+                            ))
+                        },
                         FunctionIR::Unnest { columns } => {
                             let exclude = columns.iter().cloned().collect::<PlHashSet<_>>();
 
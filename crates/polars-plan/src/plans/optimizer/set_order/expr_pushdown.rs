@@ -198,6 +198,12 @@ pub fn get_frame_observing(
                 }
                 O::None
             },
+            IRAggExpr::TimeWeighted { input, by, .. } => {
+                if rec!(*input).has_frame_ordering() || rec!(*by).has_frame_ordering() {
+                    return Err(FrameOrderObserved);
+                }
+                O::None
+            },
 
             // @NOTE: This aggregation makes very little sense. We do the most pessimistic thing
             // possible here.
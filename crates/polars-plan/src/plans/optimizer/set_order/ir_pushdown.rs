@@ -109,6 +109,7 @@ pub(super) fn pushdown_orders(
                 let is_order_observing = apply.is_some()
                     || options.is_dynamic()
                     || options.is_rolling()
+                    || options.is_session()
                     || *maintain_order
                     || {
                         // _ -> Unordered
@@ -12,7 +12,9 @@ use polars::chunked_array::object::PolarsObjectSafe;
 use polars::datatypes::OwnedObject;
 use polars::datatypes::{DataType, Field, TimeUnit};
 use polars::prelude::{AnyValue, PlSmallStr, Series, TimeZone};
-use polars_compute::decimal::{DEC128_MAX_PREC, DecimalFmtBuffer, dec128_fits};
+use polars_compute::decimal::{
+    DEC128_MAX_PREC, Decimal256FmtBuffer, DecimalFmtBuffer, dec128_fits,
+};
 use polars_core::utils::any_values_to_supertype_and_n_dtypes;
 use polars_core::utils::arrow::temporal_conversions::date32_to_date;
 use polars_utils::aliases::PlFixedStateQuality;
@@ -77,6 +79,7 @@ pub(crate) fn any_value_into_py_object<'py>(
         AnyValue::Int128(v) => v.into_bound_py_any(py),
         AnyValue::Float32(v) => v.into_bound_py_any(py),
         AnyValue::Float64(v) => v.into_bound_py_any(py),
+        AnyValue::Float16(v) => v.to_f32().into_bound_py_any(py),
         AnyValue::Null => py.None().into_bound_py_any(py),
         AnyValue::Boolean(v) => v.into_bound_py_any(py),
         AnyValue::String(v) => v.into_bound_py_any(py),
@@ -102,6 +105,7 @@ pub(crate) fn any_value_into_py_object<'py>(
             time_delta.into_bound_py_any(py)
         },
         AnyValue::Time(v) => nanos_since_midnight_to_naivetime(v).into_bound_py_any(py),
+        AnyValue::Interval(v) => (v.months(), v.days(), v.ns()).into_bound_py_any(py),
         AnyValue::Array(v, _) | AnyValue::List(v) => PySeries::new(v).to_list(py),
         ref av @ AnyValue::Struct(_, _, flds) => {
             Ok(struct_dict(py, av._iter_struct_av(), flds)?.into_any())
@@ -127,6 +131,20 @@ pub(crate) fn any_value_into_py_object<'py>(
             let s = buf.format_dec128(v, scale, false, false);
             convert.call1((prec, s))
         },
+        AnyValue::Decimal256(v, prec, scale) => {
+            let convert = utils.getattr(intern!(py, "to_py_decimal"))?;
+            let mut buf = Decimal256FmtBuffer::new();
+            let s = buf.format_dec256(v, scale, false, false);
+            convert.call1((prec, s))
+        },
+        AnyValue::Uuid(v) => {
+            let uuid = py
+                .import(intern!(py, "uuid"))?
+                .getattr(intern!(py, "UUID"))?;
+            let kwargs = PyDict::new(py);
+            kwargs.set_item(intern!(py, "int"), v)?;
+            uuid.call((), Some(&kwargs))
+        },
     }
 }
 
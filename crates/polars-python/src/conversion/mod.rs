@@ -313,6 +313,14 @@ impl<'py> IntoPyObject<'py> for &Wrap<DataType> {
                 let class = pl.getattr(intern!(py, "Unknown"))?;
                 class.call0()
             },
+            DataType::Interval => Err(unsupported_dtype(&self.0)),
+            DataType::Map(_, _) => Err(unsupported_dtype(&self.0)),
+            DataType::Float16 => Err(unsupported_dtype(&self.0)),
+            DataType::Extension { .. } => Err(unsupported_dtype(&self.0)),
+            DataType::Decimal256(_, _) => Err(unsupported_dtype(&self.0)),
+            DataType::Uuid => Err(unsupported_dtype(&self.0)),
+            DataType::Union(_, _) => Err(unsupported_dtype(&self.0)),
+            DataType::Json => Err(unsupported_dtype(&self.0)),
             DataType::BinaryOffset => {
                 unimplemented!()
             },
@@ -320,6 +328,11 @@ impl<'py> IntoPyObject<'py> for &Wrap<DataType> {
     }
 }
 
+/// The error for data types that don't have a Python counterpart yet.
+fn unsupported_dtype(dtype: &DataType) -> PyErr {
+    PyTypeError::new_err(format!("data type {dtype} is not supported in Python"))
+}
+
 impl<'py> FromPyObject<'py> for Wrap<Field> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         let py = ob.py();
@@ -12,8 +12,8 @@ use super::utils::{
     create_borrowed_np_array, dtype_supports_view, polars_dtype_to_np_temporal_dtype,
     reshape_numpy_array, series_contains_null,
 };
-use crate::conversion::ObjectValue;
 use crate::conversion::chunked_array::{decimal_to_pyobject_iter, time_to_pyobject_iter};
+use crate::conversion::{ObjectValue, Wrap};
 use crate::series::PySeries;
 
 #[pymethods]
@@ -204,6 +204,10 @@ fn series_to_numpy_with_copy(py: Python<'_>, s: &Series, writable: bool) -> PyOb
             series_to_numpy(py, &s, writable, true).unwrap()
         },
         Float32 => numeric_series_to_numpy::<Float32Type, f32>(py, s),
+        Float16 => {
+            let s = s.cast(&DataType::Float32).unwrap();
+            series_to_numpy(py, &s, writable, true).unwrap()
+        },
         Float64 => numeric_series_to_numpy::<Float64Type, f64>(py, s),
         Boolean => boolean_series_to_numpy(py, s),
         Date => date_series_to_numpy(py, s),
@@ -264,7 +268,19 @@ fn series_to_numpy_with_copy(py: Python<'_>, s: &Series, writable: bool) -> PyOb
                 .map(|v| v.into_py_any(py).unwrap());
             PyArray1::from_iter(py, values).into_py_any(py).unwrap()
         },
+        Interval => any_value_series_to_numpy(py, s),
+        Decimal256(_, _) => any_value_series_to_numpy(py, s),
+        Uuid => any_value_series_to_numpy(py, s),
+        Union(_, _) => any_value_series_to_numpy(py, s),
+        Json => any_value_series_to_numpy(py, s),
+        Extension { .. } => {
+            series_to_numpy_with_copy(py, s.extension().unwrap().storage(), writable)
+        },
         List(_) => list_series_to_numpy(py, s, writable),
+        Map(_, _) => {
+            let entries = s.map().unwrap().entries().clone().into_series();
+            list_series_to_numpy(py, &entries, writable)
+        },
         Array(_, _) => array_series_to_numpy(py, s, writable),
         Struct(_) => {
             let ca = s.struct_().unwrap();
@@ -289,6 +305,13 @@ fn series_to_numpy_with_copy(py: Python<'_>, s: &Series, writable: bool) -> PyOb
     }
 }
 
+/// Convert a Series without a dedicated conversion to an object array, one value at a time.
+fn any_value_series_to_numpy(py: Python<'_>, s: &Series) -> PyObject {
+    let s = s.rechunk();
+    let values = s.iter().map(|av| Wrap(av).into_py_any(py).unwrap());
+    PyArray1::from_iter(py, values).into_py_any(py).unwrap()
+}
+
 /// Convert numeric types to f32 or f64 with NaN representing a null value.
 fn numeric_series_to_numpy<T, U>(py: Python<'_>, s: &Series) -> PyObject
 where
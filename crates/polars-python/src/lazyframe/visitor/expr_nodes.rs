@@ -169,6 +169,7 @@ pub enum PyStringFunction {
     Strptime,
     Split,
     ToDecimal,
+    ToDuration,
     Titlecase,
     Uppercase,
     ZFill,
@@ -702,6 +703,11 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 arguments: vec![expr.0, quantile.0],
                 options: Into::<&str>::into(interpol).into_py_any(py)?,
             },
+            IRAggExpr::TimeWeighted { input, by, method } => Agg {
+                name: "time_weighted".into_py_any(py)?,
+                arguments: vec![input.0, by.0],
+                options: Into::<&str>::into(method).into_py_any(py)?,
+            },
             IRAggExpr::Sum(n) => Agg {
                 name: "sum".into_py_any(py)?,
                 arguments: vec![n.0],
@@ -763,6 +769,9 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 IRFunctionExpr::ListExpr(_) => {
                     return Err(PyNotImplementedError::new_err("list expr"));
                 },
+                IRFunctionExpr::MapExpr(_) => {
+                    return Err(PyNotImplementedError::new_err("map expr"));
+                },
                 IRFunctionExpr::Bitwise(_) => {
                     return Err(PyNotImplementedError::new_err("bitwise expr"));
                 },
@@ -885,12 +894,18 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                         options.cache,
                     )
                         .into_py_any(py),
+                    IRStringFunction::StrptimeMany(..) => {
+                        return Err(PyNotImplementedError::new_err("strptime_many"));
+                    },
                     IRStringFunction::Split(inclusive) => {
                         (PyStringFunction::Split, inclusive).into_py_any(py)
                     },
                     IRStringFunction::ToDecimal { scale } => {
                         (PyStringFunction::ToDecimal, scale).into_py_any(py)
                     },
+                    IRStringFunction::ToDuration { time_unit, strict } => {
+                        (PyStringFunction::ToDuration, Wrap(*time_unit), strict).into_py_any(py)
+                    },
                     #[cfg(feature = "nightly")]
                     IRStringFunction::Titlecase => (PyStringFunction::Titlecase,).into_py_any(py),
                     IRStringFunction::Uppercase => (PyStringFunction::Uppercase,).into_py_any(py),
@@ -1011,15 +1026,30 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     IRTemporalFunction::ConvertTimeZone(time_zone) => {
                         (PyTemporalFunction::ConvertTimeZone, time_zone.as_str()).into_py_any(py)
                     },
+                    #[cfg(feature = "timezones")]
+                    IRTemporalFunction::ConvertTimeZoneBy
+                    | IRTemporalFunction::ReplaceTimeZoneBy(_) => {
+                        return Err(PyNotImplementedError::new_err("per-row time zones"));
+                    },
                     IRTemporalFunction::TimeStamp(time_unit) => {
                         (PyTemporalFunction::TimeStamp, Wrap(*time_unit)).into_py_any(py)
                     },
-                    IRTemporalFunction::Truncate => (PyTemporalFunction::Truncate,).into_py_any(py),
-                    IRTemporalFunction::OffsetBy => (PyTemporalFunction::OffsetBy,).into_py_any(py),
+                    IRTemporalFunction::Truncate(_) => {
+                        (PyTemporalFunction::Truncate,).into_py_any(py)
+                    },
+                    IRTemporalFunction::OffsetBy(_) => {
+                        (PyTemporalFunction::OffsetBy,).into_py_any(py)
+                    },
                     IRTemporalFunction::MonthStart => {
                         (PyTemporalFunction::MonthStart,).into_py_any(py)
                     },
                     IRTemporalFunction::MonthEnd => (PyTemporalFunction::MonthEnd,).into_py_any(py),
+                    IRTemporalFunction::FiscalYear(_)
+                    | IRTemporalFunction::FiscalQuarter(_)
+                    | IRTemporalFunction::FiscalPeriod(_)
+                    | IRTemporalFunction::FiscalWeek(_) => {
+                        return Err(PyNotImplementedError::new_err("fiscal calendar"));
+                    },
                     #[cfg(feature = "timezones")]
                     IRTemporalFunction::BaseUtcOffset => {
                         (PyTemporalFunction::BaseUtcOffset,).into_py_any(py)
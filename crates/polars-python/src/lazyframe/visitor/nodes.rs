@@ -629,6 +629,9 @@ pub(crate) fn into_py(py: Python<'_>, plan: &IR) -> PyResult<PyObject> {
                         .map_or_else(|| Ok(py.None()), |s| s.as_str().into_py_any(py))?,
                 )
                     .into_py_any(py)?,
+                FunctionIR::Upsample { args: _ } => {
+                    return Err(PyNotImplementedError::new_err("upsample"));
+                },
                 FunctionIR::RowIndex {
                     name,
                     schema: _,
@@ -32,6 +32,10 @@ impl PySeries {
                 DataType::Int128 => PyList::new(py, series.i128().map_err(PyPolarsErr::from)?)?,
                 DataType::Float32 => PyList::new(py, series.f32().map_err(PyPolarsErr::from)?)?,
                 DataType::Float64 => PyList::new(py, series.f64().map_err(PyPolarsErr::from)?)?,
+                DataType::Float16 => {
+                    let ca = series.float16().map_err(PyPolarsErr::from)?.to_float32();
+                    PyList::new(py, &ca)?
+                },
                 DataType::Categorical(_, _) | DataType::Enum(_, _) => {
                     with_match_categorical_physical_type!(series.dtype().cat_physical().unwrap(), |$C| {
                         PyList::new(py, series.cat::<$C>().unwrap().iter_str())?
@@ -62,6 +66,14 @@ impl PySeries {
                     }
                     v
                 },
+                DataType::Map(_, _) => {
+                    let ca = series.map().map_err(PyPolarsErr::from)?;
+                    return to_list_recursive(py, &ca.entries().clone().into_series());
+                },
+                DataType::Extension { .. } => {
+                    let ca = series.extension().map_err(PyPolarsErr::from)?;
+                    return to_list_recursive(py, ca.storage());
+                },
                 DataType::Array(_, _) => {
                     let v = PyList::empty(py);
                     let ca = series.array().map_err(PyPolarsErr::from)?;
@@ -110,6 +122,11 @@ impl PySeries {
                     let ca = series.binary().map_err(PyPolarsErr::from)?;
                     return Wrap(ca).into_bound_py_any(py);
                 },
+                DataType::Interval => any_values_to_list(py, series)?,
+                DataType::Decimal256(_, _) => any_values_to_list(py, series)?,
+                DataType::Uuid => any_values_to_list(py, series)?,
+                DataType::Union(_, _) => any_values_to_list(py, series)?,
+                DataType::Json => any_values_to_list(py, series)?,
                 DataType::Null => {
                     let null: Option<u8> = None;
                     let n = series.len();
@@ -177,3 +194,9 @@ impl PySeries {
         }
     }
 }
+
+/// Convert a Series without a dedicated conversion to a Python list, one value at a time.
+fn any_values_to_list<'py>(py: Python<'py>, series: &Series) -> PyResult<Bound<'py, PyList>> {
+    let series = series.rechunk();
+    PyList::new(py, series.iter().map(Wrap))
+}
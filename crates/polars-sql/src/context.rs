@@ -7,21 +7,33 @@ use polars_ops::frame::JoinCoalesce;
 use polars_plan::dsl::function_expr::StructFunction;
 use polars_plan::prelude::*;
 use polars_utils::format_pl_smallstr;
+use polars_utils::unique_id::UniqueId;
 use sqlparser::ast::{
-    BinaryOperator, CreateTable, Delete, Distinct, ExcludeSelectItem, Expr as SQLExpr, FromTable,
-    FunctionArg, GroupByExpr, Ident, JoinConstraint, JoinOperator, ObjectName, ObjectType, Offset,
+    Assignment, AssignmentTarget, BinaryOperator, CreateFunction, CreateFunctionBody, CreateTable,
+    Delete, DescribeAlias, Distinct, DollarQuotedString, ExcludeSelectItem, Expr as SQLExpr,
+    FromTable, FunctionArg, GroupByExpr, Ident, Insert, Join, JoinConstraint, JoinOperator,
+    MergeAction, MergeClauseKind, MergeInsertExpr, MergeInsertKind, ObjectName, ObjectType, Offset,
     OrderBy, Query, RenameSelectItem, Select, SelectItem, SetExpr, SetOperator, SetQuantifier,
-    Statement, TableAlias, TableFactor, TableWithJoins, UnaryOperator, Value as SQLValue, Values,
-    WildcardAdditionalOptions,
+    ShowStatementFilter, ShowStatementFilterPosition, Statement, TableAlias, TableFactor,
+    TableWithJoins, UnaryOperator, Value as SQLValue, Values, WildcardAdditionalOptions,
 };
+#[cfg(feature = "pivot")]
+use sqlparser::ast::{ExprWithAlias, PivotValueSource};
 use sqlparser::dialect::GenericDialect;
 use sqlparser::parser::{Parser, ParserOptions};
+use sqlparser::tokenizer::Tokenizer;
 
-use crate::function_registry::{DefaultFunctionRegistry, FunctionRegistry};
+use crate::function_registry::{DefaultFunctionRegistry, ExprFunction, FunctionRegistry};
+use crate::functions::sql_expr_has_aggregate;
+use crate::params::{
+    BoundParams, ParamKey, PreparedPlan, PreparedStatement, SQLParams,
+    number_anonymous_placeholders, param_marker,
+};
 use crate::sql_expr::{
     parse_sql_array, parse_sql_expr, resolve_compound_identifier, to_sql_interface_err,
 };
 use crate::table_functions::PolarsTableFunctions;
+use crate::types::{map_polars_dtype_to_sql, map_sql_dtype_to_polars};
 
 #[derive(Clone)]
 pub struct TableInfo {
@@ -56,10 +68,20 @@ impl SelectModifiers {
 pub struct SQLContext {
     pub(crate) table_map: PlHashMap<String, LazyFrame>,
     pub(crate) function_registry: Arc<dyn FunctionRegistry>,
+    // functions created with `CREATE FUNCTION`; these belong to this context (and its clones'
+    // copies), never to the possibly shared function registry
+    pub(crate) function_macros: PlHashMap<String, ExprFunction>,
     pub(crate) lp_arena: Arena<IR>,
     pub(crate) expr_arena: Arena<AExpr>,
 
     cte_map: PlHashMap<String, LazyFrame>,
+    max_recursion_iterations: usize,
+    prepared: PlHashMap<String, PreparedStatement>,
+    bound_params: Option<BoundParams>,
+    prepared_param_types: Option<Vec<DataType>>,
+    // set while converting a prepared query into a reusable plan, cleared by parts of the
+    // query that are evaluated during conversion
+    reusable_plan: bool,
     table_aliases: PlHashMap<String, String>,
     joined_aliases: PlHashMap<String, PlHashMap<String, String>>,
 }
@@ -67,9 +89,15 @@ pub struct SQLContext {
 impl Default for SQLContext {
     fn default() -> Self {
         Self {
-            function_registry: Arc::new(DefaultFunctionRegistry {}),
+            function_registry: Arc::new(DefaultFunctionRegistry::default()),
+            function_macros: Default::default(),
             table_map: Default::default(),
             cte_map: Default::default(),
+            max_recursion_iterations: RecursiveUnionArgs::default().max_iterations,
+            prepared: Default::default(),
+            bound_params: None,
+            prepared_param_types: None,
+            reusable_plan: false,
             table_aliases: Default::default(),
             joined_aliases: Default::default(),
             lp_arena: Default::default(),
@@ -146,9 +174,14 @@ impl SQLContext {
             ..Default::default()
         });
 
+        let mut tokens = Tokenizer::new(&GenericDialect, query)
+            .with_unescape(true)
+            .tokenize_with_location()
+            .map_err(to_sql_interface_err)?;
+        number_anonymous_placeholders(&mut tokens);
+
         let ast = parser
-            .try_with_sql(query)
-            .map_err(to_sql_interface_err)?
+            .with_tokens_with_locations(tokens)
             .parse_statements()
             .map_err(to_sql_interface_err)?;
 
@@ -169,6 +202,46 @@ impl SQLContext {
         Ok(res)
     }
 
+    /// Execute a SQL query, binding the given values to its parameters.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use polars_sql::{SQLContext, SQLParams};
+    /// # use polars_core::prelude::*;
+    /// # use polars_lazy::prelude::*;
+    /// # fn main() {
+    /// let mut ctx = SQLContext::new();
+    /// let df = df! { "a" => [1, 2, 3] }.unwrap();
+    /// ctx.register("df", df.lazy());
+    ///
+    /// let params = SQLParams::positional([2]).with_named("mult", 10);
+    /// let sql = "SELECT a * :mult AS a FROM df WHERE a >= $1";
+    /// let res = ctx.execute_with_params(sql, params).unwrap().collect().unwrap();
+    /// assert_eq!(res.height(), 2);
+    /// # }
+    /// ```
+    pub fn execute_with_params(
+        &mut self,
+        query: &str,
+        params: SQLParams,
+    ) -> PolarsResult<LazyFrame> {
+        self.bound_params = Some(params.into_bound());
+        let res = self.execute(query);
+        self.bound_params = None;
+        res
+    }
+
+    /// Execute a statement registered with `PREPARE`, binding the given (positional)
+    /// values to its parameters.
+    pub fn execute_prepared(&mut self, name: &str, params: SQLParams) -> PolarsResult<LazyFrame> {
+        let values = params.into_bound().positional;
+        let res = self.execute_prepared_statement(name, values);
+        self.cte_map.clear();
+        self.table_aliases.clear();
+        self.joined_aliases.clear();
+        res
+    }
+
     /// add a function registry to the SQLContext
     /// the registry provides the ability to add custom functions to the SQLContext
     pub fn with_function_registry(mut self, function_registry: Arc<dyn FunctionRegistry>) -> Self {
@@ -176,6 +249,13 @@ impl SQLContext {
         self
     }
 
+    /// Set the maximum number of iterations a recursive CTE may take to reach a fixpoint
+    /// before the query errors (guards against non-terminating recursion).
+    pub fn with_max_recursion_iterations(mut self, max_iterations: usize) -> Self {
+        self.max_recursion_iterations = max_iterations;
+        self
+    }
+
     /// Get the function registry of the SQLContext
     pub fn registry(&self) -> &Arc<dyn FunctionRegistry> {
         &self.function_registry
@@ -199,8 +279,39 @@ impl SQLContext {
                 ..
             } => self.execute_drop_table(stmt)?,
             stmt @ Statement::Explain { .. } => self.execute_explain(stmt)?,
+            Statement::ExplainTable { table_name, .. } => {
+                let tbl_name = table_name.0.last().unwrap().value.as_str();
+                self.execute_describe(tbl_name, None)?
+            },
+            stmt @ Statement::ShowColumns { .. } => self.execute_show_columns(stmt)?,
             stmt @ Statement::Truncate { .. } => self.execute_truncate_table(stmt)?,
             stmt @ Statement::Delete { .. } => self.execute_delete_from_table(stmt)?,
+            stmt @ Statement::Insert(_) => self.execute_insert_into_table(stmt)?,
+            stmt @ Statement::Update { .. } => self.execute_update_table(stmt)?,
+            stmt @ Statement::Merge { .. } => self.execute_merge_into_table(stmt)?,
+            stmt @ Statement::Prepare { .. } => self.execute_prepare(stmt)?,
+            Statement::Execute {
+                name,
+                parameters,
+                using,
+                ..
+            } => {
+                let values = parameters
+                    .iter()
+                    .chain(using)
+                    .map(|e| parse_sql_expr(e, self, None))
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                self.execute_prepared_statement(&name.to_string(), values)?
+            },
+            Statement::Deallocate { name, .. } => {
+                polars_ensure!(
+                    self.prepared.remove(&name.value).is_some(),
+                    SQLInterface: "prepared statement '{}' does not exist", name.value
+                );
+                df! { "Response" => ["DEALLOCATE"] }?.lazy()
+            },
+            stmt @ Statement::CreateFunction(_) => self.execute_create_function(stmt)?,
+            stmt @ Statement::DropFunction { .. } => self.execute_drop_function(stmt)?,
             _ => polars_bail!(
                 SQLInterface: "statement type is not supported:\n{:?}", ast,
             ),
@@ -226,9 +337,12 @@ impl SQLContext {
         table
             .or_else(|| self.cte_map.get(name).cloned())
             .or_else(|| {
-                self.table_aliases
-                    .get(name)
-                    .and_then(|alias| self.table_map.get(alias).cloned())
+                self.table_aliases.get(name).and_then(|alias| {
+                    self.table_map
+                        .get(alias)
+                        .or_else(|| self.cte_map.get(alias))
+                        .cloned()
+                })
             })
     }
 
@@ -455,6 +569,16 @@ impl SQLContext {
     // EXPLAIN SELECT * FROM DF
     fn execute_explain(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
         match stmt {
+            Statement::Explain {
+                describe_alias: DescribeAlias::Describe | DescribeAlias::Desc,
+                statement,
+                ..
+            } => {
+                // DESCRIBE <query> reports the schema of the query result
+                let mut lf = self.execute_statement(statement)?;
+                let schema = self.get_frame_schema(&mut lf)?;
+                Ok(describe_schema(&schema)?.lazy())
+            },
             Statement::Explain { statement, .. } => {
                 let lf = self.execute_statement(statement)?;
                 let plan = lf.describe_optimized_plan()?;
@@ -477,6 +601,132 @@ impl SQLContext {
         Ok(df.lazy())
     }
 
+    // DESCRIBE <tbl>
+    fn execute_describe(
+        &mut self,
+        tbl_name: &str,
+        filter: Option<&ShowStatementFilter>,
+    ) -> PolarsResult<LazyFrame> {
+        let Some(mut lf) = self.get_table_from_current_scope(tbl_name) else {
+            polars_bail!(SQLInterface: "relation '{}' was not found", tbl_name);
+        };
+        let schema = self.get_frame_schema(&mut lf)?;
+        let df = describe_schema(&schema)?;
+        let predicate = match filter {
+            None => return Ok(df.lazy()),
+            Some(ShowStatementFilter::Where(expr)) => expr.clone(),
+            Some(
+                ShowStatementFilter::Like(pattern)
+                | ShowStatementFilter::ILike(pattern)
+                | ShowStatementFilter::NoKeyword(pattern),
+            ) => {
+                let column_name = Box::new(SQLExpr::Identifier(Ident::new("column_name")));
+                let pattern = Box::new(SQLExpr::Value(SQLValue::SingleQuotedString(
+                    pattern.clone(),
+                )));
+                match filter {
+                    Some(ShowStatementFilter::ILike(_)) => SQLExpr::ILike {
+                        negated: false,
+                        any: false,
+                        expr: column_name,
+                        pattern,
+                        escape_char: None,
+                    },
+                    _ => SQLExpr::Like {
+                        negated: false,
+                        any: false,
+                        expr: column_name,
+                        pattern,
+                        escape_char: None,
+                    },
+                }
+            },
+        };
+        let predicate = parse_sql_expr(&predicate, self, Some(df.schema()))?;
+        Ok(df.lazy().filter(predicate))
+    }
+
+    // SHOW COLUMNS {FROM | IN} <tbl> [LIKE <pattern> | WHERE <expr>]
+    fn execute_show_columns(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        let Statement::ShowColumns { show_options, .. } = stmt else {
+            unreachable!()
+        };
+        let Some(tbl_name) = show_options
+            .show_in
+            .as_ref()
+            .and_then(|show_in| show_in.parent_name.as_ref())
+            .and_then(|name| name.0.last())
+        else {
+            polars_bail!(SQLSyntax: "SHOW COLUMNS requires a table name");
+        };
+        let filter = match &show_options.filter_position {
+            Some(
+                ShowStatementFilterPosition::Infix(filter)
+                | ShowStatementFilterPosition::Suffix(filter),
+            ) => Some(filter),
+            None => None,
+        };
+        self.execute_describe(&tbl_name.value.clone(), filter)
+    }
+
+    /// Virtual `information_schema` tables, generated from the registered tables.
+    fn get_information_schema_table(&mut self, name: &str) -> PolarsResult<LazyFrame> {
+        let tables = self.get_tables();
+        match name.to_lowercase().as_str() {
+            "tables" => {
+                let n_tables = tables.len();
+                let df = DataFrame::new(vec![
+                    Column::new(
+                        "table_catalog".into(),
+                        vec![INFORMATION_SCHEMA_CATALOG; n_tables],
+                    ),
+                    Column::new(
+                        "table_schema".into(),
+                        vec![INFORMATION_SCHEMA_SCHEMA; n_tables],
+                    ),
+                    Column::new("table_name".into(), tables),
+                    Column::new("table_type".into(), vec!["BASE TABLE"; n_tables]),
+                ])?;
+                Ok(df.lazy())
+            },
+            "columns" => {
+                let mut table_names = vec![];
+                let mut column_names = vec![];
+                let mut ordinals = vec![];
+                let mut data_types = vec![];
+                for tbl_name in tables {
+                    let mut lf = self.table_map.get(&tbl_name).cloned().unwrap();
+                    let schema = self.get_frame_schema(&mut lf)?;
+                    for (idx, (name, dtype)) in schema.iter().enumerate() {
+                        table_names.push(tbl_name.clone());
+                        column_names.push(name.to_string());
+                        ordinals.push(idx as i64 + 1);
+                        data_types.push(map_polars_dtype_to_sql(dtype));
+                    }
+                }
+                let n_columns = column_names.len();
+                let df = DataFrame::new(vec![
+                    Column::new(
+                        "table_catalog".into(),
+                        vec![INFORMATION_SCHEMA_CATALOG; n_columns],
+                    ),
+                    Column::new(
+                        "table_schema".into(),
+                        vec![INFORMATION_SCHEMA_SCHEMA; n_columns],
+                    ),
+                    Column::new("table_name".into(), table_names),
+                    Column::new("column_name".into(), column_names),
+                    Column::new("ordinal_position".into(), ordinals),
+                    Column::new("data_type".into(), data_types),
+                    // polars columns can always contain nulls
+                    Column::new("is_nullable".into(), vec!["YES"; n_columns]),
+                ])?;
+                Ok(df.lazy())
+            },
+            _ => polars_bail!(SQLInterface: "relation 'information_schema.{}' was not found", name),
+        }
+    }
+
     // DROP TABLE <tbl>
     fn execute_drop_table(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
         match stmt {
@@ -547,6 +797,332 @@ impl SQLContext {
         }
     }
 
+    // INSERT INTO <tbl> [(<col>, ...)] {VALUES (...) | SELECT ...}
+    fn execute_insert_into_table(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        if let Statement::Insert(Insert {
+            table_name,
+            columns,
+            source,
+            overwrite,
+            partitioned,
+            on,
+            returning,
+            ..
+        }) = stmt
+        {
+            if *overwrite || partitioned.is_some() || on.is_some() || returning.is_some() {
+                let error_message = match () {
+                    _ if *overwrite => "INSERT does not support OVERWRITE",
+                    _ if partitioned.is_some() => "INSERT does not support PARTITION",
+                    _ if on.is_some() => "INSERT does not support ON CONFLICT/DUPLICATE KEY",
+                    _ if returning.is_some() => "INSERT does not support the RETURNING clause",
+                    _ => unreachable!(),
+                };
+                polars_bail!(SQLInterface: error_message);
+            }
+            let tbl_name = table_name.0.first().unwrap().value.clone();
+            let Some(mut lf) = self.table_map.get(&tbl_name).cloned() else {
+                polars_bail!(SQLInterface: "table '{}' does not exist", tbl_name);
+            };
+            let Some(source) = source else {
+                polars_bail!(SQLInterface: "INSERT requires VALUES or a SELECT query")
+            };
+            let schema = self.get_frame_schema(&mut lf)?;
+            let mut src = self.execute_query(source)?;
+            let src_schema = self.get_frame_schema(&mut src)?;
+
+            // source columns are matched to the target columns by position
+            let target_names: Vec<PlSmallStr> = if columns.is_empty() {
+                schema.iter_names().cloned().collect()
+            } else {
+                columns
+                    .iter()
+                    .map(|c| PlSmallStr::from_str(c.value.as_str()))
+                    .collect()
+            };
+            polars_ensure!(
+                target_names.len() == src_schema.len(),
+                SQLSyntax: "INSERT has {} target columns but {} values",
+                target_names.len(), src_schema.len()
+            );
+            let mut values: PlHashMap<&PlSmallStr, Expr> = PlHashMap::new();
+            for (target, src_name) in target_names.iter().zip(src_schema.iter_names()) {
+                polars_ensure!(
+                    schema.contains(target),
+                    ColumnNotFound: "column '{}' does not exist in table '{}'", target, tbl_name
+                );
+                values.insert(target, col(src_name.clone()));
+            }
+            let projection = schema
+                .iter()
+                .map(|(name, dtype)| {
+                    let value = values.remove(name).unwrap_or(lit(NULL));
+                    value.strict_cast(dtype.clone()).alias(name.clone())
+                })
+                .collect::<Vec<_>>();
+
+            let lf =
+                polars_lazy::dsl::concat(vec![lf, src.select(projection)], Default::default())?;
+            self.table_map.insert(tbl_name, lf.clone());
+            Ok(lf)
+        } else {
+            polars_bail!(SQLInterface: "unexpected statement type; expected INSERT")
+        }
+    }
+
+    // UPDATE <tbl> SET <col> = <expr>, ... [WHERE ...]
+    fn execute_update_table(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        if let Statement::Update {
+            table,
+            assignments,
+            from,
+            selection,
+            returning,
+            or,
+        } = stmt
+        {
+            if from.is_some() || returning.is_some() || or.is_some() {
+                let error_message = match () {
+                    _ if from.is_some() => "UPDATE does not support the FROM clause",
+                    _ if returning.is_some() => "UPDATE does not support the RETURNING clause",
+                    _ if or.is_some() => "UPDATE does not support the OR clause",
+                    _ => unreachable!(),
+                };
+                polars_bail!(SQLInterface: error_message);
+            }
+            if !table.joins.is_empty() {
+                polars_bail!(SQLInterface: "UPDATE does not support table JOINs")
+            }
+            let tbl_name = registered_table_name(&table.relation, "UPDATE")?;
+            let (_, mut lf) = self.get_table(&table.relation)?;
+            let schema = self.get_frame_schema(&mut lf)?;
+
+            let predicate = selection
+                .as_ref()
+                .map(|e| parse_sql_expr(e, self, Some(&schema)))
+                .transpose()?;
+            let updates = self.parse_assignments(assignments, &schema)?;
+            let exprs = updates
+                .into_iter()
+                .map(|(name, value)| {
+                    let dtype = schema.get(&name).unwrap().clone();
+                    let value = match &predicate {
+                        Some(predicate) => when(predicate.clone())
+                            .then(value)
+                            .otherwise(col(name.clone())),
+                        None => value,
+                    };
+                    value.strict_cast(dtype).alias(name)
+                })
+                .collect::<Vec<_>>();
+
+            let lf = lf.with_columns(exprs);
+            self.table_map.insert(tbl_name, lf.clone());
+            Ok(lf)
+        } else {
+            polars_bail!(SQLInterface: "unexpected statement type; expected UPDATE")
+        }
+    }
+
+    // MERGE INTO <tbl> USING <source> ON <constraint>
+    //   WHEN [NOT] MATCHED [AND <cond>] THEN {UPDATE SET ... | DELETE | INSERT ...}
+    fn execute_merge_into_table(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        let Statement::Merge {
+            table,
+            source,
+            on,
+            clauses,
+            ..
+        } = stmt
+        else {
+            polars_bail!(SQLInterface: "unexpected statement type; expected MERGE")
+        };
+        const TARGET: PlSmallStr = PlSmallStr::from_static("__PL_MERGE_TARGET");
+        const SOURCE: PlSmallStr = PlSmallStr::from_static("__PL_MERGE_SOURCE");
+        const ROW: PlSmallStr = PlSmallStr::from_static("__PL_MERGE_ROW");
+
+        let tbl_name = registered_table_name(table, "MERGE")?;
+        let (l_name, mut lf) = self.get_table(table)?;
+        let (r_name, mut rf) = self.get_table(source)?;
+        let schema = self.get_frame_schema(&mut lf)?;
+
+        // full join the target and source, tracking which side(s) each row came from
+        lf = lf
+            .with_row_index(ROW, None)
+            .with_column(lit(true).alias(TARGET));
+        rf = rf.with_column(lit(true).alias(SOURCE));
+        let left_schema = self.get_frame_schema(&mut lf)?;
+        let right_schema = self.get_frame_schema(&mut rf)?;
+        let mut joined = self.process_join(
+            &TableInfo {
+                frame: lf,
+                name: (&l_name).into(),
+                schema: left_schema.clone(),
+            },
+            &TableInfo {
+                frame: rf,
+                name: (&r_name).into(),
+                schema: right_schema.clone(),
+            },
+            &JoinConstraint::On(on.as_ref().clone()),
+            JoinType::Full,
+        )?;
+        let joined_schema = self.get_frame_schema(&mut joined)?;
+        self.register_joined_aliases(&r_name, &left_schema, &right_schema, &joined_schema);
+
+        let in_target = col(TARGET).is_not_null();
+        let in_source = col(SOURCE).is_not_null();
+
+        // each row is handled by the first clause that applies to it
+        let mut handled = lit(false);
+        let mut updates: Vec<(Expr, PlHashMap<PlSmallStr, Expr>)> = vec![];
+        let mut deleted = lit(false);
+        let mut inserted = lit(false);
+        for clause in clauses {
+            let mut applies = match clause.clause_kind {
+                MergeClauseKind::Matched => in_target.clone().and(in_source.clone()),
+                MergeClauseKind::NotMatched | MergeClauseKind::NotMatchedByTarget => {
+                    in_target.clone().not()
+                },
+                MergeClauseKind::NotMatchedBySource => in_source.clone().not(),
+            };
+            if let Some(predicate) = &clause.predicate {
+                let predicate = parse_sql_expr(predicate, self, Some(&joined_schema))?;
+                applies = applies.and(predicate.fill_null(lit(false)));
+            }
+            applies = applies.and(handled.clone().not());
+            handled = handled.or(applies.clone());
+
+            match &clause.action {
+                MergeAction::Update { assignments } => {
+                    polars_ensure!(
+                        !matches!(clause.clause_kind, MergeClauseKind::NotMatched | MergeClauseKind::NotMatchedByTarget),
+                        SQLSyntax: "MERGE cannot UPDATE rows that are not matched by the target"
+                    );
+                    let values = self.parse_assignments(assignments, &joined_schema)?;
+                    for name in values.keys() {
+                        polars_ensure!(
+                            schema.contains(name),
+                            ColumnNotFound: "column '{}' does not exist in table '{}'", name, tbl_name
+                        );
+                    }
+                    updates.push((applies, values.into_iter().collect()));
+                },
+                MergeAction::Delete => {
+                    polars_ensure!(
+                        !matches!(clause.clause_kind, MergeClauseKind::NotMatched | MergeClauseKind::NotMatchedByTarget),
+                        SQLSyntax: "MERGE cannot DELETE rows that are not matched by the target"
+                    );
+                    deleted = deleted.or(applies);
+                },
+                MergeAction::Insert(MergeInsertExpr { columns, kind }) => {
+                    polars_ensure!(
+                        matches!(clause.clause_kind, MergeClauseKind::NotMatched | MergeClauseKind::NotMatchedByTarget),
+                        SQLSyntax: "MERGE can only INSERT rows that are not matched by the target"
+                    );
+                    let MergeInsertKind::Values(Values { rows, .. }) = kind else {
+                        polars_bail!(SQLInterface: "MERGE INSERT requires a VALUES clause")
+                    };
+                    polars_ensure!(rows.len() == 1, SQLSyntax: "MERGE INSERT expects a single row of VALUES");
+                    let names: Vec<PlSmallStr> = if columns.is_empty() {
+                        schema.iter_names().cloned().collect()
+                    } else {
+                        columns
+                            .iter()
+                            .map(|c| PlSmallStr::from_str(c.value.as_str()))
+                            .collect()
+                    };
+                    polars_ensure!(
+                        names.len() == rows[0].len(),
+                        SQLSyntax: "MERGE INSERT has {} target columns but {} values",
+                        names.len(), rows[0].len()
+                    );
+                    let mut values = PlHashMap::with_capacity(schema.len());
+                    for (name, value) in names.into_iter().zip(&rows[0]) {
+                        polars_ensure!(
+                            schema.contains(&name),
+                            ColumnNotFound: "column '{}' does not exist in table '{}'", name, tbl_name
+                        );
+                        values.insert(name, parse_sql_expr(value, self, Some(&joined_schema))?);
+                    }
+                    // target columns that are not inserted into are set to NULL
+                    for name in schema.iter_names() {
+                        values.entry(name.clone()).or_insert(lit(NULL));
+                    }
+                    inserted = inserted.or(applies.clone());
+                    updates.push((applies, values));
+                },
+            }
+        }
+
+        let projection = schema
+            .iter()
+            .map(|(name, dtype)| {
+                let value =
+                    updates
+                        .iter()
+                        .rev()
+                        .fold(col(name.clone()), |acc, (applies, values)| {
+                            match values.get(name) {
+                                Some(value) => {
+                                    when(applies.clone()).then(value.clone()).otherwise(acc)
+                                },
+                                None => acc,
+                            }
+                        });
+                value.strict_cast(dtype.clone()).alias(name.clone())
+            })
+            .collect::<Vec<_>>();
+
+        // a target row may only be updated or deleted by a single source row; as the
+        // frame is lazy, this is checked (and raised) when the result is collected
+        let modified_rows = col(ROW).filter(in_target.clone().and(in_source).and(handled));
+        let ambiguous = modified_rows
+            .clone()
+            .len()
+            .gt(modified_rows.n_unique())
+            .map(
+                |c| {
+                    polars_ensure!(
+                        !c.bool()?.any(),
+                        SQLInterface: "MERGE cannot update or delete a target row more than once; it was matched by multiple source rows"
+                    );
+                    Ok(c)
+                },
+                |_, fld| Ok(fld.clone()),
+            );
+
+        // keep target rows that were not deleted (once, even if they matched several
+        // source rows), and source rows that were inserted
+        let keep = in_target
+            .and(deleted.not())
+            .and(col(ROW).is_first_distinct())
+            .or(inserted)
+            .and(ambiguous.not());
+        let lf = joined.filter(keep).select(projection);
+        self.table_map.insert(tbl_name, lf.clone());
+        Ok(lf)
+    }
+
+    fn parse_assignments(
+        &mut self,
+        assignments: &[Assignment],
+        schema: &Schema,
+    ) -> PolarsResult<PlIndexMap<PlSmallStr, Expr>> {
+        let mut values = PlIndexMap::with_capacity(assignments.len());
+        for assignment in assignments {
+            let AssignmentTarget::ColumnName(ObjectName(idents)) = &assignment.target else {
+                polars_bail!(SQLInterface: "tuple assignments are not supported; found {}", assignment.target)
+            };
+            let name = PlSmallStr::from_str(idents.last().unwrap().value.as_str());
+            let value = parse_sql_expr(&assignment.value, self, Some(schema))?;
+            if values.insert(name.clone(), value).is_some() {
+                polars_bail!(SQLSyntax: "column '{}' is assigned more than once", name)
+            }
+        }
+        Ok(values)
+    }
+
     // TRUNCATE <tbl>
     fn execute_truncate_table(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
         if let Statement::Truncate {
@@ -588,24 +1164,85 @@ impl SQLContext {
 
     fn register_ctes(&mut self, query: &Query) -> PolarsResult<()> {
         if let Some(with) = &query.with {
-            if with.recursive {
-                polars_bail!(SQLInterface: "recursive CTEs are not supported")
-            }
             for cte in &with.cte_tables {
                 let cte_name = cte.alias.name.value.clone();
-                let mut lf = self.execute_query(&cte.query)?;
-                lf = self.rename_columns_from_table_alias(lf, &cte.alias)?;
+                let lf = match cte.query.body.as_ref() {
+                    SetExpr::SetOperation {
+                        op: SetOperator::Union,
+                        set_quantifier,
+                        left,
+                        right,
+                    } if with.recursive && set_expr_references_table(right, &cte_name) => self
+                        .execute_recursive_cte(
+                            &cte_name,
+                            &cte.alias,
+                            left,
+                            right,
+                            set_quantifier,
+                            &cte.query,
+                        )?,
+                    _ => {
+                        let lf = self.execute_query(&cte.query)?;
+                        self.rename_columns_from_table_alias(lf, &cte.alias)?
+                    },
+                };
                 self.register_cte(&cte_name, lf);
             }
         }
         Ok(())
     }
 
+    // WITH RECURSIVE <name> AS (<base> UNION [ALL] <step>)
+    fn execute_recursive_cte(
+        &mut self,
+        cte_name: &str,
+        alias: &TableAlias,
+        base: &SetExpr,
+        step: &SetExpr,
+        quantifier: &SetQuantifier,
+        query: &Query,
+    ) -> PolarsResult<LazyFrame> {
+        let distinct = match quantifier {
+            SetQuantifier::All => false,
+            SetQuantifier::Distinct | SetQuantifier::None => true,
+            _ => {
+                polars_bail!(SQLInterface: "'UNION {}' is not supported in recursive CTEs", quantifier)
+            },
+        };
+        // the CTE is evaluated here, so it can't be part of a reusable (prepared) plan
+        if self.reusable_plan {
+            self.reusable_plan = false;
+            polars_bail!(SQLInterface: "recursive CTEs are evaluated on EXECUTE");
+        }
+        self.register_ctes(query)?;
+        let base = self.process_query(base, query)?;
+        let base = self.rename_columns_from_table_alias(base, alias)?;
+
+        let args = RecursiveUnionArgs {
+            distinct,
+            max_iterations: self.max_recursion_iterations,
+        };
+        // the working table (rows from the previous iteration) is visible under the CTE name
+        let lf = concat_recursive(
+            base,
+            |working| {
+                self.register_cte(cte_name, working);
+                self.process_query(step, query)
+            },
+            args,
+        )?;
+        self.process_limit_offset(lf, &query.limit, &query.offset)
+    }
+
     /// execute the 'FROM' part of the query
     fn execute_from_statement(&mut self, tbl_expr: &TableWithJoins) -> PolarsResult<LazyFrame> {
         let (l_name, mut lf) = self.get_table(&tbl_expr.relation)?;
         if !tbl_expr.joins.is_empty() {
             for join in &tbl_expr.joins {
+                if is_lateral_join(join) {
+                    lf = self.process_lateral_join(lf, join)?;
+                    continue;
+                }
                 let (r_name, mut rf) = self.get_table(&join.relation)?;
                 if r_name.is_empty() {
                     // Require non-empty to avoid duplicate column errors from nested self-joins.
@@ -673,31 +1310,438 @@ impl SQLContext {
                     },
                 };
 
-                // track join-aliased columns so we can resolve them later
-                let joined_schema = self.get_frame_schema(&mut lf)?;
+                // track join-aliased columns so we can resolve them later
+                let joined_schema = self.get_frame_schema(&mut lf)?;
+                self.register_joined_aliases(&r_name, &left_schema, &right_schema, &joined_schema);
+            }
+        };
+        Ok(lf)
+    }
+
+    // <tbl> {CROSS JOIN | [LEFT] JOIN .. ON TRUE | CROSS/OUTER APPLY} {LATERAL (<subquery>) | UNNEST(..)}
+    fn process_lateral_join(&mut self, mut lf: LazyFrame, join: &Join) -> PolarsResult<LazyFrame> {
+        let keep_unmatched = match &join.join_operator {
+            JoinOperator::CrossJoin | JoinOperator::CrossApply => false,
+            JoinOperator::OuterApply => true,
+            JoinOperator::Inner(constraint) if is_trivial_join_constraint(constraint) => false,
+            JoinOperator::LeftOuter(constraint) if is_trivial_join_constraint(constraint) => true,
+            join_type => polars_bail!(
+                SQLInterface:
+                "LATERAL join type '{:?}' not currently supported (use CROSS JOIN or LEFT JOIN .. ON TRUE)",
+                join_type
+            ),
+        };
+        let left_schema = self.get_frame_schema(&mut lf)?;
+        match &join.relation {
+            TableFactor::UNNEST {
+                alias,
+                array_exprs,
+                with_offset,
+                with_offset_alias,
+                with_ordinality,
+            } => {
+                let Some(alias) = alias else {
+                    polars_bail!(SQLSyntax: "UNNEST table must have an alias");
+                };
+                let (column_names, ordinality) = unnest_column_names(
+                    alias,
+                    array_exprs.len(),
+                    *with_offset,
+                    with_offset_alias,
+                    *with_ordinality,
+                )?;
+                let exprs = array_exprs
+                    .iter()
+                    .zip(column_names)
+                    .map(|(e, name)| {
+                        let expr = parse_sql_expr(e, self, Some(&left_schema))?;
+                        Ok(match name {
+                            Some(name) => expr.alias(name),
+                            None => expr,
+                        })
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                let explode = vec![true; exprs.len()];
+                self.explode_lateral(
+                    lf,
+                    &alias.name.value,
+                    &left_schema,
+                    exprs,
+                    explode,
+                    ordinality,
+                    keep_unmatched,
+                )
+            },
+            TableFactor::Derived {
+                subquery, alias, ..
+            } => {
+                let Some(alias) = alias else {
+                    polars_bail!(SQLSyntax: "derived tables must have aliases");
+                };
+                self.process_lateral_subquery(lf, &left_schema, subquery, alias, keep_unmatched)
+            },
+            relation => {
+                polars_bail!(SQLInterface: "LATERAL is not supported for '{}'", relation)
+            },
+        }
+    }
+
+    fn process_lateral_subquery(
+        &mut self,
+        lf: LazyFrame,
+        left_schema: &SchemaRef,
+        subquery: &Query,
+        alias: &TableAlias,
+        keep_unmatched: bool,
+    ) -> PolarsResult<LazyFrame> {
+        let SetExpr::Select(select) = subquery.body.as_ref() else {
+            polars_bail!(SQLInterface: "LATERAL subquery must be a SELECT statement")
+        };
+        polars_ensure!(
+            subquery.limit.is_none() && subquery.offset.is_none() && subquery.fetch.is_none(),
+            SQLInterface: "LATERAL subqueries do not support LIMIT/OFFSET/FETCH"
+        );
+
+        if select.from.is_empty() {
+            // projection-only subquery that is evaluated against each row of the outer
+            // table, with set-returning expressions (eg: UNNEST) producing multiple rows
+            polars_ensure!(
+                select.selection.is_none(),
+                SQLInterface: "LATERAL subquery without a FROM clause cannot have a WHERE clause"
+            );
+            let mut exprs = Vec::with_capacity(select.projection.len());
+            let mut explode = Vec::with_capacity(select.projection.len());
+            for item in &select.projection {
+                let expr = match item {
+                    SelectItem::UnnamedExpr(e) => parse_sql_expr(e, self, Some(left_schema))?,
+                    SelectItem::ExprWithAlias { expr, alias } => {
+                        parse_sql_expr(expr, self, Some(left_schema))?.alias(alias.value.as_str())
+                    },
+                    _ => {
+                        polars_bail!(SQLInterface: "LATERAL subquery does not support wildcard projections")
+                    },
+                };
+                let (expr, is_exploded) = match expr {
+                    Expr::Explode { input, .. } => (input.as_ref().clone(), true),
+                    Expr::Alias(inner, name) => match inner.as_ref() {
+                        Expr::Explode { input, .. } => (input.as_ref().clone().alias(name), true),
+                        _ => (Expr::Alias(inner, name), false),
+                    },
+                    expr => (expr, false),
+                };
+                exprs.push(expr);
+                explode.push(is_exploded);
+            }
+            if !alias.columns.is_empty() {
+                polars_ensure!(
+                    alias.columns.len() == exprs.len(),
+                    SQLSyntax: "number of columns ({}) in alias '{}' does not match the number of columns in the subquery ({})",
+                    alias.columns.len(), alias.name.value, exprs.len()
+                );
+                exprs = exprs
+                    .into_iter()
+                    .zip(&alias.columns)
+                    .map(|(e, c)| e.alias(c.name.value.as_str()))
+                    .collect();
+            }
+            return self.explode_lateral(
+                lf,
+                &alias.name.value,
+                left_schema,
+                exprs,
+                explode,
+                None,
+                keep_unmatched,
+            );
+        }
+
+        // otherwise decorrelate; equality predicates between the outer and inner tables
+        // become join keys (and group keys, if the subquery aggregates)
+        let inner_names: PlHashSet<String> = select
+            .from
+            .iter()
+            .flat_map(|tbl| {
+                std::iter::once(&tbl.relation).chain(tbl.joins.iter().map(|j| &j.relation))
+            })
+            .filter_map(relation_name)
+            .collect();
+        let mut correlated = vec![];
+        let mut residual = vec![];
+        if let Some(selection) = &select.selection {
+            for conjunct in split_conjunctions(selection) {
+                match self.correlated_equality(conjunct, &inner_names) {
+                    Some(pair) => correlated.push(pair),
+                    None => residual.push(conjunct.clone()),
+                }
+            }
+        }
+        let has_group_by =
+            !matches!(&select.group_by, GroupByExpr::Expressions(exprs, _) if exprs.is_empty());
+        let aggregated = has_group_by
+            || select.projection.iter().any(|item| match item {
+                SelectItem::UnnamedExpr(e) | SelectItem::ExprWithAlias { expr: e, .. } => {
+                    sql_expr_has_aggregate(e, self)
+                },
+                _ => false,
+            });
+
+        const KEY_PREFIX: &str = "__PL_LATERAL_KEY_";
+        let key_names: Vec<PlSmallStr> = (0..correlated.len())
+            .map(|i| format_pl_smallstr!("{KEY_PREFIX}{i}"))
+            .collect();
+        let mut decorrelated = select.clone();
+        decorrelated.selection = residual.into_iter().reduce(|a, b| SQLExpr::BinaryOp {
+            left: Box::new(a),
+            op: BinaryOperator::And,
+            right: Box::new(b),
+        });
+        for ((_, inner), key) in correlated.iter().zip(&key_names) {
+            decorrelated.projection.push(SelectItem::ExprWithAlias {
+                expr: inner.clone(),
+                alias: Ident::new(key.as_str()),
+            });
+        }
+        if aggregated {
+            if let GroupByExpr::Expressions(exprs, _) = &mut decorrelated.group_by {
+                exprs.extend(correlated.iter().map(|(_, inner)| inner.clone()));
+            }
+        }
+        let mut query = subquery.clone();
+        query.body = Box::new(SetExpr::Select(decorrelated));
+        let mut rf = self.execute_query_no_ctes(&query)?;
+
+        let rf_schema = self.get_frame_schema(&mut rf)?;
+        let mut output_names: Vec<PlSmallStr> = rf_schema
+            .iter_names()
+            .filter(|name| !key_names.contains(name))
+            .cloned()
+            .collect();
+        if !alias.columns.is_empty() {
+            polars_ensure!(
+                alias.columns.len() == output_names.len(),
+                SQLSyntax: "number of columns ({}) in alias '{}' does not match the number of columns in the subquery ({})",
+                alias.columns.len(), alias.name.value, output_names.len()
+            );
+            let new_names: Vec<PlSmallStr> = alias
+                .columns
+                .iter()
+                .map(|c| PlSmallStr::from_str(c.name.value.as_str()))
+                .collect();
+            rf = rf.rename(&output_names, &new_names, true);
+            output_names = new_names;
+        }
+
+        let r_name = alias.name.value.clone();
+        let suffix = format_pl_smallstr!(":{}", r_name);
+        let mut joined = if correlated.is_empty() {
+            lf.cross_join(rf.clone(), Some(suffix))
+        } else {
+            // an aggregate without GROUP BY produces a row for every outer row
+            let how = if keep_unmatched || (aggregated && !has_group_by) {
+                JoinType::Left
+            } else {
+                JoinType::Inner
+            };
+            let left_on = correlated
+                .iter()
+                .map(|(outer, _)| parse_sql_expr(outer, self, Some(left_schema)))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            let right_on: Vec<Expr> = key_names.iter().map(|key| col(key.clone())).collect();
+            let mut joined = lf
+                .join_builder()
+                .with(rf.clone())
+                .left_on(left_on)
+                .right_on(right_on)
+                .how(how.clone())
+                .suffix(suffix)
+                .coalesce(JoinCoalesce::KeepColumns)
+                .finish()
+                .drop(cols(key_names.clone()));
+
+            if how == JoinType::Left && aggregated && !has_group_by {
+                // COUNT over no matching rows is zero (rather than NULL)
+                let counts: Vec<Expr> = select
+                    .projection
+                    .iter()
+                    .zip(&output_names)
+                    .filter(|(item, _)| match item {
+                        SelectItem::UnnamedExpr(SQLExpr::Function(f))
+                        | SelectItem::ExprWithAlias {
+                            expr: SQLExpr::Function(f),
+                            ..
+                        } => f.name.to_string().eq_ignore_ascii_case("count"),
+                        _ => false,
+                    })
+                    .map(|(_, name)| {
+                        let name = if left_schema.contains(name) {
+                            format_pl_smallstr!("{}:{}", name, r_name)
+                        } else {
+                            name.clone()
+                        };
+                        col(name).fill_null(lit(0))
+                    })
+                    .collect();
+                if !counts.is_empty() {
+                    joined = joined.with_columns(counts);
+                }
+            }
+            joined
+        };
+
+        let mut right = rf.select(
+            output_names
+                .iter()
+                .map(|name| col(name.clone()))
+                .collect::<Vec<_>>(),
+        );
+        let right_schema = self.get_frame_schema(&mut right)?;
+        let joined_schema = self.get_frame_schema(&mut joined)?;
+        self.register_joined_aliases(&r_name, left_schema, &right_schema, &joined_schema);
+        self.table_map.insert(r_name, right);
+        Ok(joined)
+    }
+
+    /// Add the (named) expressions to each row of the outer table, exploding those that
+    /// are marked as set-returning into one row per element.
+    #[allow(clippy::too_many_arguments)]
+    fn explode_lateral(
+        &mut self,
+        lf: LazyFrame,
+        r_name: &str,
+        left_schema: &SchemaRef,
+        exprs: Vec<Expr>,
+        explode: Vec<bool>,
+        ordinality: Option<(PlSmallStr, i64)>,
+        keep_unmatched: bool,
+    ) -> PolarsResult<LazyFrame> {
+        const INDEX: PlSmallStr = PlSmallStr::from_static("__PL_INDEX");
+        const LENGTH: PlSmallStr = PlSmallStr::from_static("__PL_LENGTH");
+
+        // columns that clash with the outer table are suffixed, as with regular joins
+        let mut right_schema = Schema::with_capacity(exprs.len() + 1);
+        let mut names = Vec::with_capacity(exprs.len() + 1);
+        let mut aliased = Vec::with_capacity(exprs.len());
+        for expr in exprs {
+            let field = expr.to_field(left_schema)?;
+            let name = if left_schema.contains(&field.name) {
+                format_pl_smallstr!("{}:{}", field.name, r_name)
+            } else {
+                field.name.clone()
+            };
+            aliased.push(expr.alias(name.clone()));
+            right_schema.insert(field.name.clone(), field.dtype);
+            names.push((field.name, name));
+        }
+        let exploded: Vec<PlSmallStr> = names
+            .iter()
+            .zip(&explode)
+            .filter(|(_, explode)| **explode)
+            .map(|((_, name), _)| name.clone())
+            .collect();
 
-                self.joined_aliases.insert(
-                    r_name.clone(),
-                    right_schema
-                        .iter_names()
-                        .filter_map(|name| {
-                            // col exists in both tables and is aliased in the joined result
-                            let aliased_name = format!("{name}:{r_name}");
-                            if left_schema.contains(name)
-                                && joined_schema.contains(aliased_name.as_str())
-                            {
-                                Some((name.to_string(), aliased_name))
-                            } else {
-                                None
-                            }
-                        })
-                        .collect::<PlHashMap<String, String>>(),
+        let mut lf = lf.with_columns(aliased);
+        if !exploded.is_empty() {
+            // number of rows produced for each outer row (NULL/empty arrays produce none)
+            let lengths: Vec<Expr> = exploded
+                .iter()
+                .map(|name| col(name.clone()).list().len().fill_null(lit(0)))
+                .collect();
+            lf = lf
+                .with_row_index(INDEX, None)
+                .with_column(max_horizontal(lengths)?.alias(LENGTH));
+            if !keep_unmatched {
+                lf = lf.filter(col(LENGTH).gt(lit(0)));
+            }
+            lf = lf.explode(cols(exploded));
+
+            if let Some((ord_name, offset)) = ordinality {
+                let name = if left_schema.contains(&ord_name) {
+                    format_pl_smallstr!("{}:{}", ord_name, r_name)
+                } else {
+                    ord_name.clone()
+                };
+                let position = col(INDEX)
+                    .cum_count(false)
+                    .over([col(INDEX)])
+                    .cast(DataType::Int64)
+                    + lit(offset - 1);
+                lf = lf.with_column(
+                    when(col(LENGTH).gt(lit(0)))
+                        .then(position)
+                        .otherwise(lit(NULL))
+                        .alias(name.clone()),
                 );
+                right_schema.insert(ord_name.clone(), DataType::Int64);
+                names.push((ord_name, name));
             }
-        };
+            lf = lf.drop(cols([INDEX, LENGTH]));
+        }
+
+        // register the lateral relation so that its (qualified) columns can be resolved
+        let joined_schema = self.get_frame_schema(&mut lf)?;
+        self.register_joined_aliases(r_name, left_schema, &right_schema, &joined_schema);
+        let right = lf.clone().select(
+            names
+                .iter()
+                .map(|(name, resolved)| col(resolved.clone()).alias(name.clone()))
+                .collect::<Vec<_>>(),
+        );
+        self.table_map.insert(r_name.to_string(), right);
         Ok(lf)
     }
 
+    /// If the expression is an equality between an outer and an inner column, return the
+    /// (outer, inner) pair of expressions.
+    fn correlated_equality(
+        &self,
+        expr: &SQLExpr,
+        inner_names: &PlHashSet<String>,
+    ) -> Option<(SQLExpr, SQLExpr)> {
+        let is_outer = |e: &SQLExpr| match e {
+            SQLExpr::CompoundIdentifier(idents) if idents.len() > 1 => {
+                let tbl = &idents[0].value;
+                !inner_names.contains(tbl) && self.get_table_from_current_scope(tbl).is_some()
+            },
+            _ => false,
+        };
+        match expr {
+            SQLExpr::BinaryOp {
+                left,
+                op: BinaryOperator::Eq,
+                right,
+            } => match (is_outer(left), is_outer(right)) {
+                (true, false) => Some((left.as_ref().clone(), right.as_ref().clone())),
+                (false, true) => Some((right.as_ref().clone(), left.as_ref().clone())),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    fn register_joined_aliases(
+        &mut self,
+        r_name: &str,
+        left_schema: &Schema,
+        right_schema: &Schema,
+        joined_schema: &Schema,
+    ) {
+        self.joined_aliases.insert(
+            r_name.to_string(),
+            right_schema
+                .iter_names()
+                .filter_map(|name| {
+                    // col exists in both tables and is aliased in the joined result
+                    let aliased_name = format!("{name}:{r_name}");
+                    if left_schema.contains(name) && joined_schema.contains(aliased_name.as_str()) {
+                        Some((name.to_string(), aliased_name))
+                    } else {
+                        None
+                    }
+                })
+                .collect::<PlHashMap<String, String>>(),
+        );
+    }
+
     /// Execute the 'SELECT' part of the query.
     fn execute_select(&mut self, select_stmt: &Select, query: &Query) -> PolarsResult<LazyFrame> {
         let mut lf = if select_stmt.from.is_empty() {
@@ -1095,6 +2139,308 @@ impl SQLContext {
         }
     }
 
+    // PREPARE <name> [(<type>, ..)] AS <statement>
+    fn execute_prepare(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        let Statement::Prepare {
+            name,
+            data_types,
+            statement,
+        } = stmt
+        else {
+            unreachable!()
+        };
+        let dtypes = if data_types.is_empty() {
+            None
+        } else {
+            Some(
+                data_types
+                    .iter()
+                    .map(map_sql_dtype_to_polars)
+                    .collect::<PolarsResult<Vec<_>>>()?,
+            )
+        };
+        let mut prepared = PreparedStatement {
+            statement: statement.clone(),
+            dtypes,
+            plan: None,
+        };
+        // with declared types, convert the statement once, with typed placeholders for
+        // the parameters; otherwise it is converted once the values are known
+        if let (Some(dtypes), Statement::Query(query)) = (&prepared.dtypes, &**statement) {
+            self.prepared_param_types = Some(dtypes.clone());
+            let plan = self.convert_prepared_query(query);
+            self.prepared_param_types = None;
+            prepared.plan = plan?;
+        }
+        self.prepared.insert(name.value.clone(), prepared);
+        Ok(df! { "Response" => ["PREPARE"] }?.lazy())
+    }
+
+    /// Convert a prepared query into a plan that can be reused (see [`PreparedPlan`]).
+    ///
+    /// Returns `None` if part of the query is evaluated during conversion, as the result
+    /// then only holds for these parameter values and tables.
+    fn convert_prepared_query(&mut self, query: &Query) -> PolarsResult<Option<PreparedPlan>> {
+        let table_map = std::mem::take(&mut self.table_map);
+        let mut tables = PlHashMap::with_capacity(table_map.len());
+        self.table_map = table_map
+            .iter()
+            .map(|(name, lf)| {
+                let id = UniqueId::new();
+                tables.insert(id, name.clone());
+                let stand_in = DslPlan::Cache {
+                    input: Arc::new(lf.logical_plan.clone()),
+                    id,
+                };
+                (name.clone(), LazyFrame::from(stand_in))
+            })
+            .collect();
+
+        self.reusable_plan = true;
+        let res = self.execute_query(query);
+        let reusable = std::mem::take(&mut self.reusable_plan);
+        self.table_map = table_map;
+        self.cte_map.clear();
+        self.table_aliases.clear();
+        self.joined_aliases.clear();
+        if !reusable {
+            return Ok(None);
+        }
+        let values = match self.prepared_param_types {
+            Some(_) => None,
+            None => self.bound_params.as_ref().map(|p| p.positional.clone()),
+        };
+        Ok(Some(PreparedPlan {
+            plan: res?.logical_plan,
+            tables,
+            values,
+        }))
+    }
+
+    // EXECUTE <name> [(<value>, ..)]
+    fn execute_prepared_statement(
+        &mut self,
+        name: &str,
+        values: Vec<Expr>,
+    ) -> PolarsResult<LazyFrame> {
+        let Some(mut prepared) = self.prepared.get(name).cloned() else {
+            polars_bail!(SQLInterface: "prepared statement '{}' does not exist", name);
+        };
+        let values: Vec<Expr> = match &prepared.dtypes {
+            Some(dtypes) => {
+                polars_ensure!(
+                    values.len() == dtypes.len(),
+                    SQLSyntax: "prepared statement '{}' expects {} parameter{}, found {}",
+                    name, dtypes.len(), if dtypes.len() == 1 { "" } else { "s" }, values.len()
+                );
+                values
+                    .into_iter()
+                    .zip(dtypes)
+                    .map(|(v, dtype)| v.strict_cast(dtype.clone()))
+                    .collect()
+            },
+            None => values,
+        };
+        if let Some(plan) = &prepared.plan {
+            if let Some(lf) = plan.bind(&values, &self.table_map)? {
+                return Ok(lf);
+            }
+        }
+
+        let bound = self.bound_params.replace(BoundParams {
+            positional: values.clone(),
+            ..Default::default()
+        });
+        let res = match &*prepared.statement {
+            // (re)convert the plan, with typed placeholders if the types were declared
+            Statement::Query(query) => {
+                self.prepared_param_types = prepared.dtypes.clone();
+                let plan = self.convert_prepared_query(query);
+                self.prepared_param_types = None;
+                plan
+            },
+            _ => Ok(None),
+        };
+        let res = res.and_then(|plan| {
+            if let Some(plan) = plan {
+                if let Some(lf) = plan.bind(&values, &self.table_map)? {
+                    prepared.plan = Some(plan);
+                    self.prepared.insert(name.to_string(), prepared.clone());
+                    return Ok(lf);
+                }
+            }
+            // statements that take effect (or are partly evaluated) while being converted
+            self.execute_statement(&prepared.statement)
+        });
+        self.bound_params = bound;
+        res
+    }
+
+    /// Resolve a query parameter placeholder (eg: `$1`, `:name`) to an expression.
+    pub(crate) fn resolve_placeholder(&self, placeholder: &str) -> PolarsResult<Expr> {
+        if let Some(dtypes) = &self.prepared_param_types {
+            let ParamKey::Positional(idx) = ParamKey::parse(placeholder)? else {
+                polars_bail!(SQLInterface: "prepared statements only support positional parameters (found '{}')", placeholder);
+            };
+            let Some(dtype) = dtypes.get(idx) else {
+                polars_bail!(SQLSyntax: "no type declared for query parameter '{}'", placeholder);
+            };
+            return Ok(param_marker(idx, dtype));
+        }
+        match &self.bound_params {
+            Some(params) => params.get(placeholder),
+            None => polars_bail!(
+                SQLInterface: "query parameter '{}' has no bound value (see `execute_with_params`)", placeholder
+            ),
+        }
+    }
+
+    /// Resolve a query parameter whose value is needed to convert the query (such as a
+    /// LIMIT or an INTERVAL string), rather than being used in an expression.
+    pub(crate) fn resolve_placeholder_value(
+        &self,
+        placeholder: &str,
+        clause: &str,
+    ) -> PolarsResult<LiteralValue> {
+        polars_ensure!(
+            self.prepared_param_types.is_none(),
+            SQLInterface: "{} parameters are not supported in prepared statements with declared parameter types (found '{}'); omit the types to bind the value on EXECUTE",
+            clause, placeholder
+        );
+        match self.resolve_placeholder(placeholder)? {
+            Expr::Literal(value) => Ok(value),
+            _ => {
+                polars_bail!(SQLInterface: "{} parameter '{}' must be bound to a literal value", clause, placeholder)
+            },
+        }
+    }
+
+    // CREATE [OR REPLACE] FUNCTION <name>(<arg> <type>, ..) [RETURNS <type>] AS '<sql expr>'
+    fn execute_create_function(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        let Statement::CreateFunction(CreateFunction {
+            or_replace,
+            if_not_exists,
+            name,
+            args,
+            return_type,
+            function_body,
+            language,
+            ..
+        }) = stmt
+        else {
+            unreachable!()
+        };
+        if let Some(language) = language {
+            polars_ensure!(
+                language.value.eq_ignore_ascii_case("sql"),
+                SQLInterface: "CREATE FUNCTION only supports SQL expressions (found LANGUAGE {})", language
+            );
+        }
+        let func_name = name.0.last().unwrap().value.to_lowercase();
+        if self.function_macros.contains_key(&func_name)
+            || self.function_registry.contains(&func_name)
+        {
+            if *if_not_exists {
+                return Ok(df! { "Response" => ["CREATE FUNCTION"] }?.lazy());
+            }
+            polars_ensure!(*or_replace, SQLInterface: "function '{}' already exists", func_name);
+        }
+
+        let mut params: Vec<(PlSmallStr, DataType)> = vec![];
+        for arg in args.iter().flatten() {
+            let Some(arg_name) = &arg.name else {
+                polars_bail!(SQLSyntax: "CREATE FUNCTION arguments must be named");
+            };
+            polars_ensure!(
+                arg.default_expr.is_none(),
+                SQLInterface: "CREATE FUNCTION does not (yet) support argument defaults"
+            );
+            let dtype = map_sql_dtype_to_polars(&arg.data_type)?;
+            params.push((PlSmallStr::from_str(arg_name.value.as_str()), dtype));
+        }
+        let body = match function_body {
+            Some(
+                CreateFunctionBody::AsBeforeOptions(body)
+                | CreateFunctionBody::AsAfterOptions(body),
+            ) => match body {
+                SQLExpr::Value(
+                    SQLValue::SingleQuotedString(sql)
+                    | SQLValue::DollarQuotedString(DollarQuotedString { value: sql, .. }),
+                ) => Parser::new(&GenericDialect)
+                    .try_with_sql(sql)
+                    .and_then(|mut p| p.parse_expr())
+                    .map_err(to_sql_interface_err)?,
+                body => body.clone(),
+            },
+            Some(CreateFunctionBody::Return(body)) => body.clone(),
+            None => polars_bail!(SQLSyntax: "CREATE FUNCTION requires a function body"),
+        };
+
+        // the body is expanded at each call site, with the arguments substituted
+        // for the parameter names (a scalar SQL macro)
+        let body = parse_sql_expr(&body, self, None)?;
+        for e in body.into_iter() {
+            if let Expr::Column(name) = e {
+                polars_ensure!(
+                    params.iter().any(|(p, _)| p == name),
+                    SQLInterface: "CREATE FUNCTION body references unknown argument '{}'", name
+                );
+            }
+        }
+        let mut expr_function = ExprFunction::new(PlSmallStr::from_str(&func_name), {
+            let func_name = func_name.clone();
+            move |args: Vec<Expr>| {
+                polars_ensure!(
+                    args.len() == params.len(),
+                    SQLSyntax: "{} expects {} argument{}, found {}",
+                    func_name.to_uppercase(), params.len(), if params.len() == 1 { "" } else { "s" }, args.len()
+                );
+                let args: PlHashMap<&str, Expr> = params
+                    .iter()
+                    .zip(args)
+                    .map(|((name, dtype), arg)| (name.as_str(), arg.strict_cast(dtype.clone())))
+                    .collect();
+                Ok(body.clone().map_expr(|e| match e {
+                    Expr::Column(name) => args
+                        .get(name.as_str())
+                        .cloned()
+                        .unwrap_or(Expr::Column(name)),
+                    e => e,
+                }))
+            }
+        });
+        if let Some(dtype) = return_type {
+            expr_function = expr_function.with_return_dtype(map_sql_dtype_to_polars(dtype)?);
+        }
+        self.function_macros.insert(func_name, expr_function);
+
+        Ok(df! { "Response" => ["CREATE FUNCTION"] }?.lazy())
+    }
+
+    // DROP FUNCTION [IF EXISTS] <name>
+    fn execute_drop_function(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        let Statement::DropFunction {
+            if_exists,
+            func_desc,
+            ..
+        } = stmt
+        else {
+            unreachable!()
+        };
+        for desc in func_desc {
+            let func_name = desc.name.0.last().unwrap().value.to_lowercase();
+            if self.function_macros.remove(&func_name).is_some() {
+                continue;
+            }
+            polars_ensure!(
+                !self.function_registry.contains(&func_name),
+                SQLInterface: "cannot drop function '{}'; only functions created with CREATE FUNCTION can be dropped", func_name
+            );
+            polars_ensure!(*if_exists, SQLInterface: "function '{}' does not exist", func_name);
+        }
+        Ok(df! { "Response" => ["DROP FUNCTION"] }?.lazy())
+    }
+
     fn get_table(&mut self, relation: &TableFactor) -> PolarsResult<(String, LazyFrame)> {
         match relation {
             TableFactor::Table {
@@ -1103,6 +2449,21 @@ impl SQLContext {
                 if let Some(args) = args {
                     return self.execute_table_function(name, alias, &args.args);
                 }
+                if let [schema, tbl] = name.0.as_slice() {
+                    if schema.value.eq_ignore_ascii_case("information_schema") {
+                        let lf = self.get_information_schema_table(&tbl.value)?;
+                        let tbl_name = tbl.value.clone();
+                        self.cte_map.insert(tbl_name.clone(), lf.clone());
+                        return Ok(match alias {
+                            Some(alias) => {
+                                self.table_aliases
+                                    .insert(alias.name.value.clone(), tbl_name);
+                                (alias.to_string(), lf)
+                            },
+                            None => (tbl_name, lf),
+                        });
+                    }
+                }
                 let tbl_name = name.0.first().unwrap().value.as_str();
                 if let Some(lf) = self.get_table_from_current_scope(tbl_name) {
                     match alias {
@@ -1122,7 +2483,7 @@ impl SQLContext {
                 subquery,
                 alias,
             } => {
-                polars_ensure!(!(*lateral), SQLInterface: "LATERAL not supported");
+                polars_ensure!(!(*lateral), SQLInterface: "LATERAL subqueries must follow a JOIN");
                 if let Some(alias) = alias {
                     let mut lf = self.execute_query_no_ctes(subquery)?;
                     lf = self.rename_columns_from_table_alias(lf, alias)?;
@@ -1136,39 +2497,23 @@ impl SQLContext {
                 alias,
                 array_exprs,
                 with_offset,
-                with_offset_alias: _,
-                ..
+                with_offset_alias,
+                with_ordinality,
             } => {
                 if let Some(alias) = alias {
-                    let column_names: Vec<Option<PlSmallStr>> = alias
-                        .columns
-                        .iter()
-                        .map(|c| {
-                            if c.name.value.is_empty() {
-                                None
-                            } else {
-                                Some(PlSmallStr::from_str(c.name.value.as_str()))
-                            }
-                        })
-                        .collect();
-
+                    let (column_names, ordinality) = unnest_column_names(
+                        alias,
+                        array_exprs.len(),
+                        *with_offset,
+                        with_offset_alias,
+                        *with_ordinality,
+                    )?;
                     let column_values: Vec<Series> = array_exprs
                         .iter()
                         .map(|arr| parse_sql_array(arr, self))
                         .collect::<Result<_, _>>()?;
 
-                    polars_ensure!(!column_names.is_empty(),
-                        SQLSyntax:
-                        "UNNEST table alias must also declare column names, eg: {} (a,b,c)", alias.name.to_string()
-                    );
-                    if column_names.len() != column_values.len() {
-                        let plural = if column_values.len() > 1 { "s" } else { "" };
-                        polars_bail!(
-                            SQLSyntax:
-                            "UNNEST table alias requires {} column name{}, found {}", column_values.len(), plural, column_names.len()
-                        );
-                    }
-                    let column_series: Vec<Column> = column_values
+                    let mut column_series: Vec<Column> = column_values
                         .into_iter()
                         .zip(column_names)
                         .map(|(s, name)| {
@@ -1181,13 +2526,12 @@ impl SQLContext {
                         .map(Column::from)
                         .collect();
 
-                    let lf = DataFrame::new(column_series)?.lazy();
-
-                    if *with_offset {
-                        // TODO: support 'WITH ORDINALITY|OFFSET' modifier.
-                        //  (note that 'WITH OFFSET' is BigQuery-specific syntax, not PostgreSQL)
-                        polars_bail!(SQLInterface: "UNNEST tables do not (yet) support WITH ORDINALITY|OFFSET");
+                    if let Some((name, offset)) = ordinality {
+                        let height = column_series.iter().map(|c| c.len()).max().unwrap_or(0);
+                        let positions: Vec<i64> = (0..height as i64).map(|i| i + offset).collect();
+                        column_series.push(Column::new(name, positions));
                     }
+                    let lf = DataFrame::new(column_series)?.lazy();
                     let table_name = alias.name.value.clone();
                     self.table_map.insert(table_name.clone(), lf.clone());
                     Ok((table_name, lf))
@@ -1205,11 +2549,187 @@ impl SQLContext {
                     None => Ok(("".to_string(), lf)),
                 }
             },
+            #[cfg(feature = "pivot")]
+            TableFactor::Pivot {
+                table,
+                aggregate_functions,
+                value_column,
+                value_source,
+                default_on_null,
+                alias,
+            } => self.execute_pivot(
+                table,
+                aggregate_functions,
+                value_column,
+                value_source,
+                default_on_null,
+                alias,
+            ),
+            #[cfg(feature = "pivot")]
+            TableFactor::Unpivot {
+                table,
+                value,
+                name,
+                columns,
+                alias,
+            } => self.execute_unpivot(table, value, name, columns, alias),
             // Support bare table, optionally with an alias, for now
             _ => polars_bail!(SQLInterface: "not yet implemented: {}", relation),
         }
     }
 
+    // <tbl> PIVOT (<agg> [AS <alias>], ... FOR <col> IN (<value> [AS <alias>], ...))
+    #[cfg(feature = "pivot")]
+    fn execute_pivot(
+        &mut self,
+        table: &TableFactor,
+        aggregate_functions: &[ExprWithAlias],
+        value_column: &[Ident],
+        value_source: &PivotValueSource,
+        default_on_null: &Option<SQLExpr>,
+        alias: &Option<TableAlias>,
+    ) -> PolarsResult<(String, LazyFrame)> {
+        let (tbl_name, mut lf) = self.get_table(table)?;
+        let schema = self.get_frame_schema(&mut lf)?;
+
+        // note: an explicit list of values is required, as otherwise
+        // the output schema is only known after materializing the data
+        let PivotValueSource::List(values) = value_source else {
+            polars_bail!(SQLInterface: "PIVOT requires an explicit list of values; found {}", value_source)
+        };
+        let pivot_col = match value_column.last() {
+            Some(ident) => PlSmallStr::from_str(ident.value.as_str()),
+            None => polars_bail!(SQLSyntax: "PIVOT requires a FOR column"),
+        };
+        polars_ensure!(
+            schema.contains(&pivot_col),
+            ColumnNotFound: "PIVOT column '{}' not found", pivot_col
+        );
+
+        let aggs = aggregate_functions
+            .iter()
+            .map(|agg| {
+                let expr = parse_sql_expr(&agg.expr, self, Some(&schema))?;
+                let name = match &agg.alias {
+                    Some(alias) => PlSmallStr::from_str(alias.value.as_str()),
+                    None => expr.to_field(&schema)?.name,
+                };
+                Ok((expr, name))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let default_on_null = default_on_null
+            .as_ref()
+            .map(|e| parse_sql_expr(e, self, Some(&schema)))
+            .transpose()?;
+
+        // the (implicit) group keys are all columns not used by the aggregates or the pivot
+        let mut used_cols: PlHashSet<PlSmallStr> = PlHashSet::new();
+        used_cols.insert(pivot_col.clone());
+        for (expr, _) in &aggs {
+            used_cols.extend(expr_to_leaf_column_names(expr));
+        }
+        let index: Vec<Expr> = schema
+            .iter_names()
+            .filter(|name| !used_cols.contains(*name))
+            .map(|name| col(name.clone()))
+            .collect();
+
+        let mut pivot_exprs = Vec::with_capacity(values.len() * aggs.len());
+        for value in values {
+            let value_expr = parse_sql_expr(&value.expr, self, Some(&schema))?;
+            let value_name = match (&value.alias, &value.expr) {
+                (Some(alias), _) => alias.value.clone(),
+                (None, SQLExpr::Value(SQLValue::SingleQuotedString(s))) => s.clone(),
+                (None, e) => e.to_string(),
+            };
+            let matches = col(pivot_col.clone()).eq(value_expr);
+            for (expr, agg_name) in &aggs {
+                // restrict the aggregate inputs to the rows matching the pivot value
+                let filtered = expr.clone().map_expr(|e| match e {
+                    Expr::Column(name) => col(name).filter(matches.clone()),
+                    Expr::Len => col(pivot_col.clone()).filter(matches.clone()).len(),
+                    e => e,
+                });
+                // groups without matching rows produce NULL (or the DEFAULT ON NULL value)
+                let mut pivoted = when(matches.clone().any(true))
+                    .then(filtered)
+                    .otherwise(lit(NULL));
+                if let Some(default) = &default_on_null {
+                    pivoted = pivoted.fill_null(default.clone());
+                }
+                let name = if aggs.len() == 1 {
+                    value_name.clone()
+                } else {
+                    format!("{value_name}_{agg_name}")
+                };
+                pivot_exprs.push(pivoted.alias(name));
+            }
+        }
+        let lf = if index.is_empty() {
+            lf.select(pivot_exprs)
+        } else {
+            lf.group_by_stable(index).agg(pivot_exprs)
+        };
+        self.register_table_factor_result(tbl_name, lf, alias)
+    }
+
+    // <tbl> UNPIVOT (<value> FOR <name> IN (<col>, ...))
+    #[cfg(feature = "pivot")]
+    fn execute_unpivot(
+        &mut self,
+        table: &TableFactor,
+        value: &Ident,
+        name: &Ident,
+        columns: &[Ident],
+        alias: &Option<TableAlias>,
+    ) -> PolarsResult<(String, LazyFrame)> {
+        let (tbl_name, mut lf) = self.get_table(table)?;
+        let schema = self.get_frame_schema(&mut lf)?;
+
+        let on: Vec<PlSmallStr> = columns
+            .iter()
+            .map(|c| PlSmallStr::from_str(c.value.as_str()))
+            .collect();
+        for c in &on {
+            polars_ensure!(schema.contains(c), ColumnNotFound: "UNPIVOT column '{}' not found", c);
+        }
+        let index: Vec<PlSmallStr> = schema
+            .iter_names()
+            .filter(|name| !on.contains(*name))
+            .cloned()
+            .collect();
+
+        // note: as with other SQL engines, rows with a NULL value are excluded
+        let value_name = PlSmallStr::from_str(value.value.as_str());
+        let lf = lf
+            .unpivot(UnpivotArgsDSL {
+                on: cols(on),
+                index: cols(index),
+                variable_name: Some(PlSmallStr::from_str(name.value.as_str())),
+                value_name: Some(value_name.clone()),
+            })
+            .filter(col(value_name).is_not_null());
+        self.register_table_factor_result(tbl_name, lf, alias)
+    }
+
+    /// Apply the (optional) alias of a table factor to its result and register it.
+    #[cfg(feature = "pivot")]
+    fn register_table_factor_result(
+        &mut self,
+        tbl_name: String,
+        lf: LazyFrame,
+        alias: &Option<TableAlias>,
+    ) -> PolarsResult<(String, LazyFrame)> {
+        match alias {
+            Some(alias) => {
+                let lf = self.rename_columns_from_table_alias(lf, alias)?;
+                self.table_map.insert(alias.name.value.clone(), lf.clone());
+                Ok((alias.name.value.clone(), lf))
+            },
+            None => Ok((tbl_name, lf)),
+        }
+    }
+
     fn execute_table_function(
         &mut self,
         name: &ObjectName,
@@ -1388,7 +2908,25 @@ impl SQLContext {
         limit: &Option<SQLExpr>,
         offset: &Option<Offset>,
     ) -> PolarsResult<LazyFrame> {
-        match (offset, limit) {
+        // the slice bounds are not expressions, so parameters are bound to their values here
+        let bind_param = |value: &SQLExpr, clause: &str| match value {
+            SQLExpr::Value(SQLValue::Placeholder(p)) => {
+                let n = self.resolve_placeholder_value(p, clause)?.extract_usize()?;
+                Ok(SQLExpr::Value(SQLValue::Number(n.to_string(), false)))
+            },
+            value => PolarsResult::Ok(value.clone()),
+        };
+        let limit = limit.as_ref().map(|l| bind_param(l, "LIMIT")).transpose()?;
+        let offset = offset
+            .as_ref()
+            .map(|o| {
+                PolarsResult::Ok(Offset {
+                    value: bind_param(&o.value, "OFFSET")?,
+                    rows: o.rows,
+                })
+            })
+            .transpose()?;
+        match (&offset, &limit) {
             (
                 Some(Offset {
                     value: SQLExpr::Value(SQLValue::Number(offset, _)),
@@ -1553,6 +3091,200 @@ impl SQLContext {
     }
 }
 
+const INFORMATION_SCHEMA_CATALOG: &str = "polars";
+const INFORMATION_SCHEMA_SCHEMA: &str = "public";
+
+/// Describe the columns of a schema (as returned by `DESCRIBE` and `SHOW COLUMNS`).
+fn describe_schema(schema: &Schema) -> PolarsResult<DataFrame> {
+    let n_columns = schema.len();
+    DataFrame::new(vec![
+        Column::new(
+            "column_name".into(),
+            schema.iter_names().map(|n| n.as_str()).collect::<Vec<_>>(),
+        ),
+        Column::new(
+            "column_type".into(),
+            schema
+                .iter_values()
+                .map(map_polars_dtype_to_sql)
+                .collect::<Vec<_>>(),
+        ),
+        Column::new("null".into(), vec!["YES"; n_columns]),
+    ])
+}
+
+/// Determine the UNNEST output column names (from the table alias) and the name and
+/// starting value of the optional WITH ORDINALITY/OFFSET position column.
+#[allow(clippy::type_complexity)]
+fn unnest_column_names(
+    alias: &TableAlias,
+    n_arrays: usize,
+    with_offset: bool,
+    with_offset_alias: &Option<Ident>,
+    with_ordinality: bool,
+) -> PolarsResult<(Vec<Option<PlSmallStr>>, Option<(PlSmallStr, i64)>)> {
+    polars_ensure!(
+        !(with_offset && with_ordinality),
+        SQLSyntax: "UNNEST cannot use both WITH ORDINALITY and WITH OFFSET"
+    );
+    let mut column_names: Vec<Option<PlSmallStr>> = alias
+        .columns
+        .iter()
+        .map(|c| {
+            if c.name.value.is_empty() {
+                None
+            } else {
+                Some(PlSmallStr::from_str(c.name.value.as_str()))
+            }
+        })
+        .collect();
+
+    polars_ensure!(!column_names.is_empty(),
+        SQLSyntax:
+        "UNNEST table alias must also declare column names, eg: {} (a,b,c)", alias.name.to_string()
+    );
+    // WITH ORDINALITY (1-based) can name the position column as an additional alias
+    // column; WITH OFFSET (0-based) names it with its own alias (eg: BigQuery)
+    let mut ordinality = None;
+    if with_ordinality {
+        let name = if column_names.len() == n_arrays + 1 {
+            column_names.pop().flatten()
+        } else {
+            None
+        };
+        ordinality = Some((
+            name.unwrap_or_else(|| PlSmallStr::from_static("ordinality")),
+            1,
+        ));
+    } else if with_offset {
+        let name = with_offset_alias
+            .as_ref()
+            .map(|a| PlSmallStr::from_str(a.value.as_str()));
+        ordinality = Some((name.unwrap_or_else(|| PlSmallStr::from_static("offset")), 0));
+    }
+    if column_names.len() != n_arrays {
+        let plural = if n_arrays > 1 { "s" } else { "" };
+        polars_bail!(
+            SQLSyntax:
+            "UNNEST table alias requires {} column name{}, found {}", n_arrays, plural, column_names.len()
+        );
+    }
+    Ok((column_names, ordinality))
+}
+
+/// Joined relations that are evaluated per-row of the preceding tables; LATERAL subqueries
+/// and UNNEST of column values (rather than array literals).
+fn is_lateral_join(join: &Join) -> bool {
+    let is_apply = matches!(
+        join.join_operator,
+        JoinOperator::CrossApply | JoinOperator::OuterApply
+    );
+    match &join.relation {
+        TableFactor::Derived { lateral, .. } => *lateral || is_apply,
+        TableFactor::UNNEST { array_exprs, .. } => {
+            is_apply || array_exprs.iter().any(|e| !matches!(e, SQLExpr::Array(_)))
+        },
+        _ => false,
+    }
+}
+
+/// A join constraint that does not filter any rows (eg: `ON TRUE`).
+fn is_trivial_join_constraint(constraint: &JoinConstraint) -> bool {
+    match constraint {
+        JoinConstraint::None => true,
+        JoinConstraint::On(SQLExpr::Value(SQLValue::Boolean(b))) => *b,
+        JoinConstraint::On(SQLExpr::Nested(e)) => {
+            is_trivial_join_constraint(&JoinConstraint::On(e.as_ref().clone()))
+        },
+        _ => false,
+    }
+}
+
+/// The name by which a relation's columns can be qualified.
+fn relation_name(relation: &TableFactor) -> Option<String> {
+    match relation {
+        TableFactor::Table { name, alias, .. } => Some(match alias {
+            Some(alias) => alias.name.value.clone(),
+            None => name.0.last()?.value.clone(),
+        }),
+        TableFactor::Derived { alias, .. }
+        | TableFactor::UNNEST { alias, .. }
+        | TableFactor::NestedJoin { alias, .. } => alias.as_ref().map(|a| a.name.value.clone()),
+        _ => None,
+    }
+}
+
+/// Split an expression into its top-level AND conjuncts.
+fn split_conjunctions(expr: &SQLExpr) -> Vec<&SQLExpr> {
+    match expr {
+        SQLExpr::BinaryOp {
+            left,
+            op: BinaryOperator::And,
+            right,
+        } => {
+            let mut conjuncts = split_conjunctions(left);
+            conjuncts.extend(split_conjunctions(right));
+            conjuncts
+        },
+        SQLExpr::Nested(e) => split_conjunctions(e),
+        e => vec![e],
+    }
+}
+
+/// Get the name of the registered table that a DML statement targets.
+fn registered_table_name(relation: &TableFactor, stmt_name: &str) -> PolarsResult<String> {
+    match relation {
+        TableFactor::Table {
+            name, args: None, ..
+        } => Ok(name.0.first().unwrap().value.clone()),
+        _ => polars_bail!(SQLInterface: "{} expects a table name; found {}", stmt_name, relation),
+    }
+}
+
+/// Check whether a set expression refers to the named relation (used to detect recursive CTEs).
+fn set_expr_references_table(expr: &SetExpr, name: &str) -> bool {
+    match expr {
+        SetExpr::Select(select) => select
+            .from
+            .iter()
+            .any(|tbl| table_with_joins_references_table(tbl, name)),
+        SetExpr::Query(query) => query_references_table(query, name),
+        SetExpr::SetOperation { left, right, .. } => {
+            set_expr_references_table(left, name) || set_expr_references_table(right, name)
+        },
+        SetExpr::Table(tbl) => tbl.table_name.as_deref() == Some(name),
+        _ => false,
+    }
+}
+
+fn query_references_table(query: &Query, name: &str) -> bool {
+    let in_ctes = query.with.as_ref().is_some_and(|with| {
+        with.cte_tables
+            .iter()
+            .any(|cte| query_references_table(&cte.query, name))
+    });
+    in_ctes || set_expr_references_table(&query.body, name)
+}
+
+fn table_with_joins_references_table(tbl_expr: &TableWithJoins, name: &str) -> bool {
+    table_factor_references_table(&tbl_expr.relation, name)
+        || tbl_expr
+            .joins
+            .iter()
+            .any(|join| table_factor_references_table(&join.relation, name))
+}
+
+fn table_factor_references_table(relation: &TableFactor, name: &str) -> bool {
+    match relation {
+        TableFactor::Table { name: tbl, .. } => tbl.0.first().is_some_and(|id| id.value == name),
+        TableFactor::Derived { subquery, .. } => query_references_table(subquery, name),
+        TableFactor::NestedJoin {
+            table_with_joins, ..
+        } => table_with_joins_references_table(table_with_joins, name),
+        _ => false,
+    }
+}
+
 fn collect_compound_identifiers(
     left: &[Ident],
     right: &[Ident],
@@ -1,8 +1,14 @@
 //! This module defines a FunctionRegistry for supported SQL functions and UDFs.
 
+use std::sync::Arc;
+
+use polars_core::prelude::{Column, DataType, Field, PlHashMap, Schema};
 use polars_error::{PolarsResult, polars_bail};
+use polars_plan::dsl::{BaseColumnUdf, Expr};
 use polars_plan::prelude::udf::UserDefinedFunction;
 pub use polars_plan::prelude::{Context, FunctionOptions};
+use polars_utils::pl_str::PlSmallStr;
+
 /// A registry that holds user defined functions.
 pub trait FunctionRegistry: Send + Sync {
     /// Register a function.
@@ -11,20 +17,135 @@ pub trait FunctionRegistry: Send + Sync {
     fn get_udf(&self, name: &str) -> PolarsResult<Option<UserDefinedFunction>>;
     /// Check if a function is registered.
     fn contains(&self, name: &str) -> bool;
+
+    /// Register a function that expands to an expression at the call site.
+    fn register_expr_function(&mut self, name: &str, _fun: ExprFunction) -> PolarsResult<()> {
+        polars_bail!(ComputeError: "'register_expr_function' not implemented for '{}'", name)
+    }
+    /// Get a function that expands to an expression at the call site.
+    fn get_expr_function(&self, _name: &str) -> PolarsResult<Option<ExprFunction>> {
+        Ok(None)
+    }
+    /// Remove a registered function, returning whether it existed.
+    fn unregister(&mut self, name: &str) -> PolarsResult<bool> {
+        polars_bail!(ComputeError: "'unregister' not implemented for '{}'", name)
+    }
 }
 
-/// A default registry that does not support registering or calling functions.
-pub struct DefaultFunctionRegistry {}
+/// A function that is expanded into an expression over its (expression) arguments when
+/// called from SQL; used for `CREATE FUNCTION` macros and for exposing existing expression
+/// builders (such as plugin expressions) under a SQL name.
+#[derive(Clone)]
+pub struct ExprFunction {
+    /// name
+    pub name: PlSmallStr,
+    /// Builds the expression from the call arguments.
+    pub fun: Arc<dyn Fn(Vec<Expr>) -> PolarsResult<Expr> + Send + Sync>,
+    /// The declared return type; the result is cast to this type if set.
+    pub return_dtype: Option<DataType>,
+}
+
+impl std::fmt::Debug for ExprFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("ExprFunction")
+            .field("name", &self.name)
+            .field("fun", &"<FUNC>")
+            .field("return_dtype", &self.return_dtype)
+            .finish()
+    }
+}
+
+impl ExprFunction {
+    /// Create a new ExprFunction
+    pub fn new(
+        name: PlSmallStr,
+        fun: impl Fn(Vec<Expr>) -> PolarsResult<Expr> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            name,
+            fun: Arc::new(fun),
+            return_dtype: None,
+        }
+    }
+
+    /// Declare the return type of the function.
+    pub fn with_return_dtype(mut self, dtype: DataType) -> Self {
+        self.return_dtype = Some(dtype);
+        self
+    }
+
+    /// Expand the function call into an expression.
+    pub fn call(&self, args: Vec<Expr>) -> PolarsResult<Expr> {
+        let expr = (self.fun)(args)?;
+        Ok(match &self.return_dtype {
+            Some(dtype) => expr.strict_cast(dtype.clone()),
+            None => expr,
+        })
+    }
+}
+
+/// The default in-memory function registry.
+///
+/// Function names are case-insensitive (as with the builtin SQL functions).
+#[derive(Clone, Default)]
+pub struct DefaultFunctionRegistry {
+    udfs: PlHashMap<String, UserDefinedFunction>,
+    expr_functions: PlHashMap<String, ExprFunction>,
+}
+
+impl DefaultFunctionRegistry {
+    /// Register a Rust closure as a scalar function with the given return type.
+    pub fn register_fn(
+        &mut self,
+        name: &str,
+        return_dtype: DataType,
+        f: impl Fn(&mut [Column]) -> PolarsResult<Column> + Send + Sync + 'static,
+    ) -> PolarsResult<()> {
+        let udf = UserDefinedFunction::new(
+            PlSmallStr::from_str(name),
+            BaseColumnUdf::new(f, move |_: &Schema, fields: &[Field]| {
+                let name = fields
+                    .first()
+                    .map_or_else(|| PlSmallStr::from_static("literal"), |f| f.name.clone());
+                Ok(Field::new(name, return_dtype.clone()))
+            }),
+        );
+        self.register(name, udf)
+    }
+}
 
 impl FunctionRegistry for DefaultFunctionRegistry {
-    fn register(&mut self, _name: &str, _fun: UserDefinedFunction) -> PolarsResult<()> {
-        polars_bail!(ComputeError: "'register' not implemented on DefaultFunctionRegistry'")
+    fn register(&mut self, name: &str, fun: UserDefinedFunction) -> PolarsResult<()> {
+        let name = name.to_lowercase();
+        self.expr_functions.remove(&name);
+        self.udfs.insert(name, fun);
+        Ok(())
     }
 
-    fn get_udf(&self, _name: &str) -> PolarsResult<Option<UserDefinedFunction>> {
-        polars_bail!(ComputeError: "'get_udf' not implemented on DefaultFunctionRegistry'")
+    fn get_udf(&self, name: &str) -> PolarsResult<Option<UserDefinedFunction>> {
+        Ok(self.udfs.get(&name.to_lowercase()).cloned())
     }
-    fn contains(&self, _name: &str) -> bool {
-        false
+
+    fn contains(&self, name: &str) -> bool {
+        let name = name.to_lowercase();
+        self.udfs.contains_key(&name) || self.expr_functions.contains_key(&name)
+    }
+
+    fn register_expr_function(&mut self, name: &str, fun: ExprFunction) -> PolarsResult<()> {
+        let name = name.to_lowercase();
+        self.udfs.remove(&name);
+        self.expr_functions.insert(name, fun);
+        Ok(())
+    }
+
+    fn get_expr_function(&self, name: &str) -> PolarsResult<Option<ExprFunction>> {
+        Ok(self.expr_functions.get(&name.to_lowercase()).cloned())
+    }
+
+    fn unregister(&mut self, name: &str) -> PolarsResult<bool> {
+        let name = name.to_lowercase();
+        let udf = self.udfs.remove(&name).is_some();
+        let expr_function = self.expr_functions.remove(&name).is_some();
+        Ok(udf || expr_function)
     }
 }
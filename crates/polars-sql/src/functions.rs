@@ -22,6 +22,40 @@ use sqlparser::tokenizer::Span;
 use crate::SQLContext;
 use crate::sql_expr::{adjust_one_indexed_param, parse_extract_date_part, parse_sql_expr};
 
+/// Check whether a SQL expression contains a call to an aggregate function
+/// (not counting window functions, which do not collapse the input rows).
+pub(crate) fn sql_expr_has_aggregate(expr: &SQLExpr, ctx: &SQLContext) -> bool {
+    match expr {
+        SQLExpr::Function(func) => {
+            let is_agg = func.over.is_none()
+                && PolarsSQLFunctions::try_from_sql(func, ctx).is_ok_and(|f| f.is_aggregate());
+            is_agg
+                || match &func.args {
+                    FunctionArguments::List(FunctionArgumentList { args, .. }) => {
+                        args.iter().any(|arg| match arg {
+                            FunctionArg::Named {
+                                arg: FunctionArgExpr::Expr(e),
+                                ..
+                            }
+                            | FunctionArg::Unnamed(FunctionArgExpr::Expr(e)) => {
+                                sql_expr_has_aggregate(e, ctx)
+                            },
+                            _ => false,
+                        })
+                    },
+                    _ => false,
+                }
+        },
+        SQLExpr::BinaryOp { left, right, .. } => {
+            sql_expr_has_aggregate(left, ctx) || sql_expr_has_aggregate(right, ctx)
+        },
+        SQLExpr::UnaryOp { expr, .. } | SQLExpr::Nested(expr) | SQLExpr::Cast { expr, .. } => {
+            sql_expr_has_aggregate(expr, ctx)
+        },
+        _ => false,
+    }
+}
+
 pub(crate) struct SQLFunctionVisitor<'a> {
     pub(crate) func: &'a SQLFunction,
     pub(crate) ctx: &'a mut SQLContext,
@@ -812,6 +846,29 @@ impl PolarsSQLFunctions {
 }
 
 impl PolarsSQLFunctions {
+    /// Whether the function aggregates its input (to a single value per group).
+    pub(crate) fn is_aggregate(&self) -> bool {
+        use PolarsSQLFunctions::*;
+        matches!(
+            self,
+            Avg | ArrayAgg
+                | Corr
+                | Count
+                | CovarPop
+                | CovarSamp
+                | First
+                | Last
+                | Max
+                | Median
+                | Min
+                | QuantileCont
+                | QuantileDisc
+                | StdDev
+                | Sum
+                | Variance
+        )
+    }
+
     fn try_from_sql(function: &'_ SQLFunction, ctx: &'_ SQLContext) -> PolarsResult<Self> {
         let function_name = function.name.0[0].value.to_lowercase();
         Ok(match function_name.as_str() {
@@ -955,7 +1012,8 @@ impl PolarsSQLFunctions {
             "columns" => Self::Columns,
 
             other => {
-                if ctx.function_registry.contains(other) {
+                if ctx.function_macros.contains_key(other) || ctx.function_registry.contains(other)
+                {
                     Self::Udf(other.to_string())
                 } else {
                     polars_bail!(SQLInterface: "unsupported function '{}'", other);
@@ -1581,6 +1639,12 @@ impl SQLFunctionVisitor<'_> {
             })
             .collect::<PolarsResult<Vec<_>>>()?;
 
+        if let Some(expr_function) = self.ctx.function_macros.get(func_name) {
+            return expr_function.call(args);
+        }
+        if let Some(expr_function) = self.ctx.function_registry.get_expr_function(func_name)? {
+            return expr_function.call(args);
+        }
         Ok(self
             .ctx
             .function_registry
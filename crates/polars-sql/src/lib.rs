@@ -5,9 +5,11 @@ mod context;
 pub mod function_registry;
 mod functions;
 pub mod keywords;
+mod params;
 mod sql_expr;
 mod table_functions;
 mod types;
 
 pub use context::SQLContext;
+pub use params::SQLParams;
 pub use sql_expr::sql_expr;
@@ -0,0 +1,389 @@
+//! Query parameters (placeholders) and prepared statements.
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_plan::plans::{DslFunction, StatsFunction};
+use polars_utils::format_pl_smallstr;
+use polars_utils::unique_id::UniqueId;
+use sqlparser::ast::Statement;
+use sqlparser::tokenizer::{Token, TokenWithSpan};
+
+/// Values bound to the parameters of a SQL query.
+///
+/// Positional parameters are referenced as `$1`, `$2`, ... (or `?`, numbered in order of
+/// appearance), named parameters as `:name`, `$name` or `@name`.
+#[derive(Clone, Debug, Default)]
+pub struct SQLParams {
+    positional: Vec<Scalar>,
+    named: PlHashMap<PlSmallStr, Scalar>,
+}
+
+impl SQLParams {
+    /// Create an empty set of parameters.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a set of positional parameters.
+    pub fn positional<I, S>(values: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<Scalar>,
+    {
+        Self {
+            positional: values.into_iter().map(Into::into).collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Add the next positional parameter.
+    pub fn with_value(mut self, value: impl Into<Scalar>) -> Self {
+        self.positional.push(value.into());
+        self
+    }
+
+    /// Add a named parameter.
+    pub fn with_named(mut self, name: &str, value: impl Into<Scalar>) -> Self {
+        self.named.insert(PlSmallStr::from_str(name), value.into());
+        self
+    }
+
+    pub(crate) fn into_bound(self) -> BoundParams {
+        let to_lit = |s: Scalar| Expr::Literal(LiteralValue::Scalar(s));
+        BoundParams {
+            positional: self.positional.into_iter().map(to_lit).collect(),
+            named: self
+                .named
+                .into_iter()
+                .map(|(name, s)| (name, to_lit(s)))
+                .collect(),
+        }
+    }
+}
+
+/// Parameter values, as expressions, for the statement currently being converted.
+#[derive(Clone, Default)]
+pub(crate) struct BoundParams {
+    pub(crate) positional: Vec<Expr>,
+    pub(crate) named: PlHashMap<PlSmallStr, Expr>,
+}
+
+pub(crate) enum ParamKey<'a> {
+    Positional(usize),
+    Named(&'a str),
+}
+
+impl<'a> ParamKey<'a> {
+    pub(crate) fn parse(placeholder: &'a str) -> PolarsResult<Self> {
+        let (prefix, key) = placeholder.split_at(placeholder.len().min(1));
+        if key.is_empty() {
+            polars_bail!(SQLSyntax: "invalid query parameter '{}'", placeholder);
+        }
+        Ok(match key.parse::<usize>() {
+            Ok(0) => {
+                polars_bail!(SQLSyntax: "query parameters are numbered from 1 (found '{}')", placeholder)
+            },
+            Ok(n) if matches!(prefix, "$" | "?" | ":") => Self::Positional(n - 1),
+            _ => Self::Named(key),
+        })
+    }
+}
+
+impl BoundParams {
+    pub(crate) fn get(&self, placeholder: &str) -> PolarsResult<Expr> {
+        let value = match ParamKey::parse(placeholder)? {
+            ParamKey::Positional(idx) => self.positional.get(idx),
+            ParamKey::Named(name) => self.named.get(name),
+        };
+        value.cloned().ok_or_else(
+            || polars_err!(SQLInterface: "no value bound for query parameter '{}'", placeholder),
+        )
+    }
+}
+
+/// A statement registered with `PREPARE`.
+#[derive(Clone)]
+pub(crate) struct PreparedStatement {
+    pub(crate) statement: Box<Statement>,
+    /// Declared parameter types; the values are cast to these on each `EXECUTE`.
+    pub(crate) dtypes: Option<Vec<DataType>>,
+    /// Plan of the statement, if it is a query that can be converted ahead of execution.
+    pub(crate) plan: Option<PreparedPlan>,
+}
+
+/// Converted plan of a prepared query.
+///
+/// While converting, the tables the query reads are wrapped in cache nodes; on `EXECUTE`
+/// these are replaced by the tables as they are registered at that time. The plan is stale
+/// (and has to be converted again) once one of them is gone or has a different schema.
+#[derive(Clone)]
+pub(crate) struct PreparedPlan {
+    pub(crate) plan: DslPlan,
+    /// Name of the table behind every stand-in cache node.
+    pub(crate) tables: PlHashMap<UniqueId, String>,
+    /// Parameter values the plan was converted with, `None` if it has typed placeholders
+    /// (declared parameter types) that take any values.
+    pub(crate) values: Option<Vec<Expr>>,
+}
+
+impl PreparedPlan {
+    /// Substitute the parameter values and the current tables into the plan. Returns
+    /// `None` if the plan can't be used for these values or tables.
+    pub(crate) fn bind(
+        &self,
+        values: &[Expr],
+        tables: &PlHashMap<String, LazyFrame>,
+    ) -> PolarsResult<Option<LazyFrame>> {
+        if self.values.as_deref().is_some_and(|v| v != values) {
+            return Ok(None);
+        }
+        let mut binder = PlanBinder {
+            values,
+            stand_ins: &self.tables,
+            tables,
+            stale: false,
+        };
+        let plan = binder.bind_plan(&self.plan)?;
+        Ok((!binder.stale).then(|| LazyFrame::from(plan)))
+    }
+}
+
+const PARAM_PREFIX: &str = "__PL_SQL_PARAM_";
+
+/// Typed stand-in for a parameter in a prepared plan.
+///
+/// The (inner) alias identifies the parameter; the outer one gives the marker the output
+/// name of the literal it is replaced by, so that the rest of the plan refers to the same
+/// column names before and after binding.
+pub(crate) fn param_marker(idx: usize, dtype: &DataType) -> Expr {
+    lit(NULL)
+        .cast(dtype.clone())
+        .alias(format_pl_smallstr!("{PARAM_PREFIX}{idx}"))
+        .alias(PlSmallStr::from_static("literal"))
+}
+
+/// Number the `?` placeholders in order of appearance (as `?1`, `?2`, ...) so that
+/// they bind to positional parameters independently of the order of conversion.
+pub(crate) fn number_anonymous_placeholders(tokens: &mut [TokenWithSpan]) {
+    let mut n = 0;
+    for tok in tokens.iter_mut() {
+        if let Token::Placeholder(p) = &mut tok.token {
+            if p == "?" {
+                n += 1;
+                *p = format!("?{n}");
+            }
+        }
+    }
+}
+
+struct PlanBinder<'a> {
+    values: &'a [Expr],
+    stand_ins: &'a PlHashMap<UniqueId, String>,
+    tables: &'a PlHashMap<String, LazyFrame>,
+    /// Set once a table is found to be gone or to have a different schema.
+    stale: bool,
+}
+
+impl PlanBinder<'_> {
+    fn bind_plan(&mut self, plan: &DslPlan) -> PolarsResult<DslPlan> {
+        use DslPlan::*;
+        Ok(match plan {
+            Filter { input, predicate } => Filter {
+                input: self.bind_input(input)?,
+                predicate: self.bind_expr(predicate)?,
+            },
+            Cache { input, id } => match self.stand_ins.get(id) {
+                Some(name) => self.bind_table(name, input),
+                None => Cache {
+                    input: self.bind_input(input)?,
+                    id: *id,
+                },
+            },
+            Scan { .. } | DataFrameScan { .. } => plan.clone(),
+            Select {
+                expr,
+                input,
+                options,
+            } => Select {
+                expr: self.bind_exprs(expr)?,
+                input: self.bind_input(input)?,
+                options: *options,
+            },
+            GroupBy {
+                input,
+                keys,
+                aggs,
+                maintain_order,
+                options,
+                apply,
+            } => GroupBy {
+                input: self.bind_input(input)?,
+                keys: self.bind_exprs(keys)?,
+                aggs: self.bind_exprs(aggs)?,
+                maintain_order: *maintain_order,
+                options: options.clone(),
+                apply: apply.clone(),
+            },
+            Join {
+                input_left,
+                input_right,
+                left_on,
+                right_on,
+                predicates,
+                options,
+            } => Join {
+                input_left: self.bind_input(input_left)?,
+                input_right: self.bind_input(input_right)?,
+                left_on: self.bind_exprs(left_on)?,
+                right_on: self.bind_exprs(right_on)?,
+                predicates: self.bind_exprs(predicates)?,
+                options: options.clone(),
+            },
+            HStack {
+                input,
+                exprs,
+                options,
+            } => HStack {
+                input: self.bind_input(input)?,
+                exprs: self.bind_exprs(exprs)?,
+                options: *options,
+            },
+            MatchToSchema {
+                input,
+                match_schema,
+                per_column,
+                extra_columns,
+            } => MatchToSchema {
+                input: self.bind_input(input)?,
+                match_schema: match_schema.clone(),
+                per_column: per_column.clone(),
+                extra_columns: *extra_columns,
+            },
+            PipeWithSchema { input, callback } => PipeWithSchema {
+                input: self.bind_input(input)?,
+                callback: callback.clone(),
+            },
+            Distinct { input, options } => Distinct {
+                input: self.bind_input(input)?,
+                options: options.clone(),
+            },
+            Sort {
+                input,
+                by_column,
+                slice,
+                sort_options,
+            } => Sort {
+                input: self.bind_input(input)?,
+                by_column: self.bind_exprs(by_column)?,
+                slice: *slice,
+                sort_options: sort_options.clone(),
+            },
+            Slice { input, offset, len } => Slice {
+                input: self.bind_input(input)?,
+                offset: *offset,
+                len: *len,
+            },
+            MapFunction { input, function } => MapFunction {
+                input: self.bind_input(input)?,
+                function: self.bind_function(function)?,
+            },
+            Union { inputs, args } => Union {
+                inputs: self.bind_inputs(inputs)?,
+                args: *args,
+            },
+            HConcat { inputs, options } => HConcat {
+                inputs: self.bind_inputs(inputs)?,
+                options: *options,
+            },
+            ExtContext { input, contexts } => ExtContext {
+                input: self.bind_input(input)?,
+                contexts: self.bind_inputs(contexts)?,
+            },
+            Sink { input, payload } => Sink {
+                input: self.bind_input(input)?,
+                payload: payload.clone(),
+            },
+            SinkMultiple { inputs } => SinkMultiple {
+                inputs: self.bind_inputs(inputs)?,
+            },
+            #[cfg(feature = "merge_sorted")]
+            MergeSorted {
+                input_left,
+                input_right,
+                key,
+            } => MergeSorted {
+                input_left: self.bind_input(input_left)?,
+                input_right: self.bind_input(input_right)?,
+                key: key.clone(),
+            },
+            // drop any cached conversion; the plan has changed
+            IR { dsl, .. } => self.bind_plan(dsl)?,
+            // SQL only reads these through registered tables, which are substituted whole
+            #[allow(unreachable_patterns)]
+            _ => polars_bail!(
+                SQLInterface: "cannot bind the parameters of this prepared statement"
+            ),
+        })
+    }
+
+    fn bind_input(&mut self, input: &Arc<DslPlan>) -> PolarsResult<Arc<DslPlan>> {
+        Ok(Arc::new(self.bind_plan(input)?))
+    }
+
+    fn bind_inputs(&mut self, inputs: &[DslPlan]) -> PolarsResult<Vec<DslPlan>> {
+        inputs.iter().map(|p| self.bind_plan(p)).collect()
+    }
+
+    fn bind_table(&mut self, name: &str, converted: &DslPlan) -> DslPlan {
+        let schema = |plan: &DslPlan| LazyFrame::from(plan.clone()).collect_schema().ok();
+        match self.tables.get(name) {
+            Some(table)
+                if schema(converted).is_some_and(|s| Some(s) == schema(&table.logical_plan)) =>
+            {
+                table.logical_plan.clone()
+            },
+            _ => {
+                self.stale = true;
+                converted.clone()
+            },
+        }
+    }
+
+    fn bind_function(&mut self, function: &DslFunction) -> PolarsResult<DslFunction> {
+        Ok(match function {
+            DslFunction::FillNan(value) => DslFunction::FillNan(self.bind_expr(value)?),
+            DslFunction::Stats(StatsFunction::Quantile { quantile, method }) => {
+                DslFunction::Stats(StatsFunction::Quantile {
+                    quantile: self.bind_expr(quantile)?,
+                    method: *method,
+                })
+            },
+            // the other functions take no expressions
+            function => function.clone(),
+        })
+    }
+
+    fn bind_exprs(&mut self, exprs: &[Expr]) -> PolarsResult<Vec<Expr>> {
+        exprs.iter().map(|e| self.bind_expr(e)).collect()
+    }
+
+    fn bind_expr(&mut self, expr: &Expr) -> PolarsResult<Expr> {
+        expr.clone().try_map_expr(|e| match e {
+            Expr::Alias(inner, name) => match name
+                .strip_prefix(PARAM_PREFIX)
+                .and_then(|idx| idx.parse::<usize>().ok())
+            {
+                Some(idx) => self.values.get(idx).cloned().ok_or_else(
+                    || polars_err!(SQLInterface: "no value bound for query parameter ${}", idx + 1),
+                ),
+                None => Ok(Expr::Alias(inner, name)),
+            },
+            Expr::SubPlan(plan, names) => Ok(Expr::SubPlan(
+                SpecialEq::new(Arc::new(self.bind_plan(&plan)?)),
+                names,
+            )),
+            e => Ok(e),
+        })
+    }
+}
@@ -6,6 +6,7 @@
 //! - all Polars SQL keywords [`all_keywords`]
 //! - all of polars SQL functions [`all_functions`]
 
+use std::borrow::Cow;
 use std::fmt::Display;
 use std::ops::Div;
 
@@ -122,8 +123,20 @@ impl SQLExprVisitor<'_> {
                 negated,
             } => {
                 let expr = self.visit_expr(expr)?;
-                let elems = self.visit_array_expr(list, true, Some(&expr))?;
-                let is_in = expr.is_in(elems, false);
+                let is_in = if list
+                    .iter()
+                    .any(|e| matches!(e, SQLExpr::Value(SQLValue::Placeholder(_))))
+                {
+                    // query parameters are bound as expressions, not as array values
+                    let mut is_in = lit(false);
+                    for e in list {
+                        is_in = is_in.or(expr.clone().eq(self.visit_expr(e)?));
+                    }
+                    is_in
+                } else {
+                    let elems = self.visit_array_expr(list, true, Some(&expr))?;
+                    expr.is_in(elems, false)
+                };
                 Ok(if *negated { is_in.not() } else { is_in })
             },
             SQLExpr::InSubquery {
@@ -131,7 +144,7 @@ impl SQLExprVisitor<'_> {
                 subquery,
                 negated,
             } => self.visit_in_subquery(expr, subquery, *negated),
-            SQLExpr::Interval(interval) => Ok(lit(interval_to_duration(interval, true)?)),
+            SQLExpr::Interval(interval) => interval_to_lit(self.bind_interval(interval)?.as_ref()),
             SQLExpr::IsDistinctFrom(e1, e2) => {
                 Ok(self.visit_expr(e1)?.neq_missing(self.visit_expr(e2)?))
             },
@@ -457,6 +470,21 @@ impl SQLExprVisitor<'_> {
         Ok(expr)
     }
 
+    /// Bind a query parameter used as the interval string (e.g. "INTERVAL $1").
+    fn bind_interval<'i>(&self, interval: &'i Interval) -> PolarsResult<Cow<'i, Interval>> {
+        let SQLExpr::Value(SQLValue::Placeholder(p)) = &*interval.value else {
+            return Ok(Cow::Borrowed(interval));
+        };
+        let value = self.ctx.resolve_placeholder_value(p, "INTERVAL")?;
+        let Some(s) = value.extract_str() else {
+            polars_bail!(SQLSyntax: "INTERVAL parameter '{}' must be a string", p)
+        };
+        Ok(Cow::Owned(Interval {
+            value: Box::new(SQLExpr::Value(SQLValue::SingleQuotedString(s.to_string()))),
+            ..interval.clone()
+        }))
+    }
+
     /// Visit a SQL binary operator.
     ///
     /// e.g. "column + 1", "column1 <= column2"
@@ -469,14 +497,14 @@ impl SQLExprVisitor<'_> {
         // need special handling for interval offsets and comparisons
         let (lhs, mut rhs) = match (left, op, right) {
             (_, SQLBinaryOperator::Minus, SQLExpr::Interval(v)) => {
-                let duration = interval_to_duration(v, false)?;
+                let duration = interval_to_duration(self.bind_interval(v)?.as_ref(), false)?;
                 return Ok(self
                     .visit_expr(left)?
                     .dt()
                     .offset_by(lit(format!("-{duration}"))));
             },
             (_, SQLBinaryOperator::Plus, SQLExpr::Interval(v)) => {
-                let duration = interval_to_duration(v, false)?;
+                let duration = interval_to_duration(self.bind_interval(v)?.as_ref(), false)?;
                 return Ok(self
                     .visit_expr(left)?
                     .dt()
@@ -484,8 +512,8 @@ impl SQLExprVisitor<'_> {
             },
             (SQLExpr::Interval(v1), _, SQLExpr::Interval(v2)) => {
                 // shortcut interval comparison evaluation (-> bool)
-                let d1 = interval_to_duration(v1, false)?;
-                let d2 = interval_to_duration(v2, false)?;
+                let d1 = interval_to_duration(self.bind_interval(v1)?.as_ref(), false)?;
+                let d2 = interval_to_duration(self.bind_interval(v2)?.as_ref(), false)?;
                 let res = match op {
                     SQLBinaryOperator::Gt => Ok(lit(d1 > d2)),
                     SQLBinaryOperator::Lt => Ok(lit(d1 < d2)),
@@ -804,6 +832,7 @@ impl SQLExprVisitor<'_> {
                 lit(hex::decode(x.clone()).unwrap())
             },
             SQLValue::Null => Expr::Literal(LiteralValue::untyped_null()),
+            SQLValue::Placeholder(p) => self.ctx.resolve_placeholder(p)?,
             SQLValue::Number(s, _) => {
                 // Check for existence of decimal separator dot
                 if s.contains('.') {
@@ -1080,6 +1109,20 @@ pub fn sql_expr<S: AsRef<str>>(s: S) -> PolarsResult<Expr> {
     })
 }
 
+/// Convert a standalone SQL interval into a literal; with the `dtype-interval` feature,
+/// intervals with years, quarters or months become an `Interval` (rather than raising).
+fn interval_to_lit(interval: &Interval) -> PolarsResult<Expr> {
+    #[cfg(feature = "dtype-interval")]
+    {
+        let duration = interval_to_duration(interval, false)?;
+        if duration.months() != 0 {
+            let v = duration.to_interval()?;
+            return Ok(lit(Scalar::new_interval(v.months(), v.days(), v.ns())));
+        }
+    }
+    Ok(lit(interval_to_duration(interval, true)?))
+}
+
 pub(crate) fn interval_to_duration(interval: &Interval, fixed: bool) -> PolarsResult<Duration> {
     if interval.last_field.is_some()
         || interval.leading_field.is_some()
@@ -56,6 +56,47 @@ fn timeunit_from_precision(prec: &Option<u64>) -> PolarsResult<TimeUnit> {
     })
 }
 
+/// Map a Polars datatype to the name of the equivalent SQL type (as reported by
+/// `DESCRIBE` and `information_schema.columns`).
+pub(crate) fn map_polars_dtype_to_sql(dtype: &DataType) -> String {
+    match dtype {
+        DataType::Boolean => "BOOLEAN".into(),
+        DataType::Int8 => "TINYINT".into(),
+        DataType::Int16 => "SMALLINT".into(),
+        DataType::Int32 => "INTEGER".into(),
+        DataType::Int64 => "BIGINT".into(),
+        DataType::Int128 => "HUGEINT".into(),
+        DataType::UInt8 => "UTINYINT".into(),
+        DataType::UInt16 => "USMALLINT".into(),
+        DataType::UInt32 => "UINTEGER".into(),
+        DataType::UInt64 => "UBIGINT".into(),
+        DataType::UInt128 => "UHUGEINT".into(),
+        DataType::Float32 => "REAL".into(),
+        DataType::Float64 => "DOUBLE".into(),
+        #[cfg(feature = "dtype-decimal")]
+        DataType::Decimal(precision, scale) => format!("DECIMAL({precision},{scale})"),
+        DataType::String => "VARCHAR".into(),
+        DataType::Binary | DataType::BinaryOffset => "BINARY".into(),
+        DataType::Date => "DATE".into(),
+        DataType::Time => "TIME".into(),
+        DataType::Datetime(tu, tz) => {
+            let precision = match tu {
+                TimeUnit::Milliseconds => 3,
+                TimeUnit::Microseconds => 6,
+                TimeUnit::Nanoseconds => 9,
+            };
+            match tz {
+                Some(_) => format!("TIMESTAMP({precision}) WITH TIME ZONE"),
+                None => format!("TIMESTAMP({precision})"),
+            }
+        },
+        DataType::Duration(_) => "INTERVAL".into(),
+        DataType::List(inner) => format!("{}[]", map_polars_dtype_to_sql(inner)),
+        DataType::Null => "NULL".into(),
+        dt => dt.to_string().to_uppercase(),
+    }
+}
+
 pub(crate) fn map_sql_dtype_to_polars(dtype: &SQLDataType) -> PolarsResult<DataType> {
     Ok(match dtype {
         // ---------------------------------
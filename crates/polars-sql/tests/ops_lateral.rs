@@ -0,0 +1,203 @@
+use polars_core::df;
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+fn create_ctx() -> SQLContext {
+    let customers = df! {
+        "id" => [1, 2, 3],
+        "name" => ["alice", "bob", "carol"],
+    }
+    .unwrap();
+    let orders = df! {
+        "customer_id" => [1, 1, 2, 1],
+        "amount" => [10, 20, 5, 30],
+    }
+    .unwrap();
+    let tags = DataFrame::new(vec![
+        Column::new("id".into(), [1, 2, 3]),
+        Series::new(
+            "tags".into(),
+            [
+                Series::new("".into(), ["x", "y"]),
+                Series::new("".into(), Vec::<&str>::new()),
+                Series::new("".into(), ["z"]),
+            ],
+        )
+        .into(),
+    ])
+    .unwrap();
+
+    let mut ctx = SQLContext::new();
+    ctx.register("customers", customers.lazy());
+    ctx.register("orders", orders.lazy());
+    ctx.register("tags", tags.lazy());
+    ctx
+}
+
+#[test]
+fn test_unnest_with_ordinality() {
+    let mut ctx = create_ctx();
+    let sql = "SELECT * FROM UNNEST(['a','b','c']) WITH ORDINALITY AS t (val, pos)";
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "val" => ["a", "b", "c"],
+        "pos" => [1i64, 2, 3],
+    }
+    .unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+
+    let sql = "SELECT * FROM UNNEST(['a','b']) AS t (val) WITH OFFSET AS idx";
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "val" => ["a", "b"],
+        "idx" => [0i64, 1],
+    }
+    .unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+}
+
+#[test]
+fn test_cross_join_unnest_column() {
+    let mut ctx = create_ctx();
+    let sql = r#"
+        SELECT tags.id, u.tag, u.n
+        FROM tags
+        CROSS JOIN UNNEST(tags.tags) WITH ORDINALITY AS u (tag, n)
+        ORDER BY tags.id, u.n
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "id" => [1, 1, 3],
+        "tag" => ["x", "y", "z"],
+        "n" => [1i64, 2, 1],
+    }
+    .unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+}
+
+#[test]
+fn test_left_join_unnest_keeps_empty() {
+    let mut ctx = create_ctx();
+    let sql = r#"
+        SELECT tags.id, u.tag
+        FROM tags
+        LEFT JOIN UNNEST(tags.tags) AS u (tag) ON TRUE
+        ORDER BY tags.id, u.tag
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "id" => [1, 1, 2, 3],
+        "tag" => [Some("x"), Some("y"), None, Some("z")],
+    }
+    .unwrap();
+    assert!(
+        actual.equals_missing(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+}
+
+#[test]
+fn test_cross_join_lateral_aggregate() {
+    let mut ctx = create_ctx();
+    let sql = r#"
+        SELECT c.name, o.total, o.n
+        FROM customers c
+        CROSS JOIN LATERAL (
+          SELECT SUM(amount) AS total, COUNT(*) AS n
+          FROM orders
+          WHERE orders.customer_id = c.id
+        ) AS o
+        ORDER BY c.name
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    assert_eq!(
+        actual
+            .column("total")
+            .unwrap()
+            .cast(&DataType::Int64)
+            .unwrap()
+            .i64()
+            .unwrap()
+            .into_iter()
+            .collect::<Vec<_>>(),
+        [Some(60), Some(5), None]
+    );
+    assert_eq!(
+        actual
+            .column("n")
+            .unwrap()
+            .cast(&DataType::Int64)
+            .unwrap()
+            .i64()
+            .unwrap()
+            .into_iter()
+            .collect::<Vec<_>>(),
+        [Some(3), Some(1), Some(0)]
+    );
+}
+
+#[test]
+fn test_join_lateral_filtered_rows() {
+    let mut ctx = create_ctx();
+    let sql = r#"
+        SELECT c.name, o.amount
+        FROM customers c
+        LEFT JOIN LATERAL (
+          SELECT amount FROM orders
+          WHERE orders.customer_id = c.id AND amount >= 10
+        ) AS o ON TRUE
+        ORDER BY c.name, o.amount
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "name" => ["alice", "alice", "alice", "bob", "carol"],
+        "amount" => [Some(10), Some(20), Some(30), None, None],
+    }
+    .unwrap();
+    assert!(
+        actual.equals_missing(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+}
+
+#[test]
+fn test_lateral_projection() {
+    let mut ctx = create_ctx();
+    let sql = r#"
+        SELECT c.id, x.doubled
+        FROM customers c
+        CROSS JOIN LATERAL (SELECT c.id * 2 AS doubled) AS x
+        ORDER BY c.id
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "id" => [1, 2, 3],
+        "doubled" => [2, 4, 6],
+    }
+    .unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+}
+
+#[test]
+fn test_lateral_unsupported_join_type() {
+    let mut ctx = create_ctx();
+    let sql = r#"
+        SELECT * FROM customers c
+        JOIN LATERAL (SELECT amount FROM orders WHERE orders.customer_id = c.id) AS o
+          ON o.amount > 10
+    "#;
+    assert!(ctx.execute(sql).is_err());
+}
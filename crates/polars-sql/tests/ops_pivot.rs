@@ -0,0 +1,128 @@
+#![cfg(feature = "pivot")]
+
+use polars_core::df;
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+fn create_ctx() -> SQLContext {
+    let sales = df! {
+        "region" => ["east", "east", "west", "west", "west", "north"],
+        "quarter" => ["Q1", "Q2", "Q1", "Q1", "Q2", "Q3"],
+        "amount" => [10, 20, 5, 7, 30, 1],
+    }
+    .unwrap();
+    let wide = df! {
+        "id" => [1, 2],
+        "jan" => [Some(100), Some(150)],
+        "feb" => [Some(200), None],
+    }
+    .unwrap();
+    let mut ctx = SQLContext::new();
+    ctx.register("sales", sales.lazy());
+    ctx.register("wide", wide.lazy());
+    ctx
+}
+
+#[test]
+fn test_pivot() {
+    let mut ctx = create_ctx();
+    let sql = r#"
+        SELECT * FROM sales
+        PIVOT (SUM(amount) FOR quarter IN ('Q1', 'Q2' AS second))
+        ORDER BY region
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "region" => ["east", "north", "west"],
+        "Q1" => [Some(10), None, Some(12)],
+        "second" => [Some(20), None, Some(30)],
+    }
+    .unwrap();
+    assert!(
+        actual.equals_missing(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+}
+
+#[test]
+fn test_pivot_multiple_aggregates_default_on_null() {
+    let mut ctx = create_ctx();
+    let sql = r#"
+        SELECT * FROM sales
+        PIVOT (SUM(amount) AS total, COUNT(*) AS n FOR quarter IN ('Q1') DEFAULT ON NULL (0))
+        ORDER BY region
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    assert_eq!(actual.get_column_names(), &["region", "Q1_total", "Q1_n"]);
+    let expected = df! {
+        "region" => ["east", "north", "west"],
+        "Q1_total" => [10, 0, 12],
+    }
+    .unwrap();
+    assert!(
+        actual
+            .select(["region", "Q1_total"])
+            .unwrap()
+            .equals_missing(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+    let counts = actual
+        .column("Q1_n")
+        .unwrap()
+        .cast(&DataType::Int64)
+        .unwrap();
+    assert_eq!(
+        counts.i64().unwrap().into_iter().collect::<Vec<_>>(),
+        [Some(1), Some(0), Some(2)]
+    );
+}
+
+#[test]
+fn test_pivot_requires_value_list() {
+    let mut ctx = create_ctx();
+    let sql = "SELECT * FROM sales PIVOT (SUM(amount) FOR quarter IN (ANY))";
+    assert!(ctx.execute(sql).is_err());
+}
+
+#[test]
+fn test_unpivot() {
+    let mut ctx = create_ctx();
+    let sql = r#"
+        SELECT * FROM wide
+        UNPIVOT (amount FOR month IN (jan, feb))
+        ORDER BY id, month
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "id" => [1, 1, 2],
+        "month" => ["feb", "jan", "jan"],
+        "amount" => [200, 100, 150],
+    }
+    .unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+}
+
+#[test]
+fn test_unpivot_with_alias() {
+    let mut ctx = create_ctx();
+    let sql = r#"
+        SELECT u.m, u.v FROM wide
+        UNPIVOT (v FOR m IN (jan, feb)) AS u
+        WHERE u.id = 1
+        ORDER BY u.m
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "m" => ["feb", "jan"],
+        "v" => [200, 100],
+    }
+    .unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+}
@@ -219,6 +219,43 @@ fn test_literal_exprs() {
     assert!(df_sql.equals_missing(&df_pl));
 }
 
+#[test]
+#[cfg(feature = "dtype-interval")]
+fn test_calendar_interval_literal() {
+    let df = df! {
+        "dt" => ["2024-01-31", "2023-12-15"],
+    }
+    .unwrap()
+    .lazy()
+    .select([col("dt").cast(DataType::Date)]);
+
+    let mut context = SQLContext::new();
+    context.register("frame", df);
+    let df_sql = context
+        .execute(
+            "
+        WITH t AS (SELECT dt, INTERVAL '1 month 2 days' AS iv FROM frame)
+        SELECT iv, dt + iv AS plus, dt - iv AS minus FROM t",
+        )
+        .unwrap()
+        .collect()
+        .unwrap();
+    assert_eq!(df_sql.column("iv").unwrap().dtype(), &DataType::Interval);
+
+    let df_sql = df_sql
+        .lazy()
+        .select([all().as_expr().cast(DataType::String)])
+        .collect()
+        .unwrap();
+    let expected = df! {
+        "iv" => ["1mo 2d", "1mo 2d"],
+        "plus" => ["2024-03-02", "2024-01-17"],
+        "minus" => ["2023-12-29", "2023-11-13"],
+    }
+    .unwrap();
+    assert!(df_sql.equals(&expected));
+}
+
 #[test]
 fn test_implicit_date_string() {
     let df = df! {
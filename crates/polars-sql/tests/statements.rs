@@ -601,3 +601,649 @@ fn test_compound_invalid_3() {
     let sql = "SELECT * FROM df1 INNER JOIN df2 ON df1.a = df2.a AND b";
     let _ = ctx.execute(sql).unwrap();
 }
+
+#[test]
+fn test_recursive_cte() {
+    let mut ctx = SQLContext::new();
+    let sql = r#"
+        WITH RECURSIVE seq(n) AS (
+            SELECT 1
+            UNION ALL
+            SELECT n + 1 FROM seq WHERE n < 5
+        )
+        SELECT n FROM seq
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! { "n" => [1, 2, 3, 4, 5] }.unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+}
+
+#[test]
+fn test_recursive_cte_hierarchy() {
+    let employees = df! {
+        "id" => [1, 2, 3, 4, 5],
+        "manager_id" => [None, Some(1), Some(1), Some(2), Some(4)],
+        "name" => ["ceo", "cto", "cfo", "dev", "intern"],
+    }
+    .unwrap();
+    let mut ctx = SQLContext::new();
+    ctx.register("employees", employees.lazy());
+
+    let sql = r#"
+        WITH RECURSIVE chain AS (
+            SELECT id, name, 0 AS depth FROM employees WHERE manager_id IS NULL
+            UNION ALL
+            SELECT e.id, e.name, c.depth + 1
+            FROM employees e
+            INNER JOIN chain c ON e.manager_id = c.id
+        )
+        SELECT name, depth FROM chain ORDER BY id
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "name" => ["ceo", "cto", "cfo", "dev", "intern"],
+        "depth" => [0, 1, 1, 2, 3],
+    }
+    .unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+}
+
+#[test]
+fn test_recursive_cte_union_distinct_cycle() {
+    let edges = df! {
+        "src" => [1, 2, 3],
+        "dst" => [2, 3, 1],
+    }
+    .unwrap();
+    let mut ctx = SQLContext::new();
+    ctx.register("edges", edges.lazy());
+
+    // the graph is cyclic; UNION (DISTINCT) terminates once no new nodes are reached
+    let sql = r#"
+        WITH RECURSIVE reachable(node) AS (
+            SELECT 1
+            UNION
+            SELECT edges.dst FROM edges INNER JOIN reachable ON edges.src = reachable.node
+        )
+        SELECT node FROM reachable ORDER BY node
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! { "node" => [1, 2, 3] }.unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+}
+
+#[test]
+fn test_recursive_cte_max_iterations() {
+    let mut ctx = SQLContext::new().with_max_recursion_iterations(10);
+    let sql = r#"
+        WITH RECURSIVE seq(n) AS (
+            SELECT 1
+            UNION ALL
+            SELECT n + 1 FROM seq
+        )
+        SELECT n FROM seq
+    "#;
+    assert!(ctx.execute(sql).is_err());
+}
+
+fn create_dml_ctx() -> SQLContext {
+    let inventory = df! {
+        "product" => ["apple", "pear", "plum"],
+        "qty" => [10, 5, 0],
+    }
+    .unwrap();
+    let mut ctx = SQLContext::new();
+    ctx.register("inventory", inventory.lazy());
+    ctx
+}
+
+#[test]
+fn test_insert_into_values() {
+    let mut ctx = create_dml_ctx();
+    let _ = ctx
+        .execute("INSERT INTO inventory VALUES ('kiwi', 3), ('lime', 4)")
+        .unwrap();
+    let _ = ctx
+        .execute("INSERT INTO inventory (product) VALUES ('fig')")
+        .unwrap();
+
+    let actual = ctx
+        .execute("SELECT * FROM inventory")
+        .unwrap()
+        .collect()
+        .unwrap();
+    let expected = df! {
+        "product" => ["apple", "pear", "plum", "kiwi", "lime", "fig"],
+        "qty" => [Some(10), Some(5), Some(0), Some(3), Some(4), None],
+    }
+    .unwrap();
+    assert!(
+        actual.equals_missing(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+}
+
+#[test]
+fn test_insert_into_select() {
+    let mut ctx = create_dml_ctx();
+    let _ = ctx
+        .execute(
+            "INSERT INTO inventory SELECT product || '2', qty * 2 FROM inventory WHERE qty > 0",
+        )
+        .unwrap();
+
+    let actual = ctx
+        .execute("SELECT * FROM inventory")
+        .unwrap()
+        .collect()
+        .unwrap();
+    let expected = df! {
+        "product" => ["apple", "pear", "plum", "apple2", "pear2"],
+        "qty" => [10, 5, 0, 20, 10],
+    }
+    .unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+    assert!(
+        ctx.execute("INSERT INTO inventory VALUES (1, 2, 3)")
+            .is_err()
+    );
+    assert!(ctx.execute("INSERT INTO missing VALUES (1)").is_err());
+}
+
+#[test]
+fn test_update() {
+    let mut ctx = create_dml_ctx();
+    let _ = ctx
+        .execute("UPDATE inventory SET qty = qty + 1, product = UPPER(product) WHERE qty < 10")
+        .unwrap();
+
+    let actual = ctx
+        .execute("SELECT * FROM inventory")
+        .unwrap()
+        .collect()
+        .unwrap();
+    let expected = df! {
+        "product" => ["apple", "PEAR", "PLUM"],
+        "qty" => [10, 6, 1],
+    }
+    .unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+
+    let _ = ctx.execute("UPDATE inventory SET qty = 0").unwrap();
+    let actual = ctx
+        .execute("SELECT SUM(qty) AS total FROM inventory")
+        .unwrap()
+        .collect()
+        .unwrap();
+    assert_eq!(
+        actual.column("total").unwrap().get(0).unwrap(),
+        AnyValue::Int32(0)
+    );
+}
+
+#[test]
+fn test_merge_into() {
+    let mut ctx = create_dml_ctx();
+    let deliveries = df! {
+        "product" => ["pear", "plum", "kiwi", "lime"],
+        "qty" => [10, -5, 7, 0],
+    }
+    .unwrap();
+    ctx.register("deliveries", deliveries.lazy());
+
+    let sql = r#"
+        MERGE INTO inventory AS t
+        USING deliveries AS s
+        ON t.product = s.product
+        WHEN MATCHED AND t.qty + s.qty <= 0 THEN DELETE
+        WHEN MATCHED THEN UPDATE SET qty = t.qty + s.qty
+        WHEN NOT MATCHED AND s.qty > 0 THEN INSERT (product, qty) VALUES (s.product, s.qty)
+    "#;
+    let _ = ctx.execute(sql).unwrap();
+
+    let actual = ctx
+        .execute("SELECT * FROM inventory ORDER BY product")
+        .unwrap()
+        .collect()
+        .unwrap();
+    let expected = df! {
+        "product" => ["apple", "kiwi", "pear"],
+        "qty" => [10, 7, 15],
+    }
+    .unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+}
+
+#[test]
+fn test_merge_into_multiple_matches() {
+    let mut ctx = create_dml_ctx();
+    let deliveries = df! {
+        "product" => ["pear", "pear", "kiwi"],
+        "qty" => [1, 2, 3],
+    }
+    .unwrap();
+    ctx.register("deliveries", deliveries.lazy());
+
+    // two source rows match the same target row
+    let sql = r#"
+        MERGE INTO inventory AS t
+        USING deliveries AS s
+        ON t.product = s.product
+        WHEN MATCHED THEN UPDATE SET qty = t.qty + s.qty
+    "#;
+    let res = ctx.execute(sql).unwrap().collect();
+    assert!(res.is_err());
+    assert!(
+        res.unwrap_err()
+            .to_string()
+            .contains("matched by multiple source rows")
+    );
+
+    // that is fine if none of the matches modify the target row
+    let mut ctx = create_dml_ctx();
+    ctx.register(
+        "deliveries",
+        df! { "product" => ["pear", "pear", "kiwi"], "qty" => [1, 2, 3] }
+            .unwrap()
+            .lazy(),
+    );
+    let sql = r#"
+        MERGE INTO inventory AS t
+        USING deliveries AS s
+        ON t.product = s.product
+        WHEN NOT MATCHED THEN INSERT (product, qty) VALUES (s.product, s.qty)
+    "#;
+    let _ = ctx.execute(sql).unwrap();
+    let actual = ctx
+        .execute("SELECT * FROM inventory ORDER BY product")
+        .unwrap()
+        .collect()
+        .unwrap();
+    let expected = df! {
+        "product" => ["apple", "kiwi", "pear", "plum"],
+        "qty" => [10, 3, 5, 0],
+    }
+    .unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+}
+
+fn create_params_ctx() -> SQLContext {
+    let df = df! {
+        "a" => [1, 2, 3, 4],
+        "b" => ["w", "x", "y", "z"],
+    }
+    .unwrap();
+    let mut ctx = SQLContext::new();
+    ctx.register("df", df.lazy());
+    ctx
+}
+
+#[test]
+fn test_execute_with_params() {
+    let mut ctx = create_params_ctx();
+    let expected = df! { "a" => [20, 30], "b" => ["x", "y"] }.unwrap();
+
+    for (sql, params) in [
+        (
+            "SELECT a * $3 AS a, b FROM df WHERE a BETWEEN $1 AND $2 ORDER BY a",
+            SQLParams::positional([2, 3, 10]),
+        ),
+        (
+            // anonymous parameters are numbered in order of appearance
+            "SELECT a * ? AS a, b FROM df WHERE a >= ? AND b <> ? ORDER BY a",
+            SQLParams::new()
+                .with_value(10)
+                .with_value(2)
+                .with_value(PlSmallStr::from("z")),
+        ),
+        (
+            "SELECT a * :mult AS a, b FROM df WHERE b IN (:b1, :b2) ORDER BY a",
+            SQLParams::new()
+                .with_named("mult", 10)
+                .with_named("b1", PlSmallStr::from("x"))
+                .with_named("b2", PlSmallStr::from("y")),
+        ),
+    ] {
+        let actual = ctx
+            .execute_with_params(sql, params)
+            .unwrap()
+            .collect()
+            .unwrap();
+        assert!(
+            actual.equals(&expected),
+            "{sql}\nexpected = {expected:?}\nactual={actual:?}"
+        );
+    }
+
+    // unbound parameters raise an error
+    assert!(ctx.execute("SELECT * FROM df WHERE a = $1").is_err());
+    assert!(
+        ctx.execute_with_params("SELECT * FROM df WHERE a = $2", SQLParams::positional([1]))
+            .is_err()
+    );
+}
+
+#[test]
+fn test_prepare_execute() {
+    let mut ctx = create_params_ctx();
+    let _ = ctx
+        .execute("PREPARE q(INT, TEXT) AS SELECT a FROM df WHERE a > $1 AND b <> $2 ORDER BY a")
+        .unwrap();
+
+    let actual = ctx.execute("EXECUTE q(1, 'z')").unwrap().collect().unwrap();
+    let expected = df! { "a" => [2, 3] }.unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+
+    let actual = ctx
+        .execute_prepared(
+            "q",
+            SQLParams::positional([0]).with_value(PlSmallStr::from("w")),
+        )
+        .unwrap()
+        .collect()
+        .unwrap();
+    let expected = df! { "a" => [2, 3, 4] }.unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+
+    // parameters are cast to the declared types
+    assert!(
+        ctx.execute("EXECUTE q('not-an-int', 'z')")
+            .unwrap()
+            .collect()
+            .is_err()
+    );
+    assert!(ctx.execute("EXECUTE q(1)").is_err());
+
+    // without declared types, the statement is converted once the values are known
+    let _ = ctx
+        .execute("PREPARE r AS SELECT b FROM df WHERE a = $1")
+        .unwrap();
+    let actual = ctx.execute("EXECUTE r(4)").unwrap().collect().unwrap();
+    let expected = df! { "b" => ["z"] }.unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+
+    let _ = ctx.execute("DEALLOCATE q").unwrap();
+    assert!(ctx.execute("EXECUTE q(1, 'z')").is_err());
+}
+
+#[test]
+fn test_prepared_reads_current_tables() {
+    let mut ctx = create_params_ctx();
+    let _ = ctx
+        .execute("PREPARE q(INT) AS SELECT a FROM df WHERE a > $1 ORDER BY a")
+        .unwrap();
+    let _ = ctx
+        .execute("PREPARE r AS SELECT a FROM df WHERE a > $1 ORDER BY a")
+        .unwrap();
+    for name in ["q", "r"] {
+        let actual = ctx
+            .execute(&format!("EXECUTE {name}(2)"))
+            .unwrap()
+            .collect()
+            .unwrap();
+        let expected = df! { "a" => [3, 4] }.unwrap();
+        assert!(
+            actual.equals(&expected),
+            "expected = {expected:?}\nactual={actual:?}"
+        );
+    }
+
+    // the plans pick up a table registered after PREPARE
+    let df = df! { "a" => [5, 1, 6], "b" => ["u", "v", "w"] }.unwrap();
+    ctx.register("df", df.lazy());
+    for name in ["q", "r"] {
+        let actual = ctx
+            .execute(&format!("EXECUTE {name}(2)"))
+            .unwrap()
+            .collect()
+            .unwrap();
+        let expected = df! { "a" => [5, 6] }.unwrap();
+        assert!(
+            actual.equals(&expected),
+            "expected = {expected:?}\nactual={actual:?}"
+        );
+    }
+
+    // ... and are converted again if its schema changed
+    let df = df! { "a" => [1.5, 2.5, 3.5] }.unwrap();
+    ctx.register("df", df.lazy());
+    for name in ["q", "r"] {
+        let actual = ctx
+            .execute(&format!("EXECUTE {name}(2)"))
+            .unwrap()
+            .collect()
+            .unwrap();
+        let expected = df! { "a" => [2.5, 3.5] }.unwrap();
+        assert!(
+            actual.equals(&expected),
+            "expected = {expected:?}\nactual={actual:?}"
+        );
+    }
+
+    ctx.unregister("df");
+    assert!(ctx.execute("EXECUTE q(2)").is_err());
+    assert!(ctx.execute("EXECUTE r(2)").is_err());
+}
+
+#[test]
+fn test_prepared_params_in_subquery_and_recursive_cte() {
+    let mut ctx = create_params_ctx();
+    let _ = ctx
+        .execute(
+            "PREPARE q(INT) AS SELECT b FROM df WHERE a IN (SELECT a FROM df WHERE a > $1) ORDER BY b",
+        )
+        .unwrap();
+    let actual = ctx.execute("EXECUTE q(2)").unwrap().collect().unwrap();
+    let expected = df! { "b" => ["y", "z"] }.unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+
+    // a recursive CTE is evaluated while converting, so it binds its values on EXECUTE
+    let sql = r#"
+        WITH RECURSIVE seq(n) AS (
+            SELECT 1
+            UNION ALL
+            SELECT n + 1 FROM seq WHERE n < $1
+        )
+        SELECT n FROM seq
+    "#;
+    let _ = ctx.execute(&format!("PREPARE r(INT) AS {sql}")).unwrap();
+    let _ = ctx.execute(&format!("PREPARE s AS {sql}")).unwrap();
+    for (n, expected) in [(3, vec![1, 2, 3]), (5, vec![1, 2, 3, 4, 5])] {
+        for name in ["r", "s"] {
+            let actual = ctx
+                .execute(&format!("EXECUTE {name}({n})"))
+                .unwrap()
+                .collect()
+                .unwrap();
+            let expected = df! { "n" => &expected }.unwrap();
+            assert!(
+                actual.equals(&expected),
+                "expected = {expected:?}\nactual={actual:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_describe_and_show_columns() {
+    let mut ctx = create_params_ctx();
+    let expected = df! {
+        "column_name" => ["a", "b"],
+        "column_type" => ["INTEGER", "VARCHAR"],
+        "null" => ["YES", "YES"],
+    }
+    .unwrap();
+    for sql in [
+        "DESCRIBE df",
+        "SHOW COLUMNS FROM df",
+        "DESCRIBE SELECT * FROM df",
+    ] {
+        let actual = ctx.execute(sql).unwrap().collect().unwrap();
+        assert!(
+            actual.equals(&expected),
+            "{sql}\nexpected = {expected:?}\nactual={actual:?}"
+        );
+    }
+    let actual = ctx
+        .execute("SHOW COLUMNS FROM df LIKE 'b%'")
+        .unwrap()
+        .collect()
+        .unwrap();
+    assert_eq!(actual.height(), 1);
+    assert!(ctx.execute("DESCRIBE missing").is_err());
+}
+
+#[test]
+fn test_information_schema() {
+    let mut ctx = create_params_ctx();
+    let other = df! { "x" => [1.5], "y" => [true] }.unwrap();
+    ctx.register("other", other.lazy());
+
+    let actual = ctx
+        .execute("SELECT table_name, table_type FROM information_schema.tables ORDER BY table_name")
+        .unwrap()
+        .collect()
+        .unwrap();
+    let expected = df! {
+        "table_name" => ["df", "other"],
+        "table_type" => ["BASE TABLE", "BASE TABLE"],
+    }
+    .unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+
+    let actual = ctx
+        .execute(
+            r#"
+            SELECT c.column_name, c.ordinal_position, c.data_type, c.is_nullable
+            FROM information_schema.columns AS c
+            WHERE c.table_name = 'other'
+            ORDER BY c.ordinal_position
+            "#,
+        )
+        .unwrap()
+        .collect()
+        .unwrap();
+    let expected = df! {
+        "column_name" => ["x", "y"],
+        "ordinal_position" => [1i64, 2],
+        "data_type" => ["DOUBLE", "BOOLEAN"],
+        "is_nullable" => ["YES", "YES"],
+    }
+    .unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+    // the virtual tables are not registered
+    assert_eq!(ctx.get_tables(), ["df", "other"]);
+}
+
+#[test]
+fn test_prepared_param_names() {
+    let mut ctx = create_params_ctx();
+    let _ = ctx
+        .execute("PREPARE q(INT) AS SELECT * FROM (SELECT a, $1 FROM df) AS t ORDER BY a DESC")
+        .unwrap();
+
+    // an unaliased parameter is named like any other literal
+    let actual = ctx.execute("EXECUTE q(7)").unwrap().collect().unwrap();
+    let expected = ctx
+        .execute("SELECT * FROM (SELECT a, 7 FROM df) AS t ORDER BY a DESC")
+        .unwrap()
+        .collect()
+        .unwrap();
+    assert_eq!(actual.get_column_names(), ["a", "literal"]);
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+}
+
+#[test]
+fn test_params_limit_offset_interval() {
+    let mut ctx = create_params_ctx();
+    let expected = df! { "a" => [2, 3] }.unwrap();
+
+    let sql = "SELECT a FROM df ORDER BY a LIMIT $1 OFFSET $2";
+    let actual = ctx
+        .execute_with_params(sql, SQLParams::positional([2, 1]))
+        .unwrap()
+        .collect()
+        .unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+
+    // without declared types, these are bound on each EXECUTE
+    let _ = ctx.execute(&format!("PREPARE q AS {sql}")).unwrap();
+    let actual = ctx.execute("EXECUTE q(2, 1)").unwrap().collect().unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+
+    // with declared types the plan is built up front, which these parameters don't allow
+    let res = ctx.execute(&format!("PREPARE r(INT, INT) AS {sql}"));
+    assert!(res.is_err_and(|e| e.to_string().contains("LIMIT parameters are not supported")));
+    assert!(
+        ctx.execute_with_params(sql, SQLParams::positional([-1, 0]))
+            .is_err()
+    );
+
+    let sql = "SELECT CAST('2020-01-30' AS DATE) + INTERVAL $1 AS d";
+    let actual = ctx
+        .execute_with_params(sql, SQLParams::positional([PlSmallStr::from("3 days")]))
+        .unwrap()
+        .collect()
+        .unwrap();
+    let expected = ctx
+        .execute("SELECT CAST('2020-02-02' AS DATE) AS d")
+        .unwrap()
+        .collect()
+        .unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+    assert!(
+        ctx.execute("PREPARE s(TEXT) AS SELECT INTERVAL $1 AS i")
+            .is_err()
+    );
+}
@@ -1,9 +1,9 @@
 use polars_core::prelude::*;
-use polars_lazy::prelude::IntoLazy;
+use polars_lazy::prelude::{Expr, IntoLazy, lit};
 use polars_plan::dsl::BaseColumnUdf;
 use polars_plan::prelude::UserDefinedFunction;
 use polars_sql::SQLContext;
-use polars_sql::function_registry::FunctionRegistry;
+use polars_sql::function_registry::{DefaultFunctionRegistry, ExprFunction, FunctionRegistry};
 
 struct MyFunctionRegistry {
     functions: PlHashMap<String, UserDefinedFunction>,
@@ -115,3 +115,120 @@ fn test_udfs() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_default_registry_register_fn() -> PolarsResult<()> {
+    let mut registry = DefaultFunctionRegistry::default();
+    registry.register_fn("Add_Ten", DataType::Int64, |c: &mut [Column]| {
+        let s = c[0].as_materialized_series().cast(&DataType::Int64)?;
+        Ok(Column::from(s + 10))
+    })?;
+    registry.register_expr_function(
+        "double_it",
+        ExprFunction::new("double_it".into(), |args: Vec<Expr>| {
+            Ok(args[0].clone() * lit(2))
+        })
+        .with_return_dtype(DataType::Float64),
+    )?;
+    assert!(registry.contains("add_ten"));
+
+    let mut ctx = SQLContext::new().with_function_registry(Arc::new(registry));
+    ctx.register("df", df! { "a" => [1i32, 2, 3] }?.lazy());
+
+    let res = ctx
+        .execute("SELECT ADD_TEN(a) AS x, double_it(a) AS y FROM df")?
+        .collect()?;
+    let expected = df! {
+        "x" => [11i64, 12, 13],
+        "y" => [2.0, 4.0, 6.0],
+    }?;
+    assert!(
+        expected.equals(&res),
+        "expected = {expected:?}\nactual={res:?}"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_create_function() -> PolarsResult<()> {
+    let mut ctx = SQLContext::new();
+    ctx.register("df", df! { "a" => [1, 2, 3], "b" => [10, 20, 30] }?.lazy());
+
+    let _ =
+        ctx.execute("CREATE FUNCTION weighted(x INT, w DOUBLE) RETURNS DOUBLE AS 'x * w + 1'")?;
+    let res = ctx
+        .execute("SELECT weighted(a, b) AS w FROM df ORDER BY a")?
+        .collect()?;
+    let expected = df! { "w" => [11.0, 41.0, 91.0] }?;
+    assert!(
+        expected.equals(&res),
+        "expected = {expected:?}\nactual={res:?}"
+    );
+
+    // functions cannot be redefined unless replaced
+    assert!(
+        ctx.execute("CREATE FUNCTION weighted(x INT) AS 'x'")
+            .is_err()
+    );
+    let _ = ctx.execute("CREATE OR REPLACE FUNCTION weighted(x INT) RETURN x - 1")?;
+    let res = ctx.execute("SELECT weighted(b) AS w FROM df")?.collect()?;
+    let expected = df! { "w" => [9, 19, 29] }?;
+    assert!(
+        expected.equals(&res),
+        "expected = {expected:?}\nactual={res:?}"
+    );
+
+    // wrong number of arguments, unknown body references
+    assert!(ctx.execute("SELECT weighted(a, b) FROM df").is_err());
+    assert!(
+        ctx.execute("CREATE FUNCTION bad(x INT) AS 'x + y'")
+            .is_err()
+    );
+
+    let _ = ctx.execute("DROP FUNCTION weighted")?;
+    assert!(ctx.execute("SELECT weighted(a) FROM df").is_err());
+    assert!(ctx.execute("DROP FUNCTION weighted").is_err());
+    let _ = ctx.execute("DROP FUNCTION IF EXISTS weighted")?;
+    Ok(())
+}
+
+#[test]
+fn test_create_function_cloned_context() -> PolarsResult<()> {
+    let mut ctx = SQLContext::new();
+    ctx.register("df", df! { "a" => [1, 2, 3] }?.lazy());
+    let _ = ctx.execute("CREATE FUNCTION plus_one(x INT) AS 'x + 1'")?;
+
+    // a clone starts with the functions of the original, but doesn't share later changes
+    let mut other = ctx.clone();
+    let _ = other.execute("CREATE OR REPLACE FUNCTION plus_one(x INT) AS 'x + 10'")?;
+    let _ = other.execute("CREATE FUNCTION plus_two(x INT) AS 'x + 2'")?;
+    let res = ctx
+        .execute("SELECT plus_one(a) AS b FROM df ORDER BY a")?
+        .collect()?;
+    let expected = df! { "b" => [2, 3, 4] }?;
+    assert!(
+        expected.equals(&res),
+        "expected = {expected:?}\nactual={res:?}"
+    );
+    assert!(ctx.execute("SELECT plus_two(a) FROM df").is_err());
+
+    let _ = other.execute("DROP FUNCTION plus_one")?;
+    assert!(ctx.execute("SELECT plus_one(a) FROM df").is_ok());
+
+    // creating functions didn't touch the function registry, so it can still be mutated once
+    // the clone is gone
+    drop(other);
+    let identity = UserDefinedFunction::new(
+        "identity".into(),
+        BaseColumnUdf::new(
+            |c: &mut [Column]| Ok(c[0].clone()),
+            |_: &Schema, fs: &[Field]| Ok(fs[0].clone()),
+        ),
+    );
+    ctx.registry_mut().register("identity", identity)?;
+    assert!(ctx.registry().contains("identity"));
+
+    // registry functions cannot be dropped from SQL
+    assert!(ctx.execute("DROP FUNCTION identity").is_err());
+    Ok(())
+}
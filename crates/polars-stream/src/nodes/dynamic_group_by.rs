@@ -0,0 +1,500 @@
+use std::sync::Arc;
+
+use arrow::temporal_conversions::MICROSECONDS_IN_DAY;
+use polars_core::chunked_array::ops::row_encode::encode_rows_unordered;
+use polars_core::frame::DataFrame;
+use polars_core::prelude::{
+    BooleanChunked, Column, DataType, IdxSize, Int64Chunked, NewChunkedArray, PlHashMap, TimeUnit,
+};
+use polars_error::{PolarsResult, polars_ensure};
+use polars_plan::plans::DataFrameUdf;
+use polars_time::prelude::{ClosedWindow, Duration, DurationCalendars};
+use polars_utils::pl_str::PlSmallStr;
+
+use super::ComputeNode;
+use crate::async_executor::{JoinHandle, TaskPriority, TaskScope};
+use crate::execute::StreamingExecutionState;
+use crate::graph::PortState;
+use crate::morsel::{Morsel, MorselSeq, SourceToken};
+use crate::pipe::{RecvPort, SendPort};
+
+pub const LOWER_BOUNDARY_NAME: &str = "_lower_boundary";
+pub const UPPER_BOUNDARY_NAME: &str = "_upper_boundary";
+
+#[derive(Debug, Clone)]
+pub enum WindowKind {
+    /// `group_by_dynamic`, the in-memory map always produces the window boundaries.
+    Dynamic {
+        /// Whether the boundaries are part of the output.
+        include_boundaries: bool,
+        /// Set if the window grid starts at the first datapoint of every group.
+        grid: Option<WindowGrid>,
+    },
+    /// `group_by_session`, the in-memory map always produces the session boundaries.
+    Session {
+        /// Whether the boundaries are part of the output.
+        include_boundaries: bool,
+    },
+    /// `rolling`, every row `t` has the window `(t + offset, t + offset + period]`.
+    Rolling {
+        period: Duration,
+        offset: Duration,
+        time_unit: TimeUnit,
+    },
+}
+
+/// The window grid of a `group_by_dynamic` that starts at the first datapoint of every group,
+/// every window starts `every` after the previous one.
+#[derive(Debug, Clone)]
+pub struct WindowGrid {
+    pub every: Duration,
+    pub calendars: DurationCalendars,
+    pub time_unit: TimeUnit,
+}
+
+#[derive(Debug, Clone)]
+pub struct WindowGroupByParams {
+    pub index_column: PlSmallStr,
+    pub keys: Vec<PlSmallStr>,
+    pub closed_window: ClosedWindow,
+    /// Whether the aggregations carry in the last row from before a window, which then has to be
+    /// kept in the buffer.
+    pub carry_in: bool,
+    pub kind: WindowKind,
+}
+
+/// The buffered rows of a single group, or of all rows if there are no keys.
+#[derive(Default)]
+struct Partition {
+    buffer: DataFrame,
+    /// The largest index value of the group seen so far, in its physical representation.
+    watermark: Option<i64>,
+    /// The watermark at which windows were emitted last.
+    emitted_watermark: Option<i64>,
+    /// No pending window closes before the watermark passes this value, `None` if unknown.
+    next_close: Option<i64>,
+}
+
+/// Streaming `group_by_dynamic`, `group_by_session` and `rolling` over an index column that
+/// is sorted within every group.
+///
+/// Incoming rows are buffered per group. A window is complete as soon as the watermark (the
+/// largest index value of its group seen so far) has passed its upper boundary, as no future row
+/// can fall into it anymore. Once that happens for the earliest pending window of a group, the
+/// in-memory group-by is run over the buffered rows and the newly completed windows are emitted.
+/// Afterwards, rows that can no longer be part of a pending window are dropped from the buffer.
+pub struct DynamicGroupByNode {
+    params: WindowGroupByParams,
+    map: Arc<dyn DataFrameUdf>,
+
+    partitions: Vec<Partition>,
+    /// The partition of every group, by the row encoding of its keys.
+    partition_idx: PlHashMap<Vec<u8>, usize>,
+    seq: MorselSeq,
+}
+
+impl DynamicGroupByNode {
+    pub fn new(params: WindowGroupByParams, map: Arc<dyn DataFrameUdf>) -> Self {
+        Self {
+            params,
+            map,
+            partitions: Vec::new(),
+            partition_idx: PlHashMap::default(),
+            seq: MorselSeq::default(),
+        }
+    }
+
+    fn is_complete(&self, upper: i64, watermark: i64) -> bool {
+        match self.params.closed_window {
+            // A future row equal to the watermark can still fall into a right-closed window.
+            ClosedWindow::Right | ClosedWindow::Both => upper < watermark,
+            ClosedWindow::Left | ClosedWindow::None => upper <= watermark,
+        }
+    }
+
+    /// Returns the partition of every row, creating the partitions of new groups.
+    fn partitions_of(&mut self, df: &DataFrame) -> PolarsResult<Vec<usize>> {
+        let keys = df.select_columns(self.params.keys.iter().cloned())?;
+        let rows = encode_rows_unordered(&keys)?;
+        let mut partitions = Vec::with_capacity(df.height());
+        for key in rows.into_no_null_iter() {
+            let idx = match self.partition_idx.get(key) {
+                Some(idx) => *idx,
+                None => {
+                    let idx = self.partitions.len();
+                    self.partitions.push(Partition::default());
+                    self.partition_idx.insert(key.to_vec(), idx);
+                    idx
+                },
+            };
+            partitions.push(idx);
+        }
+        Ok(partitions)
+    }
+
+    /// Buffers the rows of `df` and returns the partitions they were added to.
+    fn push(&mut self, df: DataFrame) -> PolarsResult<Vec<usize>> {
+        if self.params.keys.is_empty() {
+            if self.partitions.is_empty() {
+                self.partitions.push(Partition::default());
+            }
+            self.push_partition(0, df)?;
+            return Ok(vec![0]);
+        }
+
+        let partitions = self.partitions_of(&df)?;
+
+        let mut rows: Vec<(usize, Vec<IdxSize>)> = Vec::new();
+        let mut slots = PlHashMap::default();
+        for (row, partition) in partitions.into_iter().enumerate() {
+            let slot = *slots.entry(partition).or_insert_with(|| {
+                rows.push((partition, Vec::new()));
+                rows.len() - 1
+            });
+            rows[slot].1.push(row as IdxSize);
+        }
+
+        let mut pushed = Vec::with_capacity(rows.len());
+        for (partition, idx) in rows {
+            // SAFETY: the indices are rows of `df`.
+            let part = unsafe { df.take_slice_unchecked(&idx) };
+            self.push_partition(partition, part)?;
+            pushed.push(partition);
+        }
+        Ok(pushed)
+    }
+
+    fn push_partition(&mut self, partition: usize, df: DataFrame) -> PolarsResult<()> {
+        let time = physical_time(df.column(&self.params.index_column)?)?;
+        polars_ensure!(
+            time.null_count() == 0,
+            ComputeError: "null values in {} not supported, fill nulls.", self.name()
+        );
+
+        let mut prev = self.partitions[partition].watermark.unwrap_or(i64::MIN);
+        for t in time.into_no_null_iter() {
+            polars_ensure!(
+                t >= prev,
+                InvalidOperation: "argument in operation '{}' is not sorted, please sort the 'expr/series/column' first",
+                self.name()
+            );
+            prev = t;
+        }
+
+        let part = &mut self.partitions[partition];
+        part.watermark = Some(prev);
+
+        if part.buffer.width() == 0 {
+            part.buffer = df;
+        } else {
+            part.buffer.vstack_mut_owned(df)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the windows of `partitions` that were completed by the rows pushed since. If
+    /// `finished` is set no more rows will arrive and all remaining windows are returned.
+    fn flush(&mut self, partitions: &[usize], finished: bool) -> PolarsResult<DataFrame> {
+        // Only the groups where the earliest pending window closed have windows to emit.
+        let evaluate: Vec<usize> = if finished {
+            (0..self.partitions.len())
+                .filter(|p| self.partitions[*p].buffer.height() > 0)
+                .collect()
+        } else {
+            partitions
+                .iter()
+                .copied()
+                .filter(|p| {
+                    let part = &self.partitions[*p];
+                    match (part.next_close, part.watermark) {
+                        (Some(upper), Some(watermark)) => self.is_complete(upper, watermark),
+                        _ => true,
+                    }
+                })
+                .collect()
+        };
+        if evaluate.is_empty() {
+            return Ok(DataFrame::empty());
+        }
+
+        let mut input = DataFrame::empty();
+        for p in &evaluate {
+            let buffer = self.partitions[*p].buffer.clone();
+            if input.width() == 0 {
+                input = buffer;
+            } else {
+                input.vstack_mut_owned(buffer)?;
+            }
+        }
+        let mut out = self.map.call_udf(input)?;
+        let out_partitions = if self.params.keys.is_empty() {
+            vec![0; out.height()]
+        } else {
+            self.partitions_of(&out)?
+        };
+
+        let mut keep_from = vec![i64::MAX; self.partitions.len()];
+        if let WindowKind::Rolling {
+            offset, time_unit, ..
+        } = &self.params.kind
+        {
+            // Future rows never have a window starting before the one of the watermark.
+            for p in &evaluate {
+                let watermark = self.partitions[*p].watermark.unwrap();
+                keep_from[*p] = duration_add(*time_unit)(offset, watermark, &Default::default())?;
+            }
+        }
+        let mut next_close = vec![None; self.partitions.len()];
+        let mut mask = Vec::with_capacity(out.height());
+        // Marks the window `upper` of partition `p` for output if it is newly completed, else
+        // keeps the rows from `keep` on.
+        let mut add_window = |p: usize, upper: i64, keep: i64| {
+            let part = &self.partitions[p];
+            let complete = finished || self.is_complete(upper, part.watermark.unwrap());
+            let emitted = part
+                .emitted_watermark
+                .is_some_and(|w| self.is_complete(upper, w));
+            mask.push(complete && !emitted);
+            if !complete {
+                keep_from[p] = keep_from[p].min(keep);
+                next_close[p] = Some(next_close[p].map_or(upper, |c: i64| c.min(upper)));
+            }
+        };
+        match &self.params.kind {
+            WindowKind::Dynamic {
+                include_boundaries,
+                grid: _,
+            }
+            | WindowKind::Session { include_boundaries } => {
+                // For sessions the lower boundary is the first datapoint, and a session is over
+                // once a value beyond its upper boundary arrived.
+                let lower = physical_time(out.column(LOWER_BOUNDARY_NAME)?)?;
+                let upper = physical_time(out.column(UPPER_BOUNDARY_NAME)?)?;
+                for ((p, lower), upper) in out_partitions
+                    .iter()
+                    .zip(lower.into_no_null_iter())
+                    .zip(upper.into_no_null_iter())
+                {
+                    add_window(*p, upper, lower);
+                }
+
+                if !include_boundaries {
+                    out = out.drop_many([LOWER_BOUNDARY_NAME, UPPER_BOUNDARY_NAME]);
+                }
+            },
+            WindowKind::Rolling {
+                period,
+                offset,
+                time_unit,
+            } => {
+                let add = duration_add(*time_unit);
+                let calendars = DurationCalendars::default();
+                let time = physical_time(out.column(&self.params.index_column)?)?;
+                for (p, t) in out_partitions.iter().zip(time.into_no_null_iter()) {
+                    let lower = add(offset, t, &calendars)?;
+                    let upper = add(period, lower, &calendars)?;
+                    add_window(*p, upper, lower.min(t));
+                }
+            },
+        }
+        let out = out.filter(&BooleanChunked::from_slice(PlSmallStr::EMPTY, &mask))?;
+
+        for p in evaluate {
+            let part = &mut self.partitions[p];
+            part.emitted_watermark = part.watermark;
+            part.next_close = next_close[p];
+            if finished {
+                part.buffer = DataFrame::empty();
+            } else {
+                self.prune(p, keep_from[p])?;
+            }
+        }
+        Ok(out)
+    }
+
+    /// Drops the buffered rows of a partition before `keep_from`, which no pending window can
+    /// contain.
+    fn prune(&mut self, partition: usize, keep_from: i64) -> PolarsResult<()> {
+        let part = &mut self.partitions[partition];
+        let time: Vec<i64> = physical_time(part.buffer.column(&self.params.index_column)?)?
+            .into_no_null_iter()
+            .collect();
+        let mut keep: Vec<bool> = time.iter().map(|t| *t >= keep_from).collect();
+
+        // Time-weighted aggregations carry in the last row before the pending windows. Earlier
+        // rows are dropped, so a null value there isn't carried past.
+        let carried = if self.params.carry_in {
+            time.iter().rposition(|t| *t < keep_from)
+        } else {
+            None
+        };
+        if let Some(last) = carried {
+            keep[last] = true;
+        }
+
+        // The window grid starts at the first datapoint, so the buffer has to start on the grid
+        // for the windows to line up. The first row is moved along the grid to the last window
+        // start before the kept rows. There it only falls into windows that were already
+        // emitted, which are filtered from the output.
+        let mut anchor = None;
+        if let WindowKind::Dynamic {
+            grid: Some(grid), ..
+        } = &self.params.kind
+        {
+            if keep.first() == Some(&false) {
+                keep[0] = true;
+                let watermark = part.watermark.unwrap();
+                let bound = carried
+                    .map_or(i64::MAX, |i| time[i])
+                    .min(keep_from.min(watermark) - 1);
+                let add = duration_add(grid.time_unit);
+                let mut start = time[0];
+                loop {
+                    let next = add(&grid.every, start, &grid.calendars)?;
+                    if next > bound {
+                        break;
+                    }
+                    start = next;
+                }
+                anchor = Some(start);
+            }
+        }
+
+        if keep.iter().all(|k| *k) {
+            return Ok(());
+        }
+        let keep = BooleanChunked::from_slice(PlSmallStr::EMPTY, &keep);
+        part.buffer = part.buffer.filter(&keep)?;
+        if let Some(anchor) = anchor {
+            move_first_row(&mut part.buffer, &self.params.index_column, anchor)?;
+        }
+        part.buffer.rechunk_mut();
+        Ok(())
+    }
+}
+
+fn duration_add(
+    time_unit: TimeUnit,
+) -> impl Fn(&Duration, i64, &DurationCalendars) -> PolarsResult<i64> {
+    move |duration, t, calendars| match time_unit {
+        TimeUnit::Nanoseconds => duration.add_ns_in(t, None, calendars),
+        TimeUnit::Microseconds => duration.add_us_in(t, None, calendars),
+        TimeUnit::Milliseconds => duration.add_ms_in(t, None, calendars),
+    }
+}
+
+/// Sets the index value of the first row to the physical value `t`, unless its data type can't
+/// represent it.
+fn move_first_row(df: &mut DataFrame, index_column: &PlSmallStr, t: i64) -> PolarsResult<()> {
+    let column = df.column(index_column)?;
+    let first = match column.dtype() {
+        DataType::Date if t % MICROSECONDS_IN_DAY != 0 => return Ok(()),
+        DataType::Date => Column::new(index_column.clone(), [(t / MICROSECONDS_IN_DAY) as i32])
+            .cast(&DataType::Date)?,
+        dtype => Column::new(index_column.clone(), [t]).cast(dtype)?,
+    };
+    let mut moved = first;
+    moved.append(&column.slice(1, column.len() - 1))?;
+    df.with_column(moved)?;
+    Ok(())
+}
+
+/// Returns the index column in the physical representation the group-by windows are
+/// computed in.
+fn physical_time(column: &Column) -> PolarsResult<Int64Chunked> {
+    let column = match column.dtype() {
+        DataType::Date => column.cast(&DataType::Datetime(TimeUnit::Microseconds, None))?,
+        _ => column.clone(),
+    };
+    let physical = column.to_physical_repr().cast(&DataType::Int64)?;
+    Ok(physical.i64()?.clone())
+}
+
+impl ComputeNode for DynamicGroupByNode {
+    fn name(&self) -> &str {
+        match self.params.kind {
+            WindowKind::Dynamic { .. } => "group_by_dynamic",
+            WindowKind::Session { .. } => "group_by_session",
+            WindowKind::Rolling { .. } => "rolling",
+        }
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        _state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 1 && send.len() == 1);
+
+        if send[0] == PortState::Done {
+            recv[0] = PortState::Done;
+            self.partitions.clear();
+            self.partition_idx.clear();
+        } else if recv[0] == PortState::Done {
+            if self.partitions.iter().any(|p| p.buffer.height() > 0) {
+                send[0] = PortState::Ready;
+            } else {
+                send[0] = PortState::Done;
+            }
+        } else {
+            recv.swap_with_slice(send);
+        }
+
+        Ok(())
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        _state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert_eq!(recv_ports.len(), 1);
+        assert_eq!(send_ports.len(), 1);
+
+        let recv = recv_ports[0].take();
+        let mut send = send_ports[0].take().unwrap().serial();
+
+        match recv {
+            None => {
+                // All input has been received, emit the windows that are still pending.
+                join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                    let df = self.flush(&[], true)?;
+                    if df.height() > 0 {
+                        _ = send
+                            .send(Morsel::new(df, self.seq.successor(), SourceToken::new()))
+                            .await;
+                    }
+                    Ok(())
+                }));
+            },
+
+            Some(recv) => {
+                let mut recv = recv.serial();
+                join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                    while let Ok(mut m) = recv.recv().await {
+                        self.seq = m.seq();
+                        if m.df().height() == 0 {
+                            continue;
+                        }
+
+                        let partitions = self.push(std::mem::take(m.df_mut()))?;
+                        let df = self.flush(&partitions, false)?;
+                        if df.height() == 0 {
+                            continue;
+                        }
+
+                        *m.df_mut() = df;
+                        if send.send(m).await.is_err() {
+                            break;
+                        }
+                    }
+                    Ok(())
+                }));
+            },
+        }
+    }
+}
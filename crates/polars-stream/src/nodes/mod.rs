@@ -1,6 +1,8 @@
 pub mod callback_sink;
 #[cfg(feature = "cum_agg")]
 pub mod cum_agg;
+#[cfg(feature = "dynamic_group_by")]
+pub mod dynamic_group_by;
 pub mod dynamic_slice;
 pub mod filter;
 pub mod gather_every;
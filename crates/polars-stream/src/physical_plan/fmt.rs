@@ -366,6 +366,29 @@ fn visualize_plan_rec(
                 &[*input][..],
             )
         },
+        #[cfg(feature = "dynamic_group_by")]
+        PhysNodeKind::DynamicGroupBy {
+            input,
+            map: _,
+            params,
+            format_str,
+        } => {
+            use crate::nodes::dynamic_group_by::WindowKind;
+
+            let mut label = match params.kind {
+                WindowKind::Dynamic { .. } => "group-by-dynamic",
+                WindowKind::Session { .. } => "group-by-session",
+                WindowKind::Rolling { .. } => "rolling",
+            }
+            .to_owned();
+            if let Some(format_str) = format_str {
+                label.push('\n');
+
+                let mut f = EscapeLabel(&mut label);
+                write!(f, "{format_str}").unwrap();
+            }
+            (label, from_ref(input))
+        },
         PhysNodeKind::GatherEvery { input, n, offset } => (
             format!("gather_every\\nn: {n}, offset: {offset}"),
             &[*input][..],
@@ -379,7 +379,8 @@ fn try_lower_elementwise_scalar_agg_expr(
                 | IRAggExpr::NUnique(..)
                 | IRAggExpr::Implode(..)
                 | IRAggExpr::Quantile { .. }
-                | IRAggExpr::AggGroups(..) => None, // TODO: allow all aggregates,
+                | IRAggExpr::AggGroups(..)
+                | IRAggExpr::TimeWeighted { .. } => None, // TODO: allow all aggregates,
             }
         },
         AExpr::Len => {
@@ -395,11 +396,182 @@ fn try_lower_elementwise_scalar_agg_expr(
     }
 }
 
+/// Builds a streaming `group_by_dynamic`, `group_by_session` or `rolling` over a sorted
+/// index column.
+///
+/// The windows are computed by the in-memory engine over the rows buffered in the
+/// [`DynamicGroupByNode`](crate::nodes::dynamic_group_by::DynamicGroupByNode), which
+/// emits them as soon as they are complete.
+#[cfg(feature = "dynamic_group_by")]
 #[allow(clippy::too_many_arguments)]
+fn try_build_streaming_window_group_by(
+    input: PhysStream,
+    keys: &[ExprIR],
+    aggs: &[ExprIR],
+    output_schema: &Arc<Schema>,
+    options: &GroupbyOptions,
+    expr_arena: &mut Arena<AExpr>,
+    phys_sm: &mut SlotMap<PhysNodeKey, PhysNode>,
+    format_str: Option<String>,
+) -> Option<PolarsResult<PhysStream>> {
+    use polars_core::prelude::{DataType, TimeUnit, TimeZone};
+    use polars_time::prelude::{ClosedWindow, Duration, StartBy};
+
+    use crate::nodes::dynamic_group_by::{
+        LOWER_BOUNDARY_NAME, UPPER_BOUNDARY_NAME, WindowGrid, WindowGroupByParams, WindowKind,
+    };
+
+    // The node splits the rows by key, so the keys have to be plain columns.
+    let key_names = keys
+        .iter()
+        .map(|key| match expr_arena.get(key.node()) {
+            AExpr::Column(name) if name == key.output_name() => Some(name.clone()),
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()?;
+    let input_schema = phys_sm[input.node].output_schema.clone();
+    let carry_in = aggs
+        .iter()
+        .any(|e| polars_expr::planner::has_time_weighted_agg(e.node(), expr_arena));
+
+    let mut inner_options = options.clone();
+    inner_options.slice = None;
+    let mut inner_schema = output_schema.as_ref().clone();
+    // The window boundaries decide when a window is complete.
+    let mut add_boundaries = |bounds_dtype: DataType| {
+        for (i, name) in [LOWER_BOUNDARY_NAME, UPPER_BOUNDARY_NAME]
+            .into_iter()
+            .enumerate()
+        {
+            inner_schema
+                .insert_at_index(key_names.len() + i, name.into(), bounds_dtype.clone())
+                .ok()?;
+        }
+        Some(())
+    };
+    // The node computes window bounds without time zone, which only matches the in-memory
+    // engine if the durations don't depend on it.
+    let time_unit_for = |index_column: &PlSmallStr, durations: &[Duration]| {
+        let (time_unit, time_zone) = match input_schema.get(index_column)? {
+            DataType::Datetime(tu, tz) => (*tu, tz.clone()),
+            DataType::Date => (TimeUnit::Microseconds, None),
+            _ => (TimeUnit::Nanoseconds, None),
+        };
+        let tz_independent = time_zone.as_ref().is_none_or(|tz| *tz == TimeZone::UTC)
+            || durations
+                .iter()
+                .all(|d| d.is_constant_duration(time_zone.as_ref()));
+        tz_independent.then_some(time_unit)
+    };
+
+    let params = if let Some(dynamic) = &mut inner_options.dynamic {
+        let include_boundaries = dynamic.include_boundaries;
+        if !include_boundaries {
+            add_boundaries(match input_schema.get(&dynamic.index_column)? {
+                DataType::Date => DataType::Datetime(TimeUnit::Microseconds, None),
+                dtype => dtype.clone(),
+            })?;
+            dynamic.include_boundaries = true;
+        }
+        // The node moves the first datapoint of a group along the window grid, which it can
+        // only do if `every` doesn't depend on the time zone. A grid starting on a weekday
+        // depends on the week of the first datapoint, so it can't be moved.
+        let grid = match dynamic.start_by {
+            StartBy::WindowBound => None,
+            StartBy::DataPoint => Some(WindowGrid {
+                every: dynamic.every,
+                calendars: dynamic.calendars.clone(),
+                time_unit: time_unit_for(&dynamic.index_column, &[dynamic.every])?,
+            }),
+            _ => return None,
+        };
+        WindowGroupByParams {
+            index_column: dynamic.index_column.clone(),
+            keys: key_names.clone(),
+            closed_window: dynamic.closed_window,
+            carry_in,
+            kind: WindowKind::Dynamic {
+                include_boundaries,
+                grid,
+            },
+        }
+    } else if let Some(session) = &mut inner_options.session {
+        let include_boundaries = session.include_boundaries;
+        if !include_boundaries {
+            add_boundaries(input_schema.get(&session.index_column)?.clone())?;
+            session.include_boundaries = true;
+        }
+        WindowGroupByParams {
+            index_column: session.index_column.clone(),
+            keys: key_names.clone(),
+            // A session ends at its upper boundary `last + gap`, a value on that bound still
+            // joins it.
+            closed_window: ClosedWindow::Right,
+            carry_in: false,
+            kind: WindowKind::Session { include_boundaries },
+        }
+    } else {
+        let rolling = inner_options.rolling.as_ref()?;
+        let time_unit = time_unit_for(&rolling.index_column, &[rolling.period, rolling.offset])?;
+        WindowGroupByParams {
+            index_column: rolling.index_column.clone(),
+            keys: key_names.clone(),
+            closed_window: rolling.closed_window,
+            carry_in,
+            kind: WindowKind::Rolling {
+                period: rolling.period,
+                offset: rolling.offset,
+                time_unit,
+            },
+        }
+    };
+
+    let lmdf = Arc::new(LateMaterializedDataFrame::default());
+    let mut lp_arena = Arena::default();
+    let input_lp_node = lp_arena.add(lmdf.clone().as_ir_node(input_schema));
+    let group_by_lp_node = lp_arena.add(IR::GroupBy {
+        input: input_lp_node,
+        keys: keys.to_vec(),
+        aggs: aggs.to_vec(),
+        schema: Arc::new(inner_schema),
+        maintain_order: false,
+        options: Arc::new(inner_options),
+        apply: None,
+    });
+    let executor = match create_physical_plan(group_by_lp_node, &mut lp_arena, expr_arena, None) {
+        Ok(executor) => Mutex::new(executor),
+        Err(e) => return Some(Err(e)),
+    };
+
+    let group_by_node = phys_sm.insert(PhysNode::new(
+        output_schema.clone(),
+        PhysNodeKind::DynamicGroupBy {
+            input,
+            map: Arc::new(move |df| {
+                lmdf.set_materialized_dataframe(df);
+                let mut state = ExecutionState::new();
+                executor.lock().execute(&mut state)
+            }),
+            params,
+            format_str,
+        },
+    ));
+    let out = PhysStream::first(group_by_node);
+
+    Some(Ok(if let Some((offset, len)) = options.slice {
+        build_slice_stream(out, offset, len, phys_sm)
+    } else {
+        out
+    }))
+}
+
+#[allow(clippy::too_many_arguments)]
+#[cfg_attr(not(feature = "dynamic_group_by"), allow(unused_variables))]
 fn try_build_streaming_group_by(
     mut input: PhysStream,
     keys: &[ExprIR],
     aggs: &[ExprIR],
+    output_schema: &Arc<Schema>,
     maintain_order: bool,
     options: Arc<GroupbyOptions>,
     apply: Option<PlanCallback<DataFrame, DataFrame>>,
@@ -407,14 +579,24 @@ fn try_build_streaming_group_by(
     phys_sm: &mut SlotMap<PhysNodeKey, PhysNode>,
     expr_cache: &mut ExprCache,
     ctx: StreamingLowerIRContext,
+    format_str: Option<String>,
 ) -> Option<PolarsResult<PhysStream>> {
     if apply.is_some() {
         return None; // TODO
     }
 
     #[cfg(feature = "dynamic_group_by")]
-    if options.dynamic.is_some() || options.rolling.is_some() {
-        return None; // TODO
+    if options.dynamic.is_some() || options.rolling.is_some() || options.session.is_some() {
+        return try_build_streaming_window_group_by(
+            input,
+            keys,
+            aggs,
+            output_schema,
+            &options,
+            expr_arena,
+            phys_sm,
+            format_str,
+        );
     }
 
     if keys.is_empty() {
@@ -582,10 +764,25 @@ pub fn build_group_by_stream(
     expr_cache: &mut ExprCache,
     ctx: StreamingLowerIRContext,
 ) -> PolarsResult<PhysStream> {
+    let format_str = ctx.prepare_visualization.then(|| {
+        let mut buffer = String::new();
+        write_group_by(
+            &mut buffer,
+            0,
+            expr_arena,
+            keys,
+            aggs,
+            apply.as_ref(),
+            maintain_order,
+        )
+        .unwrap();
+        buffer
+    });
     let streaming = try_build_streaming_group_by(
         input,
         keys,
         aggs,
+        &output_schema,
         maintain_order,
         options.clone(),
         apply.clone(),
@@ -593,24 +790,11 @@ pub fn build_group_by_stream(
         phys_sm,
         expr_cache,
         ctx,
+        format_str.clone(),
     );
     if let Some(stream) = streaming {
         stream
     } else {
-        let format_str = ctx.prepare_visualization.then(|| {
-            let mut buffer = String::new();
-            write_group_by(
-                &mut buffer,
-                0,
-                expr_arena,
-                keys,
-                aggs,
-                apply.as_ref(),
-                maintain_order,
-            )
-            .unwrap();
-            buffer
-        });
         build_group_by_fallback(
             input,
             keys,
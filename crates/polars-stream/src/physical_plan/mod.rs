@@ -290,6 +290,16 @@ pub enum PhysNodeKind {
         options: polars_plan::plans::python::PythonOptions,
     },
 
+    /// A `group_by_dynamic` or `rolling` over a sorted index column, `map` runs the in-memory
+    /// group-by over the buffered rows.
+    #[cfg(feature = "dynamic_group_by")]
+    DynamicGroupBy {
+        input: PhysStream,
+        map: Arc<dyn DataFrameUdf>,
+        params: crate::nodes::dynamic_group_by::WindowGroupByParams,
+        format_str: Option<String>,
+    },
+
     GroupBy {
         input: PhysStream,
         key: Vec<ExprIR>,
@@ -392,6 +402,12 @@ fn visit_node_inputs_mut(
                 visit(input);
             },
 
+            #[cfg(feature = "dynamic_group_by")]
+            PhysNodeKind::DynamicGroupBy { input, .. } => {
+                rec!(input.node);
+                visit(input);
+            },
+
             PhysNodeKind::InMemoryJoin {
                 input_left,
                 input_right,
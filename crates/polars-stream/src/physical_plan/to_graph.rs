@@ -582,6 +582,20 @@ fn to_graph_rec<'a>(
             )
         },
 
+        #[cfg(feature = "dynamic_group_by")]
+        DynamicGroupBy {
+            input,
+            map,
+            params,
+            format_str: _,
+        } => {
+            let input_key = to_graph_rec(input.node, ctx)?;
+            ctx.graph.add_node(
+                nodes::dynamic_group_by::DynamicGroupByNode::new(params.clone(), map.clone()),
+                [(input_key, input.port)],
+            )
+        },
+
         GatherEvery { input, n, offset } => {
             let (n, offset) = (*n, *offset);
             let input_key = to_graph_rec(input.node, ctx)?;
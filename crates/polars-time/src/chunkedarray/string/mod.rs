@@ -1,7 +1,11 @@
 pub mod infer;
 use chrono::DateTime;
+#[cfg(any(feature = "dtype-date", feature = "dtype-datetime"))]
+mod multi_format;
 mod patterns;
 mod strptime;
+#[cfg(any(feature = "dtype-date", feature = "dtype-datetime"))]
+pub use multi_format::is_tz_aware_format;
 pub use patterns::Pattern;
 #[cfg(feature = "dtype-time")]
 use polars_core::chunked_array::temporal::time_to_time64ns;
@@ -101,6 +105,37 @@ pub trait StringMethods: AsString {
         Ok(ca.with_name(string_ca.name().clone()).into_time())
     }
 
+    #[cfg(feature = "dtype-duration")]
+    /// Parse string values as durations, written either like `"1d12h"` or in ISO 8601 like
+    /// `"P1DT12H"`, and return a [`DurationChunked`].
+    ///
+    /// Values that cannot be parsed, or whose length depends on the calendar (months, business
+    /// days and fiscal periods), become null.
+    fn as_duration(&self, tu: TimeUnit) -> DurationChunked {
+        use crate::windows::calendar::{NS_DAY, NS_WEEK};
+
+        let string_ca = self.as_string();
+        let ns_per_unit = match tu {
+            TimeUnit::Nanoseconds => 1,
+            TimeUnit::Microseconds => 1_000,
+            TimeUnit::Milliseconds => 1_000_000,
+        };
+        let ca: Int64Chunked = unary_elementwise(string_ca, |opt_s| {
+            let duration = crate::Duration::try_parse(opt_s?).ok()?;
+            if duration.parsed_int || !duration.is_constant_duration(None) {
+                return None;
+            }
+            let ns = duration
+                .weeks()
+                .checked_mul(NS_WEEK)?
+                .checked_add(duration.days().checked_mul(NS_DAY)?)?
+                .checked_add(duration.nanoseconds())?;
+            let v = ns / ns_per_unit;
+            Some(if duration.negative() { -v } else { v })
+        });
+        ca.with_name(string_ca.name().clone()).into_duration(tu)
+    }
+
     #[cfg(feature = "dtype-date")]
     /// Parsing string values and return a [`DateChunked`]
     /// Different from `as_date` this function allows matches that not contain the whole string
@@ -196,6 +231,34 @@ pub trait StringMethods: AsString {
         }
     }
 
+    #[cfg(feature = "dtype-date")]
+    /// Parse string values trying each of `formats` in order, and return a [`DateChunked`].
+    ///
+    /// If no formats are given, the format is inferred for each value separately.
+    fn as_date_many(&self, formats: &[&str], use_cache: bool) -> PolarsResult<DateChunked> {
+        let string_ca = self.as_string();
+        let mut parser = if formats.is_empty() {
+            let inferred = patterns::DATE_Y_M_D.iter().chain(patterns::DATE_D_M_Y);
+            multi_format::MultiFormatParser::new(inferred.copied(), true)?
+        } else {
+            multi_format::MultiFormatParser::new(formats.iter().copied(), false)?
+        };
+        let use_cache = use_cache && string_ca.len() > 50;
+
+        let mut convert = LruCachedFunc::new(
+            |s: &str| {
+                let date = match parser.parse(s)? {
+                    multi_format::Parsed::Naive(ndt) => ndt.date(),
+                    multi_format::Parsed::Offset(dt) => dt.date_naive(),
+                };
+                Some(naive_date_to_date(date))
+            },
+            (string_ca.len() as f64).sqrt() as usize,
+        );
+        let ca = unary_elementwise(string_ca, |val| convert.eval(val?, use_cache));
+        Ok(ca.with_name(string_ca.name().clone()).into_date())
+    }
+
     #[cfg(feature = "dtype-date")]
     /// Parsing string values and return a [`DateChunked`]
     fn as_date(&self, fmt: Option<&str>, use_cache: bool) -> PolarsResult<DateChunked> {
@@ -237,6 +300,86 @@ pub trait StringMethods: AsString {
         Ok(ca.with_name(string_ca.name().clone()).into_date())
     }
 
+    #[cfg(feature = "dtype-datetime")]
+    /// Parse string values trying each of `formats` in order, and return a [`DatetimeChunked`].
+    ///
+    /// If no formats are given, the format is inferred for each value separately, from the
+    /// patterns without a UTC offset. Values parsed with an offset (e.g. `%z`) are converted to
+    /// UTC, and the output is then time zone aware, in `tz` or else in UTC. Values without an
+    /// offset are interpreted as wall time in that time zone.
+    fn as_datetime_many(
+        &self,
+        formats: &[&str],
+        tu: TimeUnit,
+        use_cache: bool,
+        tz: Option<&TimeZone>,
+        ambiguous: &StringChunked,
+    ) -> PolarsResult<DatetimeChunked> {
+        let string_ca = self.as_string();
+        let mut parser = if formats.is_empty() {
+            let inferred = patterns::DATETIME_Y_M_D
+                .iter()
+                .chain(patterns::DATETIME_D_M_Y);
+            multi_format::MultiFormatParser::new(inferred.copied(), true)?
+        } else {
+            multi_format::MultiFormatParser::new(formats.iter().copied(), false)?
+        };
+        let tz_aware = parser.has_tz_aware_format();
+        let use_cache = use_cache && string_ca.len() > 50;
+
+        let func = match tu {
+            TimeUnit::Nanoseconds => datetime_to_timestamp_ns,
+            TimeUnit::Microseconds => datetime_to_timestamp_us,
+            TimeUnit::Milliseconds => datetime_to_timestamp_ms,
+        };
+
+        let mut convert = LruCachedFunc::new(
+            |s: &str| parser.parse(s),
+            (string_ca.len() as f64).sqrt() as usize,
+        );
+        let parsed: Vec<_> = string_ca
+            .iter()
+            .map(|opt_s| convert.eval(opt_s?, use_cache))
+            .collect();
+
+        let naive: Int64Chunked = parsed
+            .iter()
+            .map(|opt_p| match opt_p {
+                Some(multi_format::Parsed::Naive(ndt)) => Some(func(*ndt)),
+                _ => None,
+            })
+            .collect_ca(string_ca.name().clone());
+        let naive = naive.into_datetime(tu, None);
+        let time_zone = match tz {
+            Some(tz) => Some(tz.clone()),
+            None if tz_aware => Some(TimeZone::UTC),
+            None => None,
+        };
+        let localized = match &time_zone {
+            #[cfg(feature = "timezones")]
+            Some(tz) => polars_ops::prelude::replace_time_zone(
+                &naive,
+                Some(tz),
+                ambiguous,
+                NonExistent::Raise,
+            )?,
+            _ => naive,
+        };
+        if !tz_aware {
+            return Ok(localized);
+        }
+
+        let ca: Int64Chunked = parsed
+            .iter()
+            .zip(localized.physical().iter())
+            .map(|(opt_p, opt_local)| match opt_p {
+                Some(multi_format::Parsed::Offset(dt)) => Some(func(dt.naive_utc())),
+                _ => opt_local,
+            })
+            .collect_ca(string_ca.name().clone());
+        Ok(ca.into_datetime(tu, time_zone))
+    }
+
     #[cfg(feature = "dtype-datetime")]
     /// Parsing string values and return a [`DatetimeChunked`].
     fn as_datetime(
@@ -0,0 +1,115 @@
+//! Parsing of string values that may each be in a different format.
+use chrono::format::ParseErrorKind;
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime};
+use polars_core::prelude::*;
+
+use super::strptime::{self, StrpTimeState};
+
+/// Whether values parsed with `fmt` carry a UTC offset.
+pub fn is_tz_aware_format(fmt: &str) -> bool {
+    ["%z", "%:z", "%::z", "%:::z", "%#z"]
+        .iter()
+        .any(|directive| fmt.contains(directive))
+        || fmt == "%+"
+}
+
+#[derive(Clone, Copy)]
+pub(super) enum Parsed {
+    Naive(NaiveDateTime),
+    Offset(DateTime<FixedOffset>),
+}
+
+struct Format {
+    fmt: String,
+    // `None` if the fast parser doesn't support the format.
+    fmt_len: Option<u16>,
+    tz_aware: bool,
+}
+
+/// Tries a list of formats on each value, using the fast parser for the formats that allow it.
+pub(super) struct MultiFormatParser {
+    formats: Vec<Format>,
+    state: StrpTimeState,
+    // Inferred formats don't overlap, so the format that parsed the previous value is tried
+    // first. Given formats are always tried in order, as a value may match several of them.
+    sticky: bool,
+    last: usize,
+}
+
+impl MultiFormatParser {
+    pub(super) fn new<'a>(
+        formats: impl IntoIterator<Item = &'a str>,
+        sticky: bool,
+    ) -> PolarsResult<Self> {
+        let formats = formats
+            .into_iter()
+            .map(|fmt| {
+                let fmt = strptime::compile_fmt(fmt)?;
+                Ok(Format {
+                    fmt_len: strptime::fmt_len(fmt.as_bytes()),
+                    tz_aware: is_tz_aware_format(&fmt),
+                    fmt,
+                })
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        polars_ensure!(!formats.is_empty(), ComputeError: "expected at least one format");
+        Ok(Self {
+            formats,
+            state: StrpTimeState::default(),
+            sticky,
+            last: 0,
+        })
+    }
+
+    #[cfg(feature = "dtype-datetime")]
+    pub(super) fn has_tz_aware_format(&self) -> bool {
+        self.formats.iter().any(|f| f.tz_aware)
+    }
+
+    pub(super) fn parse(&mut self, val: &str) -> Option<Parsed> {
+        if self.sticky {
+            if let Some(parsed) = self.parse_with(self.last, val) {
+                return Some(parsed);
+            }
+        }
+        for i in 0..self.formats.len() {
+            if self.sticky && i == self.last {
+                continue;
+            }
+            if let Some(parsed) = self.parse_with(i, val) {
+                self.last = i;
+                return Some(parsed);
+            }
+        }
+        None
+    }
+
+    fn parse_with(&mut self, i: usize, val: &str) -> Option<Parsed> {
+        let format = &self.formats[i];
+        if format.tz_aware {
+            return DateTime::parse_from_str(val, &format.fmt)
+                .ok()
+                .map(Parsed::Offset);
+        }
+        if let Some(fmt_len) = format.fmt_len {
+            // SAFETY: fmt_len is correct, it was computed with this `fmt` str.
+            if let Some(ndt) = unsafe {
+                self.state
+                    .parse(val.as_bytes(), format.fmt.as_bytes(), fmt_len)
+            } {
+                return Some(Parsed::Naive(ndt));
+            }
+        }
+        // Fallback to chrono.
+        match NaiveDateTime::parse_from_str(val, &format.fmt) {
+            Ok(ndt) => Some(Parsed::Naive(ndt)),
+            Err(parse_error) => match parse_error.kind() {
+                ParseErrorKind::NotEnough => NaiveDate::parse_from_str(val, &format.fmt)
+                    .ok()
+                    .and_then(|nd| nd.and_hms_opt(0, 0, 0))
+                    .map(Parsed::Naive),
+                _ => None,
+            },
+        }
+    }
+}
@@ -35,10 +35,20 @@ pub fn date_range(
             end.and_utc().timestamp_millis(),
         ),
     };
-    datetime_range_impl(name, start, end, interval, closed, tu, tz)
+    datetime_range_impl(
+        name,
+        start,
+        end,
+        interval,
+        closed,
+        tu,
+        tz,
+        &DurationCalendars::default(),
+    )
 }
 
 #[doc(hidden)]
+#[allow(clippy::too_many_arguments)]
 pub fn datetime_range_impl(
     name: PlSmallStr,
     start: i64,
@@ -47,10 +57,11 @@ pub fn datetime_range_impl(
     closed: ClosedWindow,
     tu: TimeUnit,
     tz: Option<&Tz>,
+    calendars: &DurationCalendars,
 ) -> PolarsResult<DatetimeChunked> {
     let out = Int64Chunked::new_vec(
         name,
-        datetime_range_i64(start, end, interval, closed, tu, tz)?,
+        datetime_range_i64(start, end, interval, closed, tu, tz, calendars)?,
     );
     let mut out = match tz {
         #[cfg(feature = "timezones")]
@@ -85,7 +96,15 @@ pub fn time_range_impl(
 ) -> PolarsResult<TimeChunked> {
     let mut out = Int64Chunked::new_vec(
         name,
-        datetime_range_i64(start, end, interval, closed, TimeUnit::Nanoseconds, None)?,
+        datetime_range_i64(
+            start,
+            end,
+            interval,
+            closed,
+            TimeUnit::Nanoseconds,
+            None,
+            &DurationCalendars::default(),
+        )?,
     )
     .into_time();
 
@@ -101,6 +120,7 @@ pub(crate) fn datetime_range_i64(
     closed: ClosedWindow,
     time_unit: TimeUnit,
     time_zone: Option<&Tz>,
+    calendars: &DurationCalendars,
 ) -> PolarsResult<Vec<i64>> {
     if start > end {
         return Ok(Vec::new());
@@ -140,31 +160,40 @@ pub(crate) fn datetime_range_i64(
         };
     }
 
-    let size = ((end - start) / duration + 1) as usize;
     let offset_fn = match time_unit {
-        TimeUnit::Nanoseconds => Duration::add_ns,
-        TimeUnit::Microseconds => Duration::add_us,
-        TimeUnit::Milliseconds => Duration::add_ms,
+        TimeUnit::Nanoseconds => Duration::add_ns_in,
+        TimeUnit::Microseconds => Duration::add_us_in,
+        TimeUnit::Milliseconds => Duration::add_ms_in,
+    };
+    // Business-day ranges start on a business day: going one business day back and forth rolls
+    // any other day forward.
+    let start = if interval.business_days() > 0 {
+        let one = Duration::one_business_day();
+        let previous = offset_fn(&-one, start, time_zone, calendars)?;
+        offset_fn(&one, previous, time_zone, calendars)?
+    } else {
+        start
     };
+    let size = ((end - start).max(0) / duration + 1) as usize;
     let mut ts = Vec::with_capacity(size);
     let mut i = match closed {
         ClosedWindow::Both | ClosedWindow::Left => 0,
         ClosedWindow::Right | ClosedWindow::None => 1,
     };
-    let mut t = offset_fn(&(interval * i), start, time_zone)?;
+    let mut t = offset_fn(&(interval * i), start, time_zone, calendars)?;
     i += 1;
     match closed {
         ClosedWindow::Both | ClosedWindow::Right => {
             while t <= end {
                 ts.push(t);
-                t = offset_fn(&(interval * i), start, time_zone)?;
+                t = offset_fn(&(interval * i), start, time_zone, calendars)?;
                 i += 1;
             }
         },
         ClosedWindow::Left | ClosedWindow::None => {
             while t < end {
                 ts.push(t);
-                t = offset_fn(&(interval * i), start, time_zone)?;
+                t = offset_fn(&(interval * i), start, time_zone, calendars)?;
                 i += 1;
             }
         },
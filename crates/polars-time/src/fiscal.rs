@@ -0,0 +1,336 @@
+use chrono::{Datelike, NaiveDate};
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Number of days from 0001-01-01 (CE) to 1970-01-01.
+const EPOCH_DAYS_FROM_CE: i32 = 719_163;
+
+/// The number of weeks in each of the three periods of a fiscal quarter.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum FiscalPattern {
+    #[default]
+    FourFourFive,
+    FourFiveFour,
+    FiveFourFour,
+}
+
+impl FiscalPattern {
+    fn weeks(self) -> [i32; 3] {
+        match self {
+            FiscalPattern::FourFourFive => [4, 4, 5],
+            FiscalPattern::FourFiveFour => [4, 5, 4],
+            FiscalPattern::FiveFourFour => [5, 4, 4],
+        }
+    }
+}
+
+/// How the first day of a fiscal year is chosen around the 1st of its start month.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum FiscalYearEnd {
+    /// Start on the last first-day-of-week on or before the 1st, i.e. the previous year ends on
+    /// the last end-of-week of the month before.
+    Last,
+    /// Start on the first-day-of-week nearest to the 1st.
+    #[default]
+    Nearest,
+}
+
+/// Which calendar year names a fiscal year.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum FiscalYearLabel {
+    /// The calendar year of the start month.
+    #[default]
+    Start,
+    /// The calendar year in which the fiscal year ends.
+    End,
+}
+
+/// A 52/53-week retail calendar.
+///
+/// Fiscal years consist of whole weeks and are split into four quarters of three periods,
+/// following `pattern`. Years with 53 weeks add the extra week to the last period.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct FiscalCalendar {
+    /// The month in which fiscal years start, from 1 to 12.
+    pub start_month: u8,
+    /// The first day of the fiscal week, from 0 (Monday) to 6 (Sunday).
+    pub week_start: u8,
+    pub pattern: FiscalPattern,
+    pub year_end: FiscalYearEnd,
+    pub year_label: FiscalYearLabel,
+}
+
+impl Default for FiscalCalendar {
+    /// A 4-4-5 calendar with weeks starting on Sunday and years ending on the Saturday nearest to
+    /// the end of January.
+    fn default() -> Self {
+        Self {
+            start_month: 2,
+            week_start: 6,
+            pattern: FiscalPattern::default(),
+            year_end: FiscalYearEnd::default(),
+            year_label: FiscalYearLabel::default(),
+        }
+    }
+}
+
+/// A date on a [`FiscalCalendar`]. Quarters, periods and weeks start at 1.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct FiscalDate {
+    pub year: i32,
+    pub quarter: i8,
+    pub period: i8,
+    pub week: i8,
+}
+
+fn out_of_range(date: i32) -> PolarsError {
+    polars_err!(ComputeError: "date {} is out of range for a fiscal calendar", date)
+}
+
+impl FiscalCalendar {
+    pub fn validate(&self) -> PolarsResult<()> {
+        polars_ensure!(
+            (1..=12).contains(&self.start_month),
+            InvalidOperation: "fiscal `start_month` must be between 1 and 12, got {}", self.start_month
+        );
+        polars_ensure!(
+            self.week_start < 7,
+            InvalidOperation: "fiscal `week_start` must be between 0 (Monday) and 6 (Sunday), got {}", self.week_start
+        );
+        Ok(())
+    }
+
+    /// The first day of the fiscal year that starts around the start month of `year`, in days
+    /// since the UNIX epoch.
+    fn year_start(&self, year: i32) -> Option<i32> {
+        let first = NaiveDate::from_ymd_opt(year, self.start_month as u32, 1)?;
+        let first = first.num_days_from_ce() - EPOCH_DAYS_FROM_CE;
+        // 1970-01-01 is a Thursday.
+        let diff = (first + 3 - self.week_start as i32).rem_euclid(7);
+        Some(match self.year_end {
+            FiscalYearEnd::Last => first - diff,
+            FiscalYearEnd::Nearest if diff <= 3 => first - diff,
+            FiscalYearEnd::Nearest => first + 7 - diff,
+        })
+    }
+
+    /// The fiscal year containing `date`, as the calendar year of its start month, and its first
+    /// day.
+    fn locate(&self, date: i32) -> Option<(i32, i32)> {
+        let year =
+            NaiveDate::from_num_days_from_ce_opt(date.checked_add(EPOCH_DAYS_FROM_CE)?)?.year();
+        let start = self.year_start(year)?;
+        if date < start {
+            return Some((year - 1, self.year_start(year - 1)?));
+        }
+        let next = self.year_start(year + 1)?;
+        if date >= next {
+            Some((year + 1, next))
+        } else {
+            Some((year, start))
+        }
+    }
+
+    /// The first day of the zero-based `period` of the fiscal `year`.
+    fn period_start(&self, year: i32, period: i32) -> Option<i32> {
+        let weeks = self.pattern.weeks();
+        let offset = (period / 3) * 13 + weeks[..(period % 3) as usize].iter().sum::<i32>();
+        Some(self.year_start(year)? + 7 * offset)
+    }
+
+    /// The number of days in the zero-based `period` of the fiscal `year`.
+    fn period_len(&self, year: i32, period: i32) -> Option<i32> {
+        if period == 11 {
+            Some(self.year_start(year + 1)? - self.period_start(year, 11)?)
+        } else {
+            Some(7 * self.pattern.weeks()[(period % 3) as usize])
+        }
+    }
+
+    /// The zero-based period of the zero-based `week`.
+    fn period_of_week(&self, week: i32) -> i32 {
+        let weeks = self.pattern.weeks();
+        let quarter = (week / 13).min(3);
+        let mut week = week - quarter * 13;
+        let mut period = 0;
+        while period < 2 && week >= weeks[period as usize] {
+            week -= weeks[period as usize];
+            period += 1;
+        }
+        quarter * 3 + period
+    }
+
+    pub fn fiscal_date(&self, date: i32) -> PolarsResult<FiscalDate> {
+        let (year, start) = self.locate(date).ok_or_else(|| out_of_range(date))?;
+        let week = (date - start) / 7;
+        let period = self.period_of_week(week);
+        let year = match self.year_label {
+            FiscalYearLabel::Start => year,
+            FiscalYearLabel::End => {
+                let end = self
+                    .year_start(year + 1)
+                    .ok_or_else(|| out_of_range(date))?
+                    - 1;
+                NaiveDate::from_num_days_from_ce_opt(end + EPOCH_DAYS_FROM_CE)
+                    .ok_or_else(|| out_of_range(date))?
+                    .year()
+            },
+        };
+        Ok(FiscalDate {
+            year,
+            quarter: (period / 3 + 1) as i8,
+            period: (period + 1) as i8,
+            week: (week + 1) as i8,
+        })
+    }
+
+    /// Move `n` fiscal periods away from `date`, keeping the day within the period where
+    /// possible, like adding calendar months.
+    pub fn offset_periods(&self, date: i32, n: i64) -> PolarsResult<i32> {
+        let (year, start) = self.locate(date).ok_or_else(|| out_of_range(date))?;
+        let period = self.period_of_week((date - start) / 7);
+        let day = date - self.period_start(year, period).unwrap();
+
+        let total = year as i64 * 12 + period as i64 + n;
+        let year = i32::try_from(total.div_euclid(12)).map_err(|_| out_of_range(date))?;
+        let period = total.rem_euclid(12) as i32;
+        let start = self
+            .period_start(year, period)
+            .ok_or_else(|| out_of_range(date))?;
+        let len = self
+            .period_len(year, period)
+            .ok_or_else(|| out_of_range(date))?;
+        Ok(start + day.min(len - 1))
+    }
+
+    /// The first day of the window of `n` fiscal periods containing `date`. Windows are counted
+    /// from the first period of the fiscal year starting in 1970.
+    pub fn truncate_periods(&self, date: i32, n: i64) -> PolarsResult<i32> {
+        let (year, start) = self.locate(date).ok_or_else(|| out_of_range(date))?;
+        let period = self.period_of_week((date - start) / 7);
+
+        let total = (year as i64 - 1970) * 12 + period as i64;
+        let total = total - total.rem_euclid(n);
+        let year = (1970 + total.div_euclid(12)) as i32;
+        self.period_start(year, total.rem_euclid(12) as i32)
+            .ok_or_else(|| out_of_range(date))
+    }
+}
+
+fn apply_fiscal<T, F>(
+    ca: &DateChunked,
+    calendar: &FiscalCalendar,
+    f: F,
+) -> PolarsResult<ChunkedArray<T>>
+where
+    T: PolarsNumericType,
+    F: Fn(FiscalDate) -> T::Native,
+{
+    calendar.validate()?;
+    ca.physical()
+        .try_apply_nonnull_values_generic(|date| calendar.fiscal_date(date).map(&f))
+}
+
+/// Extract the fiscal year, named as configured by the calendar.
+pub fn fiscal_year(ca: &DateChunked, calendar: &FiscalCalendar) -> PolarsResult<Int32Chunked> {
+    apply_fiscal(ca, calendar, |d| d.year)
+}
+
+/// Extract the fiscal quarter, from 1 to 4.
+pub fn fiscal_quarter(ca: &DateChunked, calendar: &FiscalCalendar) -> PolarsResult<Int8Chunked> {
+    apply_fiscal(ca, calendar, |d| d.quarter)
+}
+
+/// Extract the fiscal period, from 1 to 12.
+pub fn fiscal_period(ca: &DateChunked, calendar: &FiscalCalendar) -> PolarsResult<Int8Chunked> {
+    apply_fiscal(ca, calendar, |d| d.period)
+}
+
+/// Extract the week of the fiscal year, from 1 to 53.
+pub fn fiscal_week(ca: &DateChunked, calendar: &FiscalCalendar) -> PolarsResult<Int8Chunked> {
+    apply_fiscal(ca, calendar, |d| d.week)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> i32 {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap().num_days_from_ce() - EPOCH_DAYS_FROM_CE
+    }
+
+    #[test]
+    fn test_fiscal_date() {
+        let calendar = FiscalCalendar::default();
+        // Fiscal 2024 runs from Sunday 2024-02-04 to Saturday 2025-02-01 and has 52 weeks.
+        assert_eq!(calendar.locate(date(2024, 2, 3)).unwrap().0, 2023);
+        let first = calendar.fiscal_date(date(2024, 2, 4)).unwrap();
+        assert_eq!(
+            first,
+            FiscalDate {
+                year: 2024,
+                quarter: 1,
+                period: 1,
+                week: 1
+            }
+        );
+        // The third period of a 4-4-5 quarter is five weeks long.
+        let d = calendar.fiscal_date(date(2024, 2, 4) + 7 * 12).unwrap();
+        assert_eq!((d.quarter, d.period, d.week), (1, 3, 13));
+        let last = calendar.fiscal_date(date(2025, 2, 1)).unwrap();
+        assert_eq!(
+            (last.year, last.quarter, last.period, last.week),
+            (2024, 4, 12, 52)
+        );
+
+        // Fiscal 2023 has 53 weeks; the extra week is part of the last period.
+        let last = calendar.fiscal_date(date(2024, 2, 3)).unwrap();
+        assert_eq!((last.period, last.week), (12, 53));
+
+        let calendar = FiscalCalendar {
+            year_label: FiscalYearLabel::End,
+            ..Default::default()
+        };
+        assert_eq!(calendar.fiscal_date(date(2024, 2, 4)).unwrap().year, 2025);
+
+        // With `Last`, fiscal 2024 starts on the Sunday after the last Saturday of January.
+        let calendar = FiscalCalendar {
+            year_end: FiscalYearEnd::Last,
+            ..Default::default()
+        };
+        assert_eq!(
+            calendar.locate(date(2024, 1, 28)).unwrap(),
+            (2024, date(2024, 1, 28))
+        );
+    }
+
+    #[test]
+    fn test_fiscal_offset_and_truncate() {
+        let calendar = FiscalCalendar::default();
+        let start = date(2024, 2, 4);
+        // The 34th day of the five-week third period is clamped to the 28th day of the next one.
+        let d = start + 7 * 8 + 33;
+        assert_eq!(calendar.offset_periods(d, 1).unwrap(), start + 7 * 13 + 27);
+        assert_eq!(
+            calendar.offset_periods(start, -1).unwrap(),
+            date(2023, 12, 24)
+        );
+        assert_eq!(
+            calendar.offset_periods(start, 12).unwrap(),
+            date(2025, 2, 2)
+        );
+
+        assert_eq!(calendar.truncate_periods(d, 1).unwrap(), start + 7 * 8);
+        assert_eq!(calendar.truncate_periods(d, 3).unwrap(), start);
+        assert_eq!(calendar.truncate_periods(d, 12).unwrap(), start);
+    }
+}
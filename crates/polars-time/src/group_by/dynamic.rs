@@ -34,6 +34,9 @@ pub struct DynamicGroupOptions {
     pub include_boundaries: bool,
     pub closed_window: ClosedWindow,
     pub start_by: StartBy,
+    /// The calendars that business days and fiscal periods of `every`, `period` and `offset` are
+    /// counted in.
+    pub calendars: DurationCalendars,
 }
 
 impl Default for DynamicGroupOptions {
@@ -47,6 +50,7 @@ impl Default for DynamicGroupOptions {
             include_boundaries: false,
             closed_window: ClosedWindow::Left,
             start_by: Default::default(),
+            calendars: Default::default(),
         }
     }
 }
@@ -74,6 +78,29 @@ impl Default for RollingGroupOptions {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct SessionGroupOptions {
+    /// Time or index column.
+    pub index_column: PlSmallStr,
+    /// A new session starts when the next value is more than `gap` after the previous one.
+    pub gap: Duration,
+    /// Add the boundaries of every session to the DataFrame: the first value, and the last
+    /// value plus `gap`, up to which the session would have been extended.
+    pub include_boundaries: bool,
+}
+
+impl Default for SessionGroupOptions {
+    fn default() -> Self {
+        Self {
+            index_column: "".into(),
+            gap: Duration::new(1),
+            include_boundaries: false,
+        }
+    }
+}
+
 fn check_sortedness_slice(v: &[i64]) -> PolarsResult<()> {
     polars_ensure!(v.is_sorted_ascending(), ComputeError: "input data is not sorted");
     Ok(())
@@ -94,6 +121,12 @@ pub trait PolarsTemporalGroupby {
         group_by: Option<GroupsSlice>,
         options: &DynamicGroupOptions,
     ) -> PolarsResult<(Column, Vec<Column>, GroupPositions)>;
+
+    fn group_by_session(
+        &self,
+        group_by: Option<GroupsSlice>,
+        options: &SessionGroupOptions,
+    ) -> PolarsResult<(Column, Vec<Column>, GroupPositions)>;
 }
 
 impl PolarsTemporalGroupby for DataFrame {
@@ -112,6 +145,14 @@ impl PolarsTemporalGroupby for DataFrame {
     ) -> PolarsResult<(Column, Vec<Column>, GroupPositions)> {
         Wrap(self).group_by_dynamic(group_by, options)
     }
+
+    fn group_by_session(
+        &self,
+        group_by: Option<GroupsSlice>,
+        options: &SessionGroupOptions,
+    ) -> PolarsResult<(Column, Vec<Column>, GroupPositions)> {
+        Wrap(self).group_by_session(group_by, options)
+    }
 }
 
 impl Wrap<&DataFrame> {
@@ -276,7 +317,8 @@ impl Wrap<&DataFrame> {
         // A requirement for the index so we can set this such that downstream code has this info.
         dt.set_sorted_flag(IsSorted::Ascending);
 
-        let w = Window::new(options.every, options.period, options.offset);
+        let w = Window::new(options.every, options.period, options.offset)
+            .with_calendars(options.calendars.clone());
         let dt = dt.datetime().unwrap();
         let tz = dt.time_zone();
 
@@ -313,7 +355,7 @@ impl Wrap<&DataFrame> {
             let vals = dt.physical().downcast_iter().next().unwrap();
             let ts = vals.values().as_slice();
             let (groups, lower, upper) = group_by_windows(
-                w,
+                &w,
                 ts,
                 options.closed_window,
                 tu,
@@ -341,7 +383,7 @@ impl Wrap<&DataFrame> {
                 check_sortedness_slice(values)?;
 
                 let (groups, lower, upper) = group_by_windows(
-                    w,
+                    &w,
                     values,
                     options.closed_window,
                     tu,
@@ -421,6 +463,101 @@ impl Wrap<&DataFrame> {
             .map(|s| (s, bounds, groups.into_sliceable()))
     }
 
+    /// Returns: time_keys, keys, groupsproxy.
+    ///
+    /// The time key is the first value of every session. The boundaries are the first value
+    /// and the last value plus the gap.
+    fn group_by_session(
+        &self,
+        group_by: Option<GroupsSlice>,
+        options: &SessionGroupOptions,
+    ) -> PolarsResult<(Column, Vec<Column>, GroupPositions)> {
+        polars_ensure!(
+            !options.gap.is_zero() && !options.gap.negative,
+            ComputeError: "session gap should be strictly positive",
+        );
+        let time = self.0.column(&options.index_column)?.rechunk();
+        if group_by.is_none() {
+            // If by is given, the column must be sorted in the 'by' arg, which we can not check now
+            // this will be checked when the groups are materialized.
+            time.as_materialized_series()
+                .ensure_sorted_arg("group_by_session")?;
+        }
+        let time_type = time.dtype();
+
+        polars_ensure!(time.null_count() == 0, ComputeError: "null values in `group_by_session` not supported, fill nulls.");
+        ensure_duration_matches_dtype(options.gap, time_type, "gap")?;
+
+        use DataType::*;
+        let (dt, tu, tz) = match time_type {
+            Datetime(tu, tz) => (time.clone(), *tu, tz.clone()),
+            Date => (
+                time.cast(&Datetime(TimeUnit::Microseconds, None))?,
+                TimeUnit::Microseconds,
+                None,
+            ),
+            UInt32 | UInt64 | Int32 | Int64 => (
+                time.cast(&Int64)?
+                    .cast(&Datetime(TimeUnit::Nanoseconds, None))?,
+                TimeUnit::Nanoseconds,
+                None,
+            ),
+            dt => polars_bail!(
+                ComputeError:
+                "expected any of the following dtypes: {{ Date, Datetime, Int32, Int64, UInt32, UInt64 }}, got {}",
+                dt
+            ),
+        };
+        #[cfg(feature = "timezones")]
+        let tz = tz.and_then(|tz| tz.parse::<Tz>().ok());
+        #[cfg(not(feature = "timezones"))]
+        let tz: Option<Tz> = {
+            _ = tz;
+            None
+        };
+
+        let dt = dt.datetime().unwrap();
+        let vals = dt.physical().downcast_iter().next().unwrap();
+        let ts = vals.values().as_slice();
+
+        let groups = if let Some(group_by) = group_by {
+            let iter = group_by.par_iter().map(|[start, len]| {
+                let values = &ts[*start as usize..(*start + *len) as usize];
+                check_sortedness_slice(values)?;
+                group_by_sessions(values, *start, options.gap, tu, tz.as_ref())
+            });
+            let groups = POOL.install(|| iter.collect::<PolarsResult<Vec<_>>>())?;
+            POOL.install(|| flatten_par(&groups))
+        } else {
+            group_by_sessions(ts, 0, options.gap, tu, tz.as_ref())?
+        };
+        let groups = GroupsType::Slice {
+            groups,
+            rolling: false,
+        };
+
+        let time_key = unsafe { time.agg_first(&groups) };
+        let mut bounds = vec![];
+        if options.include_boundaries {
+            let add = duration_add(tu);
+            let GroupsType::Slice { groups, .. } = &groups else {
+                unreachable!()
+            };
+            let upper = groups
+                .iter()
+                .map(|[start, len]| add(&options.gap, ts[(start + len - 1) as usize], tz.as_ref()))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            let upper = Int64Chunked::from_vec(PlSmallStr::from_static(UP_NAME), upper)
+                .into_datetime(tu, dt.time_zone().clone())
+                .into_column()
+                .cast(time_type)?;
+            bounds.push(time_key.clone().with_name(PlSmallStr::from_static(LB_NAME)));
+            bounds.push(upper);
+        }
+
+        Ok((time_key, bounds, groups.into_sliceable()))
+    }
+
     /// Returns: time_keys, keys, groupsproxy
     fn impl_rolling(
         &self,
@@ -496,6 +633,89 @@ impl Wrap<&DataFrame> {
     }
 }
 
+fn duration_add(tu: TimeUnit) -> fn(&Duration, i64, Option<&Tz>) -> PolarsResult<i64> {
+    match tu {
+        TimeUnit::Nanoseconds => Duration::add_ns,
+        TimeUnit::Microseconds => Duration::add_us,
+        TimeUnit::Milliseconds => Duration::add_ms,
+    }
+}
+
+/// Splits sorted `time` values into sessions. A new session starts whenever a value is
+/// more than `gap` after its predecessor.
+fn group_by_sessions(
+    time: &[i64],
+    offset: IdxSize,
+    gap: Duration,
+    tu: TimeUnit,
+    tz: Option<&Tz>,
+) -> PolarsResult<Vec<[IdxSize; 2]>> {
+    let add = duration_add(tu);
+
+    let mut groups = Vec::new();
+    let mut start = 0;
+    for i in 1..time.len() {
+        if time[i] > add(&gap, time[i - 1], tz)? {
+            groups.push([start as IdxSize + offset, (i - start) as IdxSize]);
+            start = i;
+        }
+    }
+    if !time.is_empty() {
+        groups.push([start as IdxSize + offset, (time.len() - start) as IdxSize]);
+    }
+    Ok(groups)
+}
+
+/// The bounds of the rolling window of every value in `time`, i.e. `time + offset` and
+/// `time + offset + period`, in the dtype of `time`.
+pub fn rolling_bounds(
+    time: &Column,
+    offset: Duration,
+    period: Duration,
+) -> PolarsResult<(Column, Column)> {
+    let time_type = time.dtype();
+    let (dt, tu, tz) = match time_type {
+        DataType::Datetime(tu, tz) => (time.clone(), *tu, tz.clone()),
+        DataType::Date => (
+            time.cast(&DataType::Datetime(TimeUnit::Microseconds, None))?,
+            TimeUnit::Microseconds,
+            None,
+        ),
+        DataType::UInt32 | DataType::UInt64 | DataType::Int32 | DataType::Int64 => (
+            time.cast(&DataType::Int64)?
+                .cast(&DataType::Datetime(TimeUnit::Nanoseconds, None))?,
+            TimeUnit::Nanoseconds,
+            None,
+        ),
+        dt => polars_bail!(
+            ComputeError:
+            "expected any of the following dtypes: {{ Date, Datetime, Int32, Int64, UInt32, UInt64 }}, got {}",
+            dt
+        ),
+    };
+    #[cfg(feature = "timezones")]
+    let parsed_tz = tz.as_ref().and_then(|tz| tz.parse::<Tz>().ok());
+    #[cfg(not(feature = "timezones"))]
+    let parsed_tz: Option<Tz> = None;
+
+    let add = duration_add(tu);
+    let lower: Int64Chunked = dt
+        .datetime()
+        .unwrap()
+        .physical()
+        .try_apply_nonnull_values_generic(|t| add(&offset, t, parsed_tz.as_ref()))?;
+    let upper: Int64Chunked =
+        lower.try_apply_nonnull_values_generic(|t| add(&period, t, parsed_tz.as_ref()))?;
+    let to_time_type = |bound: Int64Chunked| {
+        let bound = bound.into_datetime(tu, tz.clone()).into_column();
+        match time_type {
+            DataType::Datetime(_, _) | DataType::Date => bound.cast(time_type),
+            _ => bound.cast(&DataType::Int64)?.cast(time_type),
+        }
+    };
+    Ok((to_time_type(lower)?, to_time_type(upper)?))
+}
+
 #[cfg(test)]
 mod test {
     use polars_compute::rolling::QuantileMethod;
@@ -638,4 +858,44 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_group_by_session() -> PolarsResult<()> {
+        let t = Column::new("t".into(), [1i64, 2, 4, 10, 11, 20]);
+        let a = Column::new("a".into(), [1, 2, 3, 4, 5, 6]);
+        let df = DataFrame::new(vec![t, a.clone()])?;
+
+        let (time_key, bounds, groups) = df.group_by_session(
+            None,
+            &SessionGroupOptions {
+                index_column: "t".into(),
+                gap: Duration::parse("2i"),
+                include_boundaries: true,
+            },
+        )?;
+
+        assert_eq!(time_key, Column::new("t".into(), [1i64, 10, 20]));
+        assert_eq!(
+            bounds,
+            [
+                Column::new(LB_NAME.into(), [1i64, 10, 20]),
+                Column::new(UP_NAME.into(), [6i64, 13, 22]),
+            ]
+        );
+        let sum = unsafe { a.agg_sum(&groups) };
+        assert_eq!(sum, Column::new("a".into(), [6, 9, 6]));
+
+        // A gap equal to the difference keeps the values in one session.
+        let (time_key, _, _) = df.group_by_session(
+            None,
+            &SessionGroupOptions {
+                index_column: "t".into(),
+                gap: Duration::parse("6i"),
+                include_boundaries: false,
+            },
+        )?;
+        assert_eq!(time_key, Column::new("t".into(), [1i64, 20]));
+
+        Ok(())
+    }
 }
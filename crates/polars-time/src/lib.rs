@@ -10,6 +10,8 @@ pub mod chunkedarray;
 mod date_range;
 #[cfg(feature = "timezones")]
 mod dst_offset;
+#[cfg(feature = "fiscal")]
+mod fiscal;
 mod group_by;
 #[cfg(feature = "month_end")]
 mod month_end;
@@ -21,6 +23,8 @@ pub mod prelude;
 #[cfg(any(feature = "dtype-date", feature = "dtype-datetime"))]
 pub mod replace;
 mod round;
+#[cfg(feature = "rrule")]
+mod rrule;
 pub mod series;
 mod truncate;
 mod upsample;
@@ -32,6 +36,8 @@ pub use base_utc_offset::*;
 pub use date_range::*;
 #[cfg(feature = "timezones")]
 pub use dst_offset::*;
+#[cfg(feature = "fiscal")]
+pub use fiscal::*;
 #[cfg(any(feature = "dtype-date", feature = "dtype-datetime"))]
 pub use group_by::dynamic::*;
 #[cfg(feature = "month_end")]
@@ -43,11 +49,13 @@ pub use offset_by::*;
 #[cfg(any(feature = "dtype-date", feature = "dtype-datetime"))]
 pub use replace::*;
 pub use round::*;
+#[cfg(feature = "rrule")]
+pub use rrule::*;
 #[cfg(feature = "dtype-date")]
 pub use truncate::*;
 pub use upsample::*;
 #[cfg(feature = "timezones")]
 pub use utils::known_timezones;
-pub use windows::duration::Duration;
+pub use windows::duration::{Duration, DurationCalendars};
 pub use windows::group_by::ClosedWindow;
 pub use windows::window::Window;
@@ -3,12 +3,13 @@ use polars_core::prelude::arity::broadcast_try_binary_elementwise;
 use polars_core::prelude::*;
 use polars_core::series::IsSorted;
 
-use crate::Duration;
+use crate::{Duration, DurationCalendars};
 
 fn apply_offsets_to_datetime(
     datetime: &Logical<DatetimeType, Int64Type>,
     offsets: &StringChunked,
     time_zone: Option<&Tz>,
+    calendars: &DurationCalendars,
 ) -> PolarsResult<Int64Chunked> {
     match offsets.len() {
         1 => match offsets.get(0) {
@@ -27,29 +28,30 @@ fn apply_offsets_to_datetime(
                     Ok(datetime.phys.clone().wrapping_add_scalar(duration))
                 } else {
                     let offset_fn = match datetime.time_unit() {
-                        TimeUnit::Milliseconds => Duration::add_ms,
-                        TimeUnit::Microseconds => Duration::add_us,
-                        TimeUnit::Nanoseconds => Duration::add_ns,
+                        TimeUnit::Milliseconds => Duration::add_ms_in,
+                        TimeUnit::Microseconds => Duration::add_us_in,
+                        TimeUnit::Nanoseconds => Duration::add_ns_in,
                     };
-                    datetime
-                        .phys
-                        .try_apply_nonnull_values_generic(|v| offset_fn(offset, v, time_zone))
+                    datetime.phys.try_apply_nonnull_values_generic(|v| {
+                        offset_fn(offset, v, time_zone, calendars)
+                    })
                 }
             },
             _ => Ok(datetime.phys.apply(|_| None)),
         },
         _ => {
             let offset_fn = match datetime.time_unit() {
-                TimeUnit::Milliseconds => Duration::add_ms,
-                TimeUnit::Microseconds => Duration::add_us,
-                TimeUnit::Nanoseconds => Duration::add_ns,
+                TimeUnit::Milliseconds => Duration::add_ms_in,
+                TimeUnit::Microseconds => Duration::add_us_in,
+                TimeUnit::Nanoseconds => Duration::add_ns_in,
             };
             broadcast_try_binary_elementwise(
                 datetime.physical(),
                 offsets,
                 |timestamp_opt, offset_opt| match (timestamp_opt, offset_opt) {
                     (Some(timestamp), Some(offset)) => {
-                        offset_fn(&Duration::try_parse(offset)?, timestamp, time_zone).map(Some)
+                        let offset = Duration::try_parse(offset)?;
+                        offset_fn(&offset, timestamp, time_zone, calendars).map(Some)
                     },
                     _ => Ok(None),
                 },
@@ -58,7 +60,82 @@ fn apply_offsets_to_datetime(
     }
 }
 
-pub fn impl_offset_by(ts: &Series, offsets: &Series) -> PolarsResult<Series> {
+#[cfg(feature = "dtype-interval")]
+fn apply_intervals_to_datetime(
+    datetime: &Logical<DatetimeType, Int64Type>,
+    intervals: &IntervalChunked,
+    time_zone: Option<&Tz>,
+) -> PolarsResult<Int64Chunked> {
+    let offset_fn = match datetime.time_unit() {
+        TimeUnit::Milliseconds => Duration::add_ms,
+        TimeUnit::Microseconds => Duration::add_us,
+        TimeUnit::Nanoseconds => Duration::add_ns,
+    };
+    broadcast_try_binary_elementwise(
+        datetime.physical(),
+        intervals.physical(),
+        |timestamp_opt, interval_opt| match (timestamp_opt, interval_opt) {
+            (Some(timestamp), Some(interval)) => {
+                Duration::from_interval(interval_from_physical(interval))
+                    .iter()
+                    .filter(|offset| !offset.is_zero())
+                    .try_fold(timestamp, |t, offset| offset_fn(offset, t, time_zone))
+                    .map(Some)
+            },
+            _ => Ok(None),
+        },
+    )
+}
+
+/// Offset a `Date` or `Datetime` Series by an `Interval` Series; the months are added first,
+/// followed by the (calendar) days and then the fixed part of each interval.
+#[cfg(feature = "dtype-interval")]
+fn impl_offset_by_interval(ts: &Series, intervals: &IntervalChunked) -> PolarsResult<Series> {
+    let mut out = match ts.dtype() {
+        DataType::Date => {
+            let ts = ts.cast(&DataType::Datetime(TimeUnit::Microseconds, None))?;
+            let out = apply_intervals_to_datetime(ts.datetime().unwrap(), intervals, None)?;
+            out.into_datetime(TimeUnit::Microseconds, None)
+                .cast(&DataType::Date)?
+        },
+        DataType::Datetime(tu, tz) => {
+            let datetime = ts.datetime().unwrap();
+            let out = match tz {
+                #[cfg(feature = "timezones")]
+                Some(tz) => apply_intervals_to_datetime(
+                    datetime,
+                    intervals,
+                    tz.parse::<Tz>().ok().as_ref(),
+                )?,
+                _ => apply_intervals_to_datetime(datetime, intervals, None)?,
+            };
+            out.into_datetime(*tu, tz.clone()).into_series()
+        },
+        dt => polars_bail!(
+            ComputeError: "cannot use 'offset_by' on Series of datatype {}", dt,
+        ),
+    };
+    out.set_sorted_flag(IsSorted::Not);
+    Ok(out)
+}
+
+/// Offset a `Date` or `Datetime` Series by a String Series of durations, counting business days and
+/// fiscal periods in `calendars`.
+pub fn impl_offset_by(
+    ts: &Series,
+    offsets: &Series,
+    calendars: &DurationCalendars,
+) -> PolarsResult<Series> {
+    #[cfg(feature = "dtype-interval")]
+    if let Ok(intervals) = offsets.interval() {
+        polars_ensure!(
+            ts.len() == intervals.len() || intervals.len() == 1 || ts.len() == 1,
+            length_mismatch = "dt.offset_by",
+            ts.len(),
+            intervals.len()
+        );
+        return impl_offset_by_interval(ts, intervals);
+    }
     let offsets = offsets.str()?;
 
     polars_ensure!(
@@ -95,7 +172,7 @@ pub fn impl_offset_by(ts: &Series, offsets: &Series) -> PolarsResult<Series> {
                 .cast(&DataType::Datetime(TimeUnit::Microseconds, None))
                 .unwrap();
             let datetime = ts.datetime().unwrap();
-            let out = apply_offsets_to_datetime(datetime, offsets, None)?;
+            let out = apply_offsets_to_datetime(datetime, offsets, None, calendars)?;
             out.cast(&DataType::Datetime(TimeUnit::Microseconds, None))
                 .unwrap()
                 .cast(&DataType::Date)
@@ -105,10 +182,13 @@ pub fn impl_offset_by(ts: &Series, offsets: &Series) -> PolarsResult<Series> {
 
             let out = match tz {
                 #[cfg(feature = "timezones")]
-                Some(tz) => {
-                    apply_offsets_to_datetime(datetime, offsets, tz.parse::<Tz>().ok().as_ref())?
-                },
-                _ => apply_offsets_to_datetime(datetime, offsets, None)?,
+                Some(tz) => apply_offsets_to_datetime(
+                    datetime,
+                    offsets,
+                    tz.parse::<Tz>().ok().as_ref(),
+                    calendars,
+                )?,
+                _ => apply_offsets_to_datetime(datetime, offsets, None, calendars)?,
             };
             out.cast(&DataType::Datetime(*tu, tz.clone()))
         },
@@ -31,9 +31,12 @@ impl PolarsRound for DatetimeChunked {
                     polars_bail!(ComputeError: "cannot round a Datetime to a negative duration")
                 }
                 if (time_zone.is_none() || time_zone == &Some(TimeZone::UTC))
-                    && (every_parsed.months() == 0 && every_parsed.weeks() == 0)
+                    && (every_parsed.months() == 0
+                        && every_parsed.weeks() == 0
+                        && every_parsed.business_days() == 0
+                        && every_parsed.fiscal_periods() == 0)
                 {
-                    // ... yes we can! Weeks, months, and time zones require extra logic.
+                    // ... yes we can! Weeks, months, calendar units, and time zones require extra logic.
                     // But in this simple case, it's just simple integer arithmetic.
                     let every = match self.time_unit() {
                         TimeUnit::Milliseconds => every_parsed.duration_ms(),
@@ -0,0 +1,524 @@
+//! Recurrence rules as defined in RFC 5545, e.g. `FREQ=MONTHLY;BYDAY=3FR` for every third
+//! Friday of the month.
+use arrow::legacy::time_zone::Tz;
+use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta, Timelike, Weekday};
+#[cfg(feature = "timezones")]
+use chrono::{LocalResult, Offset, TimeZone as _};
+use polars_core::prelude::*;
+use polars_core::series::IsSorted;
+
+// Guards against rules that never (or hardly ever) match, e.g. `FREQ=SECONDLY;BYMONTHDAY=30;BYMONTH=2`.
+const MAX_PERIODS: usize = 10_000_000;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RRuleFrequency {
+    Yearly,
+    Monthly,
+    Weekly,
+    Daily,
+    Hourly,
+    Minutely,
+    Secondly,
+}
+
+/// A parsed RFC 5545 recurrence rule. The start of the recurrence (`DTSTART`) isn't part of the
+/// rule, it is given separately.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RRule {
+    pub freq: RRuleFrequency,
+    pub interval: u32,
+    pub count: Option<usize>,
+    /// The inclusive end of the recurrence, and whether it is in UTC (`Z` suffix) rather than in
+    /// local time.
+    pub until: Option<(NaiveDateTime, bool)>,
+    pub by_month: Vec<u32>,
+    pub by_month_day: Vec<i32>,
+    pub by_year_day: Vec<i32>,
+    /// Weekdays, optionally restricted to their n-th (or n-th last, if negative) occurrence
+    /// within the month or year.
+    pub by_day: Vec<(Option<i32>, Weekday)>,
+    pub by_hour: Vec<u32>,
+    pub by_minute: Vec<u32>,
+    pub by_second: Vec<u32>,
+    pub by_set_pos: Vec<i32>,
+    pub week_start: Weekday,
+}
+
+fn parse_weekday(s: &str) -> Option<Weekday> {
+    Some(match s {
+        "MO" => Weekday::Mon,
+        "TU" => Weekday::Tue,
+        "WE" => Weekday::Wed,
+        "TH" => Weekday::Thu,
+        "FR" => Weekday::Fri,
+        "SA" => Weekday::Sat,
+        "SU" => Weekday::Sun,
+        _ => return None,
+    })
+}
+
+fn parse_until(s: &str) -> Option<(NaiveDateTime, bool)> {
+    let (s, utc) = match s.strip_suffix('Z') {
+        Some(s) => (s, true),
+        None => (s, false),
+    };
+    let ndt = match s.len() {
+        8 => NaiveDate::parse_from_str(s, "%Y%m%d")
+            .ok()?
+            .and_time(NaiveTime::MIN),
+        15 => NaiveDateTime::parse_from_str(s, "%Y%m%dT%H%M%S").ok()?,
+        _ => return None,
+    };
+    Some((ndt, utc))
+}
+
+/// Parse a comma-separated list of integers, each of which must satisfy `valid`.
+fn parse_list<T: std::str::FromStr + Ord>(s: &str, valid: impl Fn(&T) -> bool) -> Option<Vec<T>> {
+    let mut out = s
+        .split(',')
+        .map(|v| v.parse::<T>().ok().filter(&valid))
+        .collect::<Option<Vec<_>>>()?;
+    out.sort();
+    out.dedup();
+    Some(out)
+}
+
+impl RRule {
+    /// Parse a recurrence rule such as `"FREQ=MONTHLY;INTERVAL=3;BYDAY=1MO"`, optionally
+    /// prefixed with `"RRULE:"`.
+    ///
+    /// All rule parts except `BYWEEKNO` are supported.
+    pub fn parse(rule: &str) -> PolarsResult<Self> {
+        let invalid = |reason: &str| polars_err!(InvalidOperation: "invalid recurrence rule '{}': {}", rule, reason);
+        let body = rule.trim();
+        let body = body.strip_prefix("RRULE:").unwrap_or(body);
+
+        let mut freq = None;
+        let mut out = RRule {
+            freq: RRuleFrequency::Daily,
+            interval: 1,
+            count: None,
+            until: None,
+            by_month: vec![],
+            by_month_day: vec![],
+            by_year_day: vec![],
+            by_day: vec![],
+            by_hour: vec![],
+            by_minute: vec![],
+            by_second: vec![],
+            by_set_pos: vec![],
+            week_start: Weekday::Mon,
+        };
+        for part in body.split(';').filter(|part| !part.is_empty()) {
+            let (key, value) = part
+                .split_once('=')
+                .ok_or_else(|| invalid(&format!("expected KEY=VALUE, got '{part}'")))?;
+            let value = value.to_ascii_uppercase();
+            let bad_value = || invalid(&format!("invalid value for {key}: '{value}'"));
+            let nonzero_within = |max: i32| move |v: &i32| *v != 0 && v.abs() <= max;
+            match key.to_ascii_uppercase().as_str() {
+                "FREQ" => {
+                    freq = Some(match value.as_str() {
+                        "YEARLY" => RRuleFrequency::Yearly,
+                        "MONTHLY" => RRuleFrequency::Monthly,
+                        "WEEKLY" => RRuleFrequency::Weekly,
+                        "DAILY" => RRuleFrequency::Daily,
+                        "HOURLY" => RRuleFrequency::Hourly,
+                        "MINUTELY" => RRuleFrequency::Minutely,
+                        "SECONDLY" => RRuleFrequency::Secondly,
+                        _ => return Err(bad_value()),
+                    })
+                },
+                "INTERVAL" => {
+                    out.interval = value
+                        .parse()
+                        .ok()
+                        .filter(|v| *v > 0)
+                        .ok_or_else(bad_value)?
+                },
+                "COUNT" => out.count = Some(value.parse().map_err(|_| bad_value())?),
+                "UNTIL" => out.until = Some(parse_until(&value).ok_or_else(bad_value)?),
+                "BYMONTH" => {
+                    out.by_month =
+                        parse_list(&value, |v| (1..=12).contains(v)).ok_or_else(bad_value)?
+                },
+                "BYMONTHDAY" => {
+                    out.by_month_day =
+                        parse_list(&value, nonzero_within(31)).ok_or_else(bad_value)?
+                },
+                "BYYEARDAY" => {
+                    out.by_year_day =
+                        parse_list(&value, nonzero_within(366)).ok_or_else(bad_value)?
+                },
+                "BYDAY" => {
+                    out.by_day = value
+                        .split(',')
+                        .map(|v| {
+                            let (n, day) = v.split_at(v.len().checked_sub(2)?);
+                            let n = match n {
+                                "" => None,
+                                n => Some(n.parse::<i32>().ok().filter(nonzero_within(53))?),
+                            };
+                            Some((n, parse_weekday(day)?))
+                        })
+                        .collect::<Option<_>>()
+                        .ok_or_else(bad_value)?
+                },
+                "BYHOUR" => out.by_hour = parse_list(&value, |v| *v < 24).ok_or_else(bad_value)?,
+                "BYMINUTE" => {
+                    out.by_minute = parse_list(&value, |v| *v < 60).ok_or_else(bad_value)?
+                },
+                "BYSECOND" => {
+                    out.by_second = parse_list(&value, |v| *v < 60).ok_or_else(bad_value)?
+                },
+                "BYSETPOS" => {
+                    out.by_set_pos =
+                        parse_list(&value, nonzero_within(366)).ok_or_else(bad_value)?
+                },
+                "WKST" => out.week_start = parse_weekday(&value).ok_or_else(bad_value)?,
+                "BYWEEKNO" => return Err(invalid("BYWEEKNO is not supported")),
+                _ => return Err(invalid(&format!("unknown rule part '{key}'"))),
+            }
+        }
+        out.freq = freq.ok_or_else(|| invalid("FREQ is required"))?;
+
+        use RRuleFrequency::*;
+        polars_ensure!(
+            out.count.is_none() || out.until.is_none(),
+            InvalidOperation: "invalid recurrence rule '{}': COUNT and UNTIL cannot both be set", rule
+        );
+        polars_ensure!(
+            matches!(out.freq, Yearly | Monthly) || out.by_day.iter().all(|(n, _)| n.is_none()),
+            InvalidOperation: "invalid recurrence rule '{}': BYDAY can only have an occurrence (e.g. 3FR) with FREQ=MONTHLY or FREQ=YEARLY", rule
+        );
+        polars_ensure!(
+            !matches!(out.freq, Monthly | Weekly | Daily) || out.by_year_day.is_empty(),
+            InvalidOperation: "invalid recurrence rule '{}': BYYEARDAY cannot be used with FREQ=MONTHLY, FREQ=WEEKLY or FREQ=DAILY", rule
+        );
+        polars_ensure!(
+            out.freq != Weekly || out.by_month_day.is_empty(),
+            InvalidOperation: "invalid recurrence rule '{}': BYMONTHDAY cannot be used with FREQ=WEEKLY", rule
+        );
+        Ok(out)
+    }
+
+    /// Whether the occurrences can be represented as dates, i.e. they are all at midnight.
+    pub fn is_daily_or_coarser(&self) -> bool {
+        self.freq <= RRuleFrequency::Daily
+            && self.by_hour.is_empty()
+            && self.by_minute.is_empty()
+            && self.by_second.is_empty()
+    }
+
+    /// The dates and (for frequencies finer than daily) the instant of the `k`-th period.
+    fn period(&self, start: NaiveDateTime, k: i64) -> Option<(Vec<NaiveDate>, NaiveDateTime)> {
+        let steps = k.checked_mul(self.interval as i64)?;
+        let date = start.date();
+        let days = |first: NaiveDate, n: u64| first.iter_days().take(n as usize).collect();
+        Some(match self.freq {
+            RRuleFrequency::Yearly => {
+                let year = i32::try_from(date.year() as i64 + steps).ok()?;
+                let first = NaiveDate::from_ymd_opt(year, 1, 1)?;
+                let n = NaiveDate::from_ymd_opt(year + 1, 1, 1)?.signed_duration_since(first);
+                (
+                    days(first, n.num_days() as u64),
+                    first.and_time(NaiveTime::MIN),
+                )
+            },
+            RRuleFrequency::Monthly => {
+                let months = date.year() as i64 * 12 + date.month0() as i64 + steps;
+                let year = i32::try_from(months.div_euclid(12)).ok()?;
+                let month = months.rem_euclid(12) as u32 + 1;
+                let first = NaiveDate::from_ymd_opt(year, month, 1)?;
+                let next = first.checked_add_months(chrono::Months::new(1))?;
+                let n = next.signed_duration_since(first).num_days();
+                (days(first, n as u64), first.and_time(NaiveTime::MIN))
+            },
+            RRuleFrequency::Weekly => {
+                let offset = (date.weekday().num_days_from_monday() + 7
+                    - self.week_start.num_days_from_monday())
+                    % 7;
+                let first = date
+                    .checked_sub_signed(TimeDelta::days(offset as i64))?
+                    .checked_add_signed(TimeDelta::try_weeks(steps)?)?;
+                (days(first, 7), first.and_time(NaiveTime::MIN))
+            },
+            RRuleFrequency::Daily => {
+                let day = date.checked_add_signed(TimeDelta::try_days(steps)?)?;
+                (vec![day], day.and_time(NaiveTime::MIN))
+            },
+            RRuleFrequency::Hourly | RRuleFrequency::Minutely | RRuleFrequency::Secondly => {
+                let (truncated, unit) = match self.freq {
+                    RRuleFrequency::Hourly => (
+                        start.date().and_hms_opt(start.hour(), 0, 0)?,
+                        TimeDelta::try_hours(steps)?,
+                    ),
+                    RRuleFrequency::Minutely => (
+                        start.date().and_hms_opt(start.hour(), start.minute(), 0)?,
+                        TimeDelta::try_minutes(steps)?,
+                    ),
+                    _ => (start.with_nanosecond(0)?, TimeDelta::try_seconds(steps)?),
+                };
+                let instant = truncated.checked_add_signed(unit)?;
+                (vec![instant.date()], instant)
+            },
+        })
+    }
+
+    /// The occurrences from `start` (`DTSTART`) on, up to and including `end`, in local time.
+    pub fn occurrences(
+        &self,
+        start: NaiveDateTime,
+        end: Option<NaiveDateTime>,
+    ) -> PolarsResult<Vec<NaiveDateTime>> {
+        polars_ensure!(
+            self.count.is_some() || end.is_some(),
+            InvalidOperation: "a recurrence rule needs COUNT or UNTIL, or an end"
+        );
+        use RRuleFrequency::*;
+
+        // Without any day rules, the day of the start is repeated.
+        let mut by_month = self.by_month.clone();
+        let mut by_month_day = self.by_month_day.clone();
+        let mut by_day = self.by_day.clone();
+        if self.by_month_day.is_empty() && self.by_year_day.is_empty() && self.by_day.is_empty() {
+            match self.freq {
+                Yearly => {
+                    if by_month.is_empty() {
+                        by_month = vec![start.month()];
+                    }
+                    by_month_day = vec![start.day() as i32];
+                },
+                Monthly => by_month_day = vec![start.day() as i32],
+                Weekly => by_day = vec![(None, start.weekday())],
+                _ => {},
+            }
+        }
+        // Occurrences in BYDAY count within the month, unless the rule is yearly without months.
+        let nth_in_month = self.freq == Monthly || !self.by_month.is_empty();
+
+        let matches_day = |date: &NaiveDate| {
+            let (day, month_length) = (date.day() as i32, days_in_month(date));
+            let (year_day, year_length) = (date.ordinal() as i32, days_in_year(date));
+            let (nth, length) = if nth_in_month {
+                (day, month_length)
+            } else {
+                (year_day, year_length)
+            };
+            (by_month.is_empty() || by_month.contains(&date.month()))
+                && (by_month_day.is_empty()
+                    || by_month_day
+                        .iter()
+                        .any(|&d| d == day || d == day - month_length - 1))
+                && (self.by_year_day.is_empty()
+                    || self
+                        .by_year_day
+                        .iter()
+                        .any(|&d| d == year_day || d == year_day - year_length - 1))
+                && (by_day.is_empty()
+                    || by_day.iter().any(|&(n, weekday)| {
+                        weekday == date.weekday()
+                            && n.is_none_or(|n| {
+                                n == (nth - 1) / 7 + 1 || n == -((length - nth) / 7 + 1)
+                            })
+                    }))
+        };
+        // Time parts coarser than the frequency are fixed by the period and only filtered,
+        // finer ones are expanded.
+        let time_part = |by: &[u32], default: u32, value: u32, fixed: bool| -> Vec<u32> {
+            match (fixed, by.is_empty()) {
+                (true, true) => vec![value],
+                (true, false) => by.iter().copied().filter(|&v| v == value).collect(),
+                (false, true) => vec![default],
+                (false, false) => by.to_vec(),
+            }
+        };
+
+        let mut out = Vec::new();
+        for k in 0.. {
+            polars_ensure!(
+                k < MAX_PERIODS as i64,
+                ComputeError: "recurrence rule did not produce enough occurrences"
+            );
+            let Some((dates, instant)) = self.period(start, k) else {
+                break;
+            };
+            if end.is_some_and(|end| instant.date() > end.date()) {
+                break;
+            }
+            let hours = time_part(
+                &self.by_hour,
+                start.hour(),
+                instant.hour(),
+                self.freq > Daily,
+            );
+            let minutes = time_part(
+                &self.by_minute,
+                start.minute(),
+                instant.minute(),
+                self.freq > Hourly,
+            );
+            let seconds = time_part(
+                &self.by_second,
+                start.second(),
+                instant.second(),
+                self.freq > Minutely,
+            );
+
+            let mut candidates = Vec::new();
+            for date in dates.iter().filter(|date| matches_day(date)) {
+                for &h in &hours {
+                    for &m in &minutes {
+                        for &s in &seconds {
+                            candidates.extend(date.and_hms_opt(h, m, s));
+                        }
+                    }
+                }
+            }
+            if !self.by_set_pos.is_empty() {
+                let n = candidates.len() as i32;
+                let mut selected: Vec<_> = self
+                    .by_set_pos
+                    .iter()
+                    .filter_map(|&pos| {
+                        let idx = if pos > 0 { pos - 1 } else { n + pos };
+                        (0..n).contains(&idx).then(|| candidates[idx as usize])
+                    })
+                    .collect();
+                selected.sort();
+                selected.dedup();
+                candidates = selected;
+            }
+
+            for candidate in candidates {
+                if candidate < start {
+                    continue;
+                }
+                if end.is_some_and(|end| candidate > end) {
+                    return Ok(out);
+                }
+                out.push(candidate);
+                if self.count == Some(out.len()) {
+                    return Ok(out);
+                }
+            }
+        }
+        Ok(out)
+    }
+}
+
+fn days_in_month(date: &NaiveDate) -> i32 {
+    let first = date.with_day(1).unwrap();
+    first
+        .checked_add_months(chrono::Months::new(1))
+        .map_or(31, |next| {
+            next.signed_duration_since(first).num_days() as i32
+        })
+}
+
+fn days_in_year(date: &NaiveDate) -> i32 {
+    if date.leap_year() { 366 } else { 365 }
+}
+
+/// Convert a local time to UTC the way RFC 5545 prescribes: ambiguous times resolve to the
+/// first occurrence, and times in a gap use the UTC offset from before the gap.
+#[cfg(feature = "timezones")]
+fn local_to_utc(ndt: NaiveDateTime, tz: &Tz) -> NaiveDateTime {
+    match tz.from_local_datetime(&ndt) {
+        LocalResult::Single(dt) => dt.naive_utc(),
+        LocalResult::Ambiguous(earliest, _) => earliest.naive_utc(),
+        LocalResult::None => {
+            let before = tz.offset_from_utc_datetime(&(ndt - TimeDelta::days(1)));
+            ndt - TimeDelta::seconds(before.fix().local_minus_utc() as i64)
+        },
+    }
+}
+
+/// Create a [`DatetimeChunked`] with the occurrences of a recurrence rule, from `start` up to
+/// and including `end` (both in local time).
+///
+/// The rule is evaluated in the local time of `tz`, so occurrences keep their wall time across
+/// daylight saving time transitions.
+pub fn rrule_range_impl(
+    name: PlSmallStr,
+    rule: &RRule,
+    start: NaiveDateTime,
+    end: Option<NaiveDateTime>,
+    tu: TimeUnit,
+    tz: Option<&Tz>,
+) -> PolarsResult<DatetimeChunked> {
+    let until = rule.until.map(|(until, _utc)| match tz {
+        #[cfg(feature = "timezones")]
+        Some(tz) if _utc => tz.from_utc_datetime(&until).naive_local(),
+        _ => until,
+    });
+    let end = match (end, until) {
+        (Some(end), Some(until)) => Some(end.min(until)),
+        (end, until) => end.or(until),
+    };
+    let occurrences = rule.occurrences(start, end)?;
+
+    let func = match tu {
+        TimeUnit::Nanoseconds => datetime_to_timestamp_ns,
+        TimeUnit::Microseconds => datetime_to_timestamp_us,
+        TimeUnit::Milliseconds => datetime_to_timestamp_ms,
+    };
+    let values = occurrences.into_iter().map(|ndt| match tz {
+        #[cfg(feature = "timezones")]
+        Some(tz) => func(local_to_utc(ndt, tz)),
+        _ => func(ndt),
+    });
+    let out = Int64Chunked::from_iter_values(name, values);
+    let mut out = match tz {
+        #[cfg(feature = "timezones")]
+        Some(tz) => out.into_datetime(tu, Some(TimeZone::from_chrono(tz))),
+        _ => out.into_datetime(tu, None),
+    };
+    out.physical_mut().set_sorted_flag(IsSorted::Ascending);
+    Ok(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn dt(d: u32, h: u32, m: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2024, 1, d)
+            .unwrap()
+            .and_hms_opt(h, m, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_occurrences() {
+        let rule = RRule::parse("FREQ=WEEKLY;BYDAY=TU,TH;BYHOUR=9,17;COUNT=5").unwrap();
+        assert_eq!(
+            rule.occurrences(dt(1, 9, 0), None).unwrap(),
+            [
+                dt(2, 9, 0),
+                dt(2, 17, 0),
+                dt(4, 9, 0),
+                dt(4, 17, 0),
+                dt(9, 9, 0)
+            ]
+        );
+
+        let rule = RRule::parse("FREQ=MINUTELY;INTERVAL=90;BYHOUR=9,10,11;COUNT=3").unwrap();
+        assert_eq!(
+            rule.occurrences(dt(1, 9, 0), None).unwrap(),
+            [dt(1, 9, 0), dt(1, 10, 30), dt(2, 9, 0)]
+        );
+
+        let rule = RRule::parse("FREQ=MONTHLY;BYMONTHDAY=-1").unwrap();
+        let end = NaiveDate::from_ymd_opt(2024, 3, 31)
+            .unwrap()
+            .and_time(NaiveTime::MIN);
+        let days: Vec<_> = rule
+            .occurrences(dt(15, 0, 0), Some(end))
+            .unwrap()
+            .iter()
+            .map(|ndt| (ndt.month(), ndt.day()))
+            .collect();
+        assert_eq!(days, [(1, 31), (2, 29), (3, 31)]);
+    }
+}
@@ -291,6 +291,10 @@ pub trait TemporalMethods: AsSeries {
             DataType::Duration(_) => s
                 .duration()
                 .map(|ca| Ok(ca.to_string(format)?.into_series()))?,
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => s
+                .interval()
+                .map(|ca| Ok(ca.to_string(format)?.into_series()))?,
             dt => polars_bail!(opq = to_string, dt),
         }
     }
@@ -7,7 +7,14 @@ use polars_utils::cache::LruCache;
 use crate::prelude::*;
 
 pub trait PolarsTruncate {
-    fn truncate(&self, tz: Option<&Tz>, every: &StringChunked) -> PolarsResult<Self>
+    /// Truncate to the windows of `every`, counting business days and fiscal periods in
+    /// `calendars`.
+    fn truncate(
+        &self,
+        tz: Option<&Tz>,
+        every: &StringChunked,
+        calendars: &DurationCalendars,
+    ) -> PolarsResult<Self>
     where
         Self: Sized;
 }
@@ -19,7 +26,12 @@ pub(crate) fn fast_truncate(t: i64, every: i64) -> i64 {
 }
 
 impl PolarsTruncate for DatetimeChunked {
-    fn truncate(&self, tz: Option<&Tz>, every: &StringChunked) -> PolarsResult<Self> {
+    fn truncate(
+        &self,
+        tz: Option<&Tz>,
+        every: &StringChunked,
+        calendars: &DurationCalendars,
+    ) -> PolarsResult<Self> {
         polars_ensure!(
             self.len() == every.len() || self.len() == 1 || every.len() == 1,
             length_mismatch = "dt.truncate",
@@ -38,9 +50,12 @@ impl PolarsTruncate for DatetimeChunked {
                     polars_bail!(ComputeError: "cannot truncate a Datetime to a negative duration")
                 }
                 if (time_zone.is_none() || time_zone.as_ref() == Some(&TimeZone::UTC))
-                    && (every_parsed.months() == 0 && every_parsed.weeks() == 0)
+                    && (every_parsed.months() == 0
+                        && every_parsed.weeks() == 0
+                        && every_parsed.business_days() == 0
+                        && every_parsed.fiscal_periods() == 0)
                 {
-                    // ... yes we can! Weeks, months, and time zones require extra logic.
+                    // ... yes we can! Weeks, months, calendar units, and time zones require extra logic.
                     // But in this simple case, it's just simple integer arithmetic.
                     let every = match self.time_unit() {
                         TimeUnit::Milliseconds => every_parsed.duration_ms(),
@@ -52,7 +67,8 @@ impl PolarsTruncate for DatetimeChunked {
                         .apply_values(|t| fast_truncate(t, every))
                         .into_datetime(self.time_unit(), time_zone.clone()));
                 } else {
-                    let w = Window::new(every_parsed, every_parsed, offset);
+                    let w = Window::new(every_parsed, every_parsed, offset)
+                        .with_calendars(calendars.clone());
                     let out = match self.time_unit() {
                         TimeUnit::Milliseconds => self
                             .physical()
@@ -76,9 +92,9 @@ impl PolarsTruncate for DatetimeChunked {
         let mut duration_cache = LruCache::with_capacity((every.len() as f64).sqrt() as usize);
 
         let func = match self.time_unit() {
-            TimeUnit::Nanoseconds => Window::truncate_ns,
-            TimeUnit::Microseconds => Window::truncate_us,
-            TimeUnit::Milliseconds => Window::truncate_ms,
+            TimeUnit::Nanoseconds => Duration::truncate_ns_in,
+            TimeUnit::Microseconds => Duration::truncate_us_in,
+            TimeUnit::Milliseconds => Duration::truncate_ms_in,
         };
 
         let out = broadcast_try_binary_elementwise(
@@ -92,8 +108,7 @@ impl PolarsTruncate for DatetimeChunked {
                         polars_bail!(ComputeError: "cannot truncate a Datetime to a negative duration")
                     }
 
-                    let w = Window::new(every, every, offset);
-                    func(&w, timestamp, tz).map(Some)
+                    func(&every, timestamp, tz, calendars).map(Some)
                 },
                 _ => Ok(None),
             },
@@ -103,7 +118,12 @@ impl PolarsTruncate for DatetimeChunked {
 }
 
 impl PolarsTruncate for DateChunked {
-    fn truncate(&self, _tz: Option<&Tz>, every: &StringChunked) -> PolarsResult<Self> {
+    fn truncate(
+        &self,
+        _tz: Option<&Tz>,
+        every: &StringChunked,
+        calendars: &DurationCalendars,
+    ) -> PolarsResult<Self> {
         polars_ensure!(
             self.len() == every.len() || self.len() == 1 || every.len() == 1,
             length_mismatch = "dt.truncate",
@@ -119,7 +139,7 @@ impl PolarsTruncate for DateChunked {
                     if every.negative {
                         polars_bail!(ComputeError: "cannot truncate a Date to a negative duration")
                     }
-                    let w = Window::new(every, every, offset);
+                    let w = Window::new(every, every, offset).with_calendars(calendars.clone());
                     self.physical().try_apply_nonnull_values_generic(|t| {
                         Ok((w.truncate_ms(MILLISECONDS_IN_DAY * t as i64, None)?
                             / MILLISECONDS_IN_DAY) as i32)
@@ -140,10 +160,12 @@ impl PolarsTruncate for DateChunked {
                             polars_bail!(ComputeError: "cannot truncate a Date to a negative duration")
                         }
 
-                        let w = Window::new(every, every, offset);
                         Ok(Some(
-                            (w.truncate_ms(MILLISECONDS_IN_DAY * t as i64, None)?
-                                / MILLISECONDS_IN_DAY) as i32,
+                            (every.truncate_ms_in(
+                                MILLISECONDS_IN_DAY * t as i64,
+                                None,
+                                calendars,
+                            )? / MILLISECONDS_IN_DAY) as i32,
                         ))
                     },
                     _ => Ok(None),
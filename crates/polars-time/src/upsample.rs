@@ -208,6 +208,7 @@ fn upsample_single_impl(
                         ClosedWindow::Both,
                         *tu,
                         tz.as_ref(),
+                        &DurationCalendars::default(),
                     )?
                     .into_series()
                     .into_frame();
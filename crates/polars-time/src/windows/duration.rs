@@ -1,6 +1,8 @@
 use std::cmp::Ordering;
 use std::fmt::{Display, Formatter};
 use std::ops::{Mul, Neg};
+#[cfg(feature = "business")]
+use std::sync::{Arc, LazyLock};
 
 #[cfg(feature = "timezones")]
 use arrow::legacy::kernels::{Ambiguous, NonExistent};
@@ -16,12 +18,18 @@ use polars_core::prelude::{
     datetime_to_timestamp_us, polars_bail,
 };
 use polars_error::polars_ensure;
+#[cfg(any(feature = "business", feature = "fiscal"))]
+use polars_error::polars_err;
+#[cfg(feature = "business")]
+use polars_ops::series::BusinessCalendar;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 use super::calendar::{
     NS_DAY, NS_HOUR, NS_MICROSECOND, NS_MILLISECOND, NS_MINUTE, NS_SECOND, NS_WEEK,
 };
+#[cfg(feature = "fiscal")]
+use crate::fiscal::FiscalCalendar;
 #[cfg(feature = "timezones")]
 use crate::utils::{localize_datetime_opt, try_localize_datetime, unlocalize_datetime};
 use crate::windows::calendar::{DAYS_PER_MONTH, is_leap_year};
@@ -38,12 +46,47 @@ pub struct Duration {
     days: i64,
     // the number of nanoseconds for the duration
     nsecs: i64,
+    // the number of business days for the duration, never mixed with the other units
+    business_days: i32,
+    // the number of fiscal periods for the duration, never mixed with the other units
+    fiscal_periods: i32,
     // indicates if the duration is negative
     pub(crate) negative: bool,
     // indicates if an integer string was passed. e.g. "2i"
     pub parsed_int: bool,
 }
 
+/// The calendars that the business days (`bd`) and fiscal periods (`fp`, `fq`, `fy`) of a
+/// [`Duration`] are counted in.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct DurationCalendars {
+    /// The business calendar, Monday to Friday without holidays if `None`.
+    #[cfg(feature = "business")]
+    pub business: Option<Arc<BusinessCalendar>>,
+    /// The fiscal calendar, required by durations with fiscal periods.
+    #[cfg(feature = "fiscal")]
+    pub fiscal: Option<FiscalCalendar>,
+}
+
+impl DurationCalendars {
+    #[cfg(feature = "business")]
+    fn business_calendar(&self) -> &BusinessCalendar {
+        static WEEKDAYS: LazyLock<BusinessCalendar> = LazyLock::new(Default::default);
+        self.business.as_deref().unwrap_or(&WEEKDAYS)
+    }
+
+    #[cfg(feature = "fiscal")]
+    fn fiscal_calendar(&self) -> PolarsResult<&FiscalCalendar> {
+        let calendar = self.fiscal.as_ref().ok_or_else(
+            || polars_err!(InvalidOperation: "fiscal periods require a fiscal calendar; pass it to the operation"),
+        )?;
+        calendar.validate()?;
+        Ok(calendar)
+    }
+}
+
 impl PartialOrd<Self> for Duration {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
@@ -65,6 +108,8 @@ impl Neg for Duration {
             weeks: self.weeks,
             days: self.days,
             nsecs: self.nsecs,
+            business_days: self.business_days,
+            fiscal_periods: self.fiscal_periods,
             negative: !self.negative,
             parsed_int: self.parsed_int,
         }
@@ -88,6 +133,16 @@ impl Display for Duration {
         if self.days > 0 {
             write!(f, "{}d", self.days)?
         }
+        if self.business_days > 0 {
+            write!(f, "{}bd", self.business_days)?
+        }
+        if self.fiscal_periods > 0 {
+            match self.fiscal_periods {
+                n if n % 12 == 0 => write!(f, "{}fy", n / 12)?,
+                n if n % 3 == 0 => write!(f, "{}fq", n / 3)?,
+                n => write!(f, "{n}fp")?,
+            }
+        }
         if self.nsecs > 0 {
             let secs = self.nsecs / NANOSECONDS;
             if secs * NANOSECONDS == self.nsecs {
@@ -113,11 +168,61 @@ impl Duration {
             weeks: 0,
             days: 0,
             nsecs: fixed_slots.abs(),
+            business_days: 0,
+            fiscal_periods: 0,
             negative: fixed_slots < 0,
             parsed_int: true,
         }
     }
 
+    /// Split an interval into its calendar month, calendar day, and fixed (nanosecond) parts,
+    /// in the order in which they are applied to a timestamp.
+    ///
+    /// The parts are kept apart as they may have different signs.
+    #[cfg(feature = "dtype-interval")]
+    pub fn from_interval(interval: arrow::types::months_days_ns) -> [Self; 3] {
+        let part = |months: i64, days: i64, nsecs: i64, negative: bool| Duration {
+            months,
+            weeks: 0,
+            days,
+            nsecs,
+            business_days: 0,
+            fiscal_periods: 0,
+            negative,
+            parsed_int: false,
+        };
+        let (months, days, nsecs) = (
+            interval.months() as i64,
+            interval.days() as i64,
+            interval.ns(),
+        );
+        [
+            part(months.abs(), 0, 0, months < 0),
+            part(0, days.abs(), 0, days < 0),
+            part(0, 0, nsecs.abs(), nsecs < 0),
+        ]
+    }
+
+    /// Convert to an interval; weeks are counted as seven calendar days.
+    #[cfg(feature = "dtype-interval")]
+    pub fn to_interval(&self) -> PolarsResult<arrow::types::months_days_ns> {
+        polars_ensure!(
+            self.business_days == 0 && self.fiscal_periods == 0,
+            ComputeError: "duration '{}' with business days or fiscal periods cannot be converted to an interval", self
+        );
+        let sign = if self.negative { -1 } else { 1 };
+        let months = i32::try_from(sign * self.months);
+        let days = i32::try_from(sign * (self.weeks * 7 + self.days));
+        match (months, days) {
+            (Ok(months), Ok(days)) => Ok(arrow::types::months_days_ns::new(
+                months,
+                days,
+                sign * self.nsecs,
+            )),
+            _ => polars_bail!(ComputeError: "duration '{}' is out of range for an interval", self),
+        }
+    }
+
     /// Parse a string into a `Duration`
     ///
     /// Strings are composed of a sequence of number-unit pairs, such as `5d` (5 days). A string may begin with a minus
@@ -144,12 +249,28 @@ impl Duration {
     /// * `q`: calendar quarter
     /// * `y`:  calendar year
     /// * `i`:  index value (only for {Int32, Int64} dtypes)
+    /// * `bd`: business day (requires the `business` feature)
+    /// * `fp`: fiscal period (requires the `fiscal` feature)
+    /// * `fq`: fiscal quarter (requires the `fiscal` feature)
+    /// * `fy`: fiscal year (requires the `fiscal` feature)
     ///
     /// By "calendar day", we mean the corresponding time on the next
     /// day (which may not be 24 hours, depending on daylight savings).
     /// Similarly for "calendar week", "calendar month", "calendar quarter",
     /// and "calendar year".
     ///
+    /// Business days skip weekends and holidays and cannot be combined with other units. They
+    /// follow the business calendar of the operation, see [`DurationCalendars`], which is Monday
+    /// to Friday without holidays by default.
+    ///
+    /// Fiscal periods (`fp`), quarters (`fq`) and years (`fy`) follow the weeks of the fiscal
+    /// calendar of the operation, see [`DurationCalendars`]. They cannot be combined with other
+    /// units either.
+    ///
+    /// ISO 8601 durations such as `"PT1H30M"` or `"P1Y2M10DT2H30M"` are accepted as well,
+    /// optionally with a leading sign. Years and months are calendar months, weeks and days are
+    /// calendar weeks and days, and only the seconds may have a fraction.
+    ///
     /// # Panics
     /// If the given str is invalid for any reason.
     pub fn parse(duration: &str) -> Self {
@@ -193,12 +314,21 @@ impl Duration {
                 }
             }
         }
+        let negative = s.starts_with('-');
+        if !as_interval {
+            if let Some(iso) = s.trim_start_matches(['-', '+']).strip_prefix('P') {
+                return Self::parse_iso(s, iso, negative);
+            }
+            if let Some(out) = Self::parse_calendar_unit(s, negative)? {
+                return Ok(out);
+            }
+        }
+
         let mut months = 0;
         let mut weeks = 0;
         let mut days = 0;
         let mut nsecs = 0;
 
-        let negative = s.starts_with('-');
         let mut iter = s.char_indices().peekable();
         let mut start = 0;
 
@@ -269,6 +399,9 @@ impl Duration {
                         nsecs += n;
                         parsed_int = true;
                     },
+                    "bd" | "fp" | "fq" | "fy" if !as_interval => {
+                        polars_bail!(InvalidOperation: "'{unit}' cannot be combined with other units in the duration string '{}'", s);
+                    },
                     _ if as_interval => match &*unit {
                         // interval-only (verbose/sql) matches
                         "nanosecond" | "nanoseconds" => nsecs += n,
@@ -309,11 +442,154 @@ impl Duration {
             weeks: weeks.abs(),
             days: days.abs(),
             nsecs: nsecs.abs(),
+            business_days: 0,
+            fiscal_periods: 0,
             negative,
             parsed_int,
         })
     }
 
+    /// Parse the part of an ISO 8601 duration after the `P` designator, e.g. `1Y2M10DT2H30M`.
+    fn parse_iso(s: &str, iso: &str, negative: bool) -> PolarsResult<Self> {
+        let Some((months, weeks, days, nsecs)) = Self::parse_iso_units(iso) else {
+            polars_bail!(InvalidOperation: "invalid ISO 8601 duration string '{}'", s)
+        };
+        Ok(Duration {
+            months,
+            weeks,
+            days,
+            nsecs,
+            negative,
+            ..Self::from_nsecs(0)
+        })
+    }
+
+    /// Returns the months, weeks, days and nanoseconds of an ISO 8601 duration, or `None` if it
+    /// is invalid or overflows. Only the seconds may have a fraction, of at most nine digits.
+    fn parse_iso_units(iso: &str) -> Option<(i64, i64, i64, i64)> {
+        let mut months = 0i64;
+        let mut weeks = 0i64;
+        let mut days = 0i64;
+        let mut nsecs = 0i64;
+        // designators in the order in which they must appear; 'T' separates the time part
+        const DESIGNATORS: &[u8] = b"YMWDTHMS";
+        let mut position = 0;
+        let mut rest = iso;
+        let mut in_time = false;
+        let mut parsed_any = false;
+
+        while !rest.is_empty() {
+            if let Some(r) = rest.strip_prefix('T') {
+                if in_time {
+                    return None;
+                }
+                in_time = true;
+                position = 4;
+                rest = r;
+                continue;
+            }
+            let end = rest.find(|c: char| !(c.is_ascii_digit() || c == '.' || c == ','))?;
+            let (number, r) = rest.split_at(end);
+            let designator = r.as_bytes()[0];
+            if !designator.is_ascii() {
+                return None;
+            }
+            rest = &r[1..];
+            // designators may only appear once and in order
+            let offset = DESIGNATORS[position..]
+                .iter()
+                .position(|&d| d == designator && d != b'T')?;
+            let index = position + offset;
+            if (index > 4) != in_time {
+                return None;
+            }
+            position = index + 1;
+
+            let (whole, fraction) = match number.find(['.', ',']) {
+                Some(i) => (&number[..i], Some(&number[i + 1..])),
+                None => (number, None),
+            };
+            let whole = whole.parse::<i64>().ok()?;
+            if let Some(fraction) = fraction {
+                if designator != b'S'
+                    || fraction.is_empty()
+                    || fraction.len() > 9
+                    || !fraction.bytes().all(|b| b.is_ascii_digit())
+                {
+                    return None;
+                }
+                let fraction = format!("{fraction:0<9}").parse::<i64>().ok()?;
+                nsecs = nsecs.checked_add(fraction)?;
+            }
+            let (total, unit) = match (designator, in_time) {
+                (b'Y', false) => (&mut months, 12),
+                (b'M', false) => (&mut months, 1),
+                (b'W', false) => (&mut weeks, 1),
+                (b'D', false) => (&mut days, 1),
+                (b'H', true) => (&mut nsecs, NS_HOUR),
+                (b'M', true) => (&mut nsecs, NS_MINUTE),
+                (b'S', true) => (&mut nsecs, NS_SECOND),
+                _ => return None,
+            };
+            *total = total.checked_add(whole.checked_mul(unit)?)?;
+            parsed_any = true;
+        }
+        (parsed_any && !iso.ends_with('T')).then_some((months, weeks, days, nsecs))
+    }
+
+    /// Parse `"{n}{unit}"` for the calendar units `bd`, `fp`, `fq` and `fy`. Returns `None` if
+    /// the string does not start with one of those.
+    fn parse_calendar_unit(s: &str, negative: bool) -> PolarsResult<Option<Self>> {
+        let body = s.trim_start_matches(['-', '+']);
+        let (n, rest) = body.split_at(
+            body.find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(body.len()),
+        );
+        let (unit, rest) = rest.split_at(
+            rest.find(|c: char| !c.is_ascii_alphabetic())
+                .unwrap_or(rest.len()),
+        );
+        if !matches!(unit, "bd" | "fp" | "fq" | "fy") {
+            return Ok(None);
+        }
+        let Ok(n) = n.parse::<i32>() else {
+            polars_bail!(InvalidOperation:
+                "expected a single leading integer for '{}' in the duration string '{}'", unit, s
+            );
+        };
+        polars_ensure!(
+            !rest.starts_with('['),
+            InvalidOperation: "the '{}' unit cannot name a calendar in the duration string '{}'; pass the calendar to the operation instead", unit, s
+        );
+        polars_ensure!(
+            rest.is_empty(),
+            InvalidOperation: "'{}' cannot be combined with other units in the duration string '{}'", unit, s
+        );
+        let mut out = if unit == "bd" {
+            polars_ensure!(
+                cfg!(feature = "business"),
+                InvalidOperation: "the 'bd' unit requires the `business` feature"
+            );
+            Self::from_business_days(n)
+        } else {
+            polars_ensure!(
+                cfg!(feature = "fiscal"),
+                InvalidOperation: "the '{}' unit requires the `fiscal` feature", unit
+            );
+            let periods = match unit {
+                "fp" => 1,
+                "fq" => 3,
+                _ => 12,
+            };
+            let Some(n) = n.checked_mul(periods) else {
+                polars_bail!(InvalidOperation: "too many fiscal periods in the duration string '{}'", s);
+            };
+            Self::from_fiscal_periods(n)
+        };
+        out.negative ^= negative;
+        Ok(Some(out))
+    }
+
     fn to_positive(v: i64) -> (bool, i64) {
         if v < 0 { (true, -v) } else { (false, v) }
     }
@@ -371,6 +647,8 @@ impl Duration {
             weeks: 0,
             days: 0,
             nsecs,
+            business_days: 0,
+            fiscal_periods: 0,
             negative,
             parsed_int: false,
         }
@@ -384,6 +662,8 @@ impl Duration {
             weeks: 0,
             days: 0,
             nsecs: 0,
+            business_days: 0,
+            fiscal_periods: 0,
             negative,
             parsed_int: false,
         }
@@ -397,6 +677,8 @@ impl Duration {
             weeks,
             days: 0,
             nsecs: 0,
+            business_days: 0,
+            fiscal_periods: 0,
             negative,
             parsed_int: false,
         }
@@ -410,18 +692,53 @@ impl Duration {
             weeks: 0,
             days,
             nsecs: 0,
+            business_days: 0,
+            fiscal_periods: 0,
             negative,
             parsed_int: false,
         }
     }
 
+    /// Creates a [`Duration`] that represents a number of business days.
+    fn from_business_days(v: i32) -> Self {
+        Self {
+            business_days: v.abs(),
+            negative: v < 0,
+            ..Self::from_nsecs(0)
+        }
+    }
+
+    /// Creates a [`Duration`] that represents a number of fiscal periods.
+    fn from_fiscal_periods(v: i32) -> Self {
+        Self {
+            fiscal_periods: v.abs(),
+            negative: v < 0,
+            ..Self::from_nsecs(0)
+        }
+    }
+
+    /// A single business day.
+    pub(crate) fn one_business_day() -> Self {
+        Self::from_business_days(1)
+    }
+
     /// `true` if zero duration.
     pub fn is_zero(&self) -> bool {
-        self.months == 0 && self.weeks == 0 && self.days == 0 && self.nsecs == 0
+        self.months == 0
+            && self.weeks == 0
+            && self.days == 0
+            && self.nsecs == 0
+            && self.business_days == 0
+            && self.fiscal_periods == 0
     }
 
     pub fn months_only(&self) -> bool {
-        self.months != 0 && self.weeks == 0 && self.days == 0 && self.nsecs == 0
+        self.months != 0
+            && self.weeks == 0
+            && self.days == 0
+            && self.nsecs == 0
+            && self.business_days == 0
+            && self.fiscal_periods == 0
     }
 
     pub fn months(&self) -> i64 {
@@ -429,7 +746,12 @@ impl Duration {
     }
 
     pub fn weeks_only(&self) -> bool {
-        self.months == 0 && self.weeks != 0 && self.days == 0 && self.nsecs == 0
+        self.months == 0
+            && self.weeks != 0
+            && self.days == 0
+            && self.nsecs == 0
+            && self.business_days == 0
+            && self.fiscal_periods == 0
     }
 
     pub fn weeks(&self) -> i64 {
@@ -437,13 +759,26 @@ impl Duration {
     }
 
     pub fn days_only(&self) -> bool {
-        self.months == 0 && self.weeks == 0 && self.days != 0 && self.nsecs == 0
+        self.months == 0
+            && self.weeks == 0
+            && self.days != 0
+            && self.nsecs == 0
+            && self.business_days == 0
+            && self.fiscal_periods == 0
     }
 
     pub fn days(&self) -> i64 {
         self.days
     }
 
+    pub fn business_days(&self) -> i64 {
+        self.business_days as i64
+    }
+
+    pub fn fiscal_periods(&self) -> i64 {
+        self.fiscal_periods as i64
+    }
+
     /// Returns whether the duration consists of full days.
     ///
     /// Note that 24 hours is not considered a full day due to possible
@@ -454,11 +789,15 @@ impl Duration {
 
     pub fn is_constant_duration(&self, time_zone: Option<&TimeZone>) -> bool {
         if time_zone.is_none() || time_zone == Some(&TimeZone::UTC) {
-            self.months == 0
+            self.months == 0 && self.business_days == 0 && self.fiscal_periods == 0
         } else {
             // For non-native, non-UTC time zones, 1 calendar day is not
             // necessarily 24 hours due to daylight savings time.
-            self.months == 0 && self.weeks == 0 && self.days == 0
+            self.months == 0
+                && self.weeks == 0
+                && self.days == 0
+                && self.business_days == 0
+                && self.fiscal_periods == 0
         }
     }
 
@@ -475,24 +814,26 @@ impl Duration {
     /// Estimated duration of the window duration. Not a very good one if not a constant duration.
     #[doc(hidden)]
     pub const fn duration_ns(&self) -> i64 {
-        self.months * 28 * 24 * 3600 * NANOSECONDS
+        (self.months + self.fiscal_periods as i64) * 28 * 24 * 3600 * NANOSECONDS
             + self.weeks * NS_WEEK
-            + self.days * NS_DAY
+            + (self.days + self.business_days as i64) * NS_DAY
             + self.nsecs
     }
 
     #[doc(hidden)]
     pub const fn duration_us(&self) -> i64 {
-        self.months * 28 * 24 * 3600 * MICROSECONDS
-            + (self.weeks * NS_WEEK / 1000 + self.nsecs / 1000 + self.days * NS_DAY / 1000)
+        (self.months + self.fiscal_periods as i64) * 28 * 24 * 3600 * MICROSECONDS
+            + (self.weeks * NS_WEEK / 1000
+                + self.nsecs / 1000
+                + (self.days + self.business_days as i64) * NS_DAY / 1000)
     }
 
     #[doc(hidden)]
     pub const fn duration_ms(&self) -> i64 {
-        self.months * 28 * 24 * 3600 * MILLISECONDS
+        (self.months + self.fiscal_periods as i64) * 28 * 24 * 3600 * MILLISECONDS
             + (self.weeks * NS_WEEK / 1_000_000
                 + self.nsecs / 1_000_000
-                + self.days * NS_DAY / 1_000_000)
+                + (self.days + self.business_days as i64) * NS_DAY / 1_000_000)
     }
 
     #[doc(hidden)]
@@ -778,11 +1119,66 @@ impl Duration {
         }
     }
 
+    /// Truncate to the start of the local day returned by `f`, which is given the local day of
+    /// `t`; both are counted in days since 1970-01-01.
+    #[cfg(any(feature = "business", feature = "fiscal"))]
+    fn truncate_to_local_day<G, J>(
+        &self,
+        t: i64,
+        tz: Option<&Tz>,
+        timestamp_to_datetime: G,
+        datetime_to_timestamp: J,
+        daily_duration: i64,
+        f: impl FnOnce(i32) -> PolarsResult<i32>,
+    ) -> PolarsResult<i64>
+    where
+        G: Fn(i64) -> NaiveDateTime,
+        J: Fn(NaiveDateTime) -> i64,
+    {
+        let original_dt_utc = timestamp_to_datetime(t);
+        let original_dt_local = match tz {
+            #[cfg(feature = "timezones")]
+            // for UTC, use fastpath below (same as naive)
+            Some(tz) if tz != &chrono_tz::UTC => unlocalize_datetime(original_dt_utc, tz),
+            _ => original_dt_utc,
+        };
+        let day = datetime_to_timestamp(original_dt_local).div_euclid(daily_duration);
+        let day = i32::try_from(day)
+            .map_err(|_| polars_err!(ComputeError: "date is out of range for duration {}", self))?;
+        let result_t_local = f(day)? as i64 * daily_duration;
+        match tz {
+            #[cfg(feature = "timezones")]
+            // for UTC, use fastpath below (same as naive)
+            Some(tz) if tz != &chrono_tz::UTC => {
+                let result_dt_local = timestamp_to_datetime(result_t_local);
+                let result_dt_utc =
+                    self.localize_result(original_dt_local, original_dt_utc, result_dt_local, tz)?;
+                Ok(datetime_to_timestamp(result_dt_utc))
+            },
+            _ => Ok(result_t_local),
+        }
+    }
+
+    /// Truncate a day to the start of its business day window. For multiple business days,
+    /// windows are counted from the first business day on or after 1970-01-01.
+    #[cfg(feature = "business")]
+    fn truncate_business_daily(&self, day: i32, calendar: &BusinessCalendar) -> PolarsResult<i32> {
+        let day = calendar.roll_backward(day);
+        if self.business_days == 1 {
+            return Ok(day);
+        }
+        let origin = calendar.roll_forward(0);
+        let index = calendar.count(origin, day);
+        let n = self.business_days();
+        calendar.offset(origin, index.div_euclid(n) * n)
+    }
+
     #[inline]
     pub fn truncate_impl<F, G, J>(
         &self,
         t: i64,
         tz: Option<&Tz>,
+        _calendars: &DurationCalendars,
         nsecs_to_unit: F,
         timestamp_to_datetime: G,
         datetime_to_timestamp: J,
@@ -792,6 +1188,36 @@ impl Duration {
         G: Fn(i64) -> NaiveDateTime,
         J: Fn(NaiveDateTime) -> i64,
     {
+        if self.business_days != 0 {
+            #[cfg(feature = "business")]
+            return self.truncate_to_local_day(
+                t,
+                tz,
+                timestamp_to_datetime,
+                datetime_to_timestamp,
+                nsecs_to_unit(NS_DAY),
+                |day| self.truncate_business_daily(day, _calendars.business_calendar()),
+            );
+            #[cfg(not(feature = "business"))]
+            polars_bail!(InvalidOperation: "the 'bd' unit requires the `business` feature");
+        }
+        if self.fiscal_periods != 0 {
+            #[cfg(feature = "fiscal")]
+            return self.truncate_to_local_day(
+                t,
+                tz,
+                timestamp_to_datetime,
+                datetime_to_timestamp,
+                nsecs_to_unit(NS_DAY),
+                |day| {
+                    _calendars
+                        .fiscal_calendar()?
+                        .truncate_periods(day, self.fiscal_periods())
+                },
+            );
+            #[cfg(not(feature = "fiscal"))]
+            polars_bail!(InvalidOperation: "fiscal units require the `fiscal` feature");
+        }
         match (self.months, self.weeks, self.days, self.nsecs) {
             (0, 0, 0, 0) => polars_bail!(ComputeError: "duration cannot be zero"),
             // truncate by ns/us/ms
@@ -847,9 +1273,21 @@ impl Duration {
     // Truncate the given ns timestamp by the window boundary.
     #[inline]
     pub fn truncate_ns(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
+        self.truncate_ns_in(t, tz, &DurationCalendars::default())
+    }
+
+    /// Like [`Duration::truncate_ns`], counting business days and fiscal periods in `calendars`.
+    #[inline]
+    pub fn truncate_ns_in(
+        &self,
+        t: i64,
+        tz: Option<&Tz>,
+        calendars: &DurationCalendars,
+    ) -> PolarsResult<i64> {
         self.truncate_impl(
             t,
             tz,
+            calendars,
             |nsecs| nsecs,
             timestamp_ns_to_datetime,
             datetime_to_timestamp_ns,
@@ -859,9 +1297,21 @@ impl Duration {
     // Truncate the given ns timestamp by the window boundary.
     #[inline]
     pub fn truncate_us(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
+        self.truncate_us_in(t, tz, &DurationCalendars::default())
+    }
+
+    /// Like [`Duration::truncate_us`], counting business days and fiscal periods in `calendars`.
+    #[inline]
+    pub fn truncate_us_in(
+        &self,
+        t: i64,
+        tz: Option<&Tz>,
+        calendars: &DurationCalendars,
+    ) -> PolarsResult<i64> {
         self.truncate_impl(
             t,
             tz,
+            calendars,
             |nsecs| nsecs / 1000,
             timestamp_us_to_datetime,
             datetime_to_timestamp_us,
@@ -871,9 +1321,21 @@ impl Duration {
     // Truncate the given ms timestamp by the window boundary.
     #[inline]
     pub fn truncate_ms(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
+        self.truncate_ms_in(t, tz, &DurationCalendars::default())
+    }
+
+    /// Like [`Duration::truncate_ms`], counting business days and fiscal periods in `calendars`.
+    #[inline]
+    pub fn truncate_ms_in(
+        &self,
+        t: i64,
+        tz: Option<&Tz>,
+        calendars: &DurationCalendars,
+    ) -> PolarsResult<i64> {
         self.truncate_impl(
             t,
             tz,
+            calendars,
             |nsecs| nsecs / 1_000_000,
             timestamp_ms_to_datetime,
             datetime_to_timestamp_ms,
@@ -884,6 +1346,7 @@ impl Duration {
         &self,
         mut t: i64,
         tz: Option<&Tz>,
+        _calendars: &DurationCalendars,
         nsecs_to_unit: F,
         timestamp_to_datetime: G,
         datetime_to_timestamp: J,
@@ -938,6 +1401,50 @@ impl Duration {
             };
         }
 
+        if d.business_days > 0 {
+            #[cfg(feature = "business")]
+            {
+                let calendar = _calendars.business_calendar();
+                let n = if d.negative {
+                    -d.business_days()
+                } else {
+                    d.business_days()
+                };
+                t = self.add_local_days(
+                    t,
+                    tz,
+                    &timestamp_to_datetime,
+                    &datetime_to_timestamp,
+                    nsecs_to_unit(NS_DAY),
+                    |day| calendar.offset(day, n),
+                )?;
+            }
+            #[cfg(not(feature = "business"))]
+            polars_bail!(InvalidOperation: "the 'bd' unit requires the `business` feature");
+        }
+
+        if d.fiscal_periods > 0 {
+            #[cfg(feature = "fiscal")]
+            {
+                let calendar = _calendars.fiscal_calendar()?;
+                let n = if d.negative {
+                    -d.fiscal_periods()
+                } else {
+                    d.fiscal_periods()
+                };
+                t = self.add_local_days(
+                    t,
+                    tz,
+                    &timestamp_to_datetime,
+                    &datetime_to_timestamp,
+                    nsecs_to_unit(NS_DAY),
+                    |day| calendar.offset_periods(day, n),
+                )?;
+            }
+            #[cfg(not(feature = "fiscal"))]
+            polars_bail!(InvalidOperation: "fiscal units require the `fiscal` feature");
+        }
+
         if d.days > 0 {
             let t_days = nsecs_to_unit(NS_DAY) * self.days;
             match tz {
@@ -963,11 +1470,65 @@ impl Duration {
         Ok(t)
     }
 
+    /// Move to the same local time of day on the local day returned by `f`, which is given the
+    /// local day of `t`; both are counted in days since 1970-01-01.
+    #[cfg(any(feature = "business", feature = "fiscal"))]
+    fn add_local_days<G, J>(
+        &self,
+        t: i64,
+        tz: Option<&Tz>,
+        _timestamp_to_datetime: G,
+        _datetime_to_timestamp: J,
+        daily_duration: i64,
+        f: impl FnOnce(i32) -> PolarsResult<i32>,
+    ) -> PolarsResult<i64>
+    where
+        G: Fn(i64) -> NaiveDateTime,
+        J: Fn(NaiveDateTime) -> i64,
+    {
+        let t = match tz {
+            #[cfg(feature = "timezones")]
+            // for UTC, use fastpath below (same as naive)
+            Some(tz) if tz != &chrono_tz::UTC => {
+                _datetime_to_timestamp(unlocalize_datetime(_timestamp_to_datetime(t), tz))
+            },
+            _ => t,
+        };
+        let day = i32::try_from(t.div_euclid(daily_duration))
+            .map_err(|_| polars_err!(ComputeError: "date is out of range for duration {}", self))?;
+        let t = f(day)? as i64 * daily_duration + t.rem_euclid(daily_duration);
+        match tz {
+            #[cfg(feature = "timezones")]
+            // for UTC, use fastpath below (same as naive)
+            Some(tz) if tz != &chrono_tz::UTC => Ok(_datetime_to_timestamp(
+                try_localize_datetime(
+                    _timestamp_to_datetime(t),
+                    tz,
+                    Ambiguous::Raise,
+                    NonExistent::Raise,
+                )?
+                .expect("we didn't use Ambiguous::Null or NonExistent::Null"),
+            )),
+            _ => Ok(t),
+        }
+    }
+
     pub fn add_ns(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
+        self.add_ns_in(t, tz, &DurationCalendars::default())
+    }
+
+    /// Like [`Duration::add_ns`], counting business days and fiscal periods in `calendars`.
+    pub fn add_ns_in(
+        &self,
+        t: i64,
+        tz: Option<&Tz>,
+        calendars: &DurationCalendars,
+    ) -> PolarsResult<i64> {
         let d = self;
         let new_t = self.add_impl_month_week_or_day(
             t,
             tz,
+            calendars,
             |nsecs| nsecs,
             timestamp_ns_to_datetime,
             datetime_to_timestamp_ns,
@@ -977,10 +1538,21 @@ impl Duration {
     }
 
     pub fn add_us(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
+        self.add_us_in(t, tz, &DurationCalendars::default())
+    }
+
+    /// Like [`Duration::add_us`], counting business days and fiscal periods in `calendars`.
+    pub fn add_us_in(
+        &self,
+        t: i64,
+        tz: Option<&Tz>,
+        calendars: &DurationCalendars,
+    ) -> PolarsResult<i64> {
         let d = self;
         let new_t = self.add_impl_month_week_or_day(
             t,
             tz,
+            calendars,
             |nsecs| nsecs / 1000,
             timestamp_us_to_datetime,
             datetime_to_timestamp_us,
@@ -990,10 +1562,21 @@ impl Duration {
     }
 
     pub fn add_ms(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
+        self.add_ms_in(t, tz, &DurationCalendars::default())
+    }
+
+    /// Like [`Duration::add_ms`], counting business days and fiscal periods in `calendars`.
+    pub fn add_ms_in(
+        &self,
+        t: i64,
+        tz: Option<&Tz>,
+        calendars: &DurationCalendars,
+    ) -> PolarsResult<i64> {
         let d = self;
         let new_t = self.add_impl_month_week_or_day(
             t,
             tz,
+            calendars,
             |nsecs| nsecs / 1_000_000,
             timestamp_ms_to_datetime,
             datetime_to_timestamp_ms,
@@ -1015,6 +1598,8 @@ impl Mul<i64> for Duration {
         self.weeks *= rhs;
         self.days *= rhs;
         self.nsecs *= rhs;
+        self.business_days = (self.business_days() * rhs) as i32;
+        self.fiscal_periods = (self.fiscal_periods() * rhs) as i32;
         self
     }
 }
@@ -1061,6 +1646,8 @@ pub fn ensure_duration_matches_dtype(
         DataType::Datetime(_, _) | DataType::Date | DataType::Duration(_) | DataType::Time => {
             polars_ensure!(!duration.parsed_int,
                 InvalidOperation: "`{}` duration may not be a parsed integer (i.e. use '2d', not '2i') when working with a temporal column", variable_name);
+            polars_ensure!((duration.business_days == 0 && duration.fiscal_periods == 0) || matches!(dtype, DataType::Datetime(_, _) | DataType::Date),
+                InvalidOperation: "`{}` duration may only use business days or fiscal periods when working with a Date or Datetime column", variable_name);
         },
         _ => {
             polars_bail!(InvalidOperation: "unsupported data type: {} for temporal/index column, expected UInt64, UInt32, Int64, Int32, Datetime, Date, Duration, or Time", dtype)
@@ -1090,6 +1677,55 @@ mod test {
         assert_eq!(out.weeks(), 5);
     }
 
+    #[test]
+    fn test_parse_iso() {
+        let out = Duration::parse("PT1H30M");
+        assert_eq!(out.nsecs, 90 * NS_MINUTE);
+        assert_eq!(out.months, 0);
+
+        let out = Duration::parse("P1Y2M10DT2H30M");
+        assert_eq!(out.months, 14);
+        assert_eq!(out.days, 10);
+        assert_eq!(out.nsecs, 2 * NS_HOUR + 30 * NS_MINUTE);
+
+        let out = Duration::parse("-P2W");
+        assert_eq!(out.weeks, 2);
+        assert!(out.negative);
+
+        let out = Duration::parse("PT0.5S");
+        assert_eq!(out.nsecs, NS_SECOND / 2);
+        assert_eq!(Duration::parse("PT1,000000001S").nsecs, NS_SECOND + 1);
+        assert_eq!(Duration::parse("P1M"), Duration::parse("1mo"));
+        assert_eq!(Duration::parse("PT1M"), Duration::parse("1m"));
+        assert_eq!(Duration::parse("PT1H0.5S").nsecs, NS_HOUR + NS_SECOND / 2);
+
+        for s in [
+            "P",
+            "PT",
+            "P1DT",
+            "P1H",
+            "PT1D",
+            "P1D1Y",
+            "PT1S1M",
+            "P1.5D",
+            "PT1.S",
+            "P1DT1HT1M",
+            "P1",
+            "PTH",
+            "P1Dx",
+            // overflow
+            "P999999999999999999Y",
+            "PT9223372036854775807H",
+            "PT9223372036854775807.5S",
+        ] {
+            assert!(Duration::try_parse(s).is_err(), "{s}");
+        }
+        assert!(matches!(
+            Duration::try_parse("P999999999999999999Y"),
+            Err(polars_error::PolarsError::InvalidOperation(_))
+        ));
+    }
+
     #[test]
     fn test_add_ns() {
         let t = 1;
@@ -1114,6 +1750,113 @@ mod test {
         );
     }
 
+    #[test]
+    #[cfg(feature = "business")]
+    fn test_business_days() {
+        let out = Duration::parse("-3bd");
+        assert_eq!(out.business_days(), 3);
+        assert!(out.negative());
+        assert_eq!(format!("{out}"), "-3bd");
+        assert!(!out.is_constant_duration(None));
+        assert!(Duration::try_parse("1bd2h").is_err());
+        assert!(Duration::try_parse("1d1bd").is_err());
+        assert!(Duration::try_parse("1bd[some_cal]").is_err());
+        assert!(Duration::try_parse_interval("1 bd").is_err());
+
+        // 2024-01-05 is a Friday.
+        let friday_noon = 19727 * NS_DAY + 12 * NS_HOUR;
+        let monday_noon = friday_noon + 3 * NS_DAY;
+        assert_eq!(
+            Duration::parse("1bd").add_ns(friday_noon, None).unwrap(),
+            monday_noon
+        );
+        assert_eq!(
+            Duration::parse("-1bd").add_ns(monday_noon, None).unwrap(),
+            friday_noon
+        );
+        // Saturday rolls to Monday.
+        let saturday = friday_noon + NS_DAY;
+        assert_eq!(
+            Duration::parse("1bd").add_ns(saturday, None).unwrap(),
+            monday_noon
+        );
+        assert_eq!(
+            Duration::parse("1bd").truncate_ns(saturday, None).unwrap(),
+            19727 * NS_DAY
+        );
+        // 1970-01-01 is a Thursday, so windows of two business days start on Thursdays and
+        // Mondays in the first week.
+        assert_eq!(
+            Duration::parse("2bd")
+                .truncate_ns(2 * NS_DAY, None)
+                .unwrap(),
+            0
+        );
+        assert_eq!(
+            Duration::parse("2bd")
+                .truncate_ns(5 * NS_DAY, None)
+                .unwrap(),
+            4 * NS_DAY
+        );
+
+        // Friday is a holiday and Monday isn't a business day of the week.
+        let calendar = BusinessCalendar::new([false, true, true, true, true, false, false])
+            .unwrap()
+            .with_holidays([19727]);
+        let calendars = DurationCalendars {
+            business: Some(Arc::new(calendar)),
+            ..Default::default()
+        };
+        assert_eq!(
+            Duration::parse("1bd")
+                .add_ns_in(friday_noon - NS_DAY, None, &calendars)
+                .unwrap(),
+            friday_noon + 4 * NS_DAY
+        );
+        assert_eq!(
+            Duration::parse("1bd")
+                .truncate_ns_in(monday_noon, None, &calendars)
+                .unwrap(),
+            (19727 - 1) * NS_DAY
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "fiscal")]
+    fn test_fiscal_periods() {
+        let out = Duration::parse("-2fq");
+        assert_eq!(out.fiscal_periods(), 6);
+        assert!(out.negative());
+        assert_eq!(format!("{out}"), "-2fq");
+        assert_eq!(format!("{}", Duration::parse("1fy")), "1fy");
+        assert_eq!(format!("{}", Duration::parse("4fp")), "4fp");
+        assert!(!out.is_constant_duration(None));
+        assert!(Duration::try_parse("1fp[nrf]").is_err());
+        assert!(Duration::try_parse("1fp1d").is_err());
+        assert!(Duration::try_parse("1d1fq").is_err());
+
+        // Fiscal 2024 starts on Sunday 2024-02-04, its second period on 2024-03-03.
+        let calendars = DurationCalendars {
+            fiscal: Some(Default::default()),
+            ..Default::default()
+        };
+        let fy_2024 = 19757;
+        let saturday_noon = (fy_2024 + 6) * NS_DAY + 12 * NS_HOUR;
+        assert_eq!(
+            Duration::parse("1fp")
+                .add_ns_in(saturday_noon, None, &calendars)
+                .unwrap(),
+            saturday_noon + 28 * NS_DAY
+        );
+        assert_eq!(
+            Duration::parse("1fq")
+                .truncate_ns_in(saturday_noon + 28 * NS_DAY, None, &calendars)
+                .unwrap(),
+            fy_2024 * NS_DAY
+        );
+        assert!(Duration::parse("1fp").add_ns(saturday_noon, None).is_err());
+    }
+
     #[test]
     fn test_display() {
         let duration = Duration::parse("1h");
@@ -143,7 +143,7 @@ fn update_groups_and_bounds(
 /// If `include_boundaries` is `false` those `lower` and `upper` vectors will be empty.
 #[allow(clippy::too_many_arguments)]
 pub fn group_by_windows(
-    window: Window,
+    window: &Window,
     time: &[i64],
     closed_window: ClosedWindow,
     tu: TimeUnit,
@@ -22,6 +22,7 @@ fn test_date_range() {
         ClosedWindow::Both,
         TimeUnit::Nanoseconds,
         None,
+        &Default::default(),
     )
     .unwrap(); // unwrapping as we pass None as the time zone
     let expected = [
@@ -59,6 +60,7 @@ fn test_feb_date_range() {
         ClosedWindow::Both,
         TimeUnit::Nanoseconds,
         None,
+        &Default::default(),
     )
     .unwrap(); // unwrapping as we pass None as the time zone
     let expected = [
@@ -112,7 +114,7 @@ fn test_groups_large_interval() {
     let dur = Duration::parse("2d");
     let w = Window::new(Duration::parse("2d"), dur, Duration::from_nsecs(0));
     let (groups, _, _) = group_by_windows(
-        w,
+        &w,
         &ts,
         ClosedWindow::Both,
         TimeUnit::Nanoseconds,
@@ -128,7 +130,7 @@ fn test_groups_large_interval() {
     assert_eq!(groups[2], [1, 3]);
     assert_eq!(groups[3], [3, 1]);
     let (groups, _, _) = group_by_windows(
-        w,
+        &w,
         &ts,
         ClosedWindow::Left,
         TimeUnit::Nanoseconds,
@@ -141,7 +143,7 @@ fn test_groups_large_interval() {
     assert_eq!(groups.len(), 3);
     assert_eq!(groups[2], [3, 1]);
     let (groups, _, _) = group_by_windows(
-        w,
+        &w,
         &ts,
         ClosedWindow::Right,
         TimeUnit::Nanoseconds,
@@ -201,6 +203,7 @@ fn test_boundaries() {
         ClosedWindow::Both,
         TimeUnit::Nanoseconds,
         None,
+        &Default::default(),
     )
     .unwrap(); // unwrapping as we pass None as the time zone
 
@@ -221,7 +224,7 @@ fn test_boundaries() {
 
     // test closed: "both" (includes both ends of the interval)
     let (groups, lower, higher) = group_by_windows(
-        w,
+        &w,
         &ts,
         ClosedWindow::Both,
         TimeUnit::Nanoseconds,
@@ -324,7 +327,7 @@ fn test_boundaries() {
 
     // test closed: "left" (should not include right end of interval)
     let (groups, _, _) = group_by_windows(
-        w,
+        &w,
         &ts,
         ClosedWindow::Left,
         TimeUnit::Nanoseconds,
@@ -340,7 +343,7 @@ fn test_boundaries() {
 
     // test closed: "right" (should not include left end of interval)
     let (groups, _, _) = group_by_windows(
-        w,
+        &w,
         &ts,
         ClosedWindow::Right,
         TimeUnit::Nanoseconds,
@@ -357,7 +360,7 @@ fn test_boundaries() {
 
     // test closed: "none" (should not include left or right end of interval)
     let (groups, _, _) = group_by_windows(
-        w,
+        &w,
         &ts,
         ClosedWindow::None,
         TimeUnit::Nanoseconds,
@@ -390,6 +393,7 @@ fn test_boundaries_2() {
         ClosedWindow::Both,
         TimeUnit::Nanoseconds,
         None,
+        &Default::default(),
     )
     .unwrap(); // unwrapping as we pass None as the time zone
 
@@ -415,7 +419,7 @@ fn test_boundaries_2() {
     );
 
     let (groups, lower, higher) = group_by_windows(
-        w,
+        &w,
         &ts,
         ClosedWindow::Left,
         TimeUnit::Nanoseconds,
@@ -524,6 +528,7 @@ fn test_boundaries_ms() {
         ClosedWindow::Both,
         TimeUnit::Milliseconds,
         None,
+        &Default::default(),
     )
     .unwrap(); // unwrapping as we pass None as the time zone
 
@@ -544,7 +549,7 @@ fn test_boundaries_ms() {
 
     // test closed: "both" (includes both ends of the interval)
     let (groups, lower, higher) = group_by_windows(
-        w,
+        &w,
         &ts,
         ClosedWindow::Both,
         TimeUnit::Milliseconds,
@@ -647,7 +652,7 @@ fn test_boundaries_ms() {
 
     // test closed: "left" (should not include right end of interval)
     let (groups, _, _) = group_by_windows(
-        w,
+        &w,
         &ts,
         ClosedWindow::Left,
         TimeUnit::Milliseconds,
@@ -663,7 +668,7 @@ fn test_boundaries_ms() {
 
     // test closed: "right" (should not include left end of interval)
     let (groups, _, _) = group_by_windows(
-        w,
+        &w,
         &ts,
         ClosedWindow::Right,
         TimeUnit::Milliseconds,
@@ -680,7 +685,7 @@ fn test_boundaries_ms() {
 
     // test closed: "none" (should not include left or right end of interval)
     let (groups, _, _) = group_by_windows(
-        w,
+        &w,
         &ts,
         ClosedWindow::None,
         TimeUnit::Milliseconds,
@@ -713,6 +718,7 @@ fn test_rolling_lookback() {
         ClosedWindow::Both,
         TimeUnit::Milliseconds,
         None,
+        &Default::default(),
     )
     .unwrap(); // unwrapping as we pass None as the time zone
 
@@ -844,7 +850,7 @@ fn test_end_membership() {
     // 2021-04-01 -> 2021-06-01     members: [1]
     // 2021-05-01 -> 2021-07-01     members: [1]
     let (groups, _, _) = group_by_windows(
-        window,
+        &window,
         &time,
         ClosedWindow::Left,
         TimeUnit::Milliseconds,
@@ -869,7 +875,7 @@ fn test_group_by_windows_membership_2791() {
         Duration::parse("0ns"),
     );
     let (groups, _, _) = group_by_windows(
-        window,
+        &window,
         &dates,
         ClosedWindow::Left,
         TimeUnit::Milliseconds,
@@ -893,7 +899,7 @@ fn test_group_by_windows_duplicates_2931() {
     );
 
     let (groups, _, _) = group_by_windows(
-        window,
+        &window,
         &dates,
         ClosedWindow::Left,
         TimeUnit::Milliseconds,
@@ -930,7 +936,7 @@ fn test_group_by_windows_offsets_3776() {
         Duration::parse("-2d"),
     );
     let (groups, _, _) = group_by_windows(
-        window,
+        &window,
         &ts,
         ClosedWindow::Right,
         TimeUnit::Nanoseconds,
@@ -21,26 +21,30 @@ use crate::prelude::*;
 /// in the window `[2020-01-01 06:00, 2020-01-03 06:00)`. To give the earliest datapoint
 /// a chance of being included, we then shift the window back by `every` to
 /// `[2019-12-31 06:00, 2020-01-02 06:00)`.
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn ensure_t_in_or_in_front_of_window(
     mut every: Duration,
     t: i64,
-    offset_fn: fn(&Duration, i64, Option<&Tz>) -> PolarsResult<i64>,
+    offset_fn: OffsetFn,
     period: Duration,
     mut start: i64,
     closed_window: ClosedWindow,
     tz: Option<&Tz>,
+    calendars: &DurationCalendars,
 ) -> PolarsResult<Bounds> {
     every.negative = !every.negative;
-    let mut stop = offset_fn(&period, start, tz)?;
+    let mut stop = offset_fn(&period, start, tz, calendars)?;
     while Bounds::new(start, stop).is_past(t, closed_window) {
-        start = offset_fn(&every, start, tz)?;
-        stop = offset_fn(&period, start, tz)?;
+        start = offset_fn(&every, start, tz, calendars)?;
+        stop = offset_fn(&period, start, tz, calendars)?;
     }
     Ok(Bounds::new_checked(start, stop))
 }
 
+type OffsetFn = fn(&Duration, i64, Option<&Tz>, &DurationCalendars) -> PolarsResult<i64>;
+
 /// Represents a window in time
-#[derive(Copy, Clone)]
+#[derive(Clone)]
 pub struct Window {
     // The ith window start is expressed via this equation:
     //   window_start_i = zero + every * i
@@ -48,6 +52,7 @@ pub struct Window {
     every: Duration,
     period: Duration,
     pub offset: Duration,
+    calendars: DurationCalendars,
 }
 
 impl Window {
@@ -57,32 +62,49 @@ impl Window {
             every,
             period,
             offset,
+            calendars: DurationCalendars::default(),
         }
     }
 
+    /// Count the business days and fiscal periods of the durations in `calendars`.
+    pub fn with_calendars(mut self, calendars: DurationCalendars) -> Self {
+        self.calendars = calendars;
+        self
+    }
+
     /// Truncate the given ns timestamp by the window boundary.
     pub fn truncate_ns(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
-        self.every.truncate_ns(t, tz)
+        self.every.truncate_ns_in(t, tz, &self.calendars)
     }
 
     /// Truncate the given us timestamp by the window boundary.
     pub fn truncate_us(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
-        self.every.truncate_us(t, tz)
+        self.every.truncate_us_in(t, tz, &self.calendars)
     }
 
     /// Truncate the given ms timestamp by the window boundary.
     pub fn truncate_ms(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
-        self.every.truncate_ms(t, tz)
+        self.every.truncate_ms_in(t, tz, &self.calendars)
+    }
+
+    fn ensure_roundable(&self) -> PolarsResult<()> {
+        polars_ensure!(
+            self.every.business_days() == 0 && self.every.fiscal_periods() == 0,
+            InvalidOperation: "cannot round to business days or fiscal periods, got {}", self.every
+        );
+        Ok(())
     }
 
     /// Round the given ns timestamp by the window boundary.
     pub fn round_ns(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
+        self.ensure_roundable()?;
         let t = t + self.every.duration_ns() / 2_i64;
         self.truncate_ns(t, tz)
     }
 
     /// Round the given us timestamp by the window boundary.
     pub fn round_us(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
+        self.ensure_roundable()?;
         let t = t + self.every.duration_ns()
             / (2 * timeunit_scale(ArrowTimeUnit::Nanosecond, ArrowTimeUnit::Microsecond) as i64);
         self.truncate_us(t, tz)
@@ -90,6 +112,7 @@ impl Window {
 
     /// Round the given ms timestamp by the window boundary.
     pub fn round_ms(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
+        self.ensure_roundable()?;
         let t = t + self.every.duration_ns()
             / (2 * timeunit_scale(ArrowTimeUnit::Nanosecond, ArrowTimeUnit::Millisecond) as i64);
         self.truncate_ms(t, tz)
@@ -105,15 +128,16 @@ impl Window {
         tz: Option<&Tz>,
     ) -> PolarsResult<Bounds> {
         let start = self.truncate_ns(t, tz)?;
-        let start = self.offset.add_ns(start, tz)?;
+        let start = self.offset.add_ns_in(start, tz, &self.calendars)?;
         ensure_t_in_or_in_front_of_window(
             self.every,
             t,
-            Duration::add_ns,
+            Duration::add_ns_in,
             self.period,
             start,
             closed_window,
             tz,
+            &self.calendars,
         )
     }
 
@@ -124,15 +148,16 @@ impl Window {
         tz: Option<&Tz>,
     ) -> PolarsResult<Bounds> {
         let start = self.truncate_us(t, tz)?;
-        let start = self.offset.add_us(start, tz)?;
+        let start = self.offset.add_us_in(start, tz, &self.calendars)?;
         ensure_t_in_or_in_front_of_window(
             self.every,
             t,
-            Duration::add_us,
+            Duration::add_us_in,
             self.period,
             start,
             closed_window,
             tz,
+            &self.calendars,
         )
     }
 
@@ -143,15 +168,16 @@ impl Window {
         tz: Option<&Tz>,
     ) -> PolarsResult<Bounds> {
         let start = self.truncate_ms(t, tz)?;
-        let start = self.offset.add_ms(start, tz)?;
+        let start = self.offset.add_ms_in(start, tz, &self.calendars)?;
         ensure_t_in_or_in_front_of_window(
             self.every,
             t,
-            Duration::add_ms,
+            Duration::add_ms_in,
             self.period,
             start,
             closed_window,
             tz,
+            &self.calendars,
         )
     }
 
@@ -178,12 +204,12 @@ impl Window {
         tz: Option<&'a Tz>,
         start_by: StartBy,
     ) -> PolarsResult<BoundsIter<'a>> {
-        BoundsIter::new(*self, closed_window, boundary, tu, tz, start_by)
+        BoundsIter::new(self, closed_window, boundary, tu, tz, start_by)
     }
 }
 
 pub struct BoundsIter<'a> {
-    window: Window,
+    window: &'a Window,
     // wrapping boundary
     boundary: Bounds,
     // boundary per window iterator
@@ -193,7 +219,7 @@ pub struct BoundsIter<'a> {
 }
 impl<'a> BoundsIter<'a> {
     fn new(
-        window: Window,
+        window: &'a Window,
         closed_window: ClosedWindow,
         boundary: Bounds,
         tu: TimeUnit,
@@ -203,12 +229,12 @@ impl<'a> BoundsIter<'a> {
         let bi = match start_by {
             StartBy::DataPoint => {
                 let mut boundary = boundary;
-                let offset_fn = match tu {
-                    TimeUnit::Nanoseconds => Duration::add_ns,
-                    TimeUnit::Microseconds => Duration::add_us,
-                    TimeUnit::Milliseconds => Duration::add_ms,
+                let offset_fn: OffsetFn = match tu {
+                    TimeUnit::Nanoseconds => Duration::add_ns_in,
+                    TimeUnit::Microseconds => Duration::add_us_in,
+                    TimeUnit::Milliseconds => Duration::add_ms_in,
                 };
-                boundary.stop = offset_fn(&window.period, boundary.start, tz)?;
+                boundary.stop = offset_fn(&window.period, boundary.start, tz, &window.calendars)?;
                 boundary
             },
             StartBy::WindowBound => match tu {
@@ -228,24 +254,25 @@ impl<'a> BoundsIter<'a> {
                     let (from, to, offset_fn): (
                         fn(i64) -> NaiveDateTime,
                         fn(NaiveDateTime) -> i64,
-                        fn(&Duration, i64, Option<&Tz>) -> PolarsResult<i64>,
+                        OffsetFn,
                     ) = match tu {
                         TimeUnit::Nanoseconds => (
                             timestamp_ns_to_datetime,
                             datetime_to_timestamp_ns,
-                            Duration::add_ns,
+                            Duration::add_ns_in,
                         ),
                         TimeUnit::Microseconds => (
                             timestamp_us_to_datetime,
                             datetime_to_timestamp_us,
-                            Duration::add_us,
+                            Duration::add_us_in,
                         ),
                         TimeUnit::Milliseconds => (
                             timestamp_ms_to_datetime,
                             datetime_to_timestamp_ms,
-                            Duration::add_ms,
+                            Duration::add_ms_in,
                         ),
                     };
+                    let calendars = &window.calendars;
                     // find beginning of the week.
                     let dt = from(boundary.start);
                     match tz {
@@ -260,9 +287,10 @@ impl<'a> BoundsIter<'a> {
                                 &Duration::parse(&format!("{}d", start_by.weekday().unwrap())),
                                 start,
                                 Some(tz),
+                                calendars,
                             )?;
                             // apply the 'offset'
-                            let start = offset_fn(&window.offset, start, Some(tz))?;
+                            let start = offset_fn(&window.offset, start, Some(tz), calendars)?;
                             // make sure the first datapoint has a chance to be included
                             // and compute the end of the window defined by the 'period'
                             ensure_t_in_or_in_front_of_window(
@@ -273,6 +301,7 @@ impl<'a> BoundsIter<'a> {
                                 start,
                                 closed_window,
                                 Some(tz),
+                                calendars,
                             )?
                         },
                         _ => {
@@ -286,10 +315,11 @@ impl<'a> BoundsIter<'a> {
                                 &Duration::parse(&format!("{}d", start_by.weekday().unwrap())),
                                 start,
                                 None,
+                                calendars,
                             )
                             .unwrap();
                             // apply the 'offset'
-                            let start = offset_fn(&window.offset, start, None).unwrap();
+                            let start = offset_fn(&window.offset, start, None, calendars).unwrap();
                             // make sure the first datapoint has a chance to be included
                             // and compute the end of the window defined by the 'period'
                             ensure_t_in_or_in_front_of_window(
@@ -300,6 +330,7 @@ impl<'a> BoundsIter<'a> {
                                 start,
                                 closed_window,
                                 None,
+                                calendars,
                             )?
                         },
                     }
@@ -326,16 +357,31 @@ impl Iterator for BoundsIter<'_> {
                 // TODO: find some way to propagate error instead of unwrapping?
                 // Issue is that `next` needs to return `Option`.
                 TimeUnit::Nanoseconds => {
-                    self.bi.start = self.window.every.add_ns(self.bi.start, self.tz).unwrap();
-                    self.bi.stop = self.window.period.add_ns(self.bi.start, self.tz).unwrap();
+                    let calendars = &self.window.calendars;
+                    self.bi.start = (self.window.every)
+                        .add_ns_in(self.bi.start, self.tz, calendars)
+                        .unwrap();
+                    self.bi.stop = (self.window.period)
+                        .add_ns_in(self.bi.start, self.tz, calendars)
+                        .unwrap();
                 },
                 TimeUnit::Microseconds => {
-                    self.bi.start = self.window.every.add_us(self.bi.start, self.tz).unwrap();
-                    self.bi.stop = self.window.period.add_us(self.bi.start, self.tz).unwrap();
+                    let calendars = &self.window.calendars;
+                    self.bi.start = (self.window.every)
+                        .add_us_in(self.bi.start, self.tz, calendars)
+                        .unwrap();
+                    self.bi.stop = (self.window.period)
+                        .add_us_in(self.bi.start, self.tz, calendars)
+                        .unwrap();
                 },
                 TimeUnit::Milliseconds => {
-                    self.bi.start = self.window.every.add_ms(self.bi.start, self.tz).unwrap();
-                    self.bi.stop = self.window.period.add_ms(self.bi.start, self.tz).unwrap();
+                    let calendars = &self.window.calendars;
+                    self.bi.start = (self.window.every)
+                        .add_ms_in(self.bi.start, self.tz, calendars)
+                        .unwrap();
+                    self.bi.stop = (self.window.period)
+                        .add_ms_in(self.bi.start, self.tz, calendars)
+                        .unwrap();
                 },
             }
             Some(out)
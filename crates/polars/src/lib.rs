@@ -265,6 +265,8 @@
 //!     - `arg_where` - Get indices where condition holds.
 //!     - `search_sorted` - Find indices where elements should be inserted to maintain order.
 //!     - `offset_by` - Add an offset to dates that take months and leap years into account.
+//!     - `fiscal` - Fiscal 4-4-5 calendars, their date parts and the `fp`, `fq` and `fy` duration units.
+//!     - `rrule` - Date ranges from RFC 5545 recurrence rules, e.g. every third Friday of the month.
 //!     - `trigonometry` - Trigonometric functions.
 //!     - `sign` - Compute the element-wise sign of a [`Series`].
 //!     - `propagate_nans` - NaN propagating min/max aggregations.
@@ -300,19 +302,31 @@
 //! | Datetime                | dtype-datetime    |
 //! | Time                    | dtype-time        |
 //! | Duration                | dtype-duration    |
+//! | Interval                | dtype-interval    |
 //! | Int8                    | dtype-i8          |
 //! | Int16                   | dtype-i16         |
 //! | UInt8                   | dtype-u8          |
 //! | UInt16                  | dtype-u16         |
+//! | Float16                 | dtype-f16         |
+//! | Decimal256              | dtype-decimal256  |
+//! | Uuid                    | dtype-uuid        |
+//! | Json                    | dtype-json        |
 //! | Categorical             | dtype-categorical |
 //! | Struct                  | dtype-struct      |
+//! | Map                     | dtype-map         |
+//! | Union                   | dtype-union       |
+//! | Extension               | dtype-extension   |
 //!
 //!
 //! Or you can choose one of the preconfigured pre-sets.
 //!
-//! * `dtype-full` - all opt-in dtypes.
+//! * `dtype-full` - all opt-in dtypes, except Interval, Map, Float16, Decimal256 and Extension.
 //! * `dtype-slim` - slim preset of opt-in dtypes.
 //!
+//! Enabling `dtype-map` changes how Parquet and IPC `MAP` columns are read: they become a
+//! [`DataType::Map`] instead of a `List` of `{key, value}` structs. Cast them to that `List` type
+//! to keep the previous representation.
+//!
 //! ## Performance
 //! To get the best performance out of Polars we recommend compiling on a nightly compiler
 //! with the features `simd` and `performant` activated. The activated cpu features also influence
@@ -155,3 +155,166 @@ fn write_and_read_ipc_empty_series() {
     let df_read = IpcReader::new(buf).finish().unwrap();
     assert!(df.equals(&df_read));
 }
+
+#[test]
+#[cfg(feature = "dtype-interval")]
+fn write_and_read_ipc_interval() {
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    let intervals = IntervalChunked::from_intervals(
+        "a".into(),
+        [
+            Some(arrow::types::months_days_ns::new(1, -2, 3)),
+            None,
+            Some(arrow::types::months_days_ns::new(-5, 0, -7)),
+        ],
+    );
+    let mut df = DataFrame::new(vec![intervals.into_series().into()]).unwrap();
+
+    IpcWriter::new(&mut buf)
+        .finish(&mut df)
+        .expect("ipc writer");
+    buf.set_position(0);
+
+    let df_read = IpcReader::new(buf).finish().unwrap();
+    assert!(df.equals_missing(&df_read));
+}
+
+#[test]
+#[cfg(feature = "dtype-extension")]
+fn write_and_read_ipc_extension() {
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    let s = Series::new("a".into(), [Some(1i64), None, Some(3)])
+        .into_extension("my.ext".into(), Some("{\"unit\":\"m\"}".into()));
+    let mut df = DataFrame::new(vec![s.into()]).unwrap();
+
+    IpcWriter::new(&mut buf)
+        .finish(&mut df)
+        .expect("ipc writer");
+    buf.set_position(0);
+
+    let df_read = IpcReader::new(buf).finish().unwrap();
+    assert_eq!(df_read.schema(), df.schema());
+    assert!(df.equals_missing(&df_read));
+}
+
+#[test]
+#[cfg(feature = "dtype-map")]
+fn write_and_read_ipc_map() {
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    let entries = df!("key" => ["a", "b"], "value" => [Some(1i32), None])
+        .unwrap()
+        .into_struct("entries".into())
+        .into_series();
+    let mut maps = entries.implode().unwrap();
+    maps.append(&ListChunked::full_null_with_dtype(
+        "m".into(),
+        1,
+        entries.dtype(),
+    ))
+    .unwrap();
+    let maps = maps.with_name("m".into()).into_map().unwrap();
+    let mut df = DataFrame::new(vec![maps.into_series().into()]).unwrap();
+
+    IpcWriter::new(&mut buf)
+        .finish(&mut df)
+        .expect("ipc writer");
+    buf.set_position(0);
+
+    let df_read = IpcReader::new(buf).finish().unwrap();
+    assert_eq!(df_read.schema(), df.schema());
+    assert!(df.equals_missing(&df_read));
+}
+
+#[test]
+#[cfg(feature = "dtype-f16")]
+fn write_and_read_ipc_float16() {
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    let values = Float16Chunked::from_f16_iter(
+        "a".into(),
+        [Some(f16::from_f32(1.5)), None, Some(f16::from_f32(-0.25))],
+    );
+    let mut df = DataFrame::new(vec![values.into_series().into()]).unwrap();
+
+    IpcWriter::new(&mut buf)
+        .finish(&mut df)
+        .expect("ipc writer");
+    buf.set_position(0);
+
+    let df_read = IpcReader::new(buf).finish().unwrap();
+    assert_eq!(df_read.schema(), df.schema());
+    assert!(df.equals_missing(&df_read));
+}
+
+#[test]
+#[cfg(feature = "dtype-decimal256")]
+fn write_and_read_ipc_decimal256() {
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    let values = Series::new(
+        "a".into(),
+        [
+            Some(format!("1{}.5", "0".repeat(50))),
+            None,
+            Some("-0.000000000000000001".to_string()),
+        ],
+    )
+    .strict_cast(&DataType::Decimal256(76, 18))
+    .unwrap();
+    let mut df = DataFrame::new(vec![values.into()]).unwrap();
+
+    IpcWriter::new(&mut buf)
+        .finish(&mut df)
+        .expect("ipc writer");
+    buf.set_position(0);
+
+    let df_read = IpcReader::new(buf).finish().unwrap();
+    assert_eq!(df_read.schema(), df.schema());
+    assert!(df.equals_missing(&df_read));
+}
+
+#[test]
+#[cfg(feature = "dtype-union")]
+fn write_and_read_ipc_union() {
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    let a = Series::new("int".into(), [Some(1i64), None, None, Some(3)]);
+    let b = Series::new("str".into(), [None, Some("x"), None, None]);
+    let mut df = DataFrame::default();
+    for mode in [UnionMode::Sparse, UnionMode::Dense] {
+        let u = StructChunked::from_series(format!("{mode:?}").into(), 4, [&a, &b].into_iter())
+            .unwrap()
+            .into_union(mode)
+            .unwrap()
+            .into_series();
+        df.with_column(u).unwrap();
+    }
+
+    IpcWriter::new(&mut buf)
+        .finish(&mut df)
+        .expect("ipc writer");
+    buf.set_position(0);
+
+    let df_read = IpcReader::new(buf).finish().unwrap();
+    assert_eq!(df_read.schema(), df.schema());
+    assert!(df.equals_missing(&df_read));
+}
+
+#[test]
+#[cfg(feature = "dtype-uuid")]
+fn write_and_read_ipc_uuid() {
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    let values = Series::new(
+        "a".into(),
+        [Some("67e55044-10b1-426f-9247-bb680e5fe0c8"), None],
+    )
+    .strict_cast(&DataType::Uuid)
+    .unwrap();
+    let mut df = DataFrame::new(vec![values.into()]).unwrap();
+
+    IpcWriter::new(&mut buf)
+        .finish(&mut df)
+        .expect("ipc writer");
+    buf.set_position(0);
+
+    let df_read = IpcReader::new(buf).finish().unwrap();
+    assert_eq!(df_read.schema(), df.schema());
+    assert!(df.equals_missing(&df_read));
+}
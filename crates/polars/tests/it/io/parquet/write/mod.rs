@@ -297,3 +297,552 @@ fn test_read_parquet_with_columns() {
     assert_eq!(df_read.shape(), (3, 2));
     df_read.equals(&expected);
 }
+
+#[test]
+#[cfg(feature = "dtype-interval")]
+fn test_write_and_read_parquet_interval() {
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    let intervals = IntervalChunked::from_intervals(
+        "a".into(),
+        [
+            Some(arrow::types::months_days_ns::new(1, -2, 3)),
+            None,
+            Some(arrow::types::months_days_ns::new(-5, 0, -7)),
+        ],
+    );
+    let mut df = DataFrame::new(vec![intervals.into_series().into()]).unwrap();
+
+    ParquetWriter::new(&mut buf)
+        .finish(&mut df)
+        .expect("parquet writer");
+    buf.set_position(0);
+
+    let df_read = ParquetReader::new(buf).finish().unwrap();
+    assert!(df.equals_missing(&df_read));
+}
+
+#[cfg(feature = "dtype-struct")]
+fn write_arrow_map() -> Vec<u8> {
+    use std::sync::Arc;
+
+    use arrow::array::{Int32Array, MapArray, StructArray, Utf8ViewArray};
+    use arrow::bitmap::Bitmap;
+    use arrow::datatypes::{ArrowDataType, ArrowSchema, Field as ArrowField};
+    use arrow::record_batch::RecordBatchT;
+    use polars_parquet::arrow::write as arrow_write;
+    use polars_parquet::arrow::write::{
+        ChildWriteOptions, ColumnWriteOptions, Encoding, FieldWriteOptions,
+        ListLikeFieldWriteOptions, RowGroupIterator, StatisticsOptions, StructFieldWriteOptions,
+    };
+
+    let entries_dtype = ArrowDataType::Struct(vec![
+        ArrowField::new("key".into(), ArrowDataType::Utf8View, false),
+        ArrowField::new("value".into(), ArrowDataType::Int32, true),
+    ]);
+    let entries = StructArray::new(
+        entries_dtype.clone(),
+        3,
+        vec![
+            Utf8ViewArray::from_slice_values(["a", "b", "c"]).boxed(),
+            Int32Array::from([Some(1), None, Some(3)]).boxed(),
+        ],
+        None,
+    );
+    let map_dtype = ArrowDataType::Map(
+        Box::new(ArrowField::new("key_value".into(), entries_dtype, false)),
+        false,
+    );
+    let map = MapArray::new(
+        map_dtype.clone(),
+        vec![0, 2, 2, 3].try_into().unwrap(),
+        entries.boxed(),
+        Some(Bitmap::from([true, false, true])),
+    );
+
+    let schema = ArrowSchema::from_iter([ArrowField::new("m".into(), map_dtype, true)]);
+    let leaf = || FieldWriteOptions::default_with_encoding(Encoding::Plain);
+    let column_options = vec![ColumnWriteOptions::default_with(
+        ChildWriteOptions::ListLike(Box::new(ListLikeFieldWriteOptions {
+            child: ColumnWriteOptions::default_with(ChildWriteOptions::Struct(Box::new(
+                StructFieldWriteOptions {
+                    children: vec![
+                        leaf().into_default_column_write_options(),
+                        leaf().into_default_column_write_options(),
+                    ],
+                },
+            ))),
+        })),
+    )];
+    let options = arrow_write::WriteOptions {
+        statistics: StatisticsOptions::full(),
+        compression: CompressionOptions::Uncompressed,
+        version: Version::V2,
+        data_page_size: None,
+    };
+
+    let batch = RecordBatchT::try_new(map.len(), Arc::new(schema.clone()), vec![map.boxed()]);
+    let row_groups = RowGroupIterator::try_new(
+        vec![batch].into_iter(),
+        &schema,
+        options,
+        column_options.clone(),
+    )
+    .unwrap();
+    let mut writer =
+        arrow_write::FileWriter::try_new(Cursor::new(vec![]), schema, options, &column_options)
+            .unwrap();
+    for group in row_groups {
+        writer.write(group.unwrap()).unwrap();
+    }
+    writer.end(None, &column_options).unwrap();
+    writer.into_inner().into_inner()
+}
+
+#[test]
+#[cfg(feature = "dtype-struct")]
+fn test_read_parquet_map_as_list() {
+    let entries = df!("key" => ["a", "b", "c"], "value" => [Some(1i32), None, Some(3)])
+        .unwrap()
+        .into_struct("key_value".into())
+        .into_series();
+    let mut expected = entries.slice(0, 2).implode().unwrap();
+    expected
+        .append(&ListChunked::full_null_with_dtype(
+            "m".into(),
+            1,
+            entries.dtype(),
+        ))
+        .unwrap();
+    expected
+        .append(&entries.slice(2, 1).implode().unwrap())
+        .unwrap();
+    let expected = expected.with_name("m".into()).into_series();
+
+    let df_read = ParquetReader::new(Cursor::new(write_arrow_map()))
+        .finish()
+        .unwrap();
+    let read = df_read.column("m").unwrap().as_materialized_series();
+    // With `dtype-map` the same entries are read as a map instead.
+    #[cfg(feature = "dtype-map")]
+    let read = {
+        assert_eq!(
+            read.dtype(),
+            &DataType::Map(Box::new(DataType::String), Box::new(DataType::Int32))
+        );
+        &read.map().unwrap().entries().clone().into_series()
+    };
+    assert_eq!(read.dtype(), expected.dtype());
+    assert!(read.equals_missing(&expected));
+}
+
+#[cfg(feature = "dtype-map")]
+fn map_df() -> DataFrame {
+    let entries = df!("key" => ["a", "b", "c"], "value" => [Some(1i32), None, Some(3)])
+        .unwrap()
+        .into_struct("entries".into())
+        .into_series();
+    let mut maps = entries.slice(0, 2).implode().unwrap();
+    maps.append(&ListChunked::full_null_with_dtype(
+        "m".into(),
+        1,
+        entries.dtype(),
+    ))
+    .unwrap();
+    maps.append(&entries.slice(2, 1).implode().unwrap())
+        .unwrap();
+    let maps = maps.with_name("m".into()).into_map().unwrap();
+    DataFrame::new(vec![maps.into_series().into()]).unwrap()
+}
+
+#[test]
+#[cfg(feature = "dtype-map")]
+fn test_write_and_read_parquet_map() {
+    use polars_parquet::arrow::read::schema::parquet_to_arrow_schema;
+    use polars_parquet::parquet::schema::types::GroupLogicalType;
+
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    let mut df = map_df();
+
+    ParquetWriter::new(&mut buf)
+        .finish(&mut df)
+        .expect("parquet writer");
+    buf.set_position(0);
+
+    // The column is written as a parquet MAP, which is also read as such by readers that ignore
+    // the embedded arrow schema.
+    let metadata = read_metadata(&mut buf).unwrap();
+    let fields = metadata.schema().fields();
+    assert!(matches!(
+        &fields[0],
+        ParquetType::GroupType {
+            logical_type: Some(GroupLogicalType::Map),
+            ..
+        }
+    ));
+    let schema = parquet_to_arrow_schema(fields);
+    assert!(matches!(
+        schema.get("m").unwrap().dtype(),
+        ArrowDataType::Map(_, _)
+    ));
+    buf.set_position(0);
+
+    let df_read = ParquetReader::new(buf).finish().unwrap();
+    assert_eq!(df_read.schema(), df.schema());
+    assert!(df.equals_missing(&df_read));
+}
+
+/// A data page v1 with the given repetition and definition levels followed by the plain encoded
+/// `values`.
+#[cfg(feature = "dtype-map")]
+fn nested_page_v1(
+    rep_levels: &[u32],
+    def_levels: &[u32],
+    def_bits: u32,
+    values: &[u8],
+    descriptor: &Descriptor,
+) -> ParquetResult<Page> {
+    use polars_parquet::parquet::CowBuffer;
+    use polars_parquet::parquet::encoding::Encoding;
+    use polars_parquet::parquet::encoding::hybrid_rle::encode;
+    use polars_parquet::parquet::page::{DataPage, DataPageHeader, DataPageHeaderV1};
+
+    let mut buffer = vec![];
+    for (levels, num_bits) in [(rep_levels, 1), (def_levels, def_bits)] {
+        let mut encoded = vec![];
+        encode::<u32, _, _>(&mut encoded, levels.iter().copied(), num_bits)?;
+        buffer.extend_from_slice(&(encoded.len() as u32).to_le_bytes());
+        buffer.extend_from_slice(&encoded);
+    }
+    buffer.extend_from_slice(values);
+
+    let header = DataPageHeaderV1 {
+        num_values: rep_levels.len() as i32,
+        encoding: Encoding::Plain.into(),
+        definition_level_encoding: Encoding::Rle.into(),
+        repetition_level_encoding: Encoding::Rle.into(),
+        statistics: None,
+    };
+    Ok(Page::Data(DataPage::new(
+        DataPageHeader::V1(header),
+        CowBuffer::Owned(buffer),
+        descriptor.clone(),
+        rep_levels.len(),
+    )))
+}
+
+#[test]
+#[cfg(feature = "dtype-map")]
+fn test_read_parquet_map_from_other_writer() -> ParquetResult<()> {
+    use polars_parquet::parquet::schema::Repetition;
+    use polars_parquet::parquet::schema::types::{
+        GroupConvertedType, GroupLogicalType, PrimitiveLogicalType,
+    };
+
+    // The standard layout of a MAP column, as written by e.g. parquet-mr and pyarrow:
+    // optional group m (MAP) {
+    //   repeated group key_value {
+    //     required binary key (STRING);
+    //     optional int32 value;
+    //   }
+    // }
+    let key = ParquetType::try_from_primitive(
+        "key".into(),
+        PhysicalType::ByteArray,
+        Repetition::Required,
+        None,
+        Some(PrimitiveLogicalType::String),
+        None,
+    )?;
+    let value = ParquetType::from_physical("value".into(), PhysicalType::Int32);
+    let key_value = ParquetType::from_group(
+        "key_value".into(),
+        Repetition::Repeated,
+        None,
+        None,
+        vec![key, value],
+        None,
+    );
+    let schema = SchemaDescriptor::new(
+        "schema".into(),
+        vec![ParquetType::from_group(
+            "m".into(),
+            Repetition::Optional,
+            Some(GroupConvertedType::Map),
+            Some(GroupLogicalType::Map),
+            vec![key_value],
+            None,
+        )],
+    );
+
+    // Rows: [{a: 1}, {b: null}], null, [], [{c: 3}]
+    let rep_levels = [0, 1, 0, 0, 0];
+    let mut keys = vec![];
+    for key in ["a", "b", "c"] {
+        keys.extend_from_slice(&(key.len() as i32).to_le_bytes());
+        keys.extend_from_slice(key.as_bytes());
+    }
+    let values: Vec<u8> = [1i32, 3].iter().flat_map(|v| v.to_le_bytes()).collect();
+    let columns = schema.columns();
+    let pages = [
+        nested_page_v1(
+            &rep_levels,
+            &[2, 2, 0, 1, 2],
+            2,
+            &keys,
+            &columns[0].descriptor,
+        )?,
+        nested_page_v1(
+            &rep_levels,
+            &[3, 2, 0, 1, 3],
+            2,
+            &values,
+            &columns[1].descriptor,
+        )?,
+    ];
+
+    let options = WriteOptions {
+        write_statistics: false,
+        version: Version::V1,
+    };
+    let columns = pages.into_iter().map(|page| {
+        Ok(DynStreamingIterator::new(Compressor::new_from_vec(
+            DynIter::new(std::iter::once(Ok(page))),
+            CompressionOptions::Uncompressed,
+            vec![],
+        )))
+    });
+    let mut writer = FileWriter::new(Cursor::new(vec![]), schema, options, None);
+    writer.write(DynIter::new(columns))?;
+    writer.end(None)?;
+    let data = writer.into_inner().into_inner();
+
+    let entries = df!("key" => ["a", "b", "c"], "value" => [Some(1i32), None, Some(3)])
+        .unwrap()
+        .into_struct("entries".into())
+        .into_series();
+    let mut expected = entries.slice(0, 2).implode().unwrap();
+    expected
+        .append(&ListChunked::full_null_with_dtype(
+            "m".into(),
+            1,
+            entries.dtype(),
+        ))
+        .unwrap();
+    expected
+        .append(&entries.slice(0, 0).implode().unwrap())
+        .unwrap();
+    expected
+        .append(&entries.slice(2, 1).implode().unwrap())
+        .unwrap();
+    let expected = expected.with_name("m".into()).into_map().unwrap();
+
+    let df_read = ParquetReader::new(Cursor::new(data)).finish().unwrap();
+    let read = df_read.column("m").unwrap().as_materialized_series();
+    assert_eq!(read.dtype(), expected.dtype());
+    assert!(read.equals_missing(&expected.into_series()));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-f16")]
+fn test_write_and_read_parquet_float16() {
+    use polars_parquet::arrow::read::schema::parquet_to_arrow_schema;
+    use polars_parquet::parquet::schema::types::PrimitiveLogicalType;
+
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    let values = Float16Chunked::from_f16_iter(
+        "a".into(),
+        [
+            Some(f16::from_f32(1.5)),
+            None,
+            Some(f16::from_f32(f32::NAN)),
+            Some(f16::from_f32(-0.25)),
+        ],
+    );
+    let mut df = DataFrame::new(vec![values.into_series().into()]).unwrap();
+
+    ParquetWriter::new(&mut buf)
+        .finish(&mut df)
+        .expect("parquet writer");
+    buf.set_position(0);
+
+    // The column is written as a parquet FLOAT16, which is also read as such by readers that
+    // ignore the embedded arrow schema.
+    let metadata = read_metadata(&mut buf).unwrap();
+    let fields = metadata.schema().fields();
+    let ParquetType::PrimitiveType(primitive_type) = &fields[0] else {
+        panic!("expected a primitive type");
+    };
+    assert_eq!(
+        primitive_type.physical_type,
+        PhysicalType::FixedLenByteArray(2)
+    );
+    assert_eq!(
+        primitive_type.logical_type,
+        Some(PrimitiveLogicalType::Float16)
+    );
+    let schema = parquet_to_arrow_schema(fields);
+    assert_eq!(schema.get("a").unwrap().dtype(), &ArrowDataType::Float16);
+    buf.set_position(0);
+
+    let df_read = ParquetReader::new(buf).finish().unwrap();
+    assert_eq!(df_read.schema(), df.schema());
+    assert!(df.equals_missing(&df_read));
+}
+
+#[test]
+#[cfg(feature = "dtype-extension")]
+fn test_write_and_read_parquet_extension() {
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    let s = Series::new("a".into(), [Some(1i64), None, Some(3)])
+        .into_extension("my.ext".into(), Some("{\"unit\":\"m\"}".into()));
+    let mut df = DataFrame::new(vec![s.into()]).unwrap();
+
+    ParquetWriter::new(&mut buf)
+        .finish(&mut df)
+        .expect("parquet writer");
+    buf.set_position(0);
+
+    let df_read = ParquetReader::new(buf).finish().unwrap();
+    assert_eq!(df_read.schema(), df.schema());
+    assert!(df.equals_missing(&df_read));
+}
+
+#[test]
+#[cfg(feature = "dtype-decimal256")]
+fn test_write_and_read_parquet_decimal256() {
+    use polars_parquet::arrow::read::schema::parquet_to_arrow_schema;
+    use polars_parquet::parquet::schema::types::PrimitiveLogicalType;
+
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    let values = Series::new(
+        "a".into(),
+        [
+            Some(format!("-1{}.5", "0".repeat(50))),
+            None,
+            Some("0.000000000000000001".to_string()),
+        ],
+    )
+    .strict_cast(&DataType::Decimal256(76, 18))
+    .unwrap();
+    let mut df = DataFrame::new(vec![values.into()]).unwrap();
+
+    ParquetWriter::new(&mut buf)
+        .finish(&mut df)
+        .expect("parquet writer");
+    buf.set_position(0);
+
+    // The column is written as a parquet DECIMAL, so that readers which ignore the embedded
+    // arrow schema still see a Decimal256.
+    let metadata = read_metadata(&mut buf).unwrap();
+    let fields = metadata.schema().fields();
+    let ParquetType::PrimitiveType(primitive_type) = &fields[0] else {
+        panic!("expected a primitive type");
+    };
+    assert_eq!(
+        primitive_type.logical_type,
+        Some(PrimitiveLogicalType::Decimal(76, 18))
+    );
+    let schema = parquet_to_arrow_schema(fields);
+    assert_eq!(
+        schema.get("a").unwrap().dtype(),
+        &ArrowDataType::Decimal256(76, 18)
+    );
+    buf.set_position(0);
+
+    let df_read = ParquetReader::new(buf).finish().unwrap();
+    assert_eq!(df_read.schema(), df.schema());
+    assert!(df.equals_missing(&df_read));
+}
+
+#[test]
+#[cfg(feature = "dtype-uuid")]
+fn test_write_and_read_parquet_uuid() {
+    use polars_parquet::arrow::read::schema::parquet_to_arrow_schema;
+    use polars_parquet::parquet::schema::types::PrimitiveLogicalType;
+
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    let values = Series::new(
+        "a".into(),
+        [
+            Some("67e55044-10b1-426f-9247-bb680e5fe0c8"),
+            None,
+            Some("00000000-0000-0000-0000-000000000001"),
+        ],
+    )
+    .strict_cast(&DataType::Uuid)
+    .unwrap();
+    let mut df = DataFrame::new(vec![values.into()]).unwrap();
+
+    ParquetWriter::new(&mut buf)
+        .finish(&mut df)
+        .expect("parquet writer");
+    buf.set_position(0);
+
+    // The column is written as a parquet UUID, so that readers which ignore the embedded arrow
+    // schema still see a UUID.
+    let metadata = read_metadata(&mut buf).unwrap();
+    let fields = metadata.schema().fields();
+    let ParquetType::PrimitiveType(primitive_type) = &fields[0] else {
+        panic!("expected a primitive type");
+    };
+    assert_eq!(
+        primitive_type.physical_type,
+        PhysicalType::FixedLenByteArray(16)
+    );
+    assert_eq!(
+        primitive_type.logical_type,
+        Some(PrimitiveLogicalType::Uuid)
+    );
+    let schema = parquet_to_arrow_schema(fields);
+    assert_eq!(schema.get("a").unwrap().dtype(), &uuid_arrow_dtype());
+    buf.set_position(0);
+
+    let df_read = ParquetReader::new(buf).finish().unwrap();
+    assert_eq!(df_read.schema(), df.schema());
+    assert!(df.equals_missing(&df_read));
+}
+
+#[test]
+#[cfg(feature = "dtype-json")]
+fn test_write_and_read_parquet_json() {
+    use polars_parquet::arrow::read::schema::parquet_to_arrow_schema;
+    use polars_parquet::parquet::schema::types::PrimitiveLogicalType;
+
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    let values = Series::new(
+        "a".into(),
+        [Some(r#"{"a": [1, 2.5, "x"], "b": null}"#), None, Some("3")],
+    )
+    .strict_cast(&DataType::Json)
+    .unwrap();
+    let mut df = DataFrame::new(vec![values.into()]).unwrap();
+
+    ParquetWriter::new(&mut buf)
+        .finish(&mut df)
+        .expect("parquet writer");
+    buf.set_position(0);
+
+    // The column is written as parquet JSON, so that readers which ignore the embedded arrow
+    // schema still see JSON.
+    let metadata = read_metadata(&mut buf).unwrap();
+    let fields = metadata.schema().fields();
+    let ParquetType::PrimitiveType(primitive_type) = &fields[0] else {
+        panic!("expected a primitive type");
+    };
+    assert_eq!(primitive_type.physical_type, PhysicalType::ByteArray);
+    assert_eq!(
+        primitive_type.logical_type,
+        Some(PrimitiveLogicalType::Json)
+    );
+    let schema = parquet_to_arrow_schema(fields);
+    assert_eq!(
+        schema.get("a").unwrap().dtype(),
+        &json_arrow_dtype(CompatLevel::newest())
+    );
+    buf.set_position(0);
+
+    let df_read = ParquetReader::new(buf).finish().unwrap();
+    assert_eq!(df_read.schema(), df.schema());
+    assert!(df.equals_missing(&df_read));
+}
@@ -113,3 +113,94 @@ fn fuzz_exprs() {
         }
     }
 }
+
+#[test]
+#[cfg(feature = "dtype-map")]
+fn test_map_namespace() -> PolarsResult<()> {
+    let df = df![
+        "id" => [1, 1, 2, 3],
+        "key" => ["a", "b", "a", "c"],
+        "value" => [Some(1), Some(2), None, Some(4)],
+    ]?;
+
+    let out = df
+        .lazy()
+        .group_by_stable([col("id")])
+        .agg([as_struct(vec![col("key"), col("value")]).alias("m")])
+        .select([col("m").map_().from_entries()])
+        .with_columns([
+            col("m").map_().get(lit("a")).alias("get"),
+            col("m").map_().contains_key(lit("a")).alias("contains"),
+            col("m").map_().keys().alias("keys"),
+        ])
+        .collect()?;
+
+    assert_eq!(
+        out.column("m")?.dtype(),
+        &DataType::Map(Box::new(DataType::String), Box::new(DataType::Int32))
+    );
+    let expected = df![
+        "get" => [Some(1), None, None],
+        "contains" => [true, true, false],
+    ]?;
+    assert!(out.select(["get", "contains"])?.equals_missing(&expected));
+    assert_eq!(
+        out.column("keys")?
+            .explode(false)?
+            .str()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        ["a", "b", "a", "c"]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "dtype-uuid", feature = "random"))]
+fn test_uuid_group_by_join() -> PolarsResult<()> {
+    let a = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+    let b = "00000000-0000-7000-8000-000000000001";
+    let left = df![
+        "id" => [a, b, a, b, a],
+        "value" => [1, 2, 3, 4, 5],
+    ]?
+    .lazy()
+    .with_column(col("id").strict_cast(DataType::Uuid));
+    let right = df![
+        "id" => [b, a],
+        "name" => ["b", "a"],
+    ]?
+    .lazy()
+    .with_column(col("id").strict_cast(DataType::Uuid));
+
+    let out = left
+        .group_by([col("id")])
+        .agg([col("value").sum()])
+        .join(
+            right,
+            [col("id")],
+            [col("id")],
+            JoinArgs::new(JoinType::Inner),
+        )
+        .sort(["id"], Default::default())
+        .collect()?;
+
+    assert_eq!(out.column("id")?.dtype(), &DataType::Uuid);
+    let expected = df![
+        "value" => [6, 9],
+        "name" => ["b", "a"],
+    ]?;
+    assert!(out.select(["value", "name"])?.equals(&expected));
+
+    let out = df!["x" => [1, 2, 3, 4]]?
+        .lazy()
+        .select([
+            col("x").uuid4(Some(0)).alias("v4"),
+            col("x").uuid7(None).alias("v7"),
+        ])
+        .collect()?;
+    assert_eq!(out.column("v4")?.dtype(), &DataType::Uuid);
+    assert_eq!(out.column("v4")?.n_unique()?, 4);
+    assert_eq!(out.column("v7")?.n_unique()?, 4);
+    Ok(())
+}
@@ -61,3 +61,521 @@ fn test_group_by_dynamic_week_bounds() -> PolarsResult<()> {
     assert_eq!(a.get(1)?, AnyValue::Int32(6));
     Ok(())
 }
+
+#[cfg(all(
+    feature = "dtype-datetime",
+    feature = "dynamic_group_by",
+    feature = "new_streaming"
+))]
+fn streaming_window_lf() -> PolarsResult<LazyFrame> {
+    // Irregular, sorted timestamps with duplicates.
+    let n = 5_000;
+    let time = Int64Chunked::from_iter_values(
+        "time".into(),
+        (0..n).map(|i: i64| (i / 2) * 13_000 + (i / 2) % 7 * 1_000),
+    )
+    .into_datetime(TimeUnit::Milliseconds, None)
+    .into_series();
+    let g = Int32Chunked::from_iter_values("g".into(), (0..n).map(|i| (i % 3) as i32));
+    let v = Float64Chunked::from_iter_values("v".into(), (0..n).map(|i| i as f64));
+    let df = df!["time" => time, "g" => g, "v" => v]?;
+
+    // Concatenate slices, so the streaming engine receives multiple morsels.
+    let parts = (0..n)
+        .step_by(700)
+        .map(|offset| df.slice(offset, 700).lazy())
+        .collect::<Vec<_>>();
+    concat(parts, UnionArgs::default())
+}
+
+#[cfg(all(
+    feature = "dtype-datetime",
+    feature = "dynamic_group_by",
+    feature = "new_streaming"
+))]
+fn assert_streaming_matches_in_memory(lf: LazyFrame, sort_by: &[&str]) -> PolarsResult<()> {
+    let sort = |df: DataFrame| df.sort(sort_by.to_vec(), SortMultipleOptions::default());
+    let expected = sort(lf.clone().collect_with_engine(Engine::InMemory)?)?;
+    let out = sort(lf.collect_with_engine(Engine::Streaming)?)?;
+    assert!(
+        out.equals_missing(&expected),
+        "streaming:\n{out}\nin-memory:\n{expected}"
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(all(
+    feature = "dtype-datetime",
+    feature = "dynamic_group_by",
+    feature = "new_streaming"
+))]
+fn test_group_by_dynamic_streaming() -> PolarsResult<()> {
+    let lf = streaming_window_lf()?;
+
+    for closed_window in [
+        ClosedWindow::Left,
+        ClosedWindow::Right,
+        ClosedWindow::Both,
+        ClosedWindow::None,
+    ] {
+        for start_by in [StartBy::WindowBound, StartBy::DataPoint] {
+            for (by, sort_by) in [(vec![], vec!["time"]), (vec![col("g")], vec!["g", "time"])] {
+                let lf = lf.clone().group_by_dynamic(
+                    col("time"),
+                    by,
+                    DynamicGroupOptions {
+                        every: Duration::parse("5m"),
+                        period: Duration::parse("12m"),
+                        offset: Duration::parse("1m"),
+                        label: Label::Right,
+                        include_boundaries: start_by == StartBy::DataPoint,
+                        closed_window,
+                        start_by,
+                        ..Default::default()
+                    },
+                );
+                let lf = lf.agg([
+                    col("v").sum().alias("sum"),
+                    col("v").first().alias("first"),
+                    len().alias("len"),
+                ]);
+                assert_streaming_matches_in_memory(lf, &sort_by)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(all(
+    feature = "dtype-datetime",
+    feature = "dynamic_group_by",
+    feature = "new_streaming"
+))]
+fn test_rolling_streaming() -> PolarsResult<()> {
+    let lf = streaming_window_lf()?;
+
+    for closed_window in [ClosedWindow::Left, ClosedWindow::Right] {
+        for offset in [None, Some(Duration::parse("1m"))] {
+            for (by, sort_by) in [(vec![], vec!["time"]), (vec![col("g")], vec!["g", "time"])] {
+                let period = Duration::parse("10m");
+                let lf = lf.clone().rolling(
+                    col("time"),
+                    by,
+                    RollingGroupOptions {
+                        period,
+                        offset: offset.unwrap_or(-period),
+                        closed_window,
+                        ..Default::default()
+                    },
+                );
+                let lf = lf.agg([col("v").sum().alias("sum"), len().alias("len")]);
+                assert_streaming_matches_in_memory(lf, &sort_by)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(all(
+    feature = "dtype-datetime",
+    feature = "dynamic_group_by",
+    feature = "new_streaming",
+    feature = "dot_diagram"
+))]
+fn test_window_streaming_sorted_per_group() -> PolarsResult<()> {
+    // Only sorted within every group, which the in-memory engine accepts with keys.
+    let lf = streaming_window_lf()?.sort(["g", "time"], SortMultipleOptions::default());
+
+    let dynamic = lf.clone().group_by_dynamic(
+        col("time"),
+        [col("g")],
+        DynamicGroupOptions {
+            every: Duration::parse("5m"),
+            period: Duration::parse("12m"),
+            offset: Duration::parse("0m"),
+            start_by: StartBy::DataPoint,
+            ..Default::default()
+        },
+    );
+    let rolling = lf.rolling(
+        col("time"),
+        [col("g")],
+        RollingGroupOptions {
+            period: Duration::parse("10m"),
+            offset: Duration::parse("-10m"),
+            ..Default::default()
+        },
+    );
+    for (lf, node) in [(dynamic, "group-by-dynamic"), (rolling, "rolling")] {
+        let lf = lf.agg([col("v").sum().alias("sum"), len().alias("len")]);
+        // The keyed windows are computed by the streaming node, not the in-memory engine.
+        assert!(lf.to_dot_streaming_phys(true)?.contains(node));
+        assert_streaming_matches_in_memory(lf, &["g", "time"])?;
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(all(
+    feature = "temporal",
+    feature = "dtype-datetime",
+    feature = "dynamic_group_by"
+))]
+fn test_group_by_session() -> PolarsResult<()> {
+    let minutes = [0i64, 5, 12, 60, 64, 0, 30, 31, 90];
+    let time = Int64Chunked::from_iter_values("time".into(), minutes.iter().map(|m| m * 60_000))
+        .into_datetime(TimeUnit::Milliseconds, None)
+        .into_series();
+    let df = df![
+        "user" => ["a", "a", "a", "a", "a", "b", "b", "b", "b"],
+        "time" => time,
+        "clicks" => [1, 2, 3, 4, 5, 6, 7, 8, 9],
+    ]?;
+
+    let out = df
+        .lazy()
+        .group_by_session(
+            col("time"),
+            [col("user")],
+            SessionGroupOptions {
+                gap: Duration::parse("10m"),
+                include_boundaries: true,
+                ..Default::default()
+            },
+        )
+        .agg([col("clicks").sum(), len()])
+        .collect()?;
+
+    let minutes_of = |name: &str| -> PolarsResult<Vec<i64>> {
+        Ok(out
+            .column(name)?
+            .datetime()?
+            .physical()
+            .into_no_null_iter()
+            .map(|t| t / 60_000)
+            .collect())
+    };
+    assert_eq!(
+        out.column("user")?
+            .str()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        ["a", "a", "b", "b", "b"]
+    );
+    assert_eq!(minutes_of("time")?, [0, 60, 0, 30, 90]);
+    assert_eq!(minutes_of("_lower_boundary")?, [0, 60, 0, 30, 90]);
+    assert_eq!(minutes_of("_upper_boundary")?, [22, 74, 10, 41, 100]);
+    assert_eq!(
+        out.column("clicks")?
+            .i32()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        [6, 9, 6, 15, 9]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(all(
+    feature = "temporal",
+    feature = "dtype-date",
+    feature = "dynamic_group_by"
+))]
+fn test_group_by_session_date_boundaries() -> PolarsResult<()> {
+    let days = [0i32, 1, 3, 4, 10];
+    let df = df![
+        "date" => Int32Chunked::from_vec("date".into(), days.to_vec()).into_date().into_series(),
+        "v" => [1, 2, 3, 4, 5],
+    ]?;
+
+    let out = df
+        .lazy()
+        .group_by_session(
+            col("date"),
+            [],
+            SessionGroupOptions {
+                gap: Duration::parse("2d"),
+                include_boundaries: true,
+                ..Default::default()
+            },
+        )
+        .agg([col("v").sum()])
+        .collect()?;
+
+    let days_of = |name: &str| -> PolarsResult<Vec<i32>> {
+        Ok(out
+            .column(name)?
+            .date()?
+            .physical()
+            .into_no_null_iter()
+            .collect())
+    };
+    assert_eq!(days_of("date")?, [0, 10]);
+    assert_eq!(days_of("_lower_boundary")?, [0, 10]);
+    assert_eq!(days_of("_upper_boundary")?, [6, 12]);
+    assert_eq!(
+        out.column("v")?
+            .i32()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        [10, 5]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(all(
+    feature = "dtype-datetime",
+    feature = "dynamic_group_by",
+    feature = "new_streaming"
+))]
+fn test_group_by_session_streaming() -> PolarsResult<()> {
+    let lf = streaming_window_lf()?;
+
+    for gap in ["10s", "13s", "1m"] {
+        for include_boundaries in [false, true] {
+            for (by, sort_by) in [(vec![], vec!["time"]), (vec![col("g")], vec!["g", "time"])] {
+                let lf = lf.clone().group_by_session(
+                    col("time"),
+                    by,
+                    SessionGroupOptions {
+                        gap: Duration::parse(gap),
+                        include_boundaries,
+                        ..Default::default()
+                    },
+                );
+                let lf = lf.agg([col("v").sum().alias("sum"), len().alias("len")]);
+                assert_streaming_matches_in_memory(lf, &sort_by)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(all(feature = "dtype-datetime", feature = "dynamic_group_by"))]
+fn minutes(name: &str, minutes: &[i64]) -> Series {
+    Int64Chunked::from_iter_values(name.into(), minutes.iter().map(|m| m * 60_000))
+        .into_datetime(TimeUnit::Milliseconds, None)
+        .into_series()
+}
+
+#[cfg(all(feature = "dtype-datetime", feature = "dynamic_group_by"))]
+fn assert_approx_eq(out: &Column, expected: &[Option<f64>]) {
+    let out: Vec<Option<f64>> = out.f64().unwrap().into_iter().collect();
+    assert_eq!(out.len(), expected.len(), "{out:?}");
+    for (o, e) in out.iter().zip(expected) {
+        match (o, e) {
+            (Some(o), Some(e)) => assert!((o - e).abs() < 1e-9, "{out:?} != {expected:?}"),
+            _ => assert_eq!(o, e, "{out:?} != {expected:?}"),
+        }
+    }
+}
+
+#[test]
+#[cfg(all(feature = "dtype-datetime", feature = "dynamic_group_by"))]
+fn test_group_by_dynamic_time_weighted() -> PolarsResult<()> {
+    let df = df![
+        "t" => minutes("t", &[0, 4, 12, 18, 28, 35, 14, 24]),
+        "g" => ["a", "a", "a", "a", "a", "a", "b", "b"],
+        "v" => [Some(0.0), Some(8.0), Some(4.0), Some(10.0), Some(20.0), None, Some(2.0), Some(4.0)],
+    ]?;
+    let aggs = [
+        col("v").time_weighted_mean(col("t")).alias("mean"),
+        col("v").time_weighted_integral(col("t")).alias("integral"),
+        col("v").time_weighted_last(col("t")).alias("last"),
+    ];
+
+    let out = df
+        .clone()
+        .lazy()
+        .group_by_dynamic(
+            col("t"),
+            [col("g")],
+            DynamicGroupOptions {
+                every: Duration::parse("10m"),
+                period: Duration::parse("10m"),
+                offset: Duration::parse("0m"),
+                start_by: StartBy::WindowBound,
+                ..Default::default()
+            },
+        )
+        .agg(aggs.clone())
+        .collect()?;
+
+    // The value at the start of a window is interpolated from the last value before it, but
+    // nothing is carried in from another group. The last value holds until the end of the
+    // window, also if it is the only one (the first window of "b").
+    assert_approx_eq(
+        out.column("mean")?,
+        &[
+            Some(6.4),
+            Some(7.1),
+            Some(16.8),
+            None,
+            Some(2.0),
+            Some(3.84),
+        ],
+    );
+    assert_approx_eq(
+        out.column("integral")?,
+        &[
+            Some(64.0 * 60_000.0),
+            Some(71.0 * 60_000.0),
+            Some(168.0 * 60_000.0),
+            None,
+            Some(12.0 * 60_000.0),
+            Some(38.4 * 60_000.0),
+        ],
+    );
+    // The last window only has a null, so the value from before it is the last one.
+    assert_approx_eq(
+        out.column("last")?,
+        &[
+            Some(8.0),
+            Some(10.0),
+            Some(20.0),
+            Some(20.0),
+            Some(2.0),
+            Some(4.0),
+        ],
+    );
+
+    // Outside of a dynamic group-by nothing is carried in.
+    let out = df
+        .lazy()
+        .filter(col("g").eq(lit("a")))
+        .select(aggs)
+        .collect()?;
+    assert_approx_eq(out.column("mean")?, &[Some(256.0 / 28.0)]);
+    assert_approx_eq(out.column("integral")?, &[Some(256.0 * 60_000.0)]);
+    assert_approx_eq(out.column("last")?, &[Some(20.0)]);
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "dtype-datetime", feature = "dynamic_group_by"))]
+fn test_time_weighted_sparse_final_sample() -> PolarsResult<()> {
+    let df = df![
+        "t" => minutes("t", &[1, 12]),
+        "v" => [5.0, 1.0],
+    ]?;
+    let out = df
+        .lazy()
+        .group_by_dynamic(
+            col("t"),
+            [],
+            DynamicGroupOptions {
+                every: Duration::parse("10m"),
+                period: Duration::parse("10m"),
+                offset: Duration::parse("0m"),
+                start_by: StartBy::WindowBound,
+                ..Default::default()
+            },
+        )
+        .agg([
+            col("v").time_weighted_mean(col("t")).alias("mean"),
+            col("v").time_weighted_integral(col("t")).alias("integral"),
+        ])
+        .collect()?;
+
+    // The last sample of a window holds until the end of the window: from 1m to 10m in the
+    // first one, and from 12m to 20m in the second one (which starts at the value 19/11,
+    // interpolated between the samples).
+    let second = (19.0 / 11.0 + 1.0) / 2.0 * 2.0 + 8.0;
+    assert_approx_eq(out.column("mean")?, &[Some(5.0), Some(second / 10.0)]);
+    assert_approx_eq(
+        out.column("integral")?,
+        &[Some(45.0 * 60_000.0), Some(second * 60_000.0)],
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "dtype-datetime", feature = "dynamic_group_by"))]
+fn test_rolling_time_weighted() -> PolarsResult<()> {
+    let df = df![
+        "t" => minutes("t", &[0, 4, 12, 18, 28]),
+        "v" => [0.0, 8.0, 4.0, 10.0, 20.0],
+    ]?;
+    let expected = [Some(0.0), Some(4.0), Some(6.0), Some(6.2), Some(15.0)];
+
+    let out = df
+        .clone()
+        .lazy()
+        .rolling(
+            col("t"),
+            [],
+            RollingGroupOptions {
+                period: Duration::parse("10m"),
+                offset: Duration::parse("-10m"),
+                closed_window: ClosedWindow::Right,
+                ..Default::default()
+            },
+        )
+        .agg([col("v").time_weighted_mean(col("t"))])
+        .collect()?;
+    assert_approx_eq(out.column("v")?, &expected);
+
+    let out = df
+        .lazy()
+        .select([col("v").rolling_time_weighted_mean_by(
+            "t",
+            Duration::parse("10m"),
+            ClosedWindow::Right,
+        )])
+        .collect()?;
+    assert_approx_eq(out.column("v")?, &expected);
+    Ok(())
+}
+
+#[test]
+#[cfg(all(
+    feature = "dtype-datetime",
+    feature = "dynamic_group_by",
+    feature = "new_streaming"
+))]
+fn test_time_weighted_streaming() -> PolarsResult<()> {
+    let lf = streaming_window_lf()?;
+    let aggs = [
+        col("v").time_weighted_mean(col("time")).alias("mean"),
+        col("v")
+            .time_weighted_integral(col("time"))
+            .alias("integral"),
+        col("v").time_weighted_last(col("time")).alias("last"),
+    ];
+
+    for (by, sort_by) in [(vec![], vec!["time"]), (vec![col("g")], vec!["g", "time"])] {
+        let dynamic = lf
+            .clone()
+            .group_by_dynamic(
+                col("time"),
+                by.clone(),
+                DynamicGroupOptions {
+                    every: Duration::parse("5m"),
+                    period: Duration::parse("12m"),
+                    offset: Duration::parse("1m"),
+                    ..Default::default()
+                },
+            )
+            .agg(aggs.clone());
+        assert_streaming_matches_in_memory(dynamic, &sort_by)?;
+
+        let rolling = lf
+            .clone()
+            .rolling(
+                col("time"),
+                by,
+                RollingGroupOptions {
+                    period: Duration::parse("10m"),
+                    offset: Duration::parse("-10m"),
+                    ..Default::default()
+                },
+            )
+            .agg(aggs.clone());
+        assert_streaming_matches_in_memory(rolling, &sort_by)?;
+    }
+    Ok(())
+}
@@ -13,6 +13,8 @@ mod predicate_queries;
 mod projection_queries;
 mod queries;
 mod schema;
+#[cfg(feature = "upsample")]
+mod upsample;
 
 use polars::prelude::*;
 
@@ -0,0 +1,155 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+
+fn hourly(hours: &[i64]) -> Series {
+    Int64Chunked::from_iter_values("time".into(), hours.iter().map(|h| h * 3_600_000))
+        .into_datetime(TimeUnit::Milliseconds, None)
+        .into_series()
+}
+
+fn upsample_lf() -> PolarsResult<LazyFrame> {
+    let df = df![
+        "time" => hourly(&[0, 3, 1, 2, 5]),
+        "g" => ["a", "a", "b", "b", "b"],
+        "v" => [Some(1i32), Some(4), Some(10), None, Some(40)],
+    ]?;
+    Ok(df.lazy())
+}
+
+#[test]
+fn test_upsample_forward_fill() -> PolarsResult<()> {
+    let out = upsample_lf()?
+        .upsample(
+            "time",
+            ["g"],
+            Duration::parse("1h"),
+            UpsampleFill::Forward(None),
+        )
+        .collect()?;
+
+    let expected = df![
+        "time" => hourly(&[0, 1, 2, 3, 1, 2, 3, 4, 5]),
+        "g" => ["a", "a", "a", "a", "b", "b", "b", "b", "b"],
+        "v" => [1i32, 1, 1, 4, 10, 10, 10, 10, 40],
+    ]?;
+    assert!(out.equals_missing(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_upsample_fill_strategies() -> PolarsResult<()> {
+    let upsample = |fill| {
+        upsample_lf()?
+            .filter(col("g").eq(lit("b")))
+            .upsample("time", ["g"], Duration::parse("1h"), fill)
+            .select([col("v")])
+            .collect()
+    };
+
+    let out = upsample(UpsampleFill::None)?;
+    assert_eq!(
+        Vec::from(out.column("v")?.i32()?),
+        [Some(10), None, None, None, Some(40)]
+    );
+
+    let out = upsample(UpsampleFill::Backward(Some(1)))?;
+    assert_eq!(
+        Vec::from(out.column("v")?.i32()?),
+        [Some(10), None, None, Some(40), Some(40)]
+    );
+
+    let out = upsample(UpsampleFill::Linear)?;
+    assert_eq!(
+        Vec::from(out.column("v")?.f64()?),
+        [Some(10.0), Some(17.5), Some(25.0), Some(32.5), Some(40.0)]
+    );
+
+    let out = upsample(UpsampleFill::Value(Scalar::from(-1i64)))?;
+    assert_eq!(
+        Vec::from(out.column("v")?.i32()?),
+        [Some(10), Some(-1), Some(-1), Some(-1), Some(40)]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_upsample_time_interpolation() -> PolarsResult<()> {
+    // 2024-01-01 and 2024-04-01.
+    let df = df![
+        "date" => Int32Chunked::from_slice("date".into(), &[19723, 19814]).into_date().into_series(),
+        "v" => [0.0f64, 91.0],
+    ]?;
+
+    let upsample = |fill| {
+        df.clone()
+            .lazy()
+            .upsample(
+                "date",
+                Vec::<PlSmallStr>::new(),
+                Duration::parse("1mo"),
+                fill,
+            )
+            .collect()
+    };
+
+    // January and March have 31 days, February has 29 days in 2024.
+    let out = upsample(UpsampleFill::Time)?;
+    assert_eq!(out.column("date")?.dtype(), &DataType::Date);
+    assert_eq!(
+        Vec::from(out.column("v")?.f64()?),
+        [Some(0.0), Some(31.0), Some(60.0), Some(91.0)]
+    );
+
+    let out = upsample(UpsampleFill::Linear)?;
+    let v = out.column("v")?.f64()?;
+    for (v, expected) in v
+        .into_no_null_iter()
+        .zip([0.0, 91.0 / 3.0, 2.0 * 91.0 / 3.0, 91.0])
+    {
+        assert!((v - expected).abs() < 1e-9);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_upsample_pushdown() -> PolarsResult<()> {
+    let q = upsample_lf()?
+        .upsample(
+            "time",
+            ["g"],
+            Duration::parse("1h"),
+            UpsampleFill::Forward(None),
+        )
+        .filter(col("g").eq(lit("b")).and(col("v").gt(lit(10))))
+        .select([col("time"), col("v")]);
+
+    // The predicate on the key is pushed below the upsample, the one on the filled values is not.
+    let plan = q.clone().describe_optimized_plan()?;
+    let upsample_pos = plan.find("UPSAMPLE").unwrap();
+    let key_pred_pos = plan.find(r#"[(col("g")) == ("b")]"#).unwrap();
+    let value_pred_pos = plan.find(r#"[(col("v")) > (10)]"#).unwrap();
+    assert!(value_pred_pos < upsample_pos && upsample_pos < key_pred_pos);
+
+    let out = q.collect()?;
+    let expected = df![
+        "time" => hourly(&[5]),
+        "v" => [40i32],
+    ]?;
+    assert!(out.equals(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_upsample_schema() -> PolarsResult<()> {
+    let mut lf = upsample_lf()?.upsample("time", ["g"], Duration::parse("1h"), UpsampleFill::Time);
+    let schema = lf.collect_schema()?;
+    assert_eq!(schema.get("v"), Some(&DataType::Float64));
+    assert_eq!(lf.collect()?.schema(), &schema);
+
+    let err = upsample_lf()?
+        .upsample("time", ["g"], Duration::parse("1i"), UpsampleFill::None)
+        .collect()
+        .unwrap_err();
+    assert!(matches!(err, PolarsError::InvalidOperation(_)));
+    Ok(())
+}
@@ -0,0 +1,148 @@
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use polars::prelude::*;
+
+fn date(y: i32, m: u32, d: u32) -> i32 {
+    (NaiveDate::from_ymd_opt(y, m, d).unwrap() - NaiveDate::default())
+        .num_days()
+        .try_into()
+        .unwrap()
+}
+
+fn dates(name: &str, values: &[i32]) -> Series {
+    Int32Chunked::from_slice(name.into(), values)
+        .into_date()
+        .into_series()
+}
+
+/// Monday to Friday, with Independence Day observed on the nearest weekday and Memorial Day
+/// on the last Monday of May.
+fn test_calendars() -> DurationCalendars {
+    let calendar = BusinessCalendar::new([true, true, true, true, true, false, false])
+        .unwrap()
+        .with_rule(HolidayRule::Fixed {
+            month: 7,
+            day: 4,
+            observance: Observance::Nearest,
+        })
+        .unwrap()
+        .with_rule(HolidayRule::NthWeekday {
+            month: 5,
+            weekday: 0,
+            n: -1,
+        })
+        .unwrap();
+    DurationCalendars {
+        business: Some(Arc::new(calendar)),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_business_day_offset_and_truncate() -> PolarsResult<()> {
+    // 2026-07-04 is a Saturday, observed on Friday 2026-07-03.
+    let df = df![
+        "date" => dates("date", &[date(2026, 7, 2), date(2026, 7, 4), date(2026, 5, 22)]),
+    ]?;
+    let out = df
+        .lazy()
+        .select([
+            col("date")
+                .dt()
+                .offset_by_in(lit("1bd"), test_calendars())
+                .alias("next"),
+            col("date").dt().offset_by(lit("-1bd")).alias("previous"),
+            col("date")
+                .dt()
+                .truncate_in(lit("1bd"), test_calendars())
+                .alias("truncated"),
+        ])
+        .collect()?;
+
+    let expected = df![
+        "next" => dates("next", &[date(2026, 7, 6), date(2026, 7, 6), date(2026, 5, 26)]),
+        "previous" => dates("previous", &[date(2026, 7, 1), date(2026, 7, 3), date(2026, 5, 21)]),
+        "truncated" => dates("truncated", &[date(2026, 7, 2), date(2026, 7, 2), date(2026, 5, 22)]),
+    ]?;
+    assert!(out.equals(&expected));
+
+    let err = df![
+        "date" => dates("date", &[date(2026, 7, 2)]),
+    ]?
+    .lazy()
+    .select([col("date").dt().offset_by(lit("1bd[it_us]"))])
+    .collect()
+    .unwrap_err();
+    assert!(err.to_string().contains("cannot name a calendar"));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "range")]
+fn test_business_day_date_range() -> PolarsResult<()> {
+    // Starts on a Sunday, which rolls forward to Monday.
+    let out = df![
+        "start" => dates("start", &[date(2026, 6, 28)]),
+        "end" => dates("end", &[date(2026, 7, 8)]),
+    ]?
+    .lazy()
+    .select([polars::lazy::dsl::date_range_in(
+        col("start").first(),
+        col("end").first(),
+        Duration::parse("2bd"),
+        ClosedWindow::Both,
+        test_calendars(),
+    )
+    .alias("date")])
+    .collect()?;
+
+    let expected = dates(
+        "date",
+        &[
+            date(2026, 6, 29),
+            date(2026, 7, 1),
+            date(2026, 7, 6),
+            date(2026, 7, 8),
+        ],
+    );
+    assert!(
+        out.column("date")?
+            .as_materialized_series()
+            .equals(&expected)
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dynamic_group_by")]
+fn test_business_day_group_by_dynamic() -> PolarsResult<()> {
+    let days = (date(2026, 7, 1)..=date(2026, 7, 7)).collect::<Vec<_>>();
+    let df = df![
+        "date" => dates("date", &days),
+        "v" => [1i32, 2, 3, 4, 5, 6, 7],
+    ]?;
+    let out = df
+        .lazy()
+        .group_by_dynamic(
+            col("date"),
+            [],
+            DynamicGroupOptions {
+                every: Duration::parse("1bd"),
+                period: Duration::parse("1bd"),
+                offset: Duration::parse("0d"),
+                calendars: test_calendars(),
+                ..Default::default()
+            },
+        )
+        .agg([col("v").sum()])
+        .collect()?;
+
+    // The observed holiday and the weekend fall in Thursday's window.
+    let expected = df![
+        "date" => dates("date", &[date(2026, 7, 1), date(2026, 7, 2), date(2026, 7, 6), date(2026, 7, 7)]),
+        "v" => [1i32, 2 + 3 + 4 + 5, 6, 7],
+    ]?;
+    assert!(out.equals(&expected));
+    Ok(())
+}
@@ -0,0 +1,122 @@
+use chrono::NaiveDate;
+use polars::prelude::*;
+
+fn date(y: i32, m: u32, d: u32) -> i32 {
+    (NaiveDate::from_ymd_opt(y, m, d).unwrap() - NaiveDate::default())
+        .num_days()
+        .try_into()
+        .unwrap()
+}
+
+fn dates(name: &str, values: &[i32]) -> Series {
+    Int32Chunked::from_slice(name.into(), values)
+        .into_date()
+        .into_series()
+}
+
+fn test_calendars() -> DurationCalendars {
+    DurationCalendars {
+        fiscal: Some(FiscalCalendar::default()),
+        ..Default::default()
+    }
+}
+
+/// Fiscal 2024 starts on 2024-02-04 and fiscal 2023, which has 53 weeks, on 2023-01-29.
+fn test_dates() -> Series {
+    dates(
+        "date",
+        &[
+            date(2024, 2, 3),
+            date(2024, 2, 4),
+            date(2024, 3, 31),
+            date(2024, 5, 5),
+        ],
+    )
+}
+
+#[test]
+fn test_fiscal_date_parts() -> PolarsResult<()> {
+    let calendar = FiscalCalendar::default();
+    let out = df!["date" => test_dates()]?
+        .lazy()
+        .select([
+            col("date").dt().fiscal_year(calendar).alias("year"),
+            col("date").dt().fiscal_quarter(calendar).alias("quarter"),
+            col("date").dt().fiscal_period(calendar).alias("period"),
+            col("date").dt().fiscal_week(calendar).alias("week"),
+        ])
+        .collect()?;
+
+    let expected = df![
+        "year" => [2023i32, 2024, 2024, 2024],
+        "quarter" => [4i8, 1, 1, 2],
+        "period" => [12i8, 1, 3, 4],
+        "week" => [53i8, 1, 9, 14],
+    ]?;
+    assert!(out.equals(&expected));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "offset_by")]
+fn test_fiscal_offset_and_truncate() -> PolarsResult<()> {
+    let out = df!["date" => test_dates()]?
+        .lazy()
+        .select([
+            col("date")
+                .dt()
+                .offset_by_in(lit("1fp"), test_calendars())
+                .alias("next"),
+            col("date")
+                .dt()
+                .truncate_in(lit("1fq"), test_calendars())
+                .alias("quarter_start"),
+        ])
+        .collect()?;
+
+    // The last day of a six-week period is clamped to the end of the next, four-week, period.
+    let expected = df![
+        "next" => dates("next", &[date(2024, 3, 2), date(2024, 3, 3), date(2024, 5, 5), date(2024, 6, 2)]),
+        "quarter_start" => dates("quarter_start", &[date(2023, 10, 29), date(2024, 2, 4), date(2024, 2, 4), date(2024, 5, 5)]),
+    ]?;
+    assert!(out.equals(&expected));
+
+    let err = df!["date" => test_dates()]?
+        .lazy()
+        .select([col("date").dt().offset_by(lit("1fq"))])
+        .collect()
+        .unwrap_err();
+    assert!(err.to_string().contains("require a fiscal calendar"));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dynamic_group_by")]
+fn test_fiscal_group_by_dynamic() -> PolarsResult<()> {
+    let df = df![
+        "date" => test_dates(),
+        "v" => [1i32, 2, 3, 4],
+    ]?;
+    let out = df
+        .lazy()
+        .group_by_dynamic(
+            col("date"),
+            [],
+            DynamicGroupOptions {
+                every: Duration::parse("1fq"),
+                period: Duration::parse("1fq"),
+                offset: Duration::parse("0d"),
+                calendars: test_calendars(),
+                ..Default::default()
+            },
+        )
+        .agg([col("v").sum()])
+        .collect()?;
+
+    let expected = df![
+        "date" => dates("date", &[date(2023, 10, 29), date(2024, 2, 4), date(2024, 5, 5)]),
+        "v" => [1i32, 2 + 3, 4],
+    ]?;
+    assert!(out.equals(&expected));
+    Ok(())
+}
@@ -0,0 +1,145 @@
+use polars::prelude::*;
+
+#[test]
+fn test_str_to_duration() -> PolarsResult<()> {
+    let df = df![
+        "s" => [Some("PT1H30M"), Some("1h30m"), Some("-P1DT0.5S"), Some("P2W"), Some("P1M"), Some("nope"), None],
+    ]?;
+    let out = df
+        .lazy()
+        .select([col("s").str().to_duration(TimeUnit::Milliseconds, false)])
+        .collect()?;
+
+    let expected = Int64Chunked::from_iter_options(
+        "s".into(),
+        [
+            Some(5_400_000),
+            Some(5_400_000),
+            Some(-86_400_500),
+            Some(1_209_600_000),
+            None,
+            None,
+            None,
+        ]
+        .into_iter(),
+    )
+    .into_duration(TimeUnit::Milliseconds);
+    assert!(
+        out.column("s")?
+            .as_materialized_series()
+            .equals_missing(&expected.into_series())
+    );
+    Ok(())
+}
+
+#[test]
+fn test_str_to_duration_strict() -> PolarsResult<()> {
+    let df = df!["s" => ["PT1H", "P1Y"]]?;
+    let out = df
+        .lazy()
+        .select([col("s").str().to_duration(TimeUnit::Microseconds, true)])
+        .collect();
+    assert!(out.is_err());
+    Ok(())
+}
+
+#[test]
+fn test_duration_to_iso_round_trip() -> PolarsResult<()> {
+    let df = df![
+        "s" => ["PT1H30M", "-P1DT2H0.25S", "PT0S", "P10D"],
+    ]?;
+    let out = df
+        .lazy()
+        .select([
+            col("s")
+                .str()
+                .to_duration(TimeUnit::Nanoseconds, true)
+                .dt()
+                .to_iso_duration()
+                .alias("iso"),
+            col("s")
+                .str()
+                .to_duration(TimeUnit::Nanoseconds, true)
+                .dt()
+                .to_iso_duration()
+                .str()
+                .to_duration(TimeUnit::Nanoseconds, true)
+                .alias("round_trip"),
+            col("s").str().to_duration(TimeUnit::Nanoseconds, true),
+        ])
+        .collect()?;
+
+    let iso = out.column("iso")?.str()?;
+    assert_eq!(
+        Vec::from(iso),
+        &[
+            Some("PT1H30M"),
+            Some("-P1DT2H0.25S"),
+            Some("PT0S"),
+            Some("P10D")
+        ]
+    );
+    assert_eq!(
+        Vec::from(out.column("round_trip")?.duration()?.physical()),
+        Vec::from(out.column("s")?.duration()?.physical())
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-interval")]
+fn test_interval_to_iso() -> PolarsResult<()> {
+    let intervals = IntervalChunked::from_intervals(
+        "i".into(),
+        [
+            Some(arrow::types::months_days_ns::new(14, 10, 9_000_000_000_000)),
+            Some(arrow::types::months_days_ns::new(-1, -2, -1_500_000_000)),
+            Some(arrow::types::months_days_ns::new(1, -2, 0)),
+            Some(arrow::types::months_days_ns::new(0, 0, 0)),
+            None,
+        ],
+    );
+    let out = df!["i" => intervals.into_series()]?
+        .lazy()
+        .select([col("i").dt().to_iso_duration()])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("i")?.str()?),
+        &[
+            Some("P1Y2M10DT2H30M"),
+            Some("-P1M2DT1.5S"),
+            Some("P1M-2D"),
+            Some("PT0S"),
+            None
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dynamic_group_by")]
+fn test_iso_window_parameters() -> PolarsResult<()> {
+    let ts = Int64Chunked::new(
+        "ts".into(),
+        [0i64, 1_800_000, 3_600_000, 5_400_000, 7_200_000],
+    )
+    .into_datetime(TimeUnit::Milliseconds, None)
+    .into_series();
+    let out = df!["ts" => ts, "v" => [1i32, 2, 3, 4, 5]]?
+        .lazy()
+        .group_by_dynamic(
+            col("ts"),
+            [],
+            DynamicGroupOptions {
+                every: Duration::parse("PT1H"),
+                period: Duration::parse("PT1H"),
+                offset: Duration::parse("PT0S"),
+                ..Default::default()
+            },
+        )
+        .agg([col("v").sum()])
+        .collect()?;
+    let v = out.column("v")?.i32()?;
+    assert_eq!(Vec::from(v), &[Some(3), Some(7), Some(5)]);
+    Ok(())
+}
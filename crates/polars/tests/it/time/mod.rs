@@ -1,2 +1,18 @@
+#[cfg(all(feature = "business", feature = "offset_by", feature = "temporal"))]
+mod business_days;
 mod date;
 mod date_range;
+#[cfg(all(feature = "fiscal", feature = "temporal"))]
+mod fiscal;
+#[cfg(all(feature = "dtype-duration", feature = "strings", feature = "temporal"))]
+mod iso_duration;
+#[cfg(all(feature = "rrule", feature = "temporal"))]
+mod rrule;
+#[cfg(all(
+    feature = "dtype-date",
+    feature = "dtype-datetime",
+    feature = "strings"
+))]
+mod strptime_many;
+#[cfg(all(feature = "timezones", feature = "temporal"))]
+mod time_zones;
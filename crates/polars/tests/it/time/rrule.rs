@@ -0,0 +1,145 @@
+use chrono::NaiveDate;
+use polars::prelude::*;
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+fn rrule_dates(start: NaiveDate, end: Option<NaiveDate>, rule: &str) -> PolarsResult<Series> {
+    let end = match end {
+        Some(end) => lit(end).cast(DataType::Date),
+        None => lit(Null {}).cast(DataType::Date),
+    };
+    let out = DataFrame::empty()
+        .lazy()
+        .select([rrule_range(lit(start).cast(DataType::Date), end, rule, None, None).alias("d")])
+        .collect()?;
+    let out = out.column("d")?.as_materialized_series().clone();
+    assert_eq!(out.dtype(), &DataType::Date);
+    Ok(out)
+}
+
+fn expected_dates(dates: &[NaiveDate]) -> Series {
+    DateChunked::from_naive_date("d".into(), dates.iter().copied()).into_series()
+}
+
+#[test]
+fn test_rrule_third_friday() -> PolarsResult<()> {
+    let out = rrule_dates(
+        date(2024, 1, 1),
+        Some(date(2024, 4, 30)),
+        "FREQ=MONTHLY;BYDAY=3FR",
+    )?;
+    let expected = expected_dates(&[
+        date(2024, 1, 19),
+        date(2024, 2, 16),
+        date(2024, 3, 15),
+        date(2024, 4, 19),
+    ]);
+    assert!(out.equals(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_rrule_last_business_day_of_month() -> PolarsResult<()> {
+    let out = rrule_dates(
+        date(2024, 1, 1),
+        None,
+        "RRULE:FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-1;COUNT=4",
+    )?;
+    let expected = expected_dates(&[
+        date(2024, 1, 31),
+        date(2024, 2, 29),
+        date(2024, 3, 29),
+        date(2024, 4, 30),
+    ]);
+    assert!(out.equals(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_rrule_first_monday_of_quarter() -> PolarsResult<()> {
+    let expected = expected_dates(&[
+        date(2024, 1, 1),
+        date(2024, 4, 1),
+        date(2024, 7, 1),
+        date(2024, 10, 7),
+    ]);
+    let out = rrule_dates(
+        date(2024, 1, 1),
+        Some(date(2024, 12, 31)),
+        "FREQ=MONTHLY;INTERVAL=3;BYDAY=1MO",
+    )?;
+    assert!(out.equals(&expected));
+    let out = rrule_dates(
+        date(2024, 1, 1),
+        None,
+        "FREQ=YEARLY;BYMONTH=1,4,7,10;BYDAY=1MO;UNTIL=20241231",
+    )?;
+    assert!(out.equals(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_rrule_defaults_to_start() -> PolarsResult<()> {
+    // Months without a 31st are skipped, as in RFC 5545.
+    let out = rrule_dates(date(2024, 1, 31), None, "FREQ=MONTHLY;COUNT=3")?;
+    let expected = expected_dates(&[date(2024, 1, 31), date(2024, 3, 31), date(2024, 5, 31)]);
+    assert!(out.equals(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_rrule_invalid() {
+    for rule in [
+        "BYDAY=MO",
+        "FREQ=DAILY;COUNT=2;UNTIL=20240101",
+        "FREQ=WEEKLY;BYDAY=1MO",
+        "FREQ=YEARLY;BYWEEKNO=1",
+        "FREQ=MONTHLY;BYMONTHDAY=0",
+    ] {
+        assert!(RRule::parse(rule).is_err(), "{rule}");
+    }
+    // Without COUNT, UNTIL or end the recurrence never stops.
+    assert!(rrule_dates(date(2024, 1, 1), None, "FREQ=DAILY").is_err());
+    // Dates can't hold hourly occurrences.
+    assert!(rrule_dates(date(2024, 1, 1), None, "FREQ=HOURLY;COUNT=2").is_err());
+}
+
+#[test]
+#[cfg(feature = "timezones")]
+fn test_rrule_keeps_wall_time_across_dst() -> PolarsResult<()> {
+    let start = date(2024, 3, 30).and_hms_opt(2, 30, 0).unwrap();
+    let out = DataFrame::empty()
+        .lazy()
+        .select([rrule_range(
+            lit(start),
+            lit(Null {}).cast(DataType::Datetime(TimeUnit::Milliseconds, None)),
+            "FREQ=DAILY;COUNT=3",
+            Some(TimeUnit::Milliseconds),
+            Some(TimeZone::opt_try_new(Some("Europe/Amsterdam"))?.unwrap()),
+        )
+        .alias("d")])
+        .collect()?;
+    let out = out.column("d")?.datetime()?;
+    assert_eq!(
+        out.dtype(),
+        &DataType::Datetime(
+            TimeUnit::Milliseconds,
+            TimeZone::opt_try_new(Some("Europe/Amsterdam"))?
+        )
+    );
+    let utc = |m: u32, d: u32, h: u32| {
+        date(2024, m, d)
+            .and_hms_opt(h, 30, 0)
+            .unwrap()
+            .and_utc()
+            .timestamp_millis()
+    };
+    // 02:30 doesn't exist on the 31st, so the offset from before the gap is used.
+    assert_eq!(
+        Vec::from(out.physical()),
+        &[Some(utc(3, 30, 1)), Some(utc(3, 31, 1)), Some(utc(4, 1, 0))]
+    );
+    Ok(())
+}
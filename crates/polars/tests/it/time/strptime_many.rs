@@ -0,0 +1,157 @@
+use chrono::NaiveDate;
+use polars::prelude::*;
+
+fn ms(y: i32, m: u32, d: u32, h: u32, min: u32) -> i64 {
+    NaiveDate::from_ymd_opt(y, m, d)
+        .unwrap()
+        .and_hms_opt(h, min, 0)
+        .unwrap()
+        .and_utc()
+        .timestamp_millis()
+}
+
+fn formats(formats: &[&str]) -> Vec<PlSmallStr> {
+    formats.iter().map(|f| (*f).into()).collect()
+}
+
+#[test]
+fn test_strptime_many_datetime() -> PolarsResult<()> {
+    let df = df![
+        "s" => [Some("2024-01-02"), Some("02/01/2024 13:00"), Some("1704153600"), Some("nope"), None],
+    ]?;
+    let out = df
+        .lazy()
+        .select([col("s").str().strptime_many(
+            DataType::Datetime(TimeUnit::Milliseconds, None),
+            StrptimeManyOptions {
+                formats: formats(&["%Y-%m-%d", "%d/%m/%Y %H:%M", "%s"]),
+                strict: false,
+                ..Default::default()
+            },
+            lit("raise"),
+        )])
+        .collect()?;
+
+    let out = out.column("s")?.datetime()?;
+    assert_eq!(
+        out.dtype(),
+        &DataType::Datetime(TimeUnit::Milliseconds, None)
+    );
+    assert_eq!(
+        Vec::from(out.physical()),
+        &[
+            Some(ms(2024, 1, 2, 0, 0)),
+            Some(ms(2024, 1, 2, 13, 0)),
+            Some(ms(2024, 1, 2, 0, 0)),
+            None,
+            None
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_strptime_many_tries_formats_in_order() -> PolarsResult<()> {
+    let df = df!["s" => ["03/04/2024", "04/13/2024"]]?;
+    let out = df
+        .lazy()
+        .select([col("s").str().strptime_many(
+            DataType::Date,
+            StrptimeManyOptions {
+                formats: formats(&["%d/%m/%Y", "%m/%d/%Y"]),
+                ..Default::default()
+            },
+            lit("raise"),
+        )])
+        .collect()?;
+
+    let expected = DateChunked::from_naive_date(
+        "s".into(),
+        [
+            NaiveDate::from_ymd_opt(2024, 4, 3).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 4, 13).unwrap(),
+        ],
+    );
+    assert!(
+        out.column("s")?
+            .as_materialized_series()
+            .equals(&expected.into_series())
+    );
+    Ok(())
+}
+
+#[test]
+fn test_strptime_many_infers_per_value() -> PolarsResult<()> {
+    let df = df!["s" => ["2024-01-02", "03.01.2024", "2024/01/04"]]?;
+    let out = df
+        .lazy()
+        .select([col("s").str().strptime_many(
+            DataType::Date,
+            StrptimeManyOptions::default(),
+            lit("raise"),
+        )])
+        .collect()?;
+
+    let expected = DateChunked::from_naive_date(
+        "s".into(),
+        (2..=4).map(|d| NaiveDate::from_ymd_opt(2024, 1, d).unwrap()),
+    );
+    assert!(
+        out.column("s")?
+            .as_materialized_series()
+            .equals(&expected.into_series())
+    );
+    Ok(())
+}
+
+#[test]
+fn test_strptime_many_strict_reports_rows() -> PolarsResult<()> {
+    let df = df!["s" => [Some("2024-01-02"), Some("nope"), None, Some("02/01/2024")]]?;
+    let err = df
+        .lazy()
+        .select([col("s").str().strptime_many(
+            DataType::Date,
+            StrptimeManyOptions {
+                formats: formats(&["%Y-%m-%d"]),
+                ..Default::default()
+            },
+            lit("raise"),
+        )])
+        .collect()
+        .unwrap_err();
+    let msg = err.to_string();
+    assert!(msg.contains("could not parse 2 out of 4 values"), "{msg}");
+    assert!(
+        msg.contains(r#"failed rows: 1 ("nope"), 3 ("02/01/2024")"#),
+        "{msg}"
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "timezones")]
+fn test_strptime_many_with_offsets() -> PolarsResult<()> {
+    let df = df!["s" => ["2024-01-02T12:00:00+01:00", "2024-01-02 12:00"]]?;
+    let out = df
+        .lazy()
+        .select([col("s").str().strptime_many(
+            DataType::Datetime(TimeUnit::Milliseconds, None),
+            StrptimeManyOptions {
+                formats: formats(&["%Y-%m-%dT%H:%M:%S%:z", "%Y-%m-%d %H:%M"]),
+                ..Default::default()
+            },
+            lit("raise"),
+        )])
+        .collect()?;
+
+    let out = out.column("s")?.datetime()?;
+    assert_eq!(
+        out.dtype(),
+        &DataType::Datetime(TimeUnit::Milliseconds, Some(TimeZone::UTC))
+    );
+    assert_eq!(
+        Vec::from(out.physical()),
+        &[Some(ms(2024, 1, 2, 11, 0)), Some(ms(2024, 1, 2, 12, 0))]
+    );
+    Ok(())
+}
@@ -0,0 +1,150 @@
+use chrono::NaiveDate;
+use polars::prelude::*;
+
+fn us(y: i32, m: u32, d: u32, h: u32, min: u32) -> i64 {
+    NaiveDate::from_ymd_opt(y, m, d)
+        .unwrap()
+        .and_hms_opt(h, min, 0)
+        .unwrap()
+        .and_utc()
+        .timestamp_micros()
+}
+
+fn datetimes(name: &str, values: &[Option<i64>], time_zone: Option<TimeZone>) -> Series {
+    Int64Chunked::from_iter_options(name.into(), values.iter().copied())
+        .into_datetime(TimeUnit::Microseconds, time_zone)
+        .into_series()
+}
+
+#[test]
+fn test_convert_time_zone_by() -> PolarsResult<()> {
+    let df = df![
+        "ts" => datetimes(
+            "ts",
+            &[
+                Some(us(2024, 7, 1, 12, 0)),
+                Some(us(2024, 7, 1, 12, 0)),
+                Some(us(2024, 7, 1, 23, 0)),
+                Some(us(2024, 7, 1, 12, 0)),
+            ],
+            Some(TimeZone::UTC),
+        ),
+        "tz" => [Some("America/New_York"), Some("Asia/Kolkata"), Some("Europe/Amsterdam"), None],
+    ]?;
+    let out = df
+        .lazy()
+        .select([
+            col("ts")
+                .dt()
+                .convert_time_zone_by(col("tz"))
+                .alias("local"),
+            col("ts")
+                .dt()
+                .convert_time_zone_by(col("tz"))
+                .dt()
+                .hour()
+                .alias("hour"),
+            col("ts")
+                .dt()
+                .convert_time_zone_by(col("tz"))
+                .dt()
+                .date()
+                .cast(DataType::Int32)
+                .alias("date"),
+        ])
+        .collect()?;
+
+    let expected = df![
+        "local" => datetimes(
+            "local",
+            &[
+                Some(us(2024, 7, 1, 8, 0)),
+                Some(us(2024, 7, 1, 17, 30)),
+                Some(us(2024, 7, 2, 1, 0)),
+                None,
+            ],
+            None,
+        ),
+        "hour" => [Some(8i8), Some(17), Some(1), None],
+        "date" => [Some(19905i32), Some(19905), Some(19906), None],
+    ]?;
+    assert!(out.equals_missing(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_replace_time_zone_by() -> PolarsResult<()> {
+    let df = df![
+        "ts" => datetimes(
+            "ts",
+            &[
+                Some(us(2024, 7, 1, 14, 0)),
+                Some(us(2024, 11, 3, 1, 30)),
+                Some(us(2024, 3, 31, 2, 30)),
+            ],
+            None,
+        ),
+        "tz" => ["Europe/Amsterdam", "America/New_York", "Europe/Amsterdam"],
+    ]?;
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("ts").dt().replace_time_zone_by(
+            col("tz"),
+            lit("earliest"),
+            NonExistent::Null,
+        )])
+        .collect()?;
+
+    // The second value is ambiguous and the third does not exist in its time zone.
+    let expected = datetimes(
+        "ts",
+        &[
+            Some(us(2024, 7, 1, 12, 0)),
+            Some(us(2024, 11, 3, 5, 30)),
+            None,
+        ],
+        Some(TimeZone::UTC),
+    );
+    assert!(
+        out.column("ts")?
+            .as_materialized_series()
+            .equals_missing(&expected)
+    );
+
+    // Converting back gives the original wall times.
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("ts")
+            .dt()
+            .replace_time_zone_by(col("tz"), lit("latest"), NonExistent::Null)
+            .dt()
+            .convert_time_zone_by(col("tz"))])
+        .collect()?;
+    assert!(
+        out.column("ts")?
+            .as_materialized_series()
+            .equals_missing(&datetimes(
+                "ts",
+                &[
+                    Some(us(2024, 7, 1, 14, 0)),
+                    Some(us(2024, 11, 3, 1, 30)),
+                    None
+                ],
+                None,
+            ))
+    );
+
+    let err = df
+        .lazy()
+        .select([col("ts").dt().replace_time_zone_by(
+            col("tz"),
+            lit("earliest"),
+            NonExistent::Raise,
+        )])
+        .collect()
+        .unwrap_err();
+    assert!(err.to_string().contains("non-existent"));
+    Ok(())
+}
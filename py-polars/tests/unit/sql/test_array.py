@@ -5,7 +5,7 @@ from typing import Any
 import pytest
 
 import polars as pl
-from polars.exceptions import SQLInterfaceError, SQLSyntaxError
+from polars.exceptions import SQLSyntaxError
 from polars.testing import assert_frame_equal
 
 
@@ -228,7 +228,36 @@ def test_unnest_table_function_errors() -> None:
             ctx.execute("SELECT * FROM UNNEST([1, 2, 3])")
 
         with pytest.raises(
-            SQLInterfaceError,
-            match=r"UNNEST tables do not \(yet\) support WITH OFFSET|ORDINALITY",
+            SQLSyntaxError,
+            match="UNNEST cannot use both WITH ORDINALITY and WITH OFFSET",
         ):
-            ctx.execute("SELECT * FROM UNNEST([1, 2, 3]) tbl (colx) WITH OFFSET")
+            ctx.execute(
+                "SELECT * FROM UNNEST([1, 2]) WITH ORDINALITY tbl (x) WITH OFFSET"
+            )
+
+
+def test_unnest_with_ordinality() -> None:
+    with pl.SQLContext(df=None, eager=True) as ctx:
+        res = ctx.execute(
+            "SELECT * FROM UNNEST(['a','b']) WITH ORDINALITY AS tbl (val, pos)"
+        )
+        assert_frame_equal(res, pl.DataFrame({"val": ["a", "b"], "pos": [1, 2]}))
+
+        res = ctx.execute("SELECT * FROM UNNEST([10, 20]) tbl (colx) WITH OFFSET")
+        assert_frame_equal(res, pl.DataFrame({"colx": [10, 20], "offset": [0, 1]}))
+
+
+def test_cross_join_unnest_lateral() -> None:
+    df = pl.DataFrame({"id": [1, 2, 3], "arr": [[1, 2], [], [3]]})
+    res = df.sql(
+        """
+        SELECT self.id, u.val, u.n
+        FROM self
+        CROSS JOIN UNNEST(self.arr) WITH ORDINALITY AS u (val, n)
+        ORDER BY self.id, u.n
+        """
+    )
+    assert_frame_equal(
+        res,
+        pl.DataFrame({"id": [1, 1, 3], "val": [1, 2, 3], "n": [1, 2, 1]}),
+    )